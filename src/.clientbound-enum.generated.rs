@@ -662,4 +662,804 @@ impl fmt::Display for ClientboundPacket {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ClientboundPacket of type {}", self.get_packet_name())
     }
+}
+impl PacketVariant<ClientboundPacket> for StatusResponse {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::StatusResponse(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for StatusPong {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::StatusPong(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for LoginDisconnect {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::LoginDisconnect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EncryptionRequest {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EncryptionRequest(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for LoginSuccess {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::LoginSuccess(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SetCompression {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SetCompression(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for LoginPluginRequest {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::LoginPluginRequest(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SpawnObject {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SpawnObject(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SpawnExperienceOrb {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SpawnExperienceOrb(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SpawnGlobalEntity {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SpawnGlobalEntity(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SpawnMob {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SpawnMob(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SpawnPainting {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SpawnPainting(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SpawnPlayer {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SpawnPlayer(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ClientboundAnimation {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ClientboundAnimation(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Statistics {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Statistics(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for AcknowledgePlayerDigging {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::AcknowledgePlayerDigging(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for BlockBreakAnimation {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::BlockBreakAnimation(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for UpdateBlockEntity {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::UpdateBlockEntity(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for BlockAction {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::BlockAction(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for BlockChange {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::BlockChange(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for BossBar {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::BossBar(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ServerDifficulty {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ServerDifficulty(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ChatMessage {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ChatMessage(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for MultiBlockChange {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::MultiBlockChange(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ClientboundTabComplete {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ClientboundTabComplete(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for DeclareCommands {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::DeclareCommands(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ClientboundConfirmTransaction {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ClientboundConfirmTransaction(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ClientboundCloseWindow {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ClientboundCloseWindow(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for WindowItems {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::WindowItems(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for WindowProperty {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::WindowProperty(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SetSlot {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SetSlot(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SetCooldown {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SetCooldown(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ClientboundPluginMessage {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ClientboundPluginMessage(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for NamedSoundEffect {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::NamedSoundEffect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for PlayDisconnect {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::PlayDisconnect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityStatus {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityStatus(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Explosion {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Explosion(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for UnloadChunk {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::UnloadChunk(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ChangeGameState {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ChangeGameState(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for OpenHorseWindow {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::OpenHorseWindow(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for KeepAlive {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::KeepAlive(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ChunkData {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ChunkData(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Effect {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Effect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Particle {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Particle(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for UpdateLight {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::UpdateLight(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for JoinGame {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::JoinGame(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Map {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Map(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for TradeList {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::TradeList(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityRelativeMove {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityRelativeMove(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityLookRelativeMove {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityLookRelativeMove(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityLook {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityLook(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Entity {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Entity(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ClientboundVehicleMove {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ClientboundVehicleMove(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for OpenBook {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::OpenBook(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for OpenWindow {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::OpenWindow(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for OpenSignEditor {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::OpenSignEditor(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for CraftRecipeResponse {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::CraftRecipeResponse(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for PlayerAbilities {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::PlayerAbilities(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for CombatEvent {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::CombatEvent(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for PlayerListItem {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::PlayerListItem(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for FacePlayer {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::FacePlayer(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for PlayerPositionAndLook {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::PlayerPositionAndLook(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for UnlockRecipes {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::UnlockRecipes(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for DestroyEntities {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::DestroyEntities(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for RemoveEntityEffect {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::RemoveEntityEffect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ResourcePackSend {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ResourcePackSend(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Respawn {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Respawn(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityHeadLook {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityHeadLook(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SelectAdvancementTab {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SelectAdvancementTab(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for WorldBorder {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::WorldBorder(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Camera {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Camera(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ClientboundHeldItemChange {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ClientboundHeldItemChange(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for UpdateViewPosition {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::UpdateViewPosition(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for UpdateViewDistance {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::UpdateViewDistance(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for DisplayScoreboard {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::DisplayScoreboard(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityMetadata {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityMetadata(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for AttachEntity {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::AttachEntity(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityVelocity {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityVelocity(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityEquipment {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityEquipment(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SetExperience {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SetExperience(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for UpdateHealth {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::UpdateHealth(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for ScoreboardObjective {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::ScoreboardObjective(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SetPassengers {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SetPassengers(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Teams {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Teams(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for UpdateScore {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::UpdateScore(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SpawnPosition {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SpawnPosition(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for TimeUpdate {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::TimeUpdate(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Title {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Title(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntitySoundEffect {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntitySoundEffect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for SoundEffect {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::SoundEffect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for StopSound {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::StopSound(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for PlayerListHeaderFooter {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::PlayerListHeaderFooter(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for NBTQueryResponse {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::NBTQueryResponse(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for CollectItem {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::CollectItem(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityTeleport {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityTeleport(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Advancements {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Advancements(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityProperties {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityProperties(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for EntityEffect {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::EntityEffect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for DeclareRecipes {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::DeclareRecipes(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ClientboundPacket> for Tags {
+    fn from_packet(packet: &ClientboundPacket) -> Option<&Self> {
+        match *packet {
+            ClientboundPacket::Tags(ref x) => Some(x),
+            _ => None,
+        }
+    }
 }
\ No newline at end of file
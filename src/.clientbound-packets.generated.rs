@@ -2349,13 +2349,13 @@ impl EntityRelativeMove {
     /// Get the entity ID
     pub fn get_entity_id(&self) -> &i32 {
         &self.entity_id
-    }    /// Get the relative distance moved in the X direction times 128
+    }    /// Get the relative distance moved in the X direction times 4096
     pub fn get_x(&self) -> &i16 {
         &self.x
-    }    /// Get the relative distance moved in the Y direction times 128
+    }    /// Get the relative distance moved in the Y direction times 4096
     pub fn get_y(&self) -> &i16 {
         &self.y
-    }    /// Get the relative distance moved in the Z direction times 128
+    }    /// Get the relative distance moved in the Z direction times 4096
     pub fn get_z(&self) -> &i16 {
         &self.z
     }    /// Get whether the entity is on the ground
@@ -2418,13 +2418,13 @@ impl EntityLookRelativeMove {
     /// Get the entity ID
     pub fn get_entity_id(&self) -> &i32 {
         &self.entity_id
-    }    /// Get the relative distance moved in the X direction times 128
+    }    /// Get the relative distance moved in the X direction times 4096
     pub fn get_x(&self) -> &i16 {
         &self.x
-    }    /// Get the relative distance moved in the Y direction times 128
+    }    /// Get the relative distance moved in the Y direction times 4096
     pub fn get_y(&self) -> &i16 {
         &self.y
-    }    /// Get the relative distance moved in the Z direction times 128
+    }    /// Get the relative distance moved in the Z direction times 4096
     pub fn get_z(&self) -> &i16 {
         &self.z
     }    /// Get the yaw
@@ -2703,7 +2703,7 @@ impl OpenSignEditor {
 #[derive(Debug, PartialEq, Clone)]
 pub struct CraftRecipeResponse {
     window_id: u8,
-    recipe: i32,
+    recipe: String,
 }
 
 impl CraftRecipeResponse {
@@ -2712,7 +2712,7 @@ impl CraftRecipeResponse {
     pub fn deserialize<R: Read>(r: &mut R) -> Result<ClientboundPacket> {
         Ok(ClientboundPacket::CraftRecipeResponse(CraftRecipeResponse {
             window_id: read_u8(r).chain_err(|| "while reading field window_id")?,
-            recipe: read_varint(r).chain_err(|| "while reading field recipe")?,
+            recipe: read_String(r).chain_err(|| "while reading field recipe")?,
 
         }))
     }
@@ -2721,11 +2721,11 @@ impl CraftRecipeResponse {
         let mut ret = Vec::new();
         write_varint(&Self::PACKET_ID, &mut ret)?;
         write_u8(&self.window_id, &mut ret)?;
-        write_varint(&self.recipe, &mut ret)?;
+        write_String(&self.recipe, &mut ret)?;
 
         Ok(ret)
     }
-    pub fn new(window_id: u8, recipe: i32) -> ClientboundPacket {
+    pub fn new(window_id: u8, recipe: String) -> ClientboundPacket {
         ClientboundPacket::CraftRecipeResponse(CraftRecipeResponse {
             window_id: window_id,
             recipe: recipe,
@@ -2734,8 +2734,8 @@ impl CraftRecipeResponse {
     /// Get the window ID
     pub fn get_window_id(&self) -> &u8 {
         &self.window_id
-    }    /// Get the recipe ID
-    pub fn get_recipe(&self) -> &i32 {
+    }    /// Get the recipe identifier
+    pub fn get_recipe(&self) -> &String {
         &self.recipe
     }
 }
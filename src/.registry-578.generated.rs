@@ -0,0 +1,2686 @@
+/* This file is automatically generated by registry.clj
+Do not manually edit this file, if you wish to make
+changes here, then edit and rerun registry.clj */
+
+/// The blocks in id order, as (name, properties, default values)
+const BLOCKS_578: &[BlockDefinition] = &[
+    ("minecraft:air", &[], &[]),
+    ("minecraft:stone", &[], &[]),
+    ("minecraft:granite", &[], &[]),
+    ("minecraft:polished_granite", &[], &[]),
+    ("minecraft:diorite", &[], &[]),
+    ("minecraft:polished_diorite", &[], &[]),
+    ("minecraft:andesite", &[], &[]),
+    ("minecraft:polished_andesite", &[], &[]),
+    ("minecraft:grass_block", &[("snowy", &["true", "false"])], &["false"]),
+    ("minecraft:dirt", &[], &[]),
+    ("minecraft:coarse_dirt", &[], &[]),
+    ("minecraft:podzol", &[("snowy", &["true", "false"])], &["false"]),
+    ("minecraft:cobblestone", &[], &[]),
+    ("minecraft:oak_planks", &[], &[]),
+    ("minecraft:spruce_planks", &[], &[]),
+    ("minecraft:birch_planks", &[], &[]),
+    ("minecraft:jungle_planks", &[], &[]),
+    ("minecraft:acacia_planks", &[], &[]),
+    ("minecraft:dark_oak_planks", &[], &[]),
+    ("minecraft:oak_sapling", &[("stage", &["0", "1"])], &["0"]),
+    ("minecraft:spruce_sapling", &[("stage", &["0", "1"])], &["0"]),
+    ("minecraft:birch_sapling", &[("stage", &["0", "1"])], &["0"]),
+    ("minecraft:jungle_sapling", &[("stage", &["0", "1"])], &["0"]),
+    ("minecraft:acacia_sapling", &[("stage", &["0", "1"])], &["0"]),
+    ("minecraft:dark_oak_sapling", &[("stage", &["0", "1"])], &["0"]),
+    ("minecraft:bedrock", &[], &[]),
+    ("minecraft:water", &[("level", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:lava", &[("level", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:sand", &[], &[]),
+    ("minecraft:red_sand", &[], &[]),
+    ("minecraft:gravel", &[], &[]),
+    ("minecraft:gold_ore", &[], &[]),
+    ("minecraft:iron_ore", &[], &[]),
+    ("minecraft:coal_ore", &[], &[]),
+    ("minecraft:oak_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:spruce_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:birch_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:jungle_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:acacia_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:dark_oak_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_spruce_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_birch_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_jungle_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_acacia_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_dark_oak_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_oak_log", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:oak_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:spruce_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:birch_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:jungle_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:acacia_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:dark_oak_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_oak_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_spruce_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_birch_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_jungle_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_acacia_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:stripped_dark_oak_wood", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:oak_leaves", &[("distance", &["1", "2", "3", "4", "5", "6", "7"]), ("persistent", &["true", "false"])], &["7", "false"]),
+    ("minecraft:spruce_leaves", &[("distance", &["1", "2", "3", "4", "5", "6", "7"]), ("persistent", &["true", "false"])], &["7", "false"]),
+    ("minecraft:birch_leaves", &[("distance", &["1", "2", "3", "4", "5", "6", "7"]), ("persistent", &["true", "false"])], &["7", "false"]),
+    ("minecraft:jungle_leaves", &[("distance", &["1", "2", "3", "4", "5", "6", "7"]), ("persistent", &["true", "false"])], &["7", "false"]),
+    ("minecraft:acacia_leaves", &[("distance", &["1", "2", "3", "4", "5", "6", "7"]), ("persistent", &["true", "false"])], &["7", "false"]),
+    ("minecraft:dark_oak_leaves", &[("distance", &["1", "2", "3", "4", "5", "6", "7"]), ("persistent", &["true", "false"])], &["7", "false"]),
+    ("minecraft:sponge", &[], &[]),
+    ("minecraft:wet_sponge", &[], &[]),
+    ("minecraft:glass", &[], &[]),
+    ("minecraft:lapis_ore", &[], &[]),
+    ("minecraft:lapis_block", &[], &[]),
+    ("minecraft:dispenser", &[("facing", &["north", "east", "south", "west", "up", "down"]), ("triggered", &["true", "false"])], &["north", "false"]),
+    ("minecraft:sandstone", &[], &[]),
+    ("minecraft:chiseled_sandstone", &[], &[]),
+    ("minecraft:cut_sandstone", &[], &[]),
+    ("minecraft:note_block", &[("instrument", &["harp", "basedrum", "snare", "hat", "bass", "flute", "bell", "guitar", "chime", "xylophone", "iron_xylophone", "cow_bell", "didgeridoo", "bit", "banjo", "pling"]), ("note", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "21", "22", "23", "24"]), ("powered", &["true", "false"])], &["harp", "0", "false"]),
+    ("minecraft:white_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:orange_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:magenta_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:light_blue_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:yellow_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:lime_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:pink_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:gray_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:light_gray_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:cyan_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:purple_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:blue_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:brown_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:green_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:red_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:black_bed", &[("facing", &["north", "south", "west", "east"]), ("occupied", &["true", "false"]), ("part", &["head", "foot"])], &["north", "false", "foot"]),
+    ("minecraft:powered_rail", &[("powered", &["true", "false"]), ("shape", &["north_south", "east_west", "ascending_east", "ascending_west", "ascending_north", "ascending_south"])], &["false", "north_south"]),
+    ("minecraft:detector_rail", &[("powered", &["true", "false"]), ("shape", &["north_south", "east_west", "ascending_east", "ascending_west", "ascending_north", "ascending_south"])], &["false", "north_south"]),
+    ("minecraft:sticky_piston", &[("extended", &["true", "false"]), ("facing", &["north", "east", "south", "west", "up", "down"])], &["false", "north"]),
+    ("minecraft:cobweb", &[], &[]),
+    ("minecraft:grass", &[], &[]),
+    ("minecraft:fern", &[], &[]),
+    ("minecraft:dead_bush", &[], &[]),
+    ("minecraft:seagrass", &[], &[]),
+    ("minecraft:tall_seagrass", &[("half", &["upper", "lower"])], &["lower"]),
+    ("minecraft:piston", &[("extended", &["true", "false"]), ("facing", &["north", "east", "south", "west", "up", "down"])], &["false", "north"]),
+    ("minecraft:piston_head", &[("facing", &["north", "east", "south", "west", "up", "down"]), ("short", &["true", "false"]), ("type", &["normal", "sticky"])], &["north", "false", "normal"]),
+    ("minecraft:white_wool", &[], &[]),
+    ("minecraft:orange_wool", &[], &[]),
+    ("minecraft:magenta_wool", &[], &[]),
+    ("minecraft:light_blue_wool", &[], &[]),
+    ("minecraft:yellow_wool", &[], &[]),
+    ("minecraft:lime_wool", &[], &[]),
+    ("minecraft:pink_wool", &[], &[]),
+    ("minecraft:gray_wool", &[], &[]),
+    ("minecraft:light_gray_wool", &[], &[]),
+    ("minecraft:cyan_wool", &[], &[]),
+    ("minecraft:purple_wool", &[], &[]),
+    ("minecraft:blue_wool", &[], &[]),
+    ("minecraft:brown_wool", &[], &[]),
+    ("minecraft:green_wool", &[], &[]),
+    ("minecraft:red_wool", &[], &[]),
+    ("minecraft:black_wool", &[], &[]),
+    ("minecraft:moving_piston", &[("facing", &["north", "east", "south", "west", "up", "down"]), ("type", &["normal", "sticky"])], &["north", "normal"]),
+    ("minecraft:dandelion", &[], &[]),
+    ("minecraft:poppy", &[], &[]),
+    ("minecraft:blue_orchid", &[], &[]),
+    ("minecraft:allium", &[], &[]),
+    ("minecraft:azure_bluet", &[], &[]),
+    ("minecraft:red_tulip", &[], &[]),
+    ("minecraft:orange_tulip", &[], &[]),
+    ("minecraft:white_tulip", &[], &[]),
+    ("minecraft:pink_tulip", &[], &[]),
+    ("minecraft:oxeye_daisy", &[], &[]),
+    ("minecraft:cornflower", &[], &[]),
+    ("minecraft:wither_rose", &[], &[]),
+    ("minecraft:lily_of_the_valley", &[], &[]),
+    ("minecraft:brown_mushroom", &[], &[]),
+    ("minecraft:red_mushroom", &[], &[]),
+    ("minecraft:gold_block", &[], &[]),
+    ("minecraft:iron_block", &[], &[]),
+    ("minecraft:bricks", &[], &[]),
+    ("minecraft:tnt", &[("unstable", &["true", "false"])], &["false"]),
+    ("minecraft:bookshelf", &[], &[]),
+    ("minecraft:mossy_cobblestone", &[], &[]),
+    ("minecraft:obsidian", &[], &[]),
+    ("minecraft:torch", &[], &[]),
+    ("minecraft:wall_torch", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:fire", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"]), ("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("west", &["true", "false"])], &["0", "false", "false", "false", "false", "false"]),
+    ("minecraft:spawner", &[], &[]),
+    ("minecraft:oak_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:chest", &[("facing", &["north", "south", "west", "east"]), ("type", &["single", "left", "right"]), ("waterlogged", &["true", "false"])], &["north", "single", "false"]),
+    ("minecraft:redstone_wire", &[("east", &["up", "side", "none"]), ("north", &["up", "side", "none"]), ("power", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"]), ("south", &["up", "side", "none"]), ("west", &["up", "side", "none"])], &["none", "none", "0", "none", "none"]),
+    ("minecraft:diamond_ore", &[], &[]),
+    ("minecraft:diamond_block", &[], &[]),
+    ("minecraft:crafting_table", &[], &[]),
+    ("minecraft:wheat", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])], &["0"]),
+    ("minecraft:farmland", &[("moisture", &["0", "1", "2", "3", "4", "5", "6", "7"])], &["0"]),
+    ("minecraft:furnace", &[("facing", &["north", "south", "west", "east"]), ("lit", &["true", "false"])], &["north", "false"]),
+    ("minecraft:oak_sign", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"]), ("waterlogged", &["true", "false"])], &["0", "false"]),
+    ("minecraft:spruce_sign", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"]), ("waterlogged", &["true", "false"])], &["0", "false"]),
+    ("minecraft:birch_sign", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"]), ("waterlogged", &["true", "false"])], &["0", "false"]),
+    ("minecraft:acacia_sign", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"]), ("waterlogged", &["true", "false"])], &["0", "false"]),
+    ("minecraft:jungle_sign", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"]), ("waterlogged", &["true", "false"])], &["0", "false"]),
+    ("minecraft:dark_oak_sign", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"]), ("waterlogged", &["true", "false"])], &["0", "false"]),
+    ("minecraft:oak_door", &[("facing", &["north", "south", "west", "east"]), ("half", &["upper", "lower"]), ("hinge", &["left", "right"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "lower", "left", "false", "false"]),
+    ("minecraft:ladder", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "false"]),
+    ("minecraft:rail", &[("shape", &["north_south", "east_west", "ascending_east", "ascending_west", "ascending_north", "ascending_south", "south_east", "south_west", "north_west", "north_east"])], &["north_south"]),
+    ("minecraft:cobblestone_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:oak_wall_sign", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "false"]),
+    ("minecraft:spruce_wall_sign", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "false"]),
+    ("minecraft:birch_wall_sign", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "false"]),
+    ("minecraft:acacia_wall_sign", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "false"]),
+    ("minecraft:jungle_wall_sign", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "false"]),
+    ("minecraft:dark_oak_wall_sign", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "false"]),
+    ("minecraft:lever", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["wall", "north", "false"]),
+    ("minecraft:stone_pressure_plate", &[("powered", &["true", "false"])], &["false"]),
+    ("minecraft:iron_door", &[("facing", &["north", "south", "west", "east"]), ("half", &["upper", "lower"]), ("hinge", &["left", "right"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "lower", "left", "false", "false"]),
+    ("minecraft:oak_pressure_plate", &[("powered", &["true", "false"])], &["false"]),
+    ("minecraft:spruce_pressure_plate", &[("powered", &["true", "false"])], &["false"]),
+    ("minecraft:birch_pressure_plate", &[("powered", &["true", "false"])], &["false"]),
+    ("minecraft:jungle_pressure_plate", &[("powered", &["true", "false"])], &["false"]),
+    ("minecraft:acacia_pressure_plate", &[("powered", &["true", "false"])], &["false"]),
+    ("minecraft:dark_oak_pressure_plate", &[("powered", &["true", "false"])], &["false"]),
+    ("minecraft:redstone_ore", &[("lit", &["true", "false"])], &["false"]),
+    ("minecraft:redstone_torch", &[("lit", &["true", "false"])], &["true"]),
+    ("minecraft:redstone_wall_torch", &[("facing", &["north", "south", "west", "east"]), ("lit", &["true", "false"])], &["north", "true"]),
+    ("minecraft:stone_button", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["wall", "north", "false"]),
+    ("minecraft:snow", &[("layers", &["1", "2", "3", "4", "5", "6", "7", "8"])], &["1"]),
+    ("minecraft:ice", &[], &[]),
+    ("minecraft:snow_block", &[], &[]),
+    ("minecraft:cactus", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:clay", &[], &[]),
+    ("minecraft:sugar_cane", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:jukebox", &[("has_record", &["true", "false"])], &["false"]),
+    ("minecraft:oak_fence", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:pumpkin", &[], &[]),
+    ("minecraft:netherrack", &[], &[]),
+    ("minecraft:soul_sand", &[], &[]),
+    ("minecraft:glowstone", &[], &[]),
+    ("minecraft:nether_portal", &[("axis", &["x", "z"])], &["x"]),
+    ("minecraft:carved_pumpkin", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:jack_o_lantern", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:cake", &[("bites", &["0", "1", "2", "3", "4", "5", "6"])], &["0"]),
+    ("minecraft:repeater", &[("delay", &["1", "2", "3", "4"]), ("facing", &["north", "south", "west", "east"]), ("locked", &["true", "false"]), ("powered", &["true", "false"])], &["1", "north", "false", "false"]),
+    ("minecraft:white_stained_glass", &[], &[]),
+    ("minecraft:orange_stained_glass", &[], &[]),
+    ("minecraft:magenta_stained_glass", &[], &[]),
+    ("minecraft:light_blue_stained_glass", &[], &[]),
+    ("minecraft:yellow_stained_glass", &[], &[]),
+    ("minecraft:lime_stained_glass", &[], &[]),
+    ("minecraft:pink_stained_glass", &[], &[]),
+    ("minecraft:gray_stained_glass", &[], &[]),
+    ("minecraft:light_gray_stained_glass", &[], &[]),
+    ("minecraft:cyan_stained_glass", &[], &[]),
+    ("minecraft:purple_stained_glass", &[], &[]),
+    ("minecraft:blue_stained_glass", &[], &[]),
+    ("minecraft:brown_stained_glass", &[], &[]),
+    ("minecraft:green_stained_glass", &[], &[]),
+    ("minecraft:red_stained_glass", &[], &[]),
+    ("minecraft:black_stained_glass", &[], &[]),
+    ("minecraft:oak_trapdoor", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("open", &["true", "false"]), ("powered", &["true", "false"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "false", "false", "false"]),
+    ("minecraft:spruce_trapdoor", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("open", &["true", "false"]), ("powered", &["true", "false"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "false", "false", "false"]),
+    ("minecraft:birch_trapdoor", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("open", &["true", "false"]), ("powered", &["true", "false"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "false", "false", "false"]),
+    ("minecraft:jungle_trapdoor", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("open", &["true", "false"]), ("powered", &["true", "false"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "false", "false", "false"]),
+    ("minecraft:acacia_trapdoor", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("open", &["true", "false"]), ("powered", &["true", "false"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "false", "false", "false"]),
+    ("minecraft:dark_oak_trapdoor", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("open", &["true", "false"]), ("powered", &["true", "false"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "false", "false", "false"]),
+    ("minecraft:stone_bricks", &[], &[]),
+    ("minecraft:mossy_stone_bricks", &[], &[]),
+    ("minecraft:cracked_stone_bricks", &[], &[]),
+    ("minecraft:chiseled_stone_bricks", &[], &[]),
+    ("minecraft:infested_stone", &[], &[]),
+    ("minecraft:infested_cobblestone", &[], &[]),
+    ("minecraft:infested_stone_bricks", &[], &[]),
+    ("minecraft:infested_mossy_stone_bricks", &[], &[]),
+    ("minecraft:infested_cracked_stone_bricks", &[], &[]),
+    ("minecraft:infested_chiseled_stone_bricks", &[], &[]),
+    ("minecraft:brown_mushroom_block", &[("down", &["true", "false"]), ("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("west", &["true", "false"])], &["true", "true", "true", "true", "true", "true"]),
+    ("minecraft:red_mushroom_block", &[("down", &["true", "false"]), ("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("west", &["true", "false"])], &["true", "true", "true", "true", "true", "true"]),
+    ("minecraft:mushroom_stem", &[("down", &["true", "false"]), ("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("west", &["true", "false"])], &["true", "true", "true", "true", "true", "true"]),
+    ("minecraft:iron_bars", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:melon", &[], &[]),
+    ("minecraft:attached_pumpkin_stem", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:attached_melon_stem", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:pumpkin_stem", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])], &["0"]),
+    ("minecraft:melon_stem", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])], &["0"]),
+    ("minecraft:vine", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:oak_fence_gate", &[("facing", &["north", "south", "west", "east"]), ("in_wall", &["true", "false"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "false", "false", "false"]),
+    ("minecraft:brick_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:stone_brick_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:mycelium", &[("snowy", &["true", "false"])], &["false"]),
+    ("minecraft:lily_pad", &[], &[]),
+    ("minecraft:nether_bricks", &[], &[]),
+    ("minecraft:nether_brick_fence", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:nether_brick_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:nether_wart", &[("age", &["0", "1", "2", "3"])], &["0"]),
+    ("minecraft:enchanting_table", &[], &[]),
+    ("minecraft:brewing_stand", &[("has_bottle_0", &["true", "false"]), ("has_bottle_1", &["true", "false"]), ("has_bottle_2", &["true", "false"])], &["false", "false", "false"]),
+    ("minecraft:cauldron", &[("level", &["0", "1", "2", "3"])], &["0"]),
+    ("minecraft:end_portal", &[], &[]),
+    ("minecraft:end_portal_frame", &[("eye", &["true", "false"]), ("facing", &["north", "south", "west", "east"])], &["false", "north"]),
+    ("minecraft:end_stone", &[], &[]),
+    ("minecraft:dragon_egg", &[], &[]),
+    ("minecraft:redstone_lamp", &[("lit", &["true", "false"])], &["false"]),
+    ("minecraft:cocoa", &[("age", &["0", "1", "2"]), ("facing", &["north", "south", "west", "east"])], &["0", "north"]),
+    ("minecraft:sandstone_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:emerald_ore", &[], &[]),
+    ("minecraft:ender_chest", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "false"]),
+    ("minecraft:tripwire_hook", &[("attached", &["true", "false"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["false", "north", "false"]),
+    ("minecraft:tripwire", &[("attached", &["true", "false"]), ("disarmed", &["true", "false"]), ("east", &["true", "false"]), ("north", &["true", "false"]), ("powered", &["true", "false"]), ("south", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false", "false", "false"]),
+    ("minecraft:emerald_block", &[], &[]),
+    ("minecraft:spruce_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:birch_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:jungle_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:command_block", &[("conditional", &["true", "false"]), ("facing", &["north", "east", "south", "west", "up", "down"])], &["false", "north"]),
+    ("minecraft:beacon", &[], &[]),
+    ("minecraft:cobblestone_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:mossy_cobblestone_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:flower_pot", &[], &[]),
+    ("minecraft:potted_oak_sapling", &[], &[]),
+    ("minecraft:potted_spruce_sapling", &[], &[]),
+    ("minecraft:potted_birch_sapling", &[], &[]),
+    ("minecraft:potted_jungle_sapling", &[], &[]),
+    ("minecraft:potted_acacia_sapling", &[], &[]),
+    ("minecraft:potted_dark_oak_sapling", &[], &[]),
+    ("minecraft:potted_fern", &[], &[]),
+    ("minecraft:potted_dandelion", &[], &[]),
+    ("minecraft:potted_poppy", &[], &[]),
+    ("minecraft:potted_blue_orchid", &[], &[]),
+    ("minecraft:potted_allium", &[], &[]),
+    ("minecraft:potted_azure_bluet", &[], &[]),
+    ("minecraft:potted_red_tulip", &[], &[]),
+    ("minecraft:potted_orange_tulip", &[], &[]),
+    ("minecraft:potted_white_tulip", &[], &[]),
+    ("minecraft:potted_pink_tulip", &[], &[]),
+    ("minecraft:potted_oxeye_daisy", &[], &[]),
+    ("minecraft:potted_cornflower", &[], &[]),
+    ("minecraft:potted_lily_of_the_valley", &[], &[]),
+    ("minecraft:potted_wither_rose", &[], &[]),
+    ("minecraft:potted_red_mushroom", &[], &[]),
+    ("minecraft:potted_brown_mushroom", &[], &[]),
+    ("minecraft:potted_dead_bush", &[], &[]),
+    ("minecraft:potted_cactus", &[], &[]),
+    ("minecraft:carrots", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])], &["0"]),
+    ("minecraft:potatoes", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])], &["0"]),
+    ("minecraft:oak_button", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["wall", "north", "false"]),
+    ("minecraft:spruce_button", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["wall", "north", "false"]),
+    ("minecraft:birch_button", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["wall", "north", "false"]),
+    ("minecraft:jungle_button", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["wall", "north", "false"]),
+    ("minecraft:acacia_button", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["wall", "north", "false"]),
+    ("minecraft:dark_oak_button", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["wall", "north", "false"]),
+    ("minecraft:skeleton_skull", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:skeleton_wall_skull", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:wither_skeleton_skull", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:wither_skeleton_wall_skull", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:zombie_head", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:zombie_wall_head", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:player_head", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:player_wall_head", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:creeper_head", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:creeper_wall_head", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:dragon_head", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:dragon_wall_head", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:anvil", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:chipped_anvil", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:damaged_anvil", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:trapped_chest", &[("facing", &["north", "south", "west", "east"]), ("type", &["single", "left", "right"]), ("waterlogged", &["true", "false"])], &["north", "single", "false"]),
+    ("minecraft:light_weighted_pressure_plate", &[("power", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:heavy_weighted_pressure_plate", &[("power", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:comparator", &[("facing", &["north", "south", "west", "east"]), ("mode", &["compare", "subtract"]), ("powered", &["true", "false"])], &["north", "compare", "false"]),
+    ("minecraft:daylight_detector", &[("inverted", &["true", "false"]), ("power", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["false", "0"]),
+    ("minecraft:redstone_block", &[], &[]),
+    ("minecraft:nether_quartz_ore", &[], &[]),
+    ("minecraft:hopper", &[("enabled", &["true", "false"]), ("facing", &["down", "north", "south", "west", "east"])], &["true", "down"]),
+    ("minecraft:quartz_block", &[], &[]),
+    ("minecraft:chiseled_quartz_block", &[], &[]),
+    ("minecraft:quartz_pillar", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:quartz_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:activator_rail", &[("powered", &["true", "false"]), ("shape", &["north_south", "east_west", "ascending_east", "ascending_west", "ascending_north", "ascending_south"])], &["false", "north_south"]),
+    ("minecraft:dropper", &[("facing", &["north", "east", "south", "west", "up", "down"]), ("triggered", &["true", "false"])], &["north", "false"]),
+    ("minecraft:white_terracotta", &[], &[]),
+    ("minecraft:orange_terracotta", &[], &[]),
+    ("minecraft:magenta_terracotta", &[], &[]),
+    ("minecraft:light_blue_terracotta", &[], &[]),
+    ("minecraft:yellow_terracotta", &[], &[]),
+    ("minecraft:lime_terracotta", &[], &[]),
+    ("minecraft:pink_terracotta", &[], &[]),
+    ("minecraft:gray_terracotta", &[], &[]),
+    ("minecraft:light_gray_terracotta", &[], &[]),
+    ("minecraft:cyan_terracotta", &[], &[]),
+    ("minecraft:purple_terracotta", &[], &[]),
+    ("minecraft:blue_terracotta", &[], &[]),
+    ("minecraft:brown_terracotta", &[], &[]),
+    ("minecraft:green_terracotta", &[], &[]),
+    ("minecraft:red_terracotta", &[], &[]),
+    ("minecraft:black_terracotta", &[], &[]),
+    ("minecraft:white_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:orange_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:magenta_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:light_blue_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:yellow_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:lime_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:pink_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:gray_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:light_gray_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:cyan_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:purple_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:blue_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:brown_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:green_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:red_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:black_stained_glass_pane", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:acacia_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:dark_oak_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:slime_block", &[], &[]),
+    ("minecraft:barrier", &[], &[]),
+    ("minecraft:iron_trapdoor", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("open", &["true", "false"]), ("powered", &["true", "false"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "false", "false", "false"]),
+    ("minecraft:prismarine", &[], &[]),
+    ("minecraft:prismarine_bricks", &[], &[]),
+    ("minecraft:dark_prismarine", &[], &[]),
+    ("minecraft:prismarine_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:prismarine_brick_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:dark_prismarine_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:prismarine_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:prismarine_brick_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:dark_prismarine_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:sea_lantern", &[], &[]),
+    ("minecraft:hay_block", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:white_carpet", &[], &[]),
+    ("minecraft:orange_carpet", &[], &[]),
+    ("minecraft:magenta_carpet", &[], &[]),
+    ("minecraft:light_blue_carpet", &[], &[]),
+    ("minecraft:yellow_carpet", &[], &[]),
+    ("minecraft:lime_carpet", &[], &[]),
+    ("minecraft:pink_carpet", &[], &[]),
+    ("minecraft:gray_carpet", &[], &[]),
+    ("minecraft:light_gray_carpet", &[], &[]),
+    ("minecraft:cyan_carpet", &[], &[]),
+    ("minecraft:purple_carpet", &[], &[]),
+    ("minecraft:blue_carpet", &[], &[]),
+    ("minecraft:brown_carpet", &[], &[]),
+    ("minecraft:green_carpet", &[], &[]),
+    ("minecraft:red_carpet", &[], &[]),
+    ("minecraft:black_carpet", &[], &[]),
+    ("minecraft:terracotta", &[], &[]),
+    ("minecraft:coal_block", &[], &[]),
+    ("minecraft:packed_ice", &[], &[]),
+    ("minecraft:sunflower", &[("half", &["upper", "lower"])], &["lower"]),
+    ("minecraft:lilac", &[("half", &["upper", "lower"])], &["lower"]),
+    ("minecraft:rose_bush", &[("half", &["upper", "lower"])], &["lower"]),
+    ("minecraft:peony", &[("half", &["upper", "lower"])], &["lower"]),
+    ("minecraft:tall_grass", &[("half", &["upper", "lower"])], &["lower"]),
+    ("minecraft:large_fern", &[("half", &["upper", "lower"])], &["lower"]),
+    ("minecraft:white_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:orange_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:magenta_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:light_blue_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:yellow_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:lime_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:pink_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:gray_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:light_gray_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:cyan_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:purple_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:blue_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:brown_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:green_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:red_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:black_banner", &[("rotation", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"])], &["0"]),
+    ("minecraft:white_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:orange_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:magenta_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:light_blue_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:yellow_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:lime_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:pink_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:gray_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:light_gray_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:cyan_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:purple_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:blue_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:brown_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:green_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:red_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:black_wall_banner", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:red_sandstone", &[], &[]),
+    ("minecraft:chiseled_red_sandstone", &[], &[]),
+    ("minecraft:cut_red_sandstone", &[], &[]),
+    ("minecraft:red_sandstone_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:oak_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:spruce_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:birch_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:jungle_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:acacia_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:dark_oak_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:stone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:smooth_stone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:sandstone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:cut_sandstone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:petrified_oak_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:cobblestone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:brick_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:stone_brick_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:nether_brick_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:quartz_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:red_sandstone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:cut_red_sandstone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:purpur_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:smooth_stone", &[], &[]),
+    ("minecraft:smooth_sandstone", &[], &[]),
+    ("minecraft:smooth_quartz", &[], &[]),
+    ("minecraft:smooth_red_sandstone", &[], &[]),
+    ("minecraft:spruce_fence_gate", &[("facing", &["north", "south", "west", "east"]), ("in_wall", &["true", "false"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "false", "false", "false"]),
+    ("minecraft:birch_fence_gate", &[("facing", &["north", "south", "west", "east"]), ("in_wall", &["true", "false"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "false", "false", "false"]),
+    ("minecraft:jungle_fence_gate", &[("facing", &["north", "south", "west", "east"]), ("in_wall", &["true", "false"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "false", "false", "false"]),
+    ("minecraft:acacia_fence_gate", &[("facing", &["north", "south", "west", "east"]), ("in_wall", &["true", "false"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "false", "false", "false"]),
+    ("minecraft:dark_oak_fence_gate", &[("facing", &["north", "south", "west", "east"]), ("in_wall", &["true", "false"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "false", "false", "false"]),
+    ("minecraft:spruce_fence", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:birch_fence", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:jungle_fence", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:acacia_fence", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:dark_oak_fence", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false"]),
+    ("minecraft:spruce_door", &[("facing", &["north", "south", "west", "east"]), ("half", &["upper", "lower"]), ("hinge", &["left", "right"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "lower", "left", "false", "false"]),
+    ("minecraft:birch_door", &[("facing", &["north", "south", "west", "east"]), ("half", &["upper", "lower"]), ("hinge", &["left", "right"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "lower", "left", "false", "false"]),
+    ("minecraft:jungle_door", &[("facing", &["north", "south", "west", "east"]), ("half", &["upper", "lower"]), ("hinge", &["left", "right"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "lower", "left", "false", "false"]),
+    ("minecraft:acacia_door", &[("facing", &["north", "south", "west", "east"]), ("half", &["upper", "lower"]), ("hinge", &["left", "right"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "lower", "left", "false", "false"]),
+    ("minecraft:dark_oak_door", &[("facing", &["north", "south", "west", "east"]), ("half", &["upper", "lower"]), ("hinge", &["left", "right"]), ("open", &["true", "false"]), ("powered", &["true", "false"])], &["north", "lower", "left", "false", "false"]),
+    ("minecraft:end_rod", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:chorus_plant", &[("down", &["true", "false"]), ("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "false", "false", "false"]),
+    ("minecraft:chorus_flower", &[("age", &["0", "1", "2", "3", "4", "5"])], &["0"]),
+    ("minecraft:purpur_block", &[], &[]),
+    ("minecraft:purpur_pillar", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:purpur_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:end_stone_bricks", &[], &[]),
+    ("minecraft:beetroots", &[("age", &["0", "1", "2", "3"])], &["0"]),
+    ("minecraft:grass_path", &[], &[]),
+    ("minecraft:end_gateway", &[], &[]),
+    ("minecraft:repeating_command_block", &[("conditional", &["true", "false"]), ("facing", &["north", "east", "south", "west", "up", "down"])], &["false", "north"]),
+    ("minecraft:chain_command_block", &[("conditional", &["true", "false"]), ("facing", &["north", "east", "south", "west", "up", "down"])], &["false", "north"]),
+    ("minecraft:frosted_ice", &[("age", &["0", "1", "2", "3"])], &["0"]),
+    ("minecraft:magma_block", &[], &[]),
+    ("minecraft:nether_wart_block", &[], &[]),
+    ("minecraft:red_nether_bricks", &[], &[]),
+    ("minecraft:bone_block", &[("axis", &["x", "y", "z"])], &["y"]),
+    ("minecraft:structure_void", &[], &[]),
+    ("minecraft:observer", &[("facing", &["north", "east", "south", "west", "up", "down"]), ("powered", &["true", "false"])], &["south", "false"]),
+    ("minecraft:shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:white_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:orange_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:magenta_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:light_blue_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:yellow_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:lime_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:pink_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:gray_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:light_gray_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:cyan_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:purple_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:blue_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:brown_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:green_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:red_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:black_shulker_box", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:white_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:orange_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:magenta_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:light_blue_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:yellow_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:lime_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:pink_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:gray_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:light_gray_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:cyan_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:purple_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:blue_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:brown_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:green_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:red_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:black_glazed_terracotta", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:white_concrete", &[], &[]),
+    ("minecraft:orange_concrete", &[], &[]),
+    ("minecraft:magenta_concrete", &[], &[]),
+    ("minecraft:light_blue_concrete", &[], &[]),
+    ("minecraft:yellow_concrete", &[], &[]),
+    ("minecraft:lime_concrete", &[], &[]),
+    ("minecraft:pink_concrete", &[], &[]),
+    ("minecraft:gray_concrete", &[], &[]),
+    ("minecraft:light_gray_concrete", &[], &[]),
+    ("minecraft:cyan_concrete", &[], &[]),
+    ("minecraft:purple_concrete", &[], &[]),
+    ("minecraft:blue_concrete", &[], &[]),
+    ("minecraft:brown_concrete", &[], &[]),
+    ("minecraft:green_concrete", &[], &[]),
+    ("minecraft:red_concrete", &[], &[]),
+    ("minecraft:black_concrete", &[], &[]),
+    ("minecraft:white_concrete_powder", &[], &[]),
+    ("minecraft:orange_concrete_powder", &[], &[]),
+    ("minecraft:magenta_concrete_powder", &[], &[]),
+    ("minecraft:light_blue_concrete_powder", &[], &[]),
+    ("minecraft:yellow_concrete_powder", &[], &[]),
+    ("minecraft:lime_concrete_powder", &[], &[]),
+    ("minecraft:pink_concrete_powder", &[], &[]),
+    ("minecraft:gray_concrete_powder", &[], &[]),
+    ("minecraft:light_gray_concrete_powder", &[], &[]),
+    ("minecraft:cyan_concrete_powder", &[], &[]),
+    ("minecraft:purple_concrete_powder", &[], &[]),
+    ("minecraft:blue_concrete_powder", &[], &[]),
+    ("minecraft:brown_concrete_powder", &[], &[]),
+    ("minecraft:green_concrete_powder", &[], &[]),
+    ("minecraft:red_concrete_powder", &[], &[]),
+    ("minecraft:black_concrete_powder", &[], &[]),
+    ("minecraft:kelp", &[("age", &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "21", "22", "23", "24", "25"])], &["0"]),
+    ("minecraft:kelp_plant", &[], &[]),
+    ("minecraft:dried_kelp_block", &[], &[]),
+    ("minecraft:turtle_egg", &[("eggs", &["1", "2", "3", "4"]), ("hatch", &["0", "1", "2"])], &["1", "0"]),
+    ("minecraft:dead_tube_coral_block", &[], &[]),
+    ("minecraft:dead_brain_coral_block", &[], &[]),
+    ("minecraft:dead_bubble_coral_block", &[], &[]),
+    ("minecraft:dead_fire_coral_block", &[], &[]),
+    ("minecraft:dead_horn_coral_block", &[], &[]),
+    ("minecraft:tube_coral_block", &[], &[]),
+    ("minecraft:brain_coral_block", &[], &[]),
+    ("minecraft:bubble_coral_block", &[], &[]),
+    ("minecraft:fire_coral_block", &[], &[]),
+    ("minecraft:horn_coral_block", &[], &[]),
+    ("minecraft:dead_tube_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_brain_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_bubble_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_fire_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_horn_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:tube_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:brain_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:bubble_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:fire_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:horn_coral", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_tube_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_brain_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_bubble_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_fire_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_horn_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:tube_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:brain_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:bubble_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:fire_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:horn_coral_fan", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:dead_tube_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:dead_brain_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:dead_bubble_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:dead_fire_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:dead_horn_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:tube_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:brain_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:bubble_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:fire_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:horn_coral_wall_fan", &[("facing", &["north", "south", "west", "east"]), ("waterlogged", &["true", "false"])], &["north", "true"]),
+    ("minecraft:sea_pickle", &[("pickles", &["1", "2", "3", "4"]), ("waterlogged", &["true", "false"])], &["1", "true"]),
+    ("minecraft:blue_ice", &[], &[]),
+    ("minecraft:conduit", &[("waterlogged", &["true", "false"])], &["true"]),
+    ("minecraft:bamboo_sapling", &[], &[]),
+    ("minecraft:bamboo", &[("age", &["0", "1"]), ("leaves", &["none", "small", "large"]), ("stage", &["0", "1"])], &["0", "none", "0"]),
+    ("minecraft:potted_bamboo", &[], &[]),
+    ("minecraft:void_air", &[], &[]),
+    ("minecraft:cave_air", &[], &[]),
+    ("minecraft:bubble_column", &[("drag", &["true", "false"])], &["true"]),
+    ("minecraft:polished_granite_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:smooth_red_sandstone_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:mossy_stone_brick_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:polished_diorite_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:mossy_cobblestone_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:end_stone_brick_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:stone_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:smooth_sandstone_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:smooth_quartz_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:granite_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:andesite_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:red_nether_brick_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:polished_andesite_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:diorite_stairs", &[("facing", &["north", "south", "west", "east"]), ("half", &["top", "bottom"]), ("shape", &["straight", "inner_left", "inner_right", "outer_left", "outer_right"]), ("waterlogged", &["true", "false"])], &["north", "bottom", "straight", "false"]),
+    ("minecraft:polished_granite_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:smooth_red_sandstone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:mossy_stone_brick_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:polished_diorite_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:mossy_cobblestone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:end_stone_brick_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:smooth_sandstone_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:smooth_quartz_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:granite_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:andesite_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:red_nether_brick_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:polished_andesite_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:diorite_slab", &[("type", &["top", "bottom", "double"]), ("waterlogged", &["true", "false"])], &["bottom", "false"]),
+    ("minecraft:brick_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:prismarine_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:red_sandstone_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:mossy_stone_brick_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:granite_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:stone_brick_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:nether_brick_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:andesite_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:red_nether_brick_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:sandstone_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:end_stone_brick_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:diorite_wall", &[("east", &["true", "false"]), ("north", &["true", "false"]), ("south", &["true", "false"]), ("up", &["true", "false"]), ("waterlogged", &["true", "false"]), ("west", &["true", "false"])], &["false", "false", "false", "true", "false", "false"]),
+    ("minecraft:scaffolding", &[("bottom", &["true", "false"]), ("distance", &["0", "1", "2", "3", "4", "5", "6", "7"]), ("waterlogged", &["true", "false"])], &["false", "7", "false"]),
+    ("minecraft:loom", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:barrel", &[("facing", &["north", "east", "south", "west", "up", "down"]), ("open", &["true", "false"])], &["north", "false"]),
+    ("minecraft:smoker", &[("facing", &["north", "south", "west", "east"]), ("lit", &["true", "false"])], &["north", "false"]),
+    ("minecraft:blast_furnace", &[("facing", &["north", "south", "west", "east"]), ("lit", &["true", "false"])], &["north", "false"]),
+    ("minecraft:cartography_table", &[], &[]),
+    ("minecraft:fletching_table", &[], &[]),
+    ("minecraft:grindstone", &[("face", &["floor", "wall", "ceiling"]), ("facing", &["north", "south", "west", "east"])], &["wall", "north"]),
+    ("minecraft:lectern", &[("facing", &["north", "south", "west", "east"]), ("has_book", &["true", "false"]), ("powered", &["true", "false"])], &["north", "false", "false"]),
+    ("minecraft:smithing_table", &[], &[]),
+    ("minecraft:stonecutter", &[("facing", &["north", "south", "west", "east"])], &["north"]),
+    ("minecraft:bell", &[("attachment", &["floor", "ceiling", "single_wall", "double_wall"]), ("facing", &["north", "south", "west", "east"]), ("powered", &["true", "false"])], &["floor", "north", "false"]),
+    ("minecraft:lantern", &[("hanging", &["true", "false"])], &["false"]),
+    ("minecraft:campfire", &[("facing", &["north", "south", "west", "east"]), ("lit", &["true", "false"]), ("signal_fire", &["true", "false"]), ("waterlogged", &["true", "false"])], &["north", "true", "false", "false"]),
+    ("minecraft:sweet_berry_bush", &[("age", &["0", "1", "2", "3"])], &["0"]),
+    ("minecraft:structure_block", &[("mode", &["save", "load", "corner", "data"])], &["save"]),
+    ("minecraft:jigsaw", &[("facing", &["north", "east", "south", "west", "up", "down"])], &["up"]),
+    ("minecraft:composter", &[("level", &["0", "1", "2", "3", "4", "5", "6", "7", "8"])], &["0"]),
+    ("minecraft:bee_nest", &[("facing", &["north", "south", "west", "east"]), ("honey_level", &["0", "1", "2", "3", "4", "5"])], &["north", "0"]),
+    ("minecraft:beehive", &[("facing", &["north", "south", "west", "east"]), ("honey_level", &["0", "1", "2", "3", "4", "5"])], &["north", "0"]),
+    ("minecraft:honey_block", &[], &[]),
+    ("minecraft:honeycomb_block", &[], &[]),
+];
+
+/// The items in id order
+const ITEMS_578: &[&str] = &[
+    "minecraft:air",
+    "minecraft:stone",
+    "minecraft:granite",
+    "minecraft:polished_granite",
+    "minecraft:diorite",
+    "minecraft:polished_diorite",
+    "minecraft:andesite",
+    "minecraft:polished_andesite",
+    "minecraft:grass_block",
+    "minecraft:dirt",
+    "minecraft:coarse_dirt",
+    "minecraft:podzol",
+    "minecraft:cobblestone",
+    "minecraft:oak_planks",
+    "minecraft:spruce_planks",
+    "minecraft:birch_planks",
+    "minecraft:jungle_planks",
+    "minecraft:acacia_planks",
+    "minecraft:dark_oak_planks",
+    "minecraft:oak_sapling",
+    "minecraft:spruce_sapling",
+    "minecraft:birch_sapling",
+    "minecraft:jungle_sapling",
+    "minecraft:acacia_sapling",
+    "minecraft:dark_oak_sapling",
+    "minecraft:bedrock",
+    "minecraft:sand",
+    "minecraft:red_sand",
+    "minecraft:gravel",
+    "minecraft:gold_ore",
+    "minecraft:iron_ore",
+    "minecraft:coal_ore",
+    "minecraft:oak_log",
+    "minecraft:spruce_log",
+    "minecraft:birch_log",
+    "minecraft:jungle_log",
+    "minecraft:acacia_log",
+    "minecraft:dark_oak_log",
+    "minecraft:stripped_oak_log",
+    "minecraft:stripped_spruce_log",
+    "minecraft:stripped_birch_log",
+    "minecraft:stripped_jungle_log",
+    "minecraft:stripped_acacia_log",
+    "minecraft:stripped_dark_oak_log",
+    "minecraft:stripped_oak_wood",
+    "minecraft:stripped_spruce_wood",
+    "minecraft:stripped_birch_wood",
+    "minecraft:stripped_jungle_wood",
+    "minecraft:stripped_acacia_wood",
+    "minecraft:stripped_dark_oak_wood",
+    "minecraft:oak_wood",
+    "minecraft:spruce_wood",
+    "minecraft:birch_wood",
+    "minecraft:jungle_wood",
+    "minecraft:acacia_wood",
+    "minecraft:dark_oak_wood",
+    "minecraft:oak_leaves",
+    "minecraft:spruce_leaves",
+    "minecraft:birch_leaves",
+    "minecraft:jungle_leaves",
+    "minecraft:acacia_leaves",
+    "minecraft:dark_oak_leaves",
+    "minecraft:sponge",
+    "minecraft:wet_sponge",
+    "minecraft:glass",
+    "minecraft:lapis_ore",
+    "minecraft:lapis_block",
+    "minecraft:dispenser",
+    "minecraft:sandstone",
+    "minecraft:chiseled_sandstone",
+    "minecraft:cut_sandstone",
+    "minecraft:note_block",
+    "minecraft:powered_rail",
+    "minecraft:detector_rail",
+    "minecraft:sticky_piston",
+    "minecraft:cobweb",
+    "minecraft:grass",
+    "minecraft:fern",
+    "minecraft:dead_bush",
+    "minecraft:seagrass",
+    "minecraft:sea_pickle",
+    "minecraft:piston",
+    "minecraft:white_wool",
+    "minecraft:orange_wool",
+    "minecraft:magenta_wool",
+    "minecraft:light_blue_wool",
+    "minecraft:yellow_wool",
+    "minecraft:lime_wool",
+    "minecraft:pink_wool",
+    "minecraft:gray_wool",
+    "minecraft:light_gray_wool",
+    "minecraft:cyan_wool",
+    "minecraft:purple_wool",
+    "minecraft:blue_wool",
+    "minecraft:brown_wool",
+    "minecraft:green_wool",
+    "minecraft:red_wool",
+    "minecraft:black_wool",
+    "minecraft:dandelion",
+    "minecraft:poppy",
+    "minecraft:blue_orchid",
+    "minecraft:allium",
+    "minecraft:azure_bluet",
+    "minecraft:red_tulip",
+    "minecraft:orange_tulip",
+    "minecraft:white_tulip",
+    "minecraft:pink_tulip",
+    "minecraft:oxeye_daisy",
+    "minecraft:cornflower",
+    "minecraft:lily_of_the_valley",
+    "minecraft:wither_rose",
+    "minecraft:brown_mushroom",
+    "minecraft:red_mushroom",
+    "minecraft:gold_block",
+    "minecraft:iron_block",
+    "minecraft:oak_slab",
+    "minecraft:spruce_slab",
+    "minecraft:birch_slab",
+    "minecraft:jungle_slab",
+    "minecraft:acacia_slab",
+    "minecraft:dark_oak_slab",
+    "minecraft:stone_slab",
+    "minecraft:smooth_stone_slab",
+    "minecraft:sandstone_slab",
+    "minecraft:cut_sandstone_slab",
+    "minecraft:petrified_oak_slab",
+    "minecraft:cobblestone_slab",
+    "minecraft:brick_slab",
+    "minecraft:stone_brick_slab",
+    "minecraft:nether_brick_slab",
+    "minecraft:quartz_slab",
+    "minecraft:red_sandstone_slab",
+    "minecraft:cut_red_sandstone_slab",
+    "minecraft:purpur_slab",
+    "minecraft:prismarine_slab",
+    "minecraft:prismarine_brick_slab",
+    "minecraft:dark_prismarine_slab",
+    "minecraft:smooth_quartz",
+    "minecraft:smooth_red_sandstone",
+    "minecraft:smooth_sandstone",
+    "minecraft:smooth_stone",
+    "minecraft:bricks",
+    "minecraft:tnt",
+    "minecraft:bookshelf",
+    "minecraft:mossy_cobblestone",
+    "minecraft:obsidian",
+    "minecraft:torch",
+    "minecraft:end_rod",
+    "minecraft:chorus_plant",
+    "minecraft:chorus_flower",
+    "minecraft:purpur_block",
+    "minecraft:purpur_pillar",
+    "minecraft:purpur_stairs",
+    "minecraft:spawner",
+    "minecraft:oak_stairs",
+    "minecraft:chest",
+    "minecraft:diamond_ore",
+    "minecraft:diamond_block",
+    "minecraft:crafting_table",
+    "minecraft:farmland",
+    "minecraft:furnace",
+    "minecraft:ladder",
+    "minecraft:rail",
+    "minecraft:cobblestone_stairs",
+    "minecraft:lever",
+    "minecraft:stone_pressure_plate",
+    "minecraft:oak_pressure_plate",
+    "minecraft:spruce_pressure_plate",
+    "minecraft:birch_pressure_plate",
+    "minecraft:jungle_pressure_plate",
+    "minecraft:acacia_pressure_plate",
+    "minecraft:dark_oak_pressure_plate",
+    "minecraft:redstone_ore",
+    "minecraft:redstone_torch",
+    "minecraft:stone_button",
+    "minecraft:snow",
+    "minecraft:ice",
+    "minecraft:snow_block",
+    "minecraft:cactus",
+    "minecraft:clay",
+    "minecraft:jukebox",
+    "minecraft:oak_fence",
+    "minecraft:spruce_fence",
+    "minecraft:birch_fence",
+    "minecraft:jungle_fence",
+    "minecraft:acacia_fence",
+    "minecraft:dark_oak_fence",
+    "minecraft:pumpkin",
+    "minecraft:carved_pumpkin",
+    "minecraft:netherrack",
+    "minecraft:soul_sand",
+    "minecraft:glowstone",
+    "minecraft:jack_o_lantern",
+    "minecraft:oak_trapdoor",
+    "minecraft:spruce_trapdoor",
+    "minecraft:birch_trapdoor",
+    "minecraft:jungle_trapdoor",
+    "minecraft:acacia_trapdoor",
+    "minecraft:dark_oak_trapdoor",
+    "minecraft:infested_stone",
+    "minecraft:infested_cobblestone",
+    "minecraft:infested_stone_bricks",
+    "minecraft:infested_mossy_stone_bricks",
+    "minecraft:infested_cracked_stone_bricks",
+    "minecraft:infested_chiseled_stone_bricks",
+    "minecraft:stone_bricks",
+    "minecraft:mossy_stone_bricks",
+    "minecraft:cracked_stone_bricks",
+    "minecraft:chiseled_stone_bricks",
+    "minecraft:brown_mushroom_block",
+    "minecraft:red_mushroom_block",
+    "minecraft:mushroom_stem",
+    "minecraft:iron_bars",
+    "minecraft:glass_pane",
+    "minecraft:melon",
+    "minecraft:vine",
+    "minecraft:oak_fence_gate",
+    "minecraft:spruce_fence_gate",
+    "minecraft:birch_fence_gate",
+    "minecraft:jungle_fence_gate",
+    "minecraft:acacia_fence_gate",
+    "minecraft:dark_oak_fence_gate",
+    "minecraft:brick_stairs",
+    "minecraft:stone_brick_stairs",
+    "minecraft:mycelium",
+    "minecraft:lily_pad",
+    "minecraft:nether_bricks",
+    "minecraft:nether_brick_fence",
+    "minecraft:nether_brick_stairs",
+    "minecraft:enchanting_table",
+    "minecraft:end_portal_frame",
+    "minecraft:end_stone",
+    "minecraft:end_stone_bricks",
+    "minecraft:dragon_egg",
+    "minecraft:redstone_lamp",
+    "minecraft:sandstone_stairs",
+    "minecraft:emerald_ore",
+    "minecraft:ender_chest",
+    "minecraft:tripwire_hook",
+    "minecraft:emerald_block",
+    "minecraft:spruce_stairs",
+    "minecraft:birch_stairs",
+    "minecraft:jungle_stairs",
+    "minecraft:command_block",
+    "minecraft:beacon",
+    "minecraft:cobblestone_wall",
+    "minecraft:mossy_cobblestone_wall",
+    "minecraft:brick_wall",
+    "minecraft:prismarine_wall",
+    "minecraft:red_sandstone_wall",
+    "minecraft:mossy_stone_brick_wall",
+    "minecraft:granite_wall",
+    "minecraft:stone_brick_wall",
+    "minecraft:nether_brick_wall",
+    "minecraft:andesite_wall",
+    "minecraft:red_nether_brick_wall",
+    "minecraft:sandstone_wall",
+    "minecraft:end_stone_brick_wall",
+    "minecraft:diorite_wall",
+    "minecraft:oak_button",
+    "minecraft:spruce_button",
+    "minecraft:birch_button",
+    "minecraft:jungle_button",
+    "minecraft:acacia_button",
+    "minecraft:dark_oak_button",
+    "minecraft:anvil",
+    "minecraft:chipped_anvil",
+    "minecraft:damaged_anvil",
+    "minecraft:trapped_chest",
+    "minecraft:light_weighted_pressure_plate",
+    "minecraft:heavy_weighted_pressure_plate",
+    "minecraft:daylight_detector",
+    "minecraft:redstone_block",
+    "minecraft:nether_quartz_ore",
+    "minecraft:hopper",
+    "minecraft:chiseled_quartz_block",
+    "minecraft:quartz_block",
+    "minecraft:quartz_pillar",
+    "minecraft:quartz_stairs",
+    "minecraft:activator_rail",
+    "minecraft:dropper",
+    "minecraft:white_terracotta",
+    "minecraft:orange_terracotta",
+    "minecraft:magenta_terracotta",
+    "minecraft:light_blue_terracotta",
+    "minecraft:yellow_terracotta",
+    "minecraft:lime_terracotta",
+    "minecraft:pink_terracotta",
+    "minecraft:gray_terracotta",
+    "minecraft:light_gray_terracotta",
+    "minecraft:cyan_terracotta",
+    "minecraft:purple_terracotta",
+    "minecraft:blue_terracotta",
+    "minecraft:brown_terracotta",
+    "minecraft:green_terracotta",
+    "minecraft:red_terracotta",
+    "minecraft:black_terracotta",
+    "minecraft:barrier",
+    "minecraft:iron_trapdoor",
+    "minecraft:hay_block",
+    "minecraft:white_carpet",
+    "minecraft:orange_carpet",
+    "minecraft:magenta_carpet",
+    "minecraft:light_blue_carpet",
+    "minecraft:yellow_carpet",
+    "minecraft:lime_carpet",
+    "minecraft:pink_carpet",
+    "minecraft:gray_carpet",
+    "minecraft:light_gray_carpet",
+    "minecraft:cyan_carpet",
+    "minecraft:purple_carpet",
+    "minecraft:blue_carpet",
+    "minecraft:brown_carpet",
+    "minecraft:green_carpet",
+    "minecraft:red_carpet",
+    "minecraft:black_carpet",
+    "minecraft:terracotta",
+    "minecraft:coal_block",
+    "minecraft:packed_ice",
+    "minecraft:acacia_stairs",
+    "minecraft:dark_oak_stairs",
+    "minecraft:slime_block",
+    "minecraft:grass_path",
+    "minecraft:sunflower",
+    "minecraft:lilac",
+    "minecraft:rose_bush",
+    "minecraft:peony",
+    "minecraft:tall_grass",
+    "minecraft:large_fern",
+    "minecraft:white_stained_glass",
+    "minecraft:orange_stained_glass",
+    "minecraft:magenta_stained_glass",
+    "minecraft:light_blue_stained_glass",
+    "minecraft:yellow_stained_glass",
+    "minecraft:lime_stained_glass",
+    "minecraft:pink_stained_glass",
+    "minecraft:gray_stained_glass",
+    "minecraft:light_gray_stained_glass",
+    "minecraft:cyan_stained_glass",
+    "minecraft:purple_stained_glass",
+    "minecraft:blue_stained_glass",
+    "minecraft:brown_stained_glass",
+    "minecraft:green_stained_glass",
+    "minecraft:red_stained_glass",
+    "minecraft:black_stained_glass",
+    "minecraft:white_stained_glass_pane",
+    "minecraft:orange_stained_glass_pane",
+    "minecraft:magenta_stained_glass_pane",
+    "minecraft:light_blue_stained_glass_pane",
+    "minecraft:yellow_stained_glass_pane",
+    "minecraft:lime_stained_glass_pane",
+    "minecraft:pink_stained_glass_pane",
+    "minecraft:gray_stained_glass_pane",
+    "minecraft:light_gray_stained_glass_pane",
+    "minecraft:cyan_stained_glass_pane",
+    "minecraft:purple_stained_glass_pane",
+    "minecraft:blue_stained_glass_pane",
+    "minecraft:brown_stained_glass_pane",
+    "minecraft:green_stained_glass_pane",
+    "minecraft:red_stained_glass_pane",
+    "minecraft:black_stained_glass_pane",
+    "minecraft:prismarine",
+    "minecraft:prismarine_bricks",
+    "minecraft:dark_prismarine",
+    "minecraft:prismarine_stairs",
+    "minecraft:prismarine_brick_stairs",
+    "minecraft:dark_prismarine_stairs",
+    "minecraft:sea_lantern",
+    "minecraft:red_sandstone",
+    "minecraft:chiseled_red_sandstone",
+    "minecraft:cut_red_sandstone",
+    "minecraft:red_sandstone_stairs",
+    "minecraft:repeating_command_block",
+    "minecraft:chain_command_block",
+    "minecraft:magma_block",
+    "minecraft:nether_wart_block",
+    "minecraft:red_nether_bricks",
+    "minecraft:bone_block",
+    "minecraft:structure_void",
+    "minecraft:observer",
+    "minecraft:shulker_box",
+    "minecraft:white_shulker_box",
+    "minecraft:orange_shulker_box",
+    "minecraft:magenta_shulker_box",
+    "minecraft:light_blue_shulker_box",
+    "minecraft:yellow_shulker_box",
+    "minecraft:lime_shulker_box",
+    "minecraft:pink_shulker_box",
+    "minecraft:gray_shulker_box",
+    "minecraft:light_gray_shulker_box",
+    "minecraft:cyan_shulker_box",
+    "minecraft:purple_shulker_box",
+    "minecraft:blue_shulker_box",
+    "minecraft:brown_shulker_box",
+    "minecraft:green_shulker_box",
+    "minecraft:red_shulker_box",
+    "minecraft:black_shulker_box",
+    "minecraft:white_glazed_terracotta",
+    "minecraft:orange_glazed_terracotta",
+    "minecraft:magenta_glazed_terracotta",
+    "minecraft:light_blue_glazed_terracotta",
+    "minecraft:yellow_glazed_terracotta",
+    "minecraft:lime_glazed_terracotta",
+    "minecraft:pink_glazed_terracotta",
+    "minecraft:gray_glazed_terracotta",
+    "minecraft:light_gray_glazed_terracotta",
+    "minecraft:cyan_glazed_terracotta",
+    "minecraft:purple_glazed_terracotta",
+    "minecraft:blue_glazed_terracotta",
+    "minecraft:brown_glazed_terracotta",
+    "minecraft:green_glazed_terracotta",
+    "minecraft:red_glazed_terracotta",
+    "minecraft:black_glazed_terracotta",
+    "minecraft:white_concrete",
+    "minecraft:orange_concrete",
+    "minecraft:magenta_concrete",
+    "minecraft:light_blue_concrete",
+    "minecraft:yellow_concrete",
+    "minecraft:lime_concrete",
+    "minecraft:pink_concrete",
+    "minecraft:gray_concrete",
+    "minecraft:light_gray_concrete",
+    "minecraft:cyan_concrete",
+    "minecraft:purple_concrete",
+    "minecraft:blue_concrete",
+    "minecraft:brown_concrete",
+    "minecraft:green_concrete",
+    "minecraft:red_concrete",
+    "minecraft:black_concrete",
+    "minecraft:white_concrete_powder",
+    "minecraft:orange_concrete_powder",
+    "minecraft:magenta_concrete_powder",
+    "minecraft:light_blue_concrete_powder",
+    "minecraft:yellow_concrete_powder",
+    "minecraft:lime_concrete_powder",
+    "minecraft:pink_concrete_powder",
+    "minecraft:gray_concrete_powder",
+    "minecraft:light_gray_concrete_powder",
+    "minecraft:cyan_concrete_powder",
+    "minecraft:purple_concrete_powder",
+    "minecraft:blue_concrete_powder",
+    "minecraft:brown_concrete_powder",
+    "minecraft:green_concrete_powder",
+    "minecraft:red_concrete_powder",
+    "minecraft:black_concrete_powder",
+    "minecraft:turtle_egg",
+    "minecraft:dead_tube_coral_block",
+    "minecraft:dead_brain_coral_block",
+    "minecraft:dead_bubble_coral_block",
+    "minecraft:dead_fire_coral_block",
+    "minecraft:dead_horn_coral_block",
+    "minecraft:tube_coral_block",
+    "minecraft:brain_coral_block",
+    "minecraft:bubble_coral_block",
+    "minecraft:fire_coral_block",
+    "minecraft:horn_coral_block",
+    "minecraft:tube_coral",
+    "minecraft:brain_coral",
+    "minecraft:bubble_coral",
+    "minecraft:fire_coral",
+    "minecraft:horn_coral",
+    "minecraft:dead_brain_coral",
+    "minecraft:dead_bubble_coral",
+    "minecraft:dead_fire_coral",
+    "minecraft:dead_horn_coral",
+    "minecraft:dead_tube_coral",
+    "minecraft:tube_coral_fan",
+    "minecraft:brain_coral_fan",
+    "minecraft:bubble_coral_fan",
+    "minecraft:fire_coral_fan",
+    "minecraft:horn_coral_fan",
+    "minecraft:dead_tube_coral_fan",
+    "minecraft:dead_brain_coral_fan",
+    "minecraft:dead_bubble_coral_fan",
+    "minecraft:dead_fire_coral_fan",
+    "minecraft:dead_horn_coral_fan",
+    "minecraft:blue_ice",
+    "minecraft:conduit",
+    "minecraft:polished_granite_stairs",
+    "minecraft:smooth_red_sandstone_stairs",
+    "minecraft:mossy_stone_brick_stairs",
+    "minecraft:polished_diorite_stairs",
+    "minecraft:mossy_cobblestone_stairs",
+    "minecraft:end_stone_brick_stairs",
+    "minecraft:stone_stairs",
+    "minecraft:smooth_sandstone_stairs",
+    "minecraft:smooth_quartz_stairs",
+    "minecraft:granite_stairs",
+    "minecraft:andesite_stairs",
+    "minecraft:red_nether_brick_stairs",
+    "minecraft:polished_andesite_stairs",
+    "minecraft:diorite_stairs",
+    "minecraft:polished_granite_slab",
+    "minecraft:smooth_red_sandstone_slab",
+    "minecraft:mossy_stone_brick_slab",
+    "minecraft:polished_diorite_slab",
+    "minecraft:mossy_cobblestone_slab",
+    "minecraft:end_stone_brick_slab",
+    "minecraft:smooth_sandstone_slab",
+    "minecraft:smooth_quartz_slab",
+    "minecraft:granite_slab",
+    "minecraft:andesite_slab",
+    "minecraft:red_nether_brick_slab",
+    "minecraft:polished_andesite_slab",
+    "minecraft:diorite_slab",
+    "minecraft:scaffolding",
+    "minecraft:iron_door",
+    "minecraft:oak_door",
+    "minecraft:spruce_door",
+    "minecraft:birch_door",
+    "minecraft:jungle_door",
+    "minecraft:acacia_door",
+    "minecraft:dark_oak_door",
+    "minecraft:repeater",
+    "minecraft:comparator",
+    "minecraft:structure_block",
+    "minecraft:jigsaw",
+    "minecraft:composter",
+    "minecraft:turtle_helmet",
+    "minecraft:scute",
+    "minecraft:iron_shovel",
+    "minecraft:iron_pickaxe",
+    "minecraft:iron_axe",
+    "minecraft:flint_and_steel",
+    "minecraft:apple",
+    "minecraft:bow",
+    "minecraft:arrow",
+    "minecraft:coal",
+    "minecraft:charcoal",
+    "minecraft:diamond",
+    "minecraft:iron_ingot",
+    "minecraft:gold_ingot",
+    "minecraft:iron_sword",
+    "minecraft:wooden_sword",
+    "minecraft:wooden_shovel",
+    "minecraft:wooden_pickaxe",
+    "minecraft:wooden_axe",
+    "minecraft:stone_sword",
+    "minecraft:stone_shovel",
+    "minecraft:stone_pickaxe",
+    "minecraft:stone_axe",
+    "minecraft:diamond_sword",
+    "minecraft:diamond_shovel",
+    "minecraft:diamond_pickaxe",
+    "minecraft:diamond_axe",
+    "minecraft:stick",
+    "minecraft:bowl",
+    "minecraft:mushroom_stew",
+    "minecraft:golden_sword",
+    "minecraft:golden_shovel",
+    "minecraft:golden_pickaxe",
+    "minecraft:golden_axe",
+    "minecraft:string",
+    "minecraft:feather",
+    "minecraft:gunpowder",
+    "minecraft:wooden_hoe",
+    "minecraft:stone_hoe",
+    "minecraft:iron_hoe",
+    "minecraft:diamond_hoe",
+    "minecraft:golden_hoe",
+    "minecraft:wheat_seeds",
+    "minecraft:wheat",
+    "minecraft:bread",
+    "minecraft:leather_helmet",
+    "minecraft:leather_chestplate",
+    "minecraft:leather_leggings",
+    "minecraft:leather_boots",
+    "minecraft:chainmail_helmet",
+    "minecraft:chainmail_chestplate",
+    "minecraft:chainmail_leggings",
+    "minecraft:chainmail_boots",
+    "minecraft:iron_helmet",
+    "minecraft:iron_chestplate",
+    "minecraft:iron_leggings",
+    "minecraft:iron_boots",
+    "minecraft:diamond_helmet",
+    "minecraft:diamond_chestplate",
+    "minecraft:diamond_leggings",
+    "minecraft:diamond_boots",
+    "minecraft:golden_helmet",
+    "minecraft:golden_chestplate",
+    "minecraft:golden_leggings",
+    "minecraft:golden_boots",
+    "minecraft:flint",
+    "minecraft:porkchop",
+    "minecraft:cooked_porkchop",
+    "minecraft:painting",
+    "minecraft:golden_apple",
+    "minecraft:enchanted_golden_apple",
+    "minecraft:oak_sign",
+    "minecraft:spruce_sign",
+    "minecraft:birch_sign",
+    "minecraft:jungle_sign",
+    "minecraft:acacia_sign",
+    "minecraft:dark_oak_sign",
+    "minecraft:bucket",
+    "minecraft:water_bucket",
+    "minecraft:lava_bucket",
+    "minecraft:minecart",
+    "minecraft:saddle",
+    "minecraft:redstone",
+    "minecraft:snowball",
+    "minecraft:oak_boat",
+    "minecraft:leather",
+    "minecraft:milk_bucket",
+    "minecraft:pufferfish_bucket",
+    "minecraft:salmon_bucket",
+    "minecraft:cod_bucket",
+    "minecraft:tropical_fish_bucket",
+    "minecraft:brick",
+    "minecraft:clay_ball",
+    "minecraft:sugar_cane",
+    "minecraft:kelp",
+    "minecraft:dried_kelp_block",
+    "minecraft:bamboo",
+    "minecraft:paper",
+    "minecraft:book",
+    "minecraft:slime_ball",
+    "minecraft:chest_minecart",
+    "minecraft:furnace_minecart",
+    "minecraft:egg",
+    "minecraft:compass",
+    "minecraft:fishing_rod",
+    "minecraft:clock",
+    "minecraft:glowstone_dust",
+    "minecraft:cod",
+    "minecraft:salmon",
+    "minecraft:tropical_fish",
+    "minecraft:pufferfish",
+    "minecraft:cooked_cod",
+    "minecraft:cooked_salmon",
+    "minecraft:ink_sac",
+    "minecraft:red_dye",
+    "minecraft:green_dye",
+    "minecraft:cocoa_beans",
+    "minecraft:lapis_lazuli",
+    "minecraft:purple_dye",
+    "minecraft:cyan_dye",
+    "minecraft:light_gray_dye",
+    "minecraft:gray_dye",
+    "minecraft:pink_dye",
+    "minecraft:lime_dye",
+    "minecraft:yellow_dye",
+    "minecraft:light_blue_dye",
+    "minecraft:magenta_dye",
+    "minecraft:orange_dye",
+    "minecraft:bone_meal",
+    "minecraft:blue_dye",
+    "minecraft:brown_dye",
+    "minecraft:black_dye",
+    "minecraft:white_dye",
+    "minecraft:bone",
+    "minecraft:sugar",
+    "minecraft:cake",
+    "minecraft:white_bed",
+    "minecraft:orange_bed",
+    "minecraft:magenta_bed",
+    "minecraft:light_blue_bed",
+    "minecraft:yellow_bed",
+    "minecraft:lime_bed",
+    "minecraft:pink_bed",
+    "minecraft:gray_bed",
+    "minecraft:light_gray_bed",
+    "minecraft:cyan_bed",
+    "minecraft:purple_bed",
+    "minecraft:blue_bed",
+    "minecraft:brown_bed",
+    "minecraft:green_bed",
+    "minecraft:red_bed",
+    "minecraft:black_bed",
+    "minecraft:cookie",
+    "minecraft:filled_map",
+    "minecraft:shears",
+    "minecraft:melon_slice",
+    "minecraft:dried_kelp",
+    "minecraft:pumpkin_seeds",
+    "minecraft:melon_seeds",
+    "minecraft:beef",
+    "minecraft:cooked_beef",
+    "minecraft:chicken",
+    "minecraft:cooked_chicken",
+    "minecraft:rotten_flesh",
+    "minecraft:ender_pearl",
+    "minecraft:blaze_rod",
+    "minecraft:ghast_tear",
+    "minecraft:gold_nugget",
+    "minecraft:nether_wart",
+    "minecraft:potion",
+    "minecraft:glass_bottle",
+    "minecraft:spider_eye",
+    "minecraft:fermented_spider_eye",
+    "minecraft:blaze_powder",
+    "minecraft:magma_cream",
+    "minecraft:brewing_stand",
+    "minecraft:cauldron",
+    "minecraft:ender_eye",
+    "minecraft:glistering_melon_slice",
+    "minecraft:bat_spawn_egg",
+    "minecraft:bee_spawn_egg",
+    "minecraft:blaze_spawn_egg",
+    "minecraft:cat_spawn_egg",
+    "minecraft:cave_spider_spawn_egg",
+    "minecraft:chicken_spawn_egg",
+    "minecraft:cod_spawn_egg",
+    "minecraft:cow_spawn_egg",
+    "minecraft:creeper_spawn_egg",
+    "minecraft:dolphin_spawn_egg",
+    "minecraft:donkey_spawn_egg",
+    "minecraft:drowned_spawn_egg",
+    "minecraft:elder_guardian_spawn_egg",
+    "minecraft:enderman_spawn_egg",
+    "minecraft:endermite_spawn_egg",
+    "minecraft:evoker_spawn_egg",
+    "minecraft:fox_spawn_egg",
+    "minecraft:ghast_spawn_egg",
+    "minecraft:guardian_spawn_egg",
+    "minecraft:horse_spawn_egg",
+    "minecraft:husk_spawn_egg",
+    "minecraft:llama_spawn_egg",
+    "minecraft:magma_cube_spawn_egg",
+    "minecraft:mooshroom_spawn_egg",
+    "minecraft:mule_spawn_egg",
+    "minecraft:ocelot_spawn_egg",
+    "minecraft:panda_spawn_egg",
+    "minecraft:parrot_spawn_egg",
+    "minecraft:phantom_spawn_egg",
+    "minecraft:pig_spawn_egg",
+    "minecraft:pillager_spawn_egg",
+    "minecraft:polar_bear_spawn_egg",
+    "minecraft:pufferfish_spawn_egg",
+    "minecraft:rabbit_spawn_egg",
+    "minecraft:ravager_spawn_egg",
+    "minecraft:salmon_spawn_egg",
+    "minecraft:sheep_spawn_egg",
+    "minecraft:shulker_spawn_egg",
+    "minecraft:silverfish_spawn_egg",
+    "minecraft:skeleton_spawn_egg",
+    "minecraft:skeleton_horse_spawn_egg",
+    "minecraft:slime_spawn_egg",
+    "minecraft:spider_spawn_egg",
+    "minecraft:squid_spawn_egg",
+    "minecraft:stray_spawn_egg",
+    "minecraft:trader_llama_spawn_egg",
+    "minecraft:tropical_fish_spawn_egg",
+    "minecraft:turtle_spawn_egg",
+    "minecraft:vex_spawn_egg",
+    "minecraft:villager_spawn_egg",
+    "minecraft:vindicator_spawn_egg",
+    "minecraft:wandering_trader_spawn_egg",
+    "minecraft:witch_spawn_egg",
+    "minecraft:wither_skeleton_spawn_egg",
+    "minecraft:wolf_spawn_egg",
+    "minecraft:zombie_spawn_egg",
+    "minecraft:zombie_horse_spawn_egg",
+    "minecraft:zombie_pigman_spawn_egg",
+    "minecraft:zombie_villager_spawn_egg",
+    "minecraft:experience_bottle",
+    "minecraft:fire_charge",
+    "minecraft:writable_book",
+    "minecraft:written_book",
+    "minecraft:emerald",
+    "minecraft:item_frame",
+    "minecraft:flower_pot",
+    "minecraft:carrot",
+    "minecraft:potato",
+    "minecraft:baked_potato",
+    "minecraft:poisonous_potato",
+    "minecraft:map",
+    "minecraft:golden_carrot",
+    "minecraft:skeleton_skull",
+    "minecraft:wither_skeleton_skull",
+    "minecraft:player_head",
+    "minecraft:zombie_head",
+    "minecraft:creeper_head",
+    "minecraft:dragon_head",
+    "minecraft:carrot_on_a_stick",
+    "minecraft:nether_star",
+    "minecraft:pumpkin_pie",
+    "minecraft:firework_rocket",
+    "minecraft:firework_star",
+    "minecraft:enchanted_book",
+    "minecraft:nether_brick",
+    "minecraft:quartz",
+    "minecraft:tnt_minecart",
+    "minecraft:hopper_minecart",
+    "minecraft:prismarine_shard",
+    "minecraft:prismarine_crystals",
+    "minecraft:rabbit",
+    "minecraft:cooked_rabbit",
+    "minecraft:rabbit_stew",
+    "minecraft:rabbit_foot",
+    "minecraft:rabbit_hide",
+    "minecraft:armor_stand",
+    "minecraft:iron_horse_armor",
+    "minecraft:golden_horse_armor",
+    "minecraft:diamond_horse_armor",
+    "minecraft:leather_horse_armor",
+    "minecraft:lead",
+    "minecraft:name_tag",
+    "minecraft:command_block_minecart",
+    "minecraft:mutton",
+    "minecraft:cooked_mutton",
+    "minecraft:white_banner",
+    "minecraft:orange_banner",
+    "minecraft:magenta_banner",
+    "minecraft:light_blue_banner",
+    "minecraft:yellow_banner",
+    "minecraft:lime_banner",
+    "minecraft:pink_banner",
+    "minecraft:gray_banner",
+    "minecraft:light_gray_banner",
+    "minecraft:cyan_banner",
+    "minecraft:purple_banner",
+    "minecraft:blue_banner",
+    "minecraft:brown_banner",
+    "minecraft:green_banner",
+    "minecraft:red_banner",
+    "minecraft:black_banner",
+    "minecraft:end_crystal",
+    "minecraft:chorus_fruit",
+    "minecraft:popped_chorus_fruit",
+    "minecraft:beetroot",
+    "minecraft:beetroot_seeds",
+    "minecraft:beetroot_soup",
+    "minecraft:dragon_breath",
+    "minecraft:splash_potion",
+    "minecraft:spectral_arrow",
+    "minecraft:tipped_arrow",
+    "minecraft:lingering_potion",
+    "minecraft:shield",
+    "minecraft:elytra",
+    "minecraft:spruce_boat",
+    "minecraft:birch_boat",
+    "minecraft:jungle_boat",
+    "minecraft:acacia_boat",
+    "minecraft:dark_oak_boat",
+    "minecraft:totem_of_undying",
+    "minecraft:shulker_shell",
+    "minecraft:iron_nugget",
+    "minecraft:knowledge_book",
+    "minecraft:debug_stick",
+    "minecraft:music_disc_13",
+    "minecraft:music_disc_cat",
+    "minecraft:music_disc_blocks",
+    "minecraft:music_disc_chirp",
+    "minecraft:music_disc_far",
+    "minecraft:music_disc_mall",
+    "minecraft:music_disc_mellohi",
+    "minecraft:music_disc_stal",
+    "minecraft:music_disc_strad",
+    "minecraft:music_disc_ward",
+    "minecraft:music_disc_11",
+    "minecraft:music_disc_wait",
+    "minecraft:trident",
+    "minecraft:phantom_membrane",
+    "minecraft:nautilus_shell",
+    "minecraft:heart_of_the_sea",
+    "minecraft:crossbow",
+    "minecraft:suspicious_stew",
+    "minecraft:loom",
+    "minecraft:flower_banner_pattern",
+    "minecraft:creeper_banner_pattern",
+    "minecraft:skull_banner_pattern",
+    "minecraft:mojang_banner_pattern",
+    "minecraft:globe_banner_pattern",
+    "minecraft:barrel",
+    "minecraft:smoker",
+    "minecraft:blast_furnace",
+    "minecraft:cartography_table",
+    "minecraft:fletching_table",
+    "minecraft:grindstone",
+    "minecraft:lectern",
+    "minecraft:smithing_table",
+    "minecraft:stonecutter",
+    "minecraft:bell",
+    "minecraft:lantern",
+    "minecraft:sweet_berries",
+    "minecraft:campfire",
+    "minecraft:honeycomb",
+    "minecraft:bee_nest",
+    "minecraft:beehive",
+    "minecraft:honey_bottle",
+    "minecraft:honey_block",
+    "minecraft:honeycomb_block",
+];
+
+/// The entity types in id order
+const ENTITIES_578: &[&str] = &[
+    "minecraft:area_effect_cloud",
+    "minecraft:armor_stand",
+    "minecraft:arrow",
+    "minecraft:bat",
+    "minecraft:bee",
+    "minecraft:blaze",
+    "minecraft:boat",
+    "minecraft:cat",
+    "minecraft:cave_spider",
+    "minecraft:chicken",
+    "minecraft:cod",
+    "minecraft:cow",
+    "minecraft:creeper",
+    "minecraft:donkey",
+    "minecraft:dolphin",
+    "minecraft:dragon_fireball",
+    "minecraft:drowned",
+    "minecraft:elder_guardian",
+    "minecraft:end_crystal",
+    "minecraft:ender_dragon",
+    "minecraft:enderman",
+    "minecraft:endermite",
+    "minecraft:evoker_fangs",
+    "minecraft:evoker",
+    "minecraft:experience_orb",
+    "minecraft:eye_of_ender",
+    "minecraft:falling_block",
+    "minecraft:firework_rocket",
+    "minecraft:fox",
+    "minecraft:ghast",
+    "minecraft:giant",
+    "minecraft:guardian",
+    "minecraft:horse",
+    "minecraft:husk",
+    "minecraft:illusioner",
+    "minecraft:item",
+    "minecraft:item_frame",
+    "minecraft:fireball",
+    "minecraft:leash_knot",
+    "minecraft:llama",
+    "minecraft:llama_spit",
+    "minecraft:magma_cube",
+    "minecraft:minecart",
+    "minecraft:chest_minecart",
+    "minecraft:command_block_minecart",
+    "minecraft:furnace_minecart",
+    "minecraft:hopper_minecart",
+    "minecraft:spawner_minecart",
+    "minecraft:tnt_minecart",
+    "minecraft:mule",
+    "minecraft:mooshroom",
+    "minecraft:ocelot",
+    "minecraft:painting",
+    "minecraft:panda",
+    "minecraft:parrot",
+    "minecraft:pig",
+    "minecraft:pufferfish",
+    "minecraft:zombie_pigman",
+    "minecraft:polar_bear",
+    "minecraft:tnt",
+    "minecraft:rabbit",
+    "minecraft:salmon",
+    "minecraft:sheep",
+    "minecraft:shulker",
+    "minecraft:shulker_bullet",
+    "minecraft:silverfish",
+    "minecraft:skeleton",
+    "minecraft:skeleton_horse",
+    "minecraft:slime",
+    "minecraft:small_fireball",
+    "minecraft:snow_golem",
+    "minecraft:snowball",
+    "minecraft:spectral_arrow",
+    "minecraft:spider",
+    "minecraft:squid",
+    "minecraft:stray",
+    "minecraft:trader_llama",
+    "minecraft:tropical_fish",
+    "minecraft:turtle",
+    "minecraft:egg",
+    "minecraft:ender_pearl",
+    "minecraft:experience_bottle",
+    "minecraft:potion",
+    "minecraft:trident",
+    "minecraft:vex",
+    "minecraft:villager",
+    "minecraft:iron_golem",
+    "minecraft:vindicator",
+    "minecraft:pillager",
+    "minecraft:wandering_trader",
+    "minecraft:witch",
+    "minecraft:wither",
+    "minecraft:wither_skeleton",
+    "minecraft:wither_skull",
+    "minecraft:wolf",
+    "minecraft:zombie",
+    "minecraft:zombie_horse",
+    "minecraft:zombie_villager",
+    "minecraft:phantom",
+    "minecraft:ravager",
+    "minecraft:lightning_bolt",
+    "minecraft:player",
+    "minecraft:fishing_bobber",
+];
+
+/// The particle types in id order
+const PARTICLES_578: &[&str] = &[
+    "minecraft:ambient_entity_effect",
+    "minecraft:angry_villager",
+    "minecraft:barrier",
+    "minecraft:block",
+    "minecraft:bubble",
+    "minecraft:cloud",
+    "minecraft:crit",
+    "minecraft:damage_indicator",
+    "minecraft:dragon_breath",
+    "minecraft:dripping_lava",
+    "minecraft:falling_lava",
+    "minecraft:landing_lava",
+    "minecraft:dripping_water",
+    "minecraft:falling_water",
+    "minecraft:dust",
+    "minecraft:effect",
+    "minecraft:elder_guardian",
+    "minecraft:enchanted_hit",
+    "minecraft:enchant",
+    "minecraft:end_rod",
+    "minecraft:entity_effect",
+    "minecraft:explosion_emitter",
+    "minecraft:explosion",
+    "minecraft:falling_dust",
+    "minecraft:firework",
+    "minecraft:fishing",
+    "minecraft:flame",
+    "minecraft:flash",
+    "minecraft:happy_villager",
+    "minecraft:composter",
+    "minecraft:heart",
+    "minecraft:instant_effect",
+    "minecraft:item",
+    "minecraft:item_slime",
+    "minecraft:item_snowball",
+    "minecraft:large_smoke",
+    "minecraft:lava",
+    "minecraft:mycelium",
+    "minecraft:note",
+    "minecraft:poof",
+    "minecraft:portal",
+    "minecraft:rain",
+    "minecraft:smoke",
+    "minecraft:sneeze",
+    "minecraft:spit",
+    "minecraft:squid_ink",
+    "minecraft:sweep_attack",
+    "minecraft:totem_of_undying",
+    "minecraft:underwater",
+    "minecraft:splash",
+    "minecraft:witch",
+    "minecraft:bubble_pop",
+    "minecraft:current_down",
+    "minecraft:bubble_column_up",
+    "minecraft:nautilus",
+    "minecraft:dolphin",
+    "minecraft:campfire_cosy_smoke",
+    "minecraft:campfire_signal_smoke",
+    "minecraft:dripping_honey",
+    "minecraft:falling_honey",
+    "minecraft:landing_honey",
+    "minecraft:falling_nectar",
+];
+
+/// The sound events in id order
+const SOUNDS_578: &[&str] = &[
+    "minecraft:ambient.cave",
+    "minecraft:ambient.underwater.enter",
+    "minecraft:ambient.underwater.exit",
+    "minecraft:ambient.underwater.loop",
+    "minecraft:ambient.underwater.loop.additions",
+    "minecraft:ambient.underwater.loop.additions.rare",
+    "minecraft:ambient.underwater.loop.additions.ultra_rare",
+    "minecraft:block.anvil.break",
+    "minecraft:block.anvil.destroy",
+    "minecraft:block.anvil.fall",
+    "minecraft:block.anvil.hit",
+    "minecraft:block.anvil.land",
+    "minecraft:block.anvil.place",
+    "minecraft:block.anvil.step",
+    "minecraft:block.anvil.use",
+    "minecraft:item.armor.equip_chain",
+    "minecraft:item.armor.equip_diamond",
+    "minecraft:item.armor.equip_elytra",
+    "minecraft:item.armor.equip_generic",
+    "minecraft:item.armor.equip_gold",
+    "minecraft:item.armor.equip_iron",
+    "minecraft:item.armor.equip_leather",
+    "minecraft:item.armor.equip_turtle",
+    "minecraft:entity.armor_stand.break",
+    "minecraft:entity.armor_stand.fall",
+    "minecraft:entity.armor_stand.hit",
+    "minecraft:entity.armor_stand.place",
+    "minecraft:entity.arrow.hit",
+    "minecraft:entity.arrow.hit_player",
+    "minecraft:entity.arrow.shoot",
+    "minecraft:item.axe.strip",
+    "minecraft:block.bamboo.break",
+    "minecraft:block.bamboo.fall",
+    "minecraft:block.bamboo.hit",
+    "minecraft:block.bamboo.place",
+    "minecraft:block.bamboo.step",
+    "minecraft:block.bamboo_sapling.break",
+    "minecraft:block.bamboo_sapling.hit",
+    "minecraft:block.bamboo_sapling.place",
+    "minecraft:block.barrel.close",
+    "minecraft:block.barrel.open",
+    "minecraft:entity.bat.ambient",
+    "minecraft:entity.bat.death",
+    "minecraft:entity.bat.hurt",
+    "minecraft:entity.bat.loop",
+    "minecraft:entity.bat.takeoff",
+    "minecraft:block.beacon.activate",
+    "minecraft:block.beacon.ambient",
+    "minecraft:block.beacon.deactivate",
+    "minecraft:block.beacon.power_select",
+    "minecraft:entity.bee.death",
+    "minecraft:entity.bee.hurt",
+    "minecraft:entity.bee.loop_aggressive",
+    "minecraft:entity.bee.loop",
+    "minecraft:entity.bee.sting",
+    "minecraft:entity.bee.pollinate",
+    "minecraft:block.beehive.drip",
+    "minecraft:block.beehive.enter",
+    "minecraft:block.beehive.exit",
+    "minecraft:block.beehive.shear",
+    "minecraft:block.beehive.work",
+    "minecraft:block.bell.use",
+    "minecraft:block.bell.resonate",
+    "minecraft:entity.blaze.ambient",
+    "minecraft:entity.blaze.burn",
+    "minecraft:entity.blaze.death",
+    "minecraft:entity.blaze.hurt",
+    "minecraft:entity.blaze.shoot",
+    "minecraft:entity.boat.paddle_land",
+    "minecraft:entity.boat.paddle_water",
+    "minecraft:item.book.page_turn",
+    "minecraft:item.book.put",
+    "minecraft:entity.fishing_bobber.retrieve",
+    "minecraft:entity.fishing_bobber.splash",
+    "minecraft:entity.fishing_bobber.throw",
+    "minecraft:block.blastfurnace.fire_crackle",
+    "minecraft:item.bottle.empty",
+    "minecraft:item.bottle.fill",
+    "minecraft:item.bottle.fill_dragonbreath",
+    "minecraft:block.brewing_stand.brew",
+    "minecraft:block.bubble_column.bubble_pop",
+    "minecraft:block.bubble_column.upwards_ambient",
+    "minecraft:block.bubble_column.upwards_inside",
+    "minecraft:block.bubble_column.whirlpool_ambient",
+    "minecraft:block.bubble_column.whirlpool_inside",
+    "minecraft:item.bucket.empty",
+    "minecraft:item.bucket.empty_fish",
+    "minecraft:item.bucket.empty_lava",
+    "minecraft:item.bucket.fill",
+    "minecraft:item.bucket.fill_fish",
+    "minecraft:item.bucket.fill_lava",
+    "minecraft:block.campfire.crackle",
+    "minecraft:entity.cat.ambient",
+    "minecraft:entity.cat.stray_ambient",
+    "minecraft:entity.cat.death",
+    "minecraft:entity.cat.eat",
+    "minecraft:entity.cat.hiss",
+    "minecraft:entity.cat.beg_for_food",
+    "minecraft:entity.cat.hurt",
+    "minecraft:entity.cat.purr",
+    "minecraft:entity.cat.purreow",
+    "minecraft:block.chest.close",
+    "minecraft:block.chest.locked",
+    "minecraft:block.chest.open",
+    "minecraft:entity.chicken.ambient",
+    "minecraft:entity.chicken.death",
+    "minecraft:entity.chicken.egg",
+    "minecraft:entity.chicken.hurt",
+    "minecraft:entity.chicken.step",
+    "minecraft:block.chorus_flower.death",
+    "minecraft:block.chorus_flower.grow",
+    "minecraft:item.chorus_fruit.teleport",
+    "minecraft:block.wool.break",
+    "minecraft:block.wool.fall",
+    "minecraft:block.wool.hit",
+    "minecraft:block.wool.place",
+    "minecraft:block.wool.step",
+    "minecraft:entity.cod.ambient",
+    "minecraft:entity.cod.death",
+    "minecraft:entity.cod.flop",
+    "minecraft:entity.cod.hurt",
+    "minecraft:block.comparator.click",
+    "minecraft:block.composter.empty",
+    "minecraft:block.composter.fill",
+    "minecraft:block.composter.fill_success",
+    "minecraft:block.composter.ready",
+    "minecraft:block.conduit.activate",
+    "minecraft:block.conduit.ambient",
+    "minecraft:block.conduit.ambient.short",
+    "minecraft:block.conduit.attack.target",
+    "minecraft:block.conduit.deactivate",
+    "minecraft:block.coral_block.break",
+    "minecraft:block.coral_block.fall",
+    "minecraft:block.coral_block.hit",
+    "minecraft:block.coral_block.place",
+    "minecraft:block.coral_block.step",
+    "minecraft:entity.cow.ambient",
+    "minecraft:entity.cow.death",
+    "minecraft:entity.cow.hurt",
+    "minecraft:entity.cow.milk",
+    "minecraft:entity.cow.step",
+    "minecraft:entity.creeper.death",
+    "minecraft:entity.creeper.hurt",
+    "minecraft:entity.creeper.primed",
+    "minecraft:block.crop.break",
+    "minecraft:item.crop.plant",
+    "minecraft:item.crossbow.hit",
+    "minecraft:item.crossbow.loading_end",
+    "minecraft:item.crossbow.loading_middle",
+    "minecraft:item.crossbow.loading_start",
+    "minecraft:item.crossbow.quick_charge_1",
+    "minecraft:item.crossbow.quick_charge_2",
+    "minecraft:item.crossbow.quick_charge_3",
+    "minecraft:item.crossbow.shoot",
+    "minecraft:block.dispenser.dispense",
+    "minecraft:block.dispenser.fail",
+    "minecraft:block.dispenser.launch",
+    "minecraft:entity.dolphin.ambient",
+    "minecraft:entity.dolphin.ambient_water",
+    "minecraft:entity.dolphin.attack",
+    "minecraft:entity.dolphin.death",
+    "minecraft:entity.dolphin.eat",
+    "minecraft:entity.dolphin.hurt",
+    "minecraft:entity.dolphin.jump",
+    "minecraft:entity.dolphin.play",
+    "minecraft:entity.dolphin.splash",
+    "minecraft:entity.dolphin.swim",
+    "minecraft:entity.donkey.ambient",
+    "minecraft:entity.donkey.angry",
+    "minecraft:entity.donkey.chest",
+    "minecraft:entity.donkey.death",
+    "minecraft:entity.donkey.hurt",
+    "minecraft:entity.drowned.ambient",
+    "minecraft:entity.drowned.ambient_water",
+    "minecraft:entity.drowned.death",
+    "minecraft:entity.drowned.death_water",
+    "minecraft:entity.drowned.hurt",
+    "minecraft:entity.drowned.hurt_water",
+    "minecraft:entity.drowned.shoot",
+    "minecraft:entity.drowned.step",
+    "minecraft:entity.drowned.swim",
+    "minecraft:entity.egg.throw",
+    "minecraft:entity.elder_guardian.ambient",
+    "minecraft:entity.elder_guardian.ambient_land",
+    "minecraft:entity.elder_guardian.curse",
+    "minecraft:entity.elder_guardian.death",
+    "minecraft:entity.elder_guardian.death_land",
+    "minecraft:entity.elder_guardian.flop",
+    "minecraft:entity.elder_guardian.hurt",
+    "minecraft:entity.elder_guardian.hurt_land",
+    "minecraft:item.elytra.flying",
+    "minecraft:block.enchantment_table.use",
+    "minecraft:block.ender_chest.close",
+    "minecraft:block.ender_chest.open",
+    "minecraft:entity.ender_dragon.ambient",
+    "minecraft:entity.ender_dragon.death",
+    "minecraft:entity.dragon_fireball.explode",
+    "minecraft:entity.ender_dragon.flap",
+    "minecraft:entity.ender_dragon.growl",
+    "minecraft:entity.ender_dragon.hurt",
+    "minecraft:entity.ender_dragon.shoot",
+    "minecraft:entity.ender_eye.death",
+    "minecraft:entity.ender_eye.launch",
+    "minecraft:entity.enderman.ambient",
+    "minecraft:entity.enderman.death",
+    "minecraft:entity.enderman.hurt",
+    "minecraft:entity.enderman.scream",
+    "minecraft:entity.enderman.stare",
+    "minecraft:entity.enderman.teleport",
+    "minecraft:entity.endermite.ambient",
+    "minecraft:entity.endermite.death",
+    "minecraft:entity.endermite.hurt",
+    "minecraft:entity.endermite.step",
+    "minecraft:entity.ender_pearl.throw",
+    "minecraft:block.end_gateway.spawn",
+    "minecraft:block.end_portal_frame.fill",
+    "minecraft:block.end_portal.spawn",
+    "minecraft:entity.evoker.ambient",
+    "minecraft:entity.evoker.cast_spell",
+    "minecraft:entity.evoker.celebrate",
+    "minecraft:entity.evoker.death",
+    "minecraft:entity.evoker_fangs.attack",
+    "minecraft:entity.evoker.hurt",
+    "minecraft:entity.evoker.prepare_attack",
+    "minecraft:entity.evoker.prepare_summon",
+    "minecraft:entity.evoker.prepare_wololo",
+    "minecraft:entity.experience_bottle.throw",
+    "minecraft:entity.experience_orb.pickup",
+    "minecraft:block.fence_gate.close",
+    "minecraft:block.fence_gate.open",
+    "minecraft:item.firecharge.use",
+    "minecraft:entity.firework_rocket.blast",
+    "minecraft:entity.firework_rocket.blast_far",
+    "minecraft:entity.firework_rocket.large_blast",
+    "minecraft:entity.firework_rocket.large_blast_far",
+    "minecraft:entity.firework_rocket.launch",
+    "minecraft:entity.firework_rocket.shoot",
+    "minecraft:entity.firework_rocket.twinkle",
+    "minecraft:entity.firework_rocket.twinkle_far",
+    "minecraft:block.fire.ambient",
+    "minecraft:block.fire.extinguish",
+    "minecraft:entity.fish.swim",
+    "minecraft:item.flintandsteel.use",
+    "minecraft:entity.fox.aggro",
+    "minecraft:entity.fox.ambient",
+    "minecraft:entity.fox.bite",
+    "minecraft:entity.fox.death",
+    "minecraft:entity.fox.eat",
+    "minecraft:entity.fox.hurt",
+    "minecraft:entity.fox.screech",
+    "minecraft:entity.fox.sleep",
+    "minecraft:entity.fox.sniff",
+    "minecraft:entity.fox.spit",
+    "minecraft:block.furnace.fire_crackle",
+    "minecraft:entity.generic.big_fall",
+    "minecraft:entity.generic.burn",
+    "minecraft:entity.generic.death",
+    "minecraft:entity.generic.drink",
+    "minecraft:entity.generic.eat",
+    "minecraft:entity.generic.explode",
+    "minecraft:entity.generic.extinguish_fire",
+    "minecraft:entity.generic.hurt",
+    "minecraft:entity.generic.small_fall",
+    "minecraft:entity.generic.splash",
+    "minecraft:entity.generic.swim",
+    "minecraft:entity.ghast.ambient",
+    "minecraft:entity.ghast.death",
+    "minecraft:entity.ghast.hurt",
+    "minecraft:entity.ghast.scream",
+    "minecraft:entity.ghast.shoot",
+    "minecraft:entity.ghast.warn",
+    "minecraft:block.glass.break",
+    "minecraft:block.glass.fall",
+    "minecraft:block.glass.hit",
+    "minecraft:block.glass.place",
+    "minecraft:block.glass.step",
+    "minecraft:block.grass.break",
+    "minecraft:block.grass.fall",
+    "minecraft:block.grass.hit",
+    "minecraft:block.grass.place",
+    "minecraft:block.grass.step",
+    "minecraft:block.gravel.break",
+    "minecraft:block.gravel.fall",
+    "minecraft:block.gravel.hit",
+    "minecraft:block.gravel.place",
+    "minecraft:block.gravel.step",
+    "minecraft:block.grindstone.use",
+    "minecraft:entity.guardian.ambient",
+    "minecraft:entity.guardian.ambient_land",
+    "minecraft:entity.guardian.attack",
+    "minecraft:entity.guardian.death",
+    "minecraft:entity.guardian.death_land",
+    "minecraft:entity.guardian.flop",
+    "minecraft:entity.guardian.hurt",
+    "minecraft:entity.guardian.hurt_land",
+    "minecraft:item.hoe.till",
+    "minecraft:block.honey_block.break",
+    "minecraft:block.honey_block.fall",
+    "minecraft:block.honey_block.hit",
+    "minecraft:block.honey_block.place",
+    "minecraft:block.honey_block.slide",
+    "minecraft:block.honey_block.step",
+    "minecraft:item.honey_bottle.drink",
+    "minecraft:entity.horse.ambient",
+    "minecraft:entity.horse.angry",
+    "minecraft:entity.horse.armor",
+    "minecraft:entity.horse.breathe",
+    "minecraft:entity.horse.death",
+    "minecraft:entity.horse.eat",
+    "minecraft:entity.horse.gallop",
+    "minecraft:entity.horse.hurt",
+    "minecraft:entity.horse.jump",
+    "minecraft:entity.horse.land",
+    "minecraft:entity.horse.saddle",
+    "minecraft:entity.horse.step",
+    "minecraft:entity.horse.step_wood",
+    "minecraft:entity.hostile.big_fall",
+    "minecraft:entity.hostile.death",
+    "minecraft:entity.hostile.hurt",
+    "minecraft:entity.hostile.small_fall",
+    "minecraft:entity.hostile.splash",
+    "minecraft:entity.hostile.swim",
+    "minecraft:entity.husk.ambient",
+    "minecraft:entity.husk.converted_to_zombie",
+    "minecraft:entity.husk.death",
+    "minecraft:entity.husk.hurt",
+    "minecraft:entity.husk.step",
+    "minecraft:entity.ravager.ambient",
+    "minecraft:entity.ravager.attack",
+    "minecraft:entity.ravager.celebrate",
+    "minecraft:entity.ravager.death",
+    "minecraft:entity.ravager.hurt",
+    "minecraft:entity.ravager.step",
+    "minecraft:entity.ravager.stunned",
+    "minecraft:entity.ravager.roar",
+    "minecraft:entity.illusioner.ambient",
+    "minecraft:entity.illusioner.cast_spell",
+    "minecraft:entity.illusioner.death",
+    "minecraft:entity.illusioner.hurt",
+    "minecraft:entity.illusioner.mirror_move",
+    "minecraft:entity.illusioner.prepare_blindness",
+    "minecraft:entity.illusioner.prepare_mirror",
+    "minecraft:block.iron_door.close",
+    "minecraft:block.iron_door.open",
+    "minecraft:entity.iron_golem.attack",
+    "minecraft:entity.iron_golem.damage",
+    "minecraft:entity.iron_golem.death",
+    "minecraft:entity.iron_golem.hurt",
+    "minecraft:entity.iron_golem.repair",
+    "minecraft:entity.iron_golem.step",
+    "minecraft:block.iron_trapdoor.close",
+    "minecraft:block.iron_trapdoor.open",
+    "minecraft:entity.item_frame.add_item",
+    "minecraft:entity.item_frame.break",
+    "minecraft:entity.item_frame.place",
+    "minecraft:entity.item_frame.remove_item",
+    "minecraft:entity.item_frame.rotate_item",
+    "minecraft:entity.item.break",
+    "minecraft:entity.item.pickup",
+    "minecraft:block.ladder.break",
+    "minecraft:block.ladder.fall",
+    "minecraft:block.ladder.hit",
+    "minecraft:block.ladder.place",
+    "minecraft:block.ladder.step",
+    "minecraft:block.lantern.break",
+    "minecraft:block.lantern.fall",
+    "minecraft:block.lantern.hit",
+    "minecraft:block.lantern.place",
+    "minecraft:block.lantern.step",
+    "minecraft:block.lava.ambient",
+    "minecraft:block.lava.extinguish",
+    "minecraft:block.lava.pop",
+    "minecraft:entity.leash_knot.break",
+    "minecraft:entity.leash_knot.place",
+    "minecraft:block.lever.click",
+    "minecraft:entity.lightning_bolt.impact",
+    "minecraft:entity.lightning_bolt.thunder",
+    "minecraft:entity.lingering_potion.throw",
+    "minecraft:entity.llama.ambient",
+    "minecraft:entity.llama.angry",
+    "minecraft:entity.llama.chest",
+    "minecraft:entity.llama.death",
+    "minecraft:entity.llama.eat",
+    "minecraft:entity.llama.hurt",
+    "minecraft:entity.llama.spit",
+    "minecraft:entity.llama.step",
+    "minecraft:entity.llama.swag",
+    "minecraft:entity.magma_cube.death",
+    "minecraft:entity.magma_cube.hurt",
+    "minecraft:entity.magma_cube.jump",
+    "minecraft:entity.magma_cube.squish",
+    "minecraft:block.metal.break",
+    "minecraft:block.metal.fall",
+    "minecraft:block.metal.hit",
+    "minecraft:block.metal.place",
+    "minecraft:block.metal_pressure_plate.click_off",
+    "minecraft:block.metal_pressure_plate.click_on",
+    "minecraft:block.metal.step",
+    "minecraft:entity.minecart.inside",
+    "minecraft:entity.minecart.riding",
+    "minecraft:entity.mooshroom.convert",
+    "minecraft:entity.mooshroom.eat",
+    "minecraft:entity.mooshroom.milk",
+    "minecraft:entity.mooshroom.suspicious_milk",
+    "minecraft:entity.mooshroom.shear",
+    "minecraft:entity.mule.ambient",
+    "minecraft:entity.mule.chest",
+    "minecraft:entity.mule.death",
+    "minecraft:entity.mule.hurt",
+    "minecraft:music.creative",
+    "minecraft:music.credits",
+    "minecraft:music.dragon",
+    "minecraft:music.end",
+    "minecraft:music.game",
+    "minecraft:music.menu",
+    "minecraft:music.nether",
+    "minecraft:music.under_water",
+    "minecraft:block.nether_wart.break",
+    "minecraft:item.nether_wart.plant",
+    "minecraft:block.note_block.basedrum",
+    "minecraft:block.note_block.bass",
+    "minecraft:block.note_block.bell",
+    "minecraft:block.note_block.chime",
+    "minecraft:block.note_block.flute",
+    "minecraft:block.note_block.guitar",
+    "minecraft:block.note_block.harp",
+    "minecraft:block.note_block.hat",
+    "minecraft:block.note_block.pling",
+    "minecraft:block.note_block.snare",
+    "minecraft:block.note_block.xylophone",
+    "minecraft:block.note_block.iron_xylophone",
+    "minecraft:block.note_block.cow_bell",
+    "minecraft:block.note_block.didgeridoo",
+    "minecraft:block.note_block.bit",
+    "minecraft:block.note_block.banjo",
+    "minecraft:entity.ocelot.hurt",
+    "minecraft:entity.ocelot.ambient",
+    "minecraft:entity.ocelot.death",
+    "minecraft:entity.painting.break",
+    "minecraft:entity.painting.place",
+    "minecraft:entity.panda.pre_sneeze",
+    "minecraft:entity.panda.sneeze",
+    "minecraft:entity.panda.ambient",
+    "minecraft:entity.panda.death",
+    "minecraft:entity.panda.eat",
+    "minecraft:entity.panda.step",
+    "minecraft:entity.panda.cant_breed",
+    "minecraft:entity.panda.aggressive_ambient",
+    "minecraft:entity.panda.worried_ambient",
+    "minecraft:entity.panda.hurt",
+    "minecraft:entity.panda.bite",
+    "minecraft:entity.parrot.ambient",
+    "minecraft:entity.parrot.death",
+    "minecraft:entity.parrot.eat",
+    "minecraft:entity.parrot.fly",
+    "minecraft:entity.parrot.hurt",
+    "minecraft:entity.parrot.imitate.blaze",
+    "minecraft:entity.parrot.imitate.creeper",
+    "minecraft:entity.parrot.imitate.drowned",
+    "minecraft:entity.parrot.imitate.elder_guardian",
+    "minecraft:entity.parrot.imitate.ender_dragon",
+    "minecraft:entity.parrot.imitate.enderman",
+    "minecraft:entity.parrot.imitate.endermite",
+    "minecraft:entity.parrot.imitate.evoker",
+    "minecraft:entity.parrot.imitate.ghast",
+    "minecraft:entity.parrot.imitate.guardian",
+    "minecraft:entity.parrot.imitate.husk",
+    "minecraft:entity.parrot.imitate.illusioner",
+    "minecraft:entity.parrot.imitate.magma_cube",
+    "minecraft:entity.parrot.imitate.panda",
+    "minecraft:entity.parrot.imitate.phantom",
+    "minecraft:entity.parrot.imitate.pillager",
+    "minecraft:entity.parrot.imitate.polar_bear",
+    "minecraft:entity.parrot.imitate.ravager",
+    "minecraft:entity.parrot.imitate.shulker",
+    "minecraft:entity.parrot.imitate.silverfish",
+    "minecraft:entity.parrot.imitate.skeleton",
+    "minecraft:entity.parrot.imitate.slime",
+    "minecraft:entity.parrot.imitate.spider",
+    "minecraft:entity.parrot.imitate.stray",
+    "minecraft:entity.parrot.imitate.vex",
+    "minecraft:entity.parrot.imitate.vindicator",
+    "minecraft:entity.parrot.imitate.witch",
+    "minecraft:entity.parrot.imitate.wither",
+    "minecraft:entity.parrot.imitate.wither_skeleton",
+    "minecraft:entity.parrot.imitate.wolf",
+    "minecraft:entity.parrot.imitate.zombie",
+    "minecraft:entity.parrot.imitate.zombie_pigman",
+    "minecraft:entity.parrot.imitate.zombie_villager",
+    "minecraft:entity.parrot.step",
+    "minecraft:entity.phantom.ambient",
+    "minecraft:entity.phantom.bite",
+    "minecraft:entity.phantom.death",
+    "minecraft:entity.phantom.flap",
+    "minecraft:entity.phantom.hurt",
+    "minecraft:entity.phantom.swoop",
+    "minecraft:entity.pig.ambient",
+    "minecraft:entity.pig.death",
+    "minecraft:entity.pig.hurt",
+    "minecraft:entity.pig.saddle",
+    "minecraft:entity.pig.step",
+    "minecraft:entity.pillager.ambient",
+    "minecraft:entity.pillager.celebrate",
+    "minecraft:entity.pillager.death",
+    "minecraft:entity.pillager.hurt",
+    "minecraft:block.piston.contract",
+    "minecraft:block.piston.extend",
+    "minecraft:entity.player.attack.crit",
+    "minecraft:entity.player.attack.knockback",
+    "minecraft:entity.player.attack.nodamage",
+    "minecraft:entity.player.attack.strong",
+    "minecraft:entity.player.attack.sweep",
+    "minecraft:entity.player.attack.weak",
+    "minecraft:entity.player.big_fall",
+    "minecraft:entity.player.breath",
+    "minecraft:entity.player.burp",
+    "minecraft:entity.player.death",
+    "minecraft:entity.player.hurt",
+    "minecraft:entity.player.hurt_drown",
+    "minecraft:entity.player.hurt_on_fire",
+    "minecraft:entity.player.hurt_sweet_berry_bush",
+    "minecraft:entity.player.levelup",
+    "minecraft:entity.player.small_fall",
+    "minecraft:entity.player.splash",
+    "minecraft:entity.player.splash.high_speed",
+    "minecraft:entity.player.swim",
+    "minecraft:entity.polar_bear.ambient",
+    "minecraft:entity.polar_bear.ambient_baby",
+    "minecraft:entity.polar_bear.death",
+    "minecraft:entity.polar_bear.hurt",
+    "minecraft:entity.polar_bear.step",
+    "minecraft:entity.polar_bear.warning",
+    "minecraft:block.portal.ambient",
+    "minecraft:block.portal.travel",
+    "minecraft:block.portal.trigger",
+    "minecraft:entity.puffer_fish.ambient",
+    "minecraft:entity.puffer_fish.blow_out",
+    "minecraft:entity.puffer_fish.blow_up",
+    "minecraft:entity.puffer_fish.death",
+    "minecraft:entity.puffer_fish.flop",
+    "minecraft:entity.puffer_fish.hurt",
+    "minecraft:entity.puffer_fish.sting",
+    "minecraft:block.pumpkin.carve",
+    "minecraft:entity.rabbit.ambient",
+    "minecraft:entity.rabbit.attack",
+    "minecraft:entity.rabbit.death",
+    "minecraft:entity.rabbit.hurt",
+    "minecraft:entity.rabbit.jump",
+    "minecraft:event.raid.horn",
+    "minecraft:music_disc.11",
+    "minecraft:music_disc.13",
+    "minecraft:music_disc.blocks",
+    "minecraft:music_disc.cat",
+    "minecraft:music_disc.chirp",
+    "minecraft:music_disc.far",
+    "minecraft:music_disc.mall",
+    "minecraft:music_disc.mellohi",
+    "minecraft:music_disc.stal",
+    "minecraft:music_disc.strad",
+    "minecraft:music_disc.wait",
+    "minecraft:music_disc.ward",
+    "minecraft:block.redstone_torch.burnout",
+    "minecraft:entity.salmon.ambient",
+    "minecraft:entity.salmon.death",
+    "minecraft:entity.salmon.flop",
+    "minecraft:entity.salmon.hurt",
+    "minecraft:block.sand.break",
+    "minecraft:block.sand.fall",
+    "minecraft:block.sand.hit",
+    "minecraft:block.sand.place",
+    "minecraft:block.sand.step",
+    "minecraft:block.scaffolding.break",
+    "minecraft:block.scaffolding.fall",
+    "minecraft:block.scaffolding.hit",
+    "minecraft:block.scaffolding.place",
+    "minecraft:block.scaffolding.step",
+    "minecraft:entity.sheep.ambient",
+    "minecraft:entity.sheep.death",
+    "minecraft:entity.sheep.hurt",
+    "minecraft:entity.sheep.shear",
+    "minecraft:entity.sheep.step",
+    "minecraft:item.shield.block",
+    "minecraft:item.shield.break",
+    "minecraft:item.shovel.flatten",
+    "minecraft:entity.shulker.ambient",
+    "minecraft:block.shulker_box.close",
+    "minecraft:block.shulker_box.open",
+    "minecraft:entity.shulker_bullet.hit",
+    "minecraft:entity.shulker_bullet.hurt",
+    "minecraft:entity.shulker.close",
+    "minecraft:entity.shulker.death",
+    "minecraft:entity.shulker.hurt",
+    "minecraft:entity.shulker.hurt_closed",
+    "minecraft:entity.shulker.open",
+    "minecraft:entity.shulker.shoot",
+    "minecraft:entity.shulker.teleport",
+    "minecraft:entity.silverfish.ambient",
+    "minecraft:entity.silverfish.death",
+    "minecraft:entity.silverfish.hurt",
+    "minecraft:entity.silverfish.step",
+    "minecraft:entity.skeleton.ambient",
+    "minecraft:entity.skeleton.death",
+    "minecraft:entity.skeleton_horse.ambient",
+    "minecraft:entity.skeleton_horse.death",
+    "minecraft:entity.skeleton_horse.hurt",
+    "minecraft:entity.skeleton_horse.swim",
+    "minecraft:entity.skeleton_horse.ambient_water",
+    "minecraft:entity.skeleton_horse.gallop_water",
+    "minecraft:entity.skeleton_horse.jump_water",
+    "minecraft:entity.skeleton_horse.step_water",
+    "minecraft:entity.skeleton.hurt",
+    "minecraft:entity.skeleton.shoot",
+    "minecraft:entity.skeleton.step",
+    "minecraft:entity.slime.attack",
+    "minecraft:entity.slime.death",
+    "minecraft:entity.slime.hurt",
+    "minecraft:entity.slime.jump",
+    "minecraft:entity.slime.squish",
+    "minecraft:block.slime_block.break",
+    "minecraft:block.slime_block.fall",
+    "minecraft:block.slime_block.hit",
+    "minecraft:block.slime_block.place",
+    "minecraft:block.slime_block.step",
+    "minecraft:entity.magma_cube.death_small",
+    "minecraft:entity.magma_cube.hurt_small",
+    "minecraft:entity.magma_cube.squish_small",
+    "minecraft:entity.slime.death_small",
+    "minecraft:entity.slime.hurt_small",
+    "minecraft:entity.slime.jump_small",
+    "minecraft:entity.slime.squish_small",
+    "minecraft:block.smoker.smoke",
+    "minecraft:entity.snowball.throw",
+    "minecraft:block.snow.break",
+    "minecraft:block.snow.fall",
+    "minecraft:entity.snow_golem.ambient",
+    "minecraft:entity.snow_golem.death",
+    "minecraft:entity.snow_golem.hurt",
+    "minecraft:entity.snow_golem.shoot",
+    "minecraft:block.snow.hit",
+    "minecraft:block.snow.place",
+    "minecraft:block.snow.step",
+    "minecraft:entity.spider.ambient",
+    "minecraft:entity.spider.death",
+    "minecraft:entity.spider.hurt",
+    "minecraft:entity.spider.step",
+    "minecraft:entity.splash_potion.break",
+    "minecraft:entity.splash_potion.throw",
+    "minecraft:entity.squid.ambient",
+    "minecraft:entity.squid.death",
+    "minecraft:entity.squid.hurt",
+    "minecraft:entity.squid.squirt",
+    "minecraft:block.stone.break",
+    "minecraft:block.stone_button.click_off",
+    "minecraft:block.stone_button.click_on",
+    "minecraft:block.stone.fall",
+    "minecraft:block.stone.hit",
+    "minecraft:block.stone.place",
+    "minecraft:block.stone_pressure_plate.click_off",
+    "minecraft:block.stone_pressure_plate.click_on",
+    "minecraft:block.stone.step",
+    "minecraft:entity.stray.ambient",
+    "minecraft:entity.stray.death",
+    "minecraft:entity.stray.hurt",
+    "minecraft:entity.stray.step",
+    "minecraft:block.sweet_berry_bush.break",
+    "minecraft:block.sweet_berry_bush.place",
+    "minecraft:item.sweet_berries.pick_from_bush",
+    "minecraft:enchant.thorns.hit",
+    "minecraft:entity.tnt.primed",
+    "minecraft:item.totem.use",
+    "minecraft:item.trident.hit",
+    "minecraft:item.trident.hit_ground",
+    "minecraft:item.trident.return",
+    "minecraft:item.trident.riptide_1",
+    "minecraft:item.trident.riptide_2",
+    "minecraft:item.trident.riptide_3",
+    "minecraft:item.trident.throw",
+    "minecraft:item.trident.thunder",
+    "minecraft:block.tripwire.attach",
+    "minecraft:block.tripwire.click_off",
+    "minecraft:block.tripwire.click_on",
+    "minecraft:block.tripwire.detach",
+    "minecraft:entity.tropical_fish.ambient",
+    "minecraft:entity.tropical_fish.death",
+    "minecraft:entity.tropical_fish.flop",
+    "minecraft:entity.tropical_fish.hurt",
+    "minecraft:entity.turtle.ambient_land",
+    "minecraft:entity.turtle.death",
+    "minecraft:entity.turtle.death_baby",
+    "minecraft:entity.turtle.egg_break",
+    "minecraft:entity.turtle.egg_crack",
+    "minecraft:entity.turtle.egg_hatch",
+    "minecraft:entity.turtle.hurt",
+    "minecraft:entity.turtle.hurt_baby",
+    "minecraft:entity.turtle.lay_egg",
+    "minecraft:entity.turtle.shamble",
+    "minecraft:entity.turtle.shamble_baby",
+    "minecraft:entity.turtle.swim",
+    "minecraft:ui.button.click",
+    "minecraft:ui.loom.select_pattern",
+    "minecraft:ui.loom.take_result",
+    "minecraft:ui.cartography_table.take_result",
+    "minecraft:ui.stonecutter.take_result",
+    "minecraft:ui.stonecutter.select_recipe",
+    "minecraft:ui.toast.challenge_complete",
+    "minecraft:ui.toast.in",
+    "minecraft:ui.toast.out",
+    "minecraft:entity.vex.ambient",
+    "minecraft:entity.vex.charge",
+    "minecraft:entity.vex.death",
+    "minecraft:entity.vex.hurt",
+    "minecraft:entity.villager.ambient",
+    "minecraft:entity.villager.celebrate",
+    "minecraft:entity.villager.death",
+    "minecraft:entity.villager.hurt",
+    "minecraft:entity.villager.no",
+    "minecraft:entity.villager.trade",
+    "minecraft:entity.villager.yes",
+    "minecraft:entity.villager.work_armorer",
+    "minecraft:entity.villager.work_butcher",
+    "minecraft:entity.villager.work_cartographer",
+    "minecraft:entity.villager.work_cleric",
+    "minecraft:entity.villager.work_farmer",
+    "minecraft:entity.villager.work_fisherman",
+    "minecraft:entity.villager.work_fletcher",
+    "minecraft:entity.villager.work_leatherworker",
+    "minecraft:entity.villager.work_librarian",
+    "minecraft:entity.villager.work_mason",
+    "minecraft:entity.villager.work_shepherd",
+    "minecraft:entity.villager.work_toolsmith",
+    "minecraft:entity.villager.work_weaponsmith",
+    "minecraft:entity.vindicator.ambient",
+    "minecraft:entity.vindicator.celebrate",
+    "minecraft:entity.vindicator.death",
+    "minecraft:entity.vindicator.hurt",
+    "minecraft:entity.wandering_trader.ambient",
+    "minecraft:entity.wandering_trader.death",
+    "minecraft:entity.wandering_trader.disappeared",
+    "minecraft:entity.wandering_trader.drink_milk",
+    "minecraft:entity.wandering_trader.drink_potion",
+    "minecraft:entity.wandering_trader.hurt",
+    "minecraft:entity.wandering_trader.no",
+    "minecraft:entity.wandering_trader.reappeared",
+    "minecraft:entity.wandering_trader.trade",
+    "minecraft:entity.wandering_trader.yes",
+    "minecraft:block.water.ambient",
+    "minecraft:weather.rain",
+    "minecraft:weather.rain.above",
+    "minecraft:block.wet_grass.break",
+    "minecraft:block.wet_grass.fall",
+    "minecraft:block.wet_grass.hit",
+    "minecraft:block.wet_grass.place",
+    "minecraft:block.wet_grass.step",
+    "minecraft:entity.witch.ambient",
+    "minecraft:entity.witch.celebrate",
+    "minecraft:entity.witch.death",
+    "minecraft:entity.witch.drink",
+    "minecraft:entity.witch.hurt",
+    "minecraft:entity.witch.throw",
+    "minecraft:entity.wither.ambient",
+    "minecraft:entity.wither.break_block",
+    "minecraft:entity.wither.death",
+    "minecraft:entity.wither.hurt",
+    "minecraft:entity.wither.shoot",
+    "minecraft:entity.wither_skeleton.ambient",
+    "minecraft:entity.wither_skeleton.death",
+    "minecraft:entity.wither_skeleton.hurt",
+    "minecraft:entity.wither_skeleton.step",
+    "minecraft:entity.wither.spawn",
+    "minecraft:entity.wolf.ambient",
+    "minecraft:entity.wolf.death",
+    "minecraft:entity.wolf.growl",
+    "minecraft:entity.wolf.howl",
+    "minecraft:entity.wolf.hurt",
+    "minecraft:entity.wolf.pant",
+    "minecraft:entity.wolf.shake",
+    "minecraft:entity.wolf.step",
+    "minecraft:entity.wolf.whine",
+    "minecraft:block.wooden_door.close",
+    "minecraft:block.wooden_door.open",
+    "minecraft:block.wooden_trapdoor.close",
+    "minecraft:block.wooden_trapdoor.open",
+    "minecraft:block.wood.break",
+    "minecraft:block.wooden_button.click_off",
+    "minecraft:block.wooden_button.click_on",
+    "minecraft:block.wood.fall",
+    "minecraft:block.wood.hit",
+    "minecraft:block.wood.place",
+    "minecraft:block.wooden_pressure_plate.click_off",
+    "minecraft:block.wooden_pressure_plate.click_on",
+    "minecraft:block.wood.step",
+    "minecraft:entity.zombie.ambient",
+    "minecraft:entity.zombie.attack_wooden_door",
+    "minecraft:entity.zombie.attack_iron_door",
+    "minecraft:entity.zombie.break_wooden_door",
+    "minecraft:entity.zombie.converted_to_drowned",
+    "minecraft:entity.zombie.death",
+    "minecraft:entity.zombie.destroy_egg",
+    "minecraft:entity.zombie_horse.ambient",
+    "minecraft:entity.zombie_horse.death",
+    "minecraft:entity.zombie_horse.hurt",
+    "minecraft:entity.zombie.hurt",
+    "minecraft:entity.zombie.infect",
+    "minecraft:entity.zombie_pigman.ambient",
+    "minecraft:entity.zombie_pigman.angry",
+    "minecraft:entity.zombie_pigman.death",
+    "minecraft:entity.zombie_pigman.hurt",
+    "minecraft:entity.zombie.step",
+    "minecraft:entity.zombie_villager.ambient",
+    "minecraft:entity.zombie_villager.converted",
+    "minecraft:entity.zombie_villager.cure",
+    "minecraft:entity.zombie_villager.death",
+    "minecraft:entity.zombie_villager.hurt",
+    "minecraft:entity.zombie_villager.step",
+];
+
+/// The enchantments in id order
+const ENCHANTMENTS_578: &[&str] = &[
+    "minecraft:protection",
+    "minecraft:fire_protection",
+    "minecraft:feather_falling",
+    "minecraft:blast_protection",
+    "minecraft:projectile_protection",
+    "minecraft:respiration",
+    "minecraft:aqua_affinity",
+    "minecraft:thorns",
+    "minecraft:depth_strider",
+    "minecraft:frost_walker",
+    "minecraft:binding_curse",
+    "minecraft:sharpness",
+    "minecraft:smite",
+    "minecraft:bane_of_arthropods",
+    "minecraft:knockback",
+    "minecraft:fire_aspect",
+    "minecraft:looting",
+    "minecraft:sweeping",
+    "minecraft:efficiency",
+    "minecraft:silk_touch",
+    "minecraft:unbreaking",
+    "minecraft:fortune",
+    "minecraft:power",
+    "minecraft:punch",
+    "minecraft:flame",
+    "minecraft:infinity",
+    "minecraft:luck_of_the_sea",
+    "minecraft:lure",
+    "minecraft:loyalty",
+    "minecraft:impaling",
+    "minecraft:riptide",
+    "minecraft:channeling",
+    "minecraft:multishot",
+    "minecraft:quick_charge",
+    "minecraft:piercing",
+    "minecraft:mending",
+    "minecraft:vanishing_curse",
+];
+
+/// The biomes, as (id, name)
+const BIOMES_578: &[(i32, &str)] = &[
+    (0, "minecraft:ocean"),
+    (1, "minecraft:plains"),
+    (2, "minecraft:desert"),
+    (3, "minecraft:mountains"),
+    (4, "minecraft:forest"),
+    (5, "minecraft:taiga"),
+    (6, "minecraft:swamp"),
+    (7, "minecraft:river"),
+    (8, "minecraft:nether"),
+    (9, "minecraft:the_end"),
+    (10, "minecraft:frozen_ocean"),
+    (11, "minecraft:frozen_river"),
+    (12, "minecraft:snowy_tundra"),
+    (13, "minecraft:snowy_mountains"),
+    (14, "minecraft:mushroom_fields"),
+    (15, "minecraft:mushroom_field_shore"),
+    (16, "minecraft:beach"),
+    (17, "minecraft:desert_hills"),
+    (18, "minecraft:wooded_hills"),
+    (19, "minecraft:taiga_hills"),
+    (20, "minecraft:mountain_edge"),
+    (21, "minecraft:jungle"),
+    (22, "minecraft:jungle_hills"),
+    (23, "minecraft:jungle_edge"),
+    (24, "minecraft:deep_ocean"),
+    (25, "minecraft:stone_shore"),
+    (26, "minecraft:snowy_beach"),
+    (27, "minecraft:birch_forest"),
+    (28, "minecraft:birch_forest_hills"),
+    (29, "minecraft:dark_forest"),
+    (30, "minecraft:snowy_taiga"),
+    (31, "minecraft:snowy_taiga_hills"),
+    (32, "minecraft:giant_tree_taiga"),
+    (33, "minecraft:giant_tree_taiga_hills"),
+    (34, "minecraft:wooded_mountains"),
+    (35, "minecraft:savanna"),
+    (36, "minecraft:savanna_plateau"),
+    (37, "minecraft:badlands"),
+    (38, "minecraft:wooded_badlands_plateau"),
+    (39, "minecraft:badlands_plateau"),
+    (40, "minecraft:small_end_islands"),
+    (41, "minecraft:end_midlands"),
+    (42, "minecraft:end_highlands"),
+    (43, "minecraft:end_barrens"),
+    (44, "minecraft:warm_ocean"),
+    (45, "minecraft:lukewarm_ocean"),
+    (46, "minecraft:cold_ocean"),
+    (47, "minecraft:deep_warm_ocean"),
+    (48, "minecraft:deep_lukewarm_ocean"),
+    (49, "minecraft:deep_cold_ocean"),
+    (50, "minecraft:deep_frozen_ocean"),
+    (127, "minecraft:the_void"),
+    (129, "minecraft:sunflower_plains"),
+    (130, "minecraft:desert_lakes"),
+    (131, "minecraft:gravelly_mountains"),
+    (132, "minecraft:flower_forest"),
+    (133, "minecraft:taiga_mountains"),
+    (134, "minecraft:swamp_hills"),
+    (140, "minecraft:ice_spikes"),
+    (149, "minecraft:modified_jungle"),
+    (151, "minecraft:modified_jungle_edge"),
+    (155, "minecraft:tall_birch_forest"),
+    (156, "minecraft:tall_birch_hills"),
+    (157, "minecraft:dark_forest_hills"),
+    (158, "minecraft:snowy_taiga_mountains"),
+    (160, "minecraft:giant_spruce_taiga"),
+    (161, "minecraft:giant_spruce_taiga_hills"),
+    (162, "minecraft:modified_gravelly_mountains"),
+    (163, "minecraft:shattered_savanna"),
+    (164, "minecraft:shattered_savanna_plateau"),
+    (165, "minecraft:eroded_badlands"),
+    (166, "minecraft:modified_wooded_badlands_plateau"),
+    (167, "minecraft:modified_badlands_plateau"),
+    (168, "minecraft:bamboo_jungle"),
+    (169, "minecraft:bamboo_jungle_hills"),
+];
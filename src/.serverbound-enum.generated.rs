@@ -376,4 +376,420 @@ impl fmt::Display for ServerboundPacket {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ServerboundPacket of type {}", self.get_packet_name())
     }
+}
+impl PacketVariant<ServerboundPacket> for Handshake {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::Handshake(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for StatusRequest {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::StatusRequest(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for StatusPing {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::StatusPing(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for LoginStart {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::LoginStart(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for EncryptionResponse {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::EncryptionResponse(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for LoginPluginResponse {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::LoginPluginResponse(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for TeleportConfirm {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::TeleportConfirm(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for QueryBlockNBT {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::QueryBlockNBT(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for SetDifficulty {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::SetDifficulty(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for ChatMessage {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::ChatMessage(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for ClientStatus {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::ClientStatus(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for ClientSettings {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::ClientSettings(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for TabComplete {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::TabComplete(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for ConfirmTransaction {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::ConfirmTransaction(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for EnchantItem {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::EnchantItem(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for ClickWindow {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::ClickWindow(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for CloseWindow {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::CloseWindow(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for PluginMessage {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::PluginMessage(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for EditBook {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::EditBook(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for QueryEntityNBT {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::QueryEntityNBT(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for UseEntity {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::UseEntity(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for KeepAlive {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::KeepAlive(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for LockDifficulty {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::LockDifficulty(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for PlayerPosition {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::PlayerPosition(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for PlayerPositionAndLook {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::PlayerPositionAndLook(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for PlayerLook {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::PlayerLook(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for Player {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::Player(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for VehicleMove {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::VehicleMove(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for SteerBoat {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::SteerBoat(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for PickItem {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::PickItem(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for CraftRecipeRequest {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::CraftRecipeRequest(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for PlayerAbilities {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::PlayerAbilities(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for PlayerDigging {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::PlayerDigging(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for EntityAction {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::EntityAction(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for SteerVehicle {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::SteerVehicle(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for RecipeBookData {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::RecipeBookData(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for NameItem {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::NameItem(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for ResourcePackStatus {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::ResourcePackStatus(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for AdvancementTab {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::AdvancementTab(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for SelectTrade {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::SelectTrade(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for SetBeaconEffect {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::SetBeaconEffect(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for HeldItemChange {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::HeldItemChange(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for UpdateCommandBlock {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::UpdateCommandBlock(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for UpdateCommandBlockMinecart {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::UpdateCommandBlockMinecart(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for CreativeInventoryAction {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::CreativeInventoryAction(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for UpdateJigsawBlock {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::UpdateJigsawBlock(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for UpdateStructureBlock {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::UpdateStructureBlock(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for UpdateSign {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::UpdateSign(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for Animation {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::Animation(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for Spectate {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::Spectate(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for PlayerBlockPlacement {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::PlayerBlockPlacement(ref x) => Some(x),
+            _ => None,
+        }
+    }
+}
+impl PacketVariant<ServerboundPacket> for UseItem {
+    fn from_packet(packet: &ServerboundPacket) -> Option<&Self> {
+        match *packet {
+            ServerboundPacket::UseItem(ref x) => Some(x),
+            _ => None,
+        }
+    }
 }
\ No newline at end of file
@@ -1245,7 +1245,7 @@ impl PickItem {
 #[derive(Debug, PartialEq, Clone)]
 pub struct CraftRecipeRequest {
     window_id: u8,
-    recipe: i32,
+    recipe: String,
     make_all: bool,
 }
 
@@ -1255,7 +1255,7 @@ impl CraftRecipeRequest {
     pub fn deserialize<R: Read>(r: &mut R) -> Result<ServerboundPacket> {
         Ok(ServerboundPacket::CraftRecipeRequest(CraftRecipeRequest {
             window_id: read_u8(r).chain_err(|| "while reading field window_id")?,
-            recipe: read_varint(r).chain_err(|| "while reading field recipe")?,
+            recipe: read_String(r).chain_err(|| "while reading field recipe")?,
             make_all: read_bool(r).chain_err(|| "while reading field make_all")?,
 
         }))
@@ -1265,12 +1265,12 @@ impl CraftRecipeRequest {
         let mut ret = Vec::new();
         write_varint(&Self::PACKET_ID, &mut ret)?;
         write_u8(&self.window_id, &mut ret)?;
-        write_varint(&self.recipe, &mut ret)?;
+        write_String(&self.recipe, &mut ret)?;
         write_bool(&self.make_all, &mut ret)?;
 
         Ok(ret)
     }
-    pub fn new(window_id: u8, recipe: i32, make_all: bool) -> ServerboundPacket {
+    pub fn new(window_id: u8, recipe: String, make_all: bool) -> ServerboundPacket {
         ServerboundPacket::CraftRecipeRequest(CraftRecipeRequest {
             window_id: window_id,
             recipe: recipe,
@@ -1280,8 +1280,8 @@ impl CraftRecipeRequest {
     /// Get the window ID
     pub fn get_window_id(&self) -> &u8 {
         &self.window_id
-    }    /// Get the recipe ID
-    pub fn get_recipe(&self) -> &i32 {
+    }    /// Get the recipe identifier
+    pub fn get_recipe(&self) -> &String {
         &self.recipe
     }    /// Get if shift was down when the item was clicked
     pub fn get_make_all(&self) -> &bool {
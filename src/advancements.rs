@@ -0,0 +1,399 @@
+//! The advancements and advancement progress sent in the Advancements packet
+//!
+//! Each Advancements packet contains a set of advancements to add, a set to
+//! remove, and the player's progress on some of them. `AdvancementTracker`
+//! applies successive packets to keep track of the current state.
+
+use errors::Result;
+use read::*;
+use slot::*;
+use write::*;
+
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+
+/// The progress on a single advancement, mapping each criterion to the time
+/// it was achieved (in milliseconds since the epoch), or None if it has not
+/// yet been achieved
+pub type AdvancementProgress = BTreeMap<String, Option<i64>>;
+
+/// The decoded contents of an Advancements packet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AdvancementsData {
+    /// Whether the client should clear all advancements before applying this
+    pub reset: bool,
+    /// The advancements to add, by identifier
+    pub advancements: BTreeMap<String, Advancement>,
+    /// The identifiers of the advancements to remove
+    pub removed: Vec<String>,
+    /// The updated progress, by advancement identifier
+    pub progress: BTreeMap<String, AdvancementProgress>,
+}
+
+/// A single advancement
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Advancement {
+    /// The identifier of the parent advancement, if any
+    pub parent: Option<String>,
+    /// How the advancement is displayed, None for hidden advancements such as
+    /// recipe unlocks
+    pub display: Option<AdvancementDisplay>,
+    /// The names of the criteria
+    pub criteria: Vec<String>,
+    /// The requirements, the advancement is done when at least one criterion
+    /// of each requirement has been achieved
+    pub requirements: Vec<Vec<String>>,
+}
+
+/// How an advancement is displayed
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdvancementDisplay {
+    /// The title, as a json chat message
+    pub title: String,
+    /// The description, as a json chat message
+    pub description: String,
+    /// The item used as the icon
+    pub icon: Option<Slot>,
+    /// The frame shown around the icon
+    pub frame: FrameType,
+    /// The background texture, only given for root advancements
+    pub background: Option<String>,
+    /// Whether a toast is shown when the advancement is achieved
+    pub show_toast: bool,
+    /// Whether the advancement is hidden until achieved
+    pub hidden: bool,
+    /// The x coordinate in the advancement tab
+    pub x: f32,
+    /// The y coordinate in the advancement tab
+    pub y: f32,
+}
+
+/// The type of frame around an advancement's icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameType {
+    Task,
+    Challenge,
+    Goal,
+}
+impl FrameType {
+    fn from_i32(val: i32) -> Result<Self> {
+        Ok(match val {
+            0 => FrameType::Task,
+            1 => FrameType::Challenge,
+            2 => FrameType::Goal,
+            _ => bail!("Advancement has invalid frame type {}", val),
+        })
+    }
+
+    fn to_i32(self) -> i32 {
+        match self {
+            FrameType::Task => 0,
+            FrameType::Challenge => 1,
+            FrameType::Goal => 2,
+        }
+    }
+}
+
+impl AdvancementsData {
+    /// Decode the raw data of an Advancements packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let reset = read_bool(&mut r)?;
+
+        let count = read_varint(&mut r)?;
+        if count < 0 {
+            bail!("Advancements had negative advancement count {}", count);
+        }
+        let mut advancements = BTreeMap::new();
+        for _ in 0..count {
+            let id = read_String(&mut r)?;
+            let _ = advancements.insert(id, Advancement::read(&mut r)?);
+        }
+
+        let count = read_varint(&mut r)?;
+        if count < 0 {
+            bail!("Advancements had negative removed count {}", count);
+        }
+        let mut removed = Vec::new();
+        for _ in 0..count {
+            removed.push(read_String(&mut r)?);
+        }
+
+        let count = read_varint(&mut r)?;
+        if count < 0 {
+            bail!("Advancements had negative progress count {}", count);
+        }
+        let mut progress = BTreeMap::new();
+        for _ in 0..count {
+            let id = read_String(&mut r)?;
+            let criteria_count = read_varint(&mut r)?;
+            if criteria_count < 0 {
+                bail!("Advancement {} had negative criteria count {}", id, criteria_count);
+            }
+            let mut criteria = BTreeMap::new();
+            for _ in 0..criteria_count {
+                let criterion = read_String(&mut r)?;
+                let achieved = if read_bool(&mut r)? {
+                    Some(read_i64(&mut r)?)
+                } else {
+                    None
+                };
+                let _ = criteria.insert(criterion, achieved);
+            }
+            let _ = progress.insert(id, criteria);
+        }
+
+        Ok(AdvancementsData {
+            reset,
+            advancements,
+            removed,
+            progress,
+        })
+    }
+
+    /// Encode into the raw data of an Advancements packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_bool(&self.reset, &mut ret)?;
+
+        write_varint(&(self.advancements.len() as i32), &mut ret)?;
+        for (id, advancement) in &self.advancements {
+            write_String(id, &mut ret)?;
+            advancement.write(&mut ret)?;
+        }
+
+        write_varint(&(self.removed.len() as i32), &mut ret)?;
+        for id in &self.removed {
+            write_String(id, &mut ret)?;
+        }
+
+        write_varint(&(self.progress.len() as i32), &mut ret)?;
+        for (id, criteria) in &self.progress {
+            write_String(id, &mut ret)?;
+            write_varint(&(criteria.len() as i32), &mut ret)?;
+            for (criterion, achieved) in criteria {
+                write_String(criterion, &mut ret)?;
+                write_bool(&achieved.is_some(), &mut ret)?;
+                if let Some(x) = *achieved {
+                    write_i64(&x, &mut ret)?;
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl Advancement {
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        let parent = if read_bool(r)? {
+            Some(read_String(r)?)
+        } else {
+            None
+        };
+        let display = if read_bool(r)? {
+            Some(AdvancementDisplay::read(r)?)
+        } else {
+            None
+        };
+
+        let count = read_varint(r)?;
+        if count < 0 {
+            bail!("Advancement had negative criteria count {}", count);
+        }
+        let mut criteria = Vec::new();
+        for _ in 0..count {
+            /* The value of each criterion is always empty */
+            criteria.push(read_String(r)?);
+        }
+
+        let count = read_varint(r)?;
+        if count < 0 {
+            bail!("Advancement had negative requirement count {}", count);
+        }
+        let mut requirements = Vec::new();
+        for _ in 0..count {
+            let inner_count = read_varint(r)?;
+            if inner_count < 0 {
+                bail!("Advancement had negative requirement length {}", inner_count);
+            }
+            let mut requirement = Vec::new();
+            for _ in 0..inner_count {
+                requirement.push(read_String(r)?);
+            }
+            requirements.push(requirement);
+        }
+
+        Ok(Advancement {
+            parent,
+            display,
+            criteria,
+            requirements,
+        })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_bool(&self.parent.is_some(), w)?;
+        if let Some(ref x) = self.parent {
+            write_String(x, w)?;
+        }
+        write_bool(&self.display.is_some(), w)?;
+        if let Some(ref x) = self.display {
+            x.write(w)?;
+        }
+        write_varint(&(self.criteria.len() as i32), w)?;
+        for x in &self.criteria {
+            write_String(x, w)?;
+        }
+        write_varint(&(self.requirements.len() as i32), w)?;
+        for requirement in &self.requirements {
+            write_varint(&(requirement.len() as i32), w)?;
+            for x in requirement {
+                write_String(x, w)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check whether the advancement is done given its progress, i.e.
+    /// whether at least one criterion of each requirement has been achieved.
+    ///
+    /// An advancement without requirements is never done, same as vanilla.
+    pub fn is_done(&self, progress: &AdvancementProgress) -> bool {
+        !self.requirements.is_empty() &&
+        self.requirements.iter().all(|requirement| {
+            requirement.iter().any(|x| progress.get(x).is_some_and(|x| x.is_some()))
+        })
+    }
+}
+
+impl AdvancementDisplay {
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        let title = read_String(r)?;
+        let description = read_String(r)?;
+        let icon = read_slot(r)?;
+        let frame = FrameType::from_i32(read_varint(r)?)?;
+        let flags = read_i32(r)?;
+        let background = if flags & 0x01 != 0 {
+            Some(read_String(r)?)
+        } else {
+            None
+        };
+        Ok(AdvancementDisplay {
+            title,
+            description,
+            icon,
+            frame,
+            background,
+            show_toast: flags & 0x02 != 0,
+            hidden: flags & 0x04 != 0,
+            x: read_f32(r)?,
+            y: read_f32(r)?,
+        })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_String(&self.title, w)?;
+        write_String(&self.description, w)?;
+        write_slot(&self.icon, w)?;
+        write_varint(&self.frame.to_i32(), w)?;
+        let mut flags = 0;
+        if self.background.is_some() {
+            flags |= 0x01;
+        }
+        if self.show_toast {
+            flags |= 0x02;
+        }
+        if self.hidden {
+            flags |= 0x04;
+        }
+        write_i32(&flags, w)?;
+        if let Some(ref x) = self.background {
+            write_String(x, w)?;
+        }
+        write_f32(&self.x, w)?;
+        write_f32(&self.y, w)
+    }
+}
+
+/// Keeps track of the advancements and the player's progress on them, by
+/// applying successive Advancements packets
+#[derive(Debug, Clone, Default)]
+pub struct AdvancementTracker {
+    advancements: BTreeMap<String, Advancement>,
+    progress: BTreeMap<String, AdvancementProgress>,
+}
+impl AdvancementTracker {
+    /// Create a new, empty tracker
+    pub fn new() -> Self {
+        AdvancementTracker::default()
+    }
+
+    /// Apply the contents of an Advancements packet.
+    ///
+    /// Returns the identifiers of the advancements that became done as a
+    /// result of this update.
+    pub fn apply(&mut self, data: &AdvancementsData) -> Vec<String> {
+        let previously_done: Vec<String> = if data.reset {
+            self.advancements.clear();
+            self.progress.clear();
+            Vec::new()
+        } else {
+            self.get_done().into_iter().map(|x| x.to_string()).collect()
+        };
+
+        for id in &data.removed {
+            let _ = self.advancements.remove(id);
+            let _ = self.progress.remove(id);
+        }
+        for (id, advancement) in &data.advancements {
+            let _ = self.advancements.insert(id.clone(), advancement.clone());
+        }
+        for (id, progress) in &data.progress {
+            let _ = self.progress.insert(id.clone(), progress.clone());
+        }
+
+        self.get_done()
+            .into_iter()
+            .filter(|x| !previously_done.iter().any(|y| y == x))
+            .map(|x| x.to_string())
+            .collect()
+    }
+
+    /// Get the advancement with the given identifier, if known
+    pub fn get_advancement(&self, id: &str) -> Option<&Advancement> {
+        self.advancements.get(id)
+    }
+
+    /// Get all the known advancements
+    pub fn get_advancements(&self) -> &BTreeMap<String, Advancement> {
+        &self.advancements
+    }
+
+    /// Get the player's progress on the given advancement, if any
+    pub fn get_progress(&self, id: &str) -> Option<&AdvancementProgress> {
+        self.progress.get(id)
+    }
+
+    /// Get the time the given criterion of the given advancement was
+    /// achieved, or None if it hasn't been achieved
+    pub fn get_criterion_time(&self, id: &str, criterion: &str) -> Option<i64> {
+        self.progress.get(id).and_then(|x| x.get(criterion)).and_then(|x| *x)
+    }
+
+    /// Check whether the given advancement is done
+    pub fn is_done(&self, id: &str) -> bool {
+        match (self.advancements.get(id), self.progress.get(id)) {
+            (Some(advancement), Some(progress)) => advancement.is_done(progress),
+            _ => false,
+        }
+    }
+
+    /// Get the identifiers of all the advancements that are done
+    pub fn get_done(&self) -> Vec<&str> {
+        self.advancements
+            .keys()
+            .filter(|x| self.is_done(x))
+            .map(|x| x.as_str())
+            .collect()
+    }
+}
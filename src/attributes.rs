@@ -0,0 +1,163 @@
+//! Entity attributes, as sent in the EntityProperties packet
+//!
+//! Each attribute (e.g. `generic.movementSpeed`) has a base value and a list
+//! of modifiers applied to it by things such as potions, sprinting and
+//! equipment. `EntityProperty::get_effective_value` applies the modifiers the
+//! same way vanilla does.
+
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::io::{Cursor, Read};
+
+/// The UUID of the modifier added to `generic.movementSpeed` while sprinting
+pub const SPRINTING_SPEED_BOOST_UUID: u128 = 0x662a6b8dda3e4c1c881396ea6097278d;
+
+/// A single attribute of an entity
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityProperty {
+    /// The attribute key, e.g. `generic.movementSpeed`
+    pub key: String,
+    /// The base value of the attribute
+    pub value: f64,
+    /// The modifiers applied to the base value
+    pub modifiers: Vec<AttributeModifier>,
+}
+
+/// A modifier of an attribute
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifier {
+    /// The UUID identifying the modifier
+    pub uuid: u128,
+    /// The amount used by the operation
+    pub amount: f64,
+    /// How the amount is applied
+    pub operation: ModifierOperation,
+}
+
+/// How an attribute modifier's amount is applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifierOperation {
+    /// Operation 0, the amount is added to the base value
+    Add,
+    /// Operation 1, the value after the Add modifiers multiplied by the
+    /// amount is added
+    MultiplyBase,
+    /// Operation 2, the value is multiplied by 1 + amount
+    MultiplyTotal,
+}
+impl ModifierOperation {
+    fn from_u8(val: u8) -> Result<Self> {
+        Ok(match val {
+            0 => ModifierOperation::Add,
+            1 => ModifierOperation::MultiplyBase,
+            2 => ModifierOperation::MultiplyTotal,
+            _ => bail!("Attribute modifier has invalid operation {}", val),
+        })
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            ModifierOperation::Add => 0,
+            ModifierOperation::MultiplyBase => 1,
+            ModifierOperation::MultiplyTotal => 2,
+        }
+    }
+}
+
+impl EntityProperty {
+    /// Create a new property without modifiers
+    pub fn new(key: &str, value: f64) -> Self {
+        EntityProperty {
+            key: key.to_string(),
+            value,
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Decode the properties in the raw data of an EntityProperties packet
+    pub fn decode_list(data: &[u8]) -> Result<Vec<EntityProperty>> {
+        let mut r = Cursor::new(data);
+        let count = read_i32(&mut r)?;
+        if count < 0 {
+            bail!("EntityProperties had negative property count {}", count);
+        }
+        let mut ret = Vec::new();
+        for _ in 0..count {
+            ret.push(EntityProperty::read(&mut r)?);
+        }
+        Ok(ret)
+    }
+
+    /// Encode the properties into the raw data of an EntityProperties packet
+    pub fn encode_list(properties: &[EntityProperty]) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_i32(&(properties.len() as i32), &mut ret)?;
+        for property in properties {
+            write_String(&property.key, &mut ret)?;
+            write_f64(&property.value, &mut ret)?;
+            write_varint(&(property.modifiers.len() as i32), &mut ret)?;
+            for modifier in &property.modifiers {
+                write_u128(&modifier.uuid, &mut ret)?;
+                write_f64(&modifier.amount, &mut ret)?;
+                write_u8(&modifier.operation.to_u8(), &mut ret)?;
+            }
+        }
+        Ok(ret)
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        let key = read_String(r)?;
+        let value = read_f64(r)?;
+        let count = read_varint(r)?;
+        if count < 0 {
+            bail!("Attribute {} had negative modifier count {}", key, count);
+        }
+        let mut modifiers = Vec::new();
+        for _ in 0..count {
+            modifiers.push(AttributeModifier {
+                uuid: read_u128(r)?,
+                amount: read_f64(r)?,
+                operation: ModifierOperation::from_u8(read_u8(r)?)?,
+            });
+        }
+        Ok(EntityProperty {
+            key,
+            value,
+            modifiers,
+        })
+    }
+
+    /// Get the modifier with the given UUID, if any
+    pub fn get_modifier(&self, uuid: u128) -> Option<&AttributeModifier> {
+        self.modifiers.iter().find(|x| x.uuid == uuid)
+    }
+
+    /// Compute the value of the attribute after applying all the modifiers.
+    ///
+    /// Like vanilla, first all Add modifiers are added to the base value,
+    /// then the MultiplyBase modifiers each add a multiple of that, and
+    /// finally the MultiplyTotal modifiers are each multiplied in. The
+    /// result is not clamped to the attribute's valid range, since those
+    /// ranges are not sent by the server.
+    pub fn get_effective_value(&self) -> f64 {
+        let base = self.value +
+                   self.get_amounts(ModifierOperation::Add).sum::<f64>();
+        let mut ret = base;
+        for amount in self.get_amounts(ModifierOperation::MultiplyBase) {
+            ret += base * amount;
+        }
+        for amount in self.get_amounts(ModifierOperation::MultiplyTotal) {
+            ret *= 1.0 + amount;
+        }
+        ret
+    }
+
+    fn get_amounts<'a>(&'a self, operation: ModifierOperation) -> impl Iterator<Item = f64> + 'a {
+        self.modifiers
+            .iter()
+            .filter(move |x| x.operation == operation)
+            .map(|x| x.amount)
+    }
+}
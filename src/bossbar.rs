@@ -0,0 +1,282 @@
+//! Boss bars, as sent in the BossBar packet
+//!
+//! Each BossBar packet performs a single action on the boss bar with the
+//! given UUID. `BossBarTracker` applies the packets to keep track of the bars
+//! currently shown.
+
+use clientbound::BossBar;
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+/// The color of a boss bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossBarColor {
+    /// Color 0, pink
+    Pink,
+    /// Color 1, blue
+    Blue,
+    /// Color 2, red
+    Red,
+    /// Color 3, green
+    Green,
+    /// Color 4, yellow
+    Yellow,
+    /// Color 5, purple
+    Purple,
+    /// Color 6, white
+    White,
+}
+impl BossBarColor {
+    fn from_i32(val: i32) -> Result<Self> {
+        Ok(match val {
+            0 => BossBarColor::Pink,
+            1 => BossBarColor::Blue,
+            2 => BossBarColor::Red,
+            3 => BossBarColor::Green,
+            4 => BossBarColor::Yellow,
+            5 => BossBarColor::Purple,
+            6 => BossBarColor::White,
+            _ => bail!("BossBar had invalid color {}", val),
+        })
+    }
+
+    fn to_i32(self) -> i32 {
+        match self {
+            BossBarColor::Pink => 0,
+            BossBarColor::Blue => 1,
+            BossBarColor::Red => 2,
+            BossBarColor::Green => 3,
+            BossBarColor::Yellow => 4,
+            BossBarColor::Purple => 5,
+            BossBarColor::White => 6,
+        }
+    }
+}
+
+/// The number of notches a boss bar is divided into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossBarDivision {
+    /// Division 0, a solid bar
+    None,
+    /// Division 1, 6 notches
+    Notches6,
+    /// Division 2, 10 notches
+    Notches10,
+    /// Division 3, 12 notches
+    Notches12,
+    /// Division 4, 20 notches
+    Notches20,
+}
+impl BossBarDivision {
+    fn from_i32(val: i32) -> Result<Self> {
+        Ok(match val {
+            0 => BossBarDivision::None,
+            1 => BossBarDivision::Notches6,
+            2 => BossBarDivision::Notches10,
+            3 => BossBarDivision::Notches12,
+            4 => BossBarDivision::Notches20,
+            _ => bail!("BossBar had invalid division {}", val),
+        })
+    }
+
+    fn to_i32(self) -> i32 {
+        match self {
+            BossBarDivision::None => 0,
+            BossBarDivision::Notches6 => 1,
+            BossBarDivision::Notches10 => 2,
+            BossBarDivision::Notches12 => 3,
+            BossBarDivision::Notches20 => 4,
+        }
+    }
+
+    /// Get the number of notches, 0 for None
+    pub fn get_notches(self) -> u8 {
+        match self {
+            BossBarDivision::None => 0,
+            BossBarDivision::Notches6 => 6,
+            BossBarDivision::Notches10 => 10,
+            BossBarDivision::Notches12 => 12,
+            BossBarDivision::Notches20 => 20,
+        }
+    }
+}
+
+/// The flag telling the client to darken the sky
+pub const FLAG_DARKEN_SKY: u8 = 0x01;
+/// The flag telling the client the bar belongs to the ender dragon, which
+/// plays the end music
+pub const FLAG_DRAGON_BAR: u8 = 0x02;
+/// The flag telling the client to create fog
+pub const FLAG_CREATE_FOG: u8 = 0x04;
+
+/// The action performed by a BossBar packet
+#[derive(Debug, Clone, PartialEq)]
+pub enum BossBarAction {
+    /// Action 0, show a new boss bar
+    Add(BossBarState),
+    /// Action 1, remove the boss bar
+    Remove,
+    /// Action 2, set the health, from 0 to 1
+    UpdateHealth(f32),
+    /// Action 3, set the title, as a json chat message
+    UpdateTitle(String),
+    /// Action 4, set the color and division
+    UpdateStyle(BossBarColor, BossBarDivision),
+    /// Action 5, set the raw flags
+    UpdateFlags(u8),
+}
+
+/// The full state of a boss bar
+#[derive(Debug, Clone, PartialEq)]
+pub struct BossBarState {
+    /// The title, as a json chat message
+    pub title: String,
+    /// The health, from 0 to 1
+    pub health: f32,
+    /// The color of the bar
+    pub color: BossBarColor,
+    /// The division of the bar
+    pub division: BossBarDivision,
+    /// The raw flags, see the FLAG_ constants
+    pub flags: u8,
+}
+
+/// The decoded contents of a BossBar packet
+#[derive(Debug, Clone, PartialEq)]
+pub struct BossBarUpdate {
+    /// The UUID identifying the boss bar
+    pub uuid: u128,
+    /// The action to perform on the bar
+    pub action: BossBarAction,
+}
+impl BossBarUpdate {
+    /// Decode the raw data of a BossBar packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let uuid = read_u128(&mut r)?;
+        let action = read_varint(&mut r)?;
+        let action = match action {
+            0 => {
+                BossBarAction::Add(BossBarState {
+                    title: read_String(&mut r)?,
+                    health: read_f32(&mut r)?,
+                    color: BossBarColor::from_i32(read_varint(&mut r)?)?,
+                    division: BossBarDivision::from_i32(read_varint(&mut r)?)?,
+                    flags: read_u8(&mut r)?,
+                })
+            },
+            1 => BossBarAction::Remove,
+            2 => BossBarAction::UpdateHealth(read_f32(&mut r)?),
+            3 => BossBarAction::UpdateTitle(read_String(&mut r)?),
+            4 => {
+                let color = BossBarColor::from_i32(read_varint(&mut r)?)?;
+                let division = BossBarDivision::from_i32(read_varint(&mut r)?)?;
+                BossBarAction::UpdateStyle(color, division)
+            },
+            5 => BossBarAction::UpdateFlags(read_u8(&mut r)?),
+            _ => bail!("BossBar had invalid action {}", action),
+        };
+        Ok(BossBarUpdate {
+            uuid,
+            action,
+        })
+    }
+
+    /// Encode into the raw data of a BossBar packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_u128(&self.uuid, &mut ret)?;
+        match self.action {
+            BossBarAction::Add(ref state) => {
+                write_varint(&0, &mut ret)?;
+                write_String(&state.title, &mut ret)?;
+                write_f32(&state.health, &mut ret)?;
+                write_varint(&state.color.to_i32(), &mut ret)?;
+                write_varint(&state.division.to_i32(), &mut ret)?;
+                write_u8(&state.flags, &mut ret)?;
+            },
+            BossBarAction::Remove => write_varint(&1, &mut ret)?,
+            BossBarAction::UpdateHealth(health) => {
+                write_varint(&2, &mut ret)?;
+                write_f32(&health, &mut ret)?;
+            },
+            BossBarAction::UpdateTitle(ref title) => {
+                write_varint(&3, &mut ret)?;
+                write_String(title, &mut ret)?;
+            },
+            BossBarAction::UpdateStyle(color, division) => {
+                write_varint(&4, &mut ret)?;
+                write_varint(&color.to_i32(), &mut ret)?;
+                write_varint(&division.to_i32(), &mut ret)?;
+            },
+            BossBarAction::UpdateFlags(flags) => {
+                write_varint(&5, &mut ret)?;
+                write_u8(&flags, &mut ret)?;
+            },
+        }
+        Ok(ret)
+    }
+}
+
+/// Keeps track of the boss bars currently shown
+#[derive(Debug, Clone, Default)]
+pub struct BossBarTracker {
+    bars: BTreeMap<u128, BossBarState>,
+}
+impl BossBarTracker {
+    /// Create a new tracker without any bars
+    pub fn new() -> Self {
+        BossBarTracker::default()
+    }
+
+    /// Apply a BossBar packet
+    pub fn handle_bossbar(&mut self, packet: &BossBar) -> Result<()> {
+        self.apply(packet.get_update()?)
+    }
+
+    /// Apply a decoded BossBar update
+    pub fn apply(&mut self, update: BossBarUpdate) -> Result<()> {
+        let uuid = update.uuid;
+        let action = match update.action {
+            BossBarAction::Add(state) => {
+                let _ = self.bars.insert(uuid, state);
+                return Ok(());
+            },
+            BossBarAction::Remove => {
+                let _ = self.bars.remove(&uuid);
+                return Ok(());
+            },
+            x => x,
+        };
+
+        let bar = match self.bars.get_mut(&uuid) {
+            Some(x) => x,
+            None => bail!("Tried updating unknown boss bar {:x}", uuid),
+        };
+        match action {
+            BossBarAction::UpdateHealth(health) => bar.health = health,
+            BossBarAction::UpdateTitle(title) => bar.title = title,
+            BossBarAction::UpdateStyle(color, division) => {
+                bar.color = color;
+                bar.division = division;
+            },
+            BossBarAction::UpdateFlags(flags) => bar.flags = flags,
+            BossBarAction::Add(..) | BossBarAction::Remove => (),
+        }
+        Ok(())
+    }
+
+    /// Get the boss bar with the given UUID, if shown
+    pub fn get_bar(&self, uuid: u128) -> Option<&BossBarState> {
+        self.bars.get(&uuid)
+    }
+
+    /// Get all the boss bars currently shown
+    pub fn get_bars(&self) -> &BTreeMap<u128, BossBarState> {
+        &self.bars
+    }
+}
@@ -0,0 +1,407 @@
+//! Chunk columns, as sent in the ChunkData packet
+//!
+//! A chunk column is 16x256x16 blocks, split into 16 sections of 16x16x16
+//! blocks stacked on top of each other. Only non-empty sections are sent.
+//! Each section's block states are packed into an array of longs, either as
+//! indices into a palette of the section's block states or, when the section
+//! has too many different states, directly as global block state ids.
+//!
+//! Block states are the ids from the block state registry (see the registry
+//! module), with 0 being air.
+
+use errors::Result;
+use read::*;
+use slot::read_nbt;
+use write::*;
+
+use std::io::{Cursor, Read};
+
+/// The number of sections in a chunk column
+pub const SECTIONS: usize = 16;
+
+/// The number of blocks in a section
+pub const SECTION_VOLUME: usize = 4096;
+
+/// The number of biomes in a full chunk, one for each 4x4x4 area
+pub const BIOMES: usize = 1024;
+
+/// The number of bits per block used for global block state ids
+const GLOBAL_BITS_PER_BLOCK: u8 = 14;
+
+/// The smallest number of bits per block used with a palette
+const MIN_BITS_PER_BLOCK: u8 = 4;
+
+/// The largest number of bits per block used with a palette
+const MAX_PALETTE_BITS_PER_BLOCK: u8 = 8;
+
+/// An empty, unnamed NBT compound, sent when no heightmaps are given
+const EMPTY_COMPOUND: [u8; 4] = [10, 0, 0, 0];
+
+/// The block states of a 16x16x16 section
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkSection {
+    blocks: Vec<i32>,
+}
+impl Default for ChunkSection {
+    fn default() -> Self {
+        ChunkSection { blocks: vec![0; SECTION_VOLUME] }
+    }
+}
+impl ChunkSection {
+    /// Create a new section filled with air
+    pub fn new() -> Self {
+        ChunkSection::default()
+    }
+
+    /// Get the block state at the given position relative to the section
+    /// (each coordinate 0-15), or None if the position is outside the section
+    pub fn get_block(&self, x: usize, y: usize, z: usize) -> Option<i32> {
+        get_index(x, y, z).map(|i| self.blocks[i])
+    }
+
+    /// Set the block state at the given position relative to the section
+    /// (each coordinate 0-15). Fails if the position is outside the section.
+    pub fn set_block(&mut self, x: usize, y: usize, z: usize, state: i32) -> Result<()> {
+        match get_index(x, y, z) {
+            Some(i) => self.blocks[i] = state,
+            None => bail!("Block ({}, {}, {}) is outside the section", x, y, z),
+        }
+        Ok(())
+    }
+
+    /// Get the number of blocks that are not air (block state 0)
+    pub fn get_block_count(&self) -> usize {
+        self.blocks.iter().filter(|&&x| x != 0).count()
+    }
+
+    /// Check whether the section is all air
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|&x| x == 0)
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        let _block_count = read_i16(r)?;
+        let mut bits = read_u8(r)?;
+        let palette = if bits <= MAX_PALETTE_BITS_PER_BLOCK {
+            bits = bits.max(MIN_BITS_PER_BLOCK);
+            let len = read_varint(r)?;
+            if len < 0 {
+                bail!("Chunk section has palette of negative length {}", len);
+            }
+            let mut palette = Vec::new();
+            for _ in 0..len {
+                palette.push(read_varint(r)?);
+            }
+            Some(palette)
+        } else {
+            None
+        };
+        if bits > 32 {
+            bail!("Chunk section has {} bits per block", bits);
+        }
+
+        let len = read_varint(r)?;
+        let expected = get_data_length(bits);
+        if len as usize != expected {
+            bail!("Chunk section with {} bits per block has {} longs, expected {}",
+                  bits,
+                  len,
+                  expected);
+        }
+        let mut data = Vec::with_capacity(expected);
+        for _ in 0..expected {
+            data.push(read_u64(r)?);
+        }
+
+        let mut blocks = Vec::with_capacity(SECTION_VOLUME);
+        for i in 0..SECTION_VOLUME {
+            let value = get_packed(&data, bits, i);
+            blocks.push(match palette {
+                Some(ref palette) => {
+                    match palette.get(value as usize) {
+                        Some(&x) => x,
+                        None => bail!("Chunk section has palette index {} out of {}", value, palette.len()),
+                    }
+                },
+                None => value as i32,
+            });
+        }
+        Ok(ChunkSection { blocks })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_i16(&(self.get_block_count() as i16), w)?;
+
+        let mut palette: Vec<i32> = self.blocks.clone();
+        palette.sort();
+        palette.dedup();
+        let bits = match bits_needed(palette.len().saturating_sub(1) as u64) {
+            x if x <= MAX_PALETTE_BITS_PER_BLOCK => x.max(MIN_BITS_PER_BLOCK),
+            _ => GLOBAL_BITS_PER_BLOCK,
+        };
+
+        let mut data = vec![0u64; get_data_length(bits)];
+        write_u8(&bits, w)?;
+        if bits <= MAX_PALETTE_BITS_PER_BLOCK {
+            write_varint(&(palette.len() as i32), w)?;
+            for state in &palette {
+                write_varint(state, w)?;
+            }
+            for (i, state) in self.blocks.iter().enumerate() {
+                let index = palette.binary_search(state).unwrap_or(0);
+                set_packed(&mut data, bits, i, index as u64);
+            }
+        } else {
+            for (i, &state) in self.blocks.iter().enumerate() {
+                if !(0..1 << GLOBAL_BITS_PER_BLOCK).contains(&state) {
+                    bail!("Block state {} does not fit in {} bits", state, GLOBAL_BITS_PER_BLOCK);
+                }
+                set_packed(&mut data, bits, i, state as u64);
+            }
+        }
+
+        write_varint(&(data.len() as i32), w)?;
+        for x in &data {
+            write_u64(x, w)?;
+        }
+        Ok(())
+    }
+}
+
+/// A chunk column, i.e. the decoded contents of a ChunkData packet
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkColumn {
+    /// The chunk x coordinate, i.e. the block x coordinate divided by 16
+    pub x: i32,
+    /// The chunk z coordinate, i.e. the block z coordinate divided by 16
+    pub z: i32,
+    /// Whether this is a full chunk. If not, only the sections that are Some
+    /// replace the ones already loaded.
+    pub full_chunk: bool,
+    /// The raw NBT of the heightmaps, if any
+    pub heightmaps: Option<Vec<u8>>,
+    /// The biome ids, one per 4x4x4 area. Only sent with full chunks.
+    pub biomes: Option<Vec<i32>>,
+    /// The sections from the bottom up, None for sections not sent
+    pub sections: Vec<Option<ChunkSection>>,
+    /// The raw NBT of each block entity
+    pub block_entities: Vec<Vec<u8>>,
+}
+impl ChunkColumn {
+    /// Create a new, full chunk column of only air
+    pub fn new(x: i32, z: i32) -> Self {
+        ChunkColumn {
+            x,
+            z,
+            full_chunk: true,
+            heightmaps: None,
+            biomes: Some(vec![0; BIOMES]),
+            sections: vec![None; SECTIONS],
+            block_entities: Vec::new(),
+        }
+    }
+
+    /// Decode the raw data of a ChunkData packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let x = read_i32(&mut r)?;
+        let z = read_i32(&mut r)?;
+        let full_chunk = read_bool(&mut r)?;
+        let mask = read_varint(&mut r)?;
+        let heightmaps = read_nbt(&mut r)?;
+        let biomes = if full_chunk {
+            let mut biomes = Vec::with_capacity(BIOMES);
+            for _ in 0..BIOMES {
+                biomes.push(read_i32(&mut r)?);
+            }
+            Some(biomes)
+        } else {
+            None
+        };
+
+        let section_data = read_prefixed_bytearray(&mut r)?;
+        let mut sr = Cursor::new(&section_data[..]);
+        let mut sections = Vec::with_capacity(SECTIONS);
+        for section in 0..SECTIONS {
+            if mask & (1 << section) != 0 {
+                sections.push(Some(ChunkSection::read(&mut sr)?));
+            } else {
+                sections.push(None);
+            }
+        }
+
+        let count = read_varint(&mut r)?;
+        let mut block_entities = Vec::new();
+        for _ in 0..count {
+            match read_nbt(&mut r)? {
+                Some(x) => block_entities.push(x),
+                None => bail!("ChunkData contained an empty block entity"),
+            }
+        }
+
+        Ok(ChunkColumn {
+            x,
+            z,
+            full_chunk,
+            heightmaps,
+            biomes,
+            sections,
+            block_entities,
+        })
+    }
+
+    /// Encode into the raw data of a ChunkData packet. Sections that are
+    /// all air are left out of full chunks.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        if self.sections.len() > SECTIONS {
+            bail!("Chunk column has {} sections, expected at most {}", self.sections.len(), SECTIONS);
+        }
+        let mut ret = Vec::new();
+        write_i32(&self.x, &mut ret)?;
+        write_i32(&self.z, &mut ret)?;
+        write_bool(&self.full_chunk, &mut ret)?;
+        write_varint(&self.get_primary_bit_mask(), &mut ret)?;
+        match self.heightmaps {
+            Some(ref x) => ret.extend_from_slice(x),
+            None => ret.extend_from_slice(&EMPTY_COMPOUND),
+        }
+        if self.full_chunk {
+            match self.biomes {
+                Some(ref biomes) if biomes.len() == BIOMES => {
+                    for x in biomes {
+                        write_i32(x, &mut ret)?;
+                    }
+                },
+                _ => bail!("Full chunks must have exactly {} biomes", BIOMES),
+            }
+        }
+
+        let mut section_data = Vec::new();
+        for section in self.get_sent_sections() {
+            section.write(&mut section_data)?;
+        }
+        write_prefixed_bytearray(&section_data, &mut ret)?;
+
+        write_varint(&(self.block_entities.len() as i32), &mut ret)?;
+        for x in &self.block_entities {
+            ret.extend_from_slice(x);
+        }
+        Ok(ret)
+    }
+
+    fn get_sent_sections(&self) -> impl Iterator<Item = &ChunkSection> {
+        let full_chunk = self.full_chunk;
+        self.sections
+            .iter()
+            .filter_map(|x| x.as_ref())
+            .filter(move |x| !full_chunk || !x.is_empty())
+    }
+
+    /// Get the mask of the sections that are sent
+    pub fn get_primary_bit_mask(&self) -> i32 {
+        self.sections
+            .iter()
+            .enumerate()
+            .filter(|&(_, x)| match *x {
+                Some(ref x) => !self.full_chunk || !x.is_empty(),
+                None => false,
+            })
+            .fold(0, |mask, (section, _)| mask | (1 << section))
+    }
+
+    /// Get the block state at the given position, with x and z relative to
+    /// the chunk (0-15) and y absolute. Blocks in missing sections are air,
+    /// and blocks outside the column are None.
+    pub fn get_block(&self, x: usize, y: i32, z: usize) -> Option<i32> {
+        if x >= 16 || z >= 16 || y < 0 || y >= (SECTIONS * 16) as i32 {
+            return None;
+        }
+        let y = y as usize;
+        match self.sections.get(y / 16).and_then(|x| x.as_ref()) {
+            Some(section) => section.get_block(x, y % 16, z),
+            None => Some(0),
+        }
+    }
+
+    /// Set the block state at the given position, with x and z relative to
+    /// the chunk (0-15) and y absolute, adding the section if missing. Fails
+    /// if the position is outside the column.
+    pub fn set_block(&mut self, x: usize, y: i32, z: usize, state: i32) -> Result<()> {
+        if x >= 16 || z >= 16 || y < 0 || y >= (SECTIONS * 16) as i32 {
+            bail!("Block ({}, {}, {}) is outside the chunk column", x, y, z);
+        }
+        let y = y as usize;
+        if self.sections.len() < SECTIONS {
+            self.sections.resize(SECTIONS, None);
+        }
+        self.sections[y / 16]
+            .get_or_insert_with(ChunkSection::new)
+            .set_block(x, y % 16, z, state)
+    }
+
+    /// Apply a chunk sent for the same position. A full chunk replaces this
+    /// one, otherwise only the sent sections are replaced.
+    pub fn merge(&mut self, other: ChunkColumn) {
+        if other.full_chunk {
+            *self = other;
+            return;
+        }
+        if self.sections.len() < SECTIONS {
+            self.sections.resize(SECTIONS, None);
+        }
+        for (i, section) in other.sections.into_iter().enumerate() {
+            if let Some(section) = section {
+                if i < SECTIONS {
+                    self.sections[i] = Some(section);
+                }
+            }
+        }
+        if other.heightmaps.is_some() {
+            self.heightmaps = other.heightmaps;
+        }
+        self.block_entities.extend(other.block_entities);
+    }
+}
+
+fn get_index(x: usize, y: usize, z: usize) -> Option<usize> {
+    if x < 16 && y < 16 && z < 16 {
+        Some(y * 256 + z * 16 + x)
+    } else {
+        None
+    }
+}
+
+/// Get the number of bits needed to represent the given value
+fn bits_needed(val: u64) -> u8 {
+    (64 - val.leading_zeros()) as u8
+}
+
+/// Get the number of longs needed to store a section with the given number
+/// of bits per block
+fn get_data_length(bits: u8) -> usize {
+    (SECTION_VOLUME * bits as usize).div_ceil(64)
+}
+
+/// Get the i'th value from the packed array. Values may span two longs.
+fn get_packed(data: &[u64], bits: u8, i: usize) -> u64 {
+    let bits = bits as usize;
+    let mask = (1u64 << bits) - 1;
+    let start = i * bits;
+    let (long, offset) = (start / 64, start % 64);
+    let mut value = data[long] >> offset;
+    if offset + bits > 64 {
+        value |= data[long + 1] << (64 - offset);
+    }
+    value & mask
+}
+
+/// Set the i'th value of the packed array, which must currently be 0
+fn set_packed(data: &mut [u64], bits: u8, i: usize, value: u64) {
+    let bits = bits as usize;
+    let start = i * bits;
+    let (long, offset) = (start / 64, start % 64);
+    data[long] |= value << offset;
+    if offset + bits > 64 {
+        data[long + 1] |= value >> (64 - offset);
+    }
+}
@@ -1,7 +1,10 @@
 use clientbound::ClientboundPacket;
 use connection::Connection;
 use errors::Result;
+use forge::{FML_MARKER, ForgeHandshake};
 use json::AuthenticationResponse;
+use plugin::{Brand, LoginPluginHandler, LoginPluginRegistry, LoginWrapper,
+             PluginChannelRouter, PluginCodec, PluginMessageHandler};
 use serverbound::ServerboundPacket;
 use {ClientState, PROTOCOL_VERSION, mojang, serverbound, utils};
 
@@ -14,6 +17,8 @@ pub struct Client {
     conn: Connection<ClientboundPacket, ServerboundPacket>,
     auto_handle: bool,
     hide_handled: bool,
+    login_plugins: LoginPluginRegistry,
+    plugin_channels: PluginChannelRouter,
 }
 impl Client {
     /// Attempt open the tcp connection to the given host and port, and
@@ -24,6 +29,8 @@ impl Client {
                conn: Connection::connect_tcp(host, port)?,
                auto_handle: false,
                hide_handled: false,
+               login_plugins: LoginPluginRegistry::new(),
+               plugin_channels: PluginChannelRouter::new(),
            })
     }
 
@@ -33,6 +40,8 @@ impl Client {
             conn: Connection::from_tcpstream(stream)?,
             auto_handle: false,
             hide_handled: false,
+            login_plugins: LoginPluginRegistry::new(),
+            plugin_channels: PluginChannelRouter::new(),
         })
     }
 
@@ -59,13 +68,56 @@ impl Client {
                                    port: u16,
                                    username: &str)
                                    -> Result<Self> {
+        Client::connect_unauthenticated_with_plugins(host,
+                                                     port,
+                                                     username,
+                                                     LoginPluginRegistry::new())
+    }
+
+    /// The same as connect_unauthenticated, but with the given login plugin
+    /// channel handlers, which will be used to respond to any
+    /// LoginPluginRequests the server sends while logging in.
+    pub fn connect_unauthenticated_with_plugins(host: &str,
+                                                port: u16,
+                                                username: &str,
+                                                login_plugins: LoginPluginRegistry)
+                                                -> Result<Self> {
+        Client::login_unauthenticated(host, port, host, username, login_plugins)
+    }
+
+    /// The same as connect_unauthenticated, but telling the server that this
+    /// is a Forge client and performing the Forge handshake with the given
+    /// handler. See the forge module.
+    pub fn connect_unauthenticated_forge(host: &str,
+                                         port: u16,
+                                         username: &str,
+                                         forge: &ForgeHandshake)
+                                         -> Result<Self> {
+        let mut login_plugins = LoginPluginRegistry::new();
+        login_plugins.register(LoginWrapper::CHANNEL, forge.clone());
+        Client::login_unauthenticated(host,
+                                      port,
+                                      &format!("{}{}", host, FML_MARKER),
+                                      username,
+                                      login_plugins)
+    }
+
+    /// Perform an unauthenticated login, sending the given server address in
+    /// the Handshake
+    fn login_unauthenticated(host: &str,
+                             port: u16,
+                             server_address: &str,
+                             username: &str,
+                             login_plugins: LoginPluginRegistry)
+                             -> Result<Self> {
 
         let timeout = time::Instant::now();
         let mut client = Client::connect_tcp(host, port)?;
+        client.set_login_plugins(login_plugins);
         client.set_auto_handle(true);
         client.set_hide_handled(true);
         let handshake = serverbound::Handshake::new(PROTOCOL_VERSION,
-                                                    host.to_string(),
+                                                    server_address.to_string(),
                                                     port,
                                                     2);
         let loginstart = serverbound::LoginStart::new(username.to_string());
@@ -129,13 +181,58 @@ impl Client {
                                  port: u16,
                                  auth: &AuthenticationResponse)
                                  -> Result<Self> {
+        Client::connect_authenticated_with_plugins(host,
+                                                   port,
+                                                   auth,
+                                                   LoginPluginRegistry::new())
+    }
+
+    /// The same as connect_authenticated, but with the given login plugin
+    /// channel handlers, which will be used to respond to any
+    /// LoginPluginRequests the server sends while logging in.
+    ///
+    /// This allows for custom login handshakes, e.g. with modded servers.
+    pub fn connect_authenticated_with_plugins(host: &str,
+                                              port: u16,
+                                              auth: &AuthenticationResponse,
+                                              login_plugins: LoginPluginRegistry)
+                                              -> Result<Self> {
+        Client::login_authenticated(host, port, host, auth, login_plugins)
+    }
+
+    /// The same as connect_authenticated, but telling the server that this
+    /// is a Forge client and performing the Forge handshake with the given
+    /// handler. See the forge module.
+    pub fn connect_authenticated_forge(host: &str,
+                                       port: u16,
+                                       auth: &AuthenticationResponse,
+                                       forge: &ForgeHandshake)
+                                       -> Result<Self> {
+        let mut login_plugins = LoginPluginRegistry::new();
+        login_plugins.register(LoginWrapper::CHANNEL, forge.clone());
+        Client::login_authenticated(host,
+                                    port,
+                                    &format!("{}{}", host, FML_MARKER),
+                                    auth,
+                                    login_plugins)
+    }
+
+    /// Perform an authenticated login, sending the given server address in
+    /// the Handshake
+    fn login_authenticated(host: &str,
+                           port: u16,
+                           server_address: &str,
+                           auth: &AuthenticationResponse,
+                           login_plugins: LoginPluginRegistry)
+                           -> Result<Self> {
 
         let timeout = time::Instant::now();
         let mut client = Client::connect_tcp(host, port)?;
+        client.set_login_plugins(login_plugins);
         client.set_auto_handle(true);
         client.set_hide_handled(true);
         let handshake = serverbound::Handshake::new(PROTOCOL_VERSION,
-                                                    host.to_string(),
+                                                    server_address.to_string(),
                                                     port,
                                                     2);
         let loginstart =
@@ -212,9 +309,13 @@ impl Client {
             let packet = self.read_packet()?;
             /* push = whether to push the packet to ret */
             let mut push = match &packet {
+                &Some(ClientboundPacket::ClientboundPluginMessage(ref p)) => {
+                    !self.plugin_channels.is_handled(p.get_channel())
+                },
                 &Some(ClientboundPacket::LoginSuccess(_)) => false,
                 &Some(ClientboundPacket::SetCompression(_)) => false,
                 &Some(ClientboundPacket::KeepAlive(_)) => false,
+                &Some(ClientboundPacket::LoginPluginRequest(_)) => false,
                 &Some(_) => true,
                 &None => break,
             };
@@ -247,13 +348,67 @@ impl Client {
         self.conn.write()
     }
 
-    /// Whether to automatically handle: KeepAlive, LoginSuccess and
-    /// SetCompression packets. Most clients won't need to manually deal with
-    /// these.
+    /// Whether to automatically handle: KeepAlive, LoginSuccess,
+    /// SetCompression, LoginPluginRequest and PluginMessage packets. Most
+    /// clients won't need to manually deal with these.
+    ///
+    /// LoginPluginRequests are passed to the registered login plugin
+    /// handlers, see register_login_plugin, and PluginMessages to the
+    /// registered plugin channel handlers, see register_plugin_channel.
+    /// PluginMessages on channels without a handler are not hidden.
     pub fn set_auto_handle(&mut self, new: bool) {
         self.auto_handle = new;
     }
 
+    /// Register a handler for LoginPluginRequests on the given channel.
+    ///
+    /// This only has an effect if auto_handle is true. Requests on channels
+    /// without a handler are responded to as not understood.
+    pub fn register_login_plugin<H: LoginPluginHandler + Send + 'static>(&mut self,
+                                                                         channel: &str,
+                                                                         handler: H) {
+        self.login_plugins.register(channel, handler)
+    }
+
+    /// Replace all the login plugin channel handlers with the given ones
+    pub fn set_login_plugins(&mut self, login_plugins: LoginPluginRegistry) {
+        self.login_plugins = login_plugins;
+    }
+
+    /// Register a handler for plugin messages on the given channel.
+    ///
+    /// This only has an effect if auto_handle is true, in which case the
+    /// `minecraft:register`, `minecraft:unregister` and `minecraft:brand`
+    /// channels are also handled automatically. Use send_plugin_register to
+    /// tell the server about the registered channels.
+    pub fn register_plugin_channel<H: PluginMessageHandler + Send + 'static>(&mut self,
+                                                                             channel: &str,
+                                                                             handler: H) {
+        self.plugin_channels.register(channel, handler)
+    }
+
+    /// Get the plugin channel router, e.g. to see which channels the server
+    /// has registered or which brand it has sent
+    pub fn get_plugin_channels(&self) -> &PluginChannelRouter {
+        &self.plugin_channels
+    }
+
+    /// Send a `minecraft:register` message to the server, containing all the
+    /// channels registered with register_plugin_channel
+    pub fn send_plugin_register(&mut self) -> Result<usize> {
+        let register = self.plugin_channels.get_register();
+        self.send(serverbound::PluginMessage::new_encoded(&register)?)
+    }
+
+    /// Send the client's brand to the server, the vanilla client sends
+    /// `vanilla` after receiving JoinGame.
+    pub fn send_brand(&mut self, brand: &str) -> Result<usize> {
+        let brand = Brand {
+            brand: brand.to_string(),
+        };
+        self.send(serverbound::PluginMessage::new_encoded(&brand)?)
+    }
+
     /// Whether or not to hide packets that have been handled by ozelot from the
     /// consumer of the library.
     ///
@@ -326,6 +481,14 @@ impl Client {
                     let keepalive = serverbound::KeepAlive::new(*p.get_id());
                     let _: usize = self.send(keepalive)?;
                 },
+                &Some(ClientboundPacket::LoginPluginRequest(ref p)) => {
+                    let response = self.login_plugins.handle_request(p)?;
+                    let _: usize = self.send(response)?;
+                },
+                &Some(ClientboundPacket::ClientboundPluginMessage(ref p)) => {
+                    let _: bool = self.plugin_channels
+                        .handle_message(p.get_channel(), p.get_data())?;
+                },
                 _ => (),
             }
         }
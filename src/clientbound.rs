@@ -6,9 +6,25 @@
 //! The goal is also to add a bunch of useful helper functions to the packets,
 //! if you feel such a function is missing, open an issue.
 
+use advancements::AdvancementsData;
+use attributes::EntityProperty;
+use bossbar::{BossBarAction, BossBarColor, BossBarDivision, BossBarState, BossBarUpdate};
+use chunk::ChunkColumn;
+use commands::CommandGraph;
 use connection::Packet;
+use dispatch::PacketVariant;
 use errors::Result;
+use light::LightData;
+use map::MapData;
+use metadata::{Metadata, decode_metadata, encode_metadata};
+use plugin::PluginCodec;
 use read::*;
+use recipes::Recipe;
+use scoreboard::TeamsUpdate;
+use slot::*;
+use tags::TagList;
+use trading::TradeListData;
+use worldborder::WorldBorderAction;
 use write::*;
 use ClientState;
 
@@ -273,7 +289,14 @@ impl ScoreboardObjective {
             write_String(x, &mut ret)?;
         }
         if let &Some(ref x) = &self.objective_type {
-            write_String(x, &mut ret)?;
+            /* The type is sent as a varint enum but exposed as the string
+             * used by the /scoreboard command */
+            let type_ = match x.as_str() {
+                "integer" => 0,
+                "hearts" => 1,
+                _ => bail!("ScoreboardObjective had invalid type {}", x),
+            };
+            write_varint(&type_, &mut ret)?;
         }
         Ok(ret)
     }
@@ -281,7 +304,15 @@ impl ScoreboardObjective {
         let name = read_String(r)?;
         let mode = read_u8(r)?;
         let (value, objective_type) = match mode {
-            0 | 2 => (Some(read_String(r)?), Some(read_String(r)?)),
+            0 | 2 => {
+                let value = read_String(r)?;
+                let objective_type = match read_varint(r)? {
+                    0 => "integer",
+                    1 => "hearts",
+                    x => bail!("ScoreboardObjective had invalid type {}", x),
+                };
+                (Some(value), Some(objective_type.to_string()))
+            },
             _ => (None, None),
         };
         Ok(ClientboundPacket::ScoreboardObjective(ScoreboardObjective {
@@ -556,3 +587,328 @@ impl StopSound {
         }))
     }
 }
+
+impl LoginPluginRequest {
+    /// Decode the data of this request using the given codec.
+    ///
+    /// Returns Ok(None) if the request is not on the codec's channel.
+    pub fn decode_data<C: PluginCodec>(&self) -> Result<Option<C>> {
+        if self.identifier == C::CHANNEL {
+            Ok(Some(C::decode(&self.data)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Create a new LoginPluginRequest with the given message id, with the
+    /// payload encoded using its codec.
+    pub fn new_encoded<C: PluginCodec>(id: i32, payload: &C) -> Result<ClientboundPacket> {
+        Ok(LoginPluginRequest::new(id,
+                                C::CHANNEL.to_string(),
+                                payload.encode()?))
+    }
+}
+
+impl ClientboundPluginMessage {
+    /// Decode the data of this plugin message using the given codec.
+    ///
+    /// Returns Ok(None) if the message is not on the codec's channel.
+    pub fn decode_data<C: PluginCodec>(&self) -> Result<Option<C>> {
+        if self.channel == C::CHANNEL {
+            Ok(Some(C::decode(&self.data)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Create a new plugin message with the payload encoded using its codec
+    pub fn new_encoded<C: PluginCodec>(payload: &C) -> Result<ClientboundPacket> {
+        Ok(ClientboundPluginMessage::new(C::CHANNEL.to_string(),
+                                         payload.encode()?))
+    }
+}
+
+impl DeclareCommands {
+    /// Decode the raw data of this packet into the command graph
+    pub fn get_graph(&self) -> Result<CommandGraph> {
+        CommandGraph::decode(&self.raw_data)
+    }
+
+    /// Create a new DeclareCommands packet advertising the given command
+    /// graph
+    pub fn new_graph(graph: &CommandGraph) -> Result<ClientboundPacket> {
+        Ok(DeclareCommands::new(graph.encode()?))
+    }
+}
+
+impl Tags {
+    /// Decode the raw data of this packet into the four tag maps
+    pub fn get_tags(&self) -> Result<TagList> {
+        TagList::decode(&self.data)
+    }
+
+    /// Create a new Tags packet from the given tags
+    pub fn new_tags(tags: &TagList) -> Result<ClientboundPacket> {
+        Ok(Tags::new(tags.encode()?))
+    }
+}
+
+impl DeclareRecipes {
+    /// Decode the raw data of this packet into the list of recipes
+    pub fn get_recipes(&self) -> Result<Vec<Recipe>> {
+        Recipe::decode_list(&self.data)
+    }
+
+    /// Create a new DeclareRecipes packet declaring the given recipes
+    pub fn new_recipes(recipes: &[Recipe]) -> Result<ClientboundPacket> {
+        Ok(DeclareRecipes::new(Recipe::encode_list(recipes)?))
+    }
+}
+
+impl Advancements {
+    /// Decode the raw data of this packet
+    pub fn get_advancements(&self) -> Result<AdvancementsData> {
+        AdvancementsData::decode(&self.data)
+    }
+
+    /// Create a new Advancements packet from the given contents
+    pub fn new_advancements(data: &AdvancementsData) -> Result<ClientboundPacket> {
+        Ok(Advancements::new(data.encode()?))
+    }
+}
+
+impl EntityProperties {
+    /// Decode the raw data of this packet into the entity's properties
+    pub fn get_properties(&self) -> Result<Vec<EntityProperty>> {
+        EntityProperty::decode_list(&self.data)
+    }
+
+    /// Create a new EntityProperties packet for the given entity with the
+    /// given properties
+    pub fn new_properties(entity_id: i32,
+                          properties: &[EntityProperty])
+                          -> Result<ClientboundPacket> {
+        Ok(EntityProperties::new(entity_id, EntityProperty::encode_list(properties)?))
+    }
+}
+
+impl Teams {
+    /// Decode the raw data of this packet
+    pub fn get_update(&self) -> Result<TeamsUpdate> {
+        TeamsUpdate::decode(&self.data)
+    }
+
+    /// Create a new Teams packet performing the given update
+    pub fn new_update(update: &TeamsUpdate) -> Result<ClientboundPacket> {
+        Ok(Teams::new(update.encode()?))
+    }
+}
+
+impl BossBar {
+    /// Decode the raw data of this packet
+    pub fn get_update(&self) -> Result<BossBarUpdate> {
+        BossBarUpdate::decode(&self.data)
+    }
+
+    /// Create a new BossBar packet performing the given action on the boss
+    /// bar with the given UUID
+    pub fn new_action(uuid: u128, action: BossBarAction) -> Result<ClientboundPacket> {
+        let update = BossBarUpdate {
+            uuid,
+            action,
+        };
+        Ok(BossBar::new(update.encode()?))
+    }
+
+    /// Create a new BossBar packet showing a new boss bar
+    pub fn new_add(uuid: u128,
+                   title: String,
+                   health: f32,
+                   color: BossBarColor,
+                   division: BossBarDivision,
+                   flags: u8)
+                   -> Result<ClientboundPacket> {
+        let state = BossBarState {
+            title,
+            health,
+            color,
+            division,
+            flags,
+        };
+        BossBar::new_action(uuid, BossBarAction::Add(state))
+    }
+
+    /// Create a new BossBar packet removing the boss bar
+    pub fn new_remove(uuid: u128) -> Result<ClientboundPacket> {
+        BossBar::new_action(uuid, BossBarAction::Remove)
+    }
+
+    /// Create a new BossBar packet updating the health of the boss bar
+    pub fn new_health(uuid: u128, health: f32) -> Result<ClientboundPacket> {
+        BossBar::new_action(uuid, BossBarAction::UpdateHealth(health))
+    }
+
+    /// Create a new BossBar packet updating the title of the boss bar
+    pub fn new_title(uuid: u128, title: String) -> Result<ClientboundPacket> {
+        BossBar::new_action(uuid, BossBarAction::UpdateTitle(title))
+    }
+
+    /// Create a new BossBar packet updating the color and division of the
+    /// boss bar
+    pub fn new_style(uuid: u128,
+                     color: BossBarColor,
+                     division: BossBarDivision)
+                     -> Result<ClientboundPacket> {
+        BossBar::new_action(uuid, BossBarAction::UpdateStyle(color, division))
+    }
+
+    /// Create a new BossBar packet updating the flags of the boss bar
+    pub fn new_flags(uuid: u128, flags: u8) -> Result<ClientboundPacket> {
+        BossBar::new_action(uuid, BossBarAction::UpdateFlags(flags))
+    }
+}
+
+impl WorldBorder {
+    /// Decode the raw data of this packet
+    pub fn get_action(&self) -> Result<WorldBorderAction> {
+        WorldBorderAction::decode(&self.data)
+    }
+
+    /// Create a new WorldBorder packet performing the given action
+    pub fn new_action(action: &WorldBorderAction) -> Result<ClientboundPacket> {
+        Ok(WorldBorder::new(action.encode()?))
+    }
+}
+
+impl Map {
+    /// Decode the raw data of this packet
+    pub fn get_map_data(&self) -> Result<MapData> {
+        MapData::decode(&self.data)
+    }
+
+    /// Create a new Map packet from the given contents
+    pub fn new_map_data(data: &MapData) -> Result<ClientboundPacket> {
+        Ok(Map::new(data.encode()?))
+    }
+}
+
+impl TradeList {
+    /// Decode the raw data of this packet
+    pub fn get_trade_list(&self) -> Result<TradeListData> {
+        TradeListData::decode(&self.data)
+    }
+
+    /// Create a new TradeList packet from the given contents
+    pub fn new_trade_list(data: &TradeListData) -> Result<ClientboundPacket> {
+        Ok(TradeList::new(data.encode()?))
+    }
+}
+
+impl UpdateLight {
+    /// Decode the light arrays of this packet
+    pub fn get_light_data(&self) -> Result<LightData> {
+        LightData::decode(self.sky_light_mask,
+                          self.block_light_mask,
+                          self.empty_sky_light_mask,
+                          self.empty_block_light_mask,
+                          &self.data)
+    }
+
+    /// Create a new UpdateLight packet for the given chunk, with the masks
+    /// computed from the given light data
+    pub fn new_light_data(chunk_x: i32,
+                          chunk_z: i32,
+                          light: &LightData)
+                          -> Result<ClientboundPacket> {
+        Ok(UpdateLight::new(chunk_x,
+                            chunk_z,
+                            light.get_sky_light_mask(),
+                            light.get_block_light_mask(),
+                            light.empty_sky_light_mask,
+                            light.empty_block_light_mask,
+                            light.encode()?))
+    }
+}
+
+impl ChunkData {
+    /// Decode the chunk column in this packet
+    pub fn get_chunk(&self) -> Result<ChunkColumn> {
+        ChunkColumn::decode(&self.data)
+    }
+
+    /// Create a new ChunkData packet containing the given chunk column
+    pub fn new_chunk(chunk: &ChunkColumn) -> Result<ClientboundPacket> {
+        Ok(ChunkData::new(chunk.encode()?))
+    }
+}
+
+impl EntityMetadata {
+    /// Decode the metadata entries of this packet
+    pub fn get_entries(&self) -> Result<Metadata> {
+        decode_metadata(&self.metadata)
+    }
+
+    /// Create a new EntityMetadata packet with the given entries
+    pub fn new_entries(entity_id: i32, metadata: &Metadata) -> Result<ClientboundPacket> {
+        Ok(EntityMetadata::new(entity_id, encode_metadata(metadata)?))
+    }
+}
+
+impl WindowItems {
+    /// Decode the items of this packet, one for each slot of the window
+    pub fn get_items(&self) -> Result<Vec<Option<Slot>>> {
+        let mut r = &self.slots[..];
+        let count = read_i16(&mut r)?;
+        if count < 0 {
+            bail!("WindowItems had negative count {}", count);
+        }
+        let mut ret = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            ret.push(read_slot(&mut r)?);
+        }
+        Ok(ret)
+    }
+
+    /// Create a new WindowItems packet setting all the slots of the window
+    pub fn new_items(window_id: u8, items: &[Option<Slot>]) -> Result<ClientboundPacket> {
+        if items.len() > i16::MAX as usize {
+            bail!("WindowItems can contain at most {} items", i16::MAX);
+        }
+        let mut slots = Vec::new();
+        write_i16(&(items.len() as i16), &mut slots)?;
+        for x in items {
+            write_slot(x, &mut slots)?;
+        }
+        Ok(WindowItems::new(window_id, slots))
+    }
+}
+
+impl SetSlot {
+    /// Decode the new item of the slot
+    pub fn get_slot(&self) -> Result<Option<Slot>> {
+        read_slot(&mut &self.slot_data[..])
+    }
+
+    /// Create a new SetSlot packet setting the given slot to the given item
+    pub fn new_slot(window_id: u8, slot_id: i16, item: &Option<Slot>) -> Result<ClientboundPacket> {
+        let mut slot_data = Vec::new();
+        write_slot(item, &mut slot_data)?;
+        Ok(SetSlot::new(window_id, slot_id, slot_data))
+    }
+}
+
+impl PlayerListItem {
+    /// Create a new PlayerListItem packet updating the latency (in ms) of
+    /// the players with the given uuids
+    pub fn new_latency(players: &[(u128, i32)]) -> Result<ClientboundPacket> {
+        let mut data = Vec::new();
+        /* Action 2 is update latency */
+        write_varint(&2, &mut data)?;
+        write_varint(&(players.len() as i32), &mut data)?;
+        for &(uuid, latency) in players {
+            write_u128(&uuid, &mut data)?;
+            write_varint(&latency, &mut data)?;
+        }
+        Ok(PlayerListItem::new(data))
+    }
+}
@@ -0,0 +1,428 @@
+//! The Brigadier command graph sent in the DeclareCommands packet
+//!
+//! The server tells the client which commands exist by sending a graph of
+//! nodes, starting at a root node. Literal nodes match a fixed word,
+//! argument nodes match a value parsed by a given parser, and a node is
+//! executable if the command may end there. A node may also redirect to
+//! another node, e.g. `/execute run` redirects back to the root.
+//!
+//! See [wiki.vg/Command Data](http://wiki.vg/Command_Data) for the details of
+//! the format.
+
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::io::{Cursor, Read, Write};
+
+/// The complete command graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandGraph {
+    /// All the nodes in the graph, children and redirects are given as
+    /// indices into this
+    pub nodes: Vec<CommandNode>,
+    /// The index of the root node
+    pub root: usize,
+}
+
+/// A single node in the command graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandNode {
+    /// What kind of node this is
+    pub kind: NodeKind,
+    /// Whether the command may end at this node
+    pub executable: bool,
+    /// The indices of the node's children
+    pub children: Vec<usize>,
+    /// The index of the node this node redirects to, if any
+    pub redirect: Option<usize>,
+    /// The identifier of the type of suggestions the client should ask the
+    /// server for, e.g. `minecraft:ask_server`, if any
+    pub suggestions: Option<String>,
+}
+
+/// The kinds of command nodes
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeKind {
+    /// The root node, there is exactly one in each graph
+    Root,
+    /// A node matching the given word literally
+    Literal(String),
+    /// A node matching an argument
+    Argument {
+        /// The name of the argument, only used for display
+        name: String,
+        /// The identifier of the parser used for the argument, e.g.
+        /// `brigadier:integer` or `minecraft:entity`
+        parser: String,
+        /// The properties of the parser
+        properties: ParserProperties,
+    },
+}
+
+/// The properties of an argument parser
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParserProperties {
+    /// The parser has no properties
+    None,
+    /// `brigadier:double` with optional min and max
+    Double(Option<f64>, Option<f64>),
+    /// `brigadier:float` with optional min and max
+    Float(Option<f32>, Option<f32>),
+    /// `brigadier:integer` with optional min and max
+    Integer(Option<i32>, Option<i32>),
+    /// `brigadier:long` with optional min and max
+    Long(Option<i64>, Option<i64>),
+    /// `brigadier:string`, with the raw behavior enum, 0 for a single word, 1
+    /// for a quotable phrase and 2 for the rest of the input
+    String(i32),
+    /// `minecraft:entity`, with the raw flags, 0x01 if only a single entity
+    /// is allowed and 0x02 if only players are allowed
+    Entity(u8),
+    /// `minecraft:score_holder`, with the raw flags, 0x01 if multiple are
+    /// allowed
+    ScoreHolder(u8),
+    /// `minecraft:range`, with whether decimals are allowed
+    Range(bool),
+}
+impl ParserProperties {
+    /// Read the properties of the given parser
+    fn read<R: Read>(parser: &str, r: &mut R) -> Result<Self> {
+        Ok(match parser {
+            "brigadier:double" => {
+                let flags = read_u8(r)?;
+                let min = if flags & 0x01 != 0 { Some(read_f64(r)?) } else { None };
+                let max = if flags & 0x02 != 0 { Some(read_f64(r)?) } else { None };
+                ParserProperties::Double(min, max)
+            },
+            "brigadier:float" => {
+                let flags = read_u8(r)?;
+                let min = if flags & 0x01 != 0 { Some(read_f32(r)?) } else { None };
+                let max = if flags & 0x02 != 0 { Some(read_f32(r)?) } else { None };
+                ParserProperties::Float(min, max)
+            },
+            "brigadier:integer" => {
+                let flags = read_u8(r)?;
+                let min = if flags & 0x01 != 0 { Some(read_i32(r)?) } else { None };
+                let max = if flags & 0x02 != 0 { Some(read_i32(r)?) } else { None };
+                ParserProperties::Integer(min, max)
+            },
+            "brigadier:long" => {
+                let flags = read_u8(r)?;
+                let min = if flags & 0x01 != 0 { Some(read_i64(r)?) } else { None };
+                let max = if flags & 0x02 != 0 { Some(read_i64(r)?) } else { None };
+                ParserProperties::Long(min, max)
+            },
+            "brigadier:string" => ParserProperties::String(read_varint(r)?),
+            "minecraft:entity" => ParserProperties::Entity(read_u8(r)?),
+            "minecraft:score_holder" => ParserProperties::ScoreHolder(read_u8(r)?),
+            "minecraft:range" => ParserProperties::Range(read_bool(r)?),
+            _ => ParserProperties::None,
+        })
+    }
+
+    /// Write the properties
+    fn write<W: Write>(&self, w: &mut W) -> Result<()> {
+        match *self {
+            ParserProperties::None => (),
+            ParserProperties::Double(min, max) => {
+                write_u8(&min_max_flags(min.is_some(), max.is_some()), w)?;
+                if let Some(x) = min {
+                    write_f64(&x, w)?;
+                }
+                if let Some(x) = max {
+                    write_f64(&x, w)?;
+                }
+            },
+            ParserProperties::Float(min, max) => {
+                write_u8(&min_max_flags(min.is_some(), max.is_some()), w)?;
+                if let Some(x) = min {
+                    write_f32(&x, w)?;
+                }
+                if let Some(x) = max {
+                    write_f32(&x, w)?;
+                }
+            },
+            ParserProperties::Integer(min, max) => {
+                write_u8(&min_max_flags(min.is_some(), max.is_some()), w)?;
+                if let Some(x) = min {
+                    write_i32(&x, w)?;
+                }
+                if let Some(x) = max {
+                    write_i32(&x, w)?;
+                }
+            },
+            ParserProperties::Long(min, max) => {
+                write_u8(&min_max_flags(min.is_some(), max.is_some()), w)?;
+                if let Some(x) = min {
+                    write_i64(&x, w)?;
+                }
+                if let Some(x) = max {
+                    write_i64(&x, w)?;
+                }
+            },
+            ParserProperties::String(x) => write_varint(&x, w)?,
+            ParserProperties::Entity(x) => write_u8(&x, w)?,
+            ParserProperties::ScoreHolder(x) => write_u8(&x, w)?,
+            ParserProperties::Range(x) => write_bool(&x, w)?,
+        }
+        Ok(())
+    }
+}
+
+fn min_max_flags(min: bool, max: bool) -> u8 {
+    (if min { 0x01 } else { 0 }) | (if max { 0x02 } else { 0 })
+}
+
+impl CommandGraph {
+    /// Decode the graph from the raw data of a DeclareCommands packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let count = read_varint(&mut r)?;
+        if count < 0 {
+            bail!("DeclareCommands had negative node count {}", count);
+        }
+        let mut nodes = Vec::new();
+        for _ in 0..count {
+            let flags = read_u8(&mut r)?;
+            let children = read_prefixed_varintarray(&mut r)?
+                .into_iter()
+                .map(|x| x as usize)
+                .collect();
+            let redirect = if flags & 0x08 != 0 {
+                Some(read_varint(&mut r)? as usize)
+            } else {
+                None
+            };
+            let kind = match flags & 0x03 {
+                0 => NodeKind::Root,
+                1 => NodeKind::Literal(read_String(&mut r)?),
+                2 => {
+                    let name = read_String(&mut r)?;
+                    let parser = read_String(&mut r)?;
+                    let properties = ParserProperties::read(&parser, &mut r)?;
+                    NodeKind::Argument {
+                        name,
+                        parser,
+                        properties,
+                    }
+                },
+                x => bail!("Command node has invalid type {}", x),
+            };
+            let suggestions = if flags & 0x10 != 0 {
+                Some(read_String(&mut r)?)
+            } else {
+                None
+            };
+            nodes.push(CommandNode {
+                kind,
+                executable: flags & 0x04 != 0,
+                children,
+                redirect,
+                suggestions,
+            });
+        }
+        let root = read_varint(&mut r)? as usize;
+
+        let graph = CommandGraph {
+            nodes,
+            root,
+        };
+        graph.validate()?;
+        Ok(graph)
+    }
+
+    /// Encode the graph into the raw data of a DeclareCommands packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        self.validate()?;
+        let mut ret = Vec::new();
+        write_varint(&(self.nodes.len() as i32), &mut ret)?;
+        for node in &self.nodes {
+            let mut flags = match node.kind {
+                NodeKind::Root => 0,
+                NodeKind::Literal(..) => 1,
+                NodeKind::Argument { .. } => 2,
+            };
+            if node.executable {
+                flags |= 0x04;
+            }
+            if node.redirect.is_some() {
+                flags |= 0x08;
+            }
+            if node.suggestions.is_some() {
+                flags |= 0x10;
+            }
+            write_u8(&flags, &mut ret)?;
+            let children: Vec<i32> = node.children.iter().map(|&x| x as i32).collect();
+            write_prefixed_varintarray(&children, &mut ret)?;
+            if let Some(x) = node.redirect {
+                write_varint(&(x as i32), &mut ret)?;
+            }
+            match node.kind {
+                NodeKind::Root => (),
+                NodeKind::Literal(ref name) => write_String(name, &mut ret)?,
+                NodeKind::Argument { ref name, ref parser, ref properties } => {
+                    write_String(name, &mut ret)?;
+                    write_String(parser, &mut ret)?;
+                    properties.write(&mut ret)?;
+                },
+            }
+            if let Some(ref x) = node.suggestions {
+                write_String(x, &mut ret)?;
+            }
+        }
+        write_varint(&(self.root as i32), &mut ret)?;
+        Ok(ret)
+    }
+
+    /// Check that all the indices in the graph point to existing nodes
+    fn validate(&self) -> Result<()> {
+        if self.root >= self.nodes.len() {
+            bail!("Command graph root index {} is out of bounds", self.root);
+        }
+        for node in &self.nodes {
+            for &x in node.children.iter().chain(node.redirect.iter()) {
+                if x >= self.nodes.len() {
+                    bail!("Command graph node index {} is out of bounds", x);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Get all the valid command paths, e.g. `tp <destination>`, without the
+    /// leading slash.
+    ///
+    /// Arguments are shown by their name in angle brackets. A path that ends
+    /// in a redirect is shown with `-> ` followed by the path of the node it
+    /// redirects to (or `-> /` for the root), since the graph may loop.
+    pub fn get_paths(&self) -> Vec<String> {
+        let mut ret = Vec::new();
+        let mut stack = vec![self.root];
+        self.collect_paths(self.root, &mut Vec::new(), &mut stack, &mut ret);
+        ret
+    }
+
+    fn collect_paths(&self,
+                     index: usize,
+                     words: &mut Vec<String>,
+                     stack: &mut Vec<usize>,
+                     ret: &mut Vec<String>) {
+        let node = &self.nodes[index];
+        if index != self.root {
+            if node.executable {
+                ret.push(words.join(" "));
+            }
+            if let Some(redirect) = node.redirect {
+                ret.push(format!("{} -> {}", words.join(" "), self.get_display_path(redirect)));
+            }
+        }
+        for &child in &node.children {
+            if stack.contains(&child) {
+                continue;
+            }
+            words.push(self.get_display_name(child));
+            stack.push(child);
+            self.collect_paths(child, words, stack, ret);
+            let _ = stack.pop();
+            let _ = words.pop();
+        }
+    }
+
+    /// Get how the given node is displayed in a path
+    fn get_display_name(&self, index: usize) -> String {
+        match self.nodes[index].kind {
+            NodeKind::Root => "/".to_string(),
+            NodeKind::Literal(ref name) => name.clone(),
+            NodeKind::Argument { ref name, .. } => format!("<{}>", name),
+        }
+    }
+
+    /// Get the path of the given node, by searching from the root
+    fn get_display_path(&self, target: usize) -> String {
+        if target == self.root {
+            return "/".to_string();
+        }
+        let mut queue = vec![(self.root, Vec::new())];
+        let mut seen = vec![false; self.nodes.len()];
+        while !queue.is_empty() {
+            let (index, words) = queue.remove(0);
+            if index == target {
+                return words.join(" ");
+            }
+            if seen[index] {
+                continue;
+            }
+            seen[index] = true;
+            for &child in &self.nodes[index].children {
+                let mut words = words.clone();
+                words.push(self.get_display_name(child));
+                queue.push((child, words));
+            }
+        }
+        self.get_display_name(target)
+    }
+
+    /// Tab-complete the given input (without the leading slash) locally
+    /// against the graph.
+    ///
+    /// Returns the possible completions of the last (possibly empty) word of
+    /// the input. Only literals can be completed, since the values valid for
+    /// arguments are not known to the client, but arguments are skipped over
+    /// when walking the graph. Words containing spaces such as quoted strings
+    /// are not supported, except for greedy strings at the end of a command.
+    pub fn complete(&self, input: &str) -> Vec<String> {
+        let words: Vec<&str> = input.split(' ').collect();
+        let (last, preceding) = words.split_last().expect("split always returns at least one element");
+
+        /* The set of nodes the next word may match */
+        let mut current = vec![self.root];
+        for word in preceding {
+            let mut next = Vec::new();
+            for &index in &current {
+                for child in self.get_children(index) {
+                    let matches = match self.nodes[child].kind {
+                        NodeKind::Root => false,
+                        NodeKind::Literal(ref name) => name == word,
+                        NodeKind::Argument { ref properties, .. } => {
+                            if *properties == ParserProperties::String(2) {
+                                /* A greedy string consumes the rest of the
+                                 * input, so there is nothing to complete */
+                                return Vec::new();
+                            }
+                            true
+                        },
+                    };
+                    if matches && !next.contains(&child) {
+                        next.push(child);
+                    }
+                }
+            }
+            current = next;
+        }
+
+        let mut ret = Vec::new();
+        for &index in &current {
+            for child in self.get_children(index) {
+                if let NodeKind::Literal(ref name) = self.nodes[child].kind {
+                    if name.starts_with(last) && !ret.contains(name) {
+                        ret.push(name.clone());
+                    }
+                }
+            }
+        }
+        ret.sort();
+        ret
+    }
+
+    /// Get the children of the given node, following its redirect if it has
+    /// one
+    fn get_children(&self, index: usize) -> Vec<usize> {
+        let node = &self.nodes[index];
+        match node.redirect {
+            Some(x) => self.nodes[x].children.clone(),
+            None => node.children.clone(),
+        }
+    }
+}
+
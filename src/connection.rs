@@ -6,7 +6,7 @@ use write::write_varint;
 use std::io::{Cursor, Read, Write};
 use std::marker::PhantomData;
 use std::net::Shutdown;
-use std::net::TcpStream;
+use std::net::{SocketAddr, TcpStream};
 use std::{io, time};
 
 use netbuf::Buf;
@@ -56,6 +56,11 @@ pub(crate) struct Connection<I: Packet, O: Packet> {
     /* When we last read something from the server. Use this to timeout the
      * connection if the connection is lost */
     last_read: time::Instant,
+    /* Whether the other end has closed the connection, in which case reading
+     * fails once the buffered packets have been read */
+    eof: bool,
+    /* Whether the last update_inbuf read everything that was available */
+    drained: bool,
     in_type: PhantomData<I>,
     out_type: PhantomData<O>,
 }
@@ -71,6 +76,8 @@ impl<I: Packet, O: Packet> Connection<I, O> {
             in_encryption: None,
             out_encryption: None,
             last_read: time::Instant::now(),
+            eof: false,
+            drained: false,
             in_type: PhantomData,
             out_type: PhantomData,
         };
@@ -164,11 +171,33 @@ impl<I: Packet, O: Packet> Connection<I, O> {
         Ok(self.stream.shutdown(Shutdown::Both)?)
     }
 
+    /// Get the address of the remote end of this connection
+    pub(crate) fn peer_addr(&self) -> Result<SocketAddr> {
+        Ok(self.stream.peer_addr()?)
+    }
+
+    /// Get another handle to the TcpStream, e.g. to be notified when it
+    /// becomes readable
+    pub(crate) fn try_clone_stream(&self) -> Result<TcpStream> {
+        Ok(self.stream.try_clone()?)
+    }
+
+    /// Get whether the last update_inbuf read everything that was available
+    /// from the TcpStream, i.e. whether more data can only arrive later
+    pub(crate) fn is_drained(&self) -> bool {
+        self.drained
+    }
+
     /// Change the client state of this connection
     pub(crate) fn set_clientstate(&mut self, new_state: ClientState) {
         self.clientstate = new_state;
     }
 
+    /// Get the current client state of this connection
+    pub(crate) fn get_clientstate(&self) -> &ClientState {
+        &self.clientstate
+    }
+
     /// Enable encryption with the given key.
     ///
     /// It is an error to enable encryption if encryption has already been
@@ -209,8 +238,19 @@ impl<I: Packet, O: Packet> Connection<I, O> {
         if let Some(ref mut enc) = self.in_encryption {
             let mut enc_buf = Buf::new();
             let n = match enc_buf.read_from(&mut self.stream) {
-                Ok(n) => n,
-                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => 0,
+                Ok(0) => {
+                    self.eof = true;
+                    self.drained = true;
+                    0
+                },
+                Ok(n) => {
+                    self.drained = false;
+                    n
+                },
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    self.drained = true;
+                    0
+                },
                 Err(e) => bail!(e),
             };
             let mut tmp = vec![0; n + 16];
@@ -218,8 +258,12 @@ impl<I: Packet, O: Packet> Connection<I, O> {
             self.buf.extend(&tmp[..n]);
         } else {
             match self.buf.read_from(&mut self.stream) {
-                Ok(_) => (),
-                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => (),
+                Ok(0) => {
+                    self.eof = true;
+                    self.drained = true;
+                },
+                Ok(_) => self.drained = false,
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => self.drained = true,
                 Err(e) => bail!(e),
             };
         }
@@ -243,7 +287,9 @@ impl<I: Packet, O: Packet> Connection<I, O> {
         let len = match self.packet_len {
             Some(x) => x,
             None => {
-                if self.last_read.elapsed() > time::Duration::new(30, 0) {
+                if self.eof {
+                    bail!("Connection closed");
+                } else if self.last_read.elapsed() > time::Duration::new(30, 0) {
                     /* If we haven't read anything for 30 seconds, timeout */
                     self.close()?;
                     bail!("Read timeout");
@@ -255,7 +301,9 @@ impl<I: Packet, O: Packet> Connection<I, O> {
 
         if self.buf.len() < len {
             /* We haven't received enough yet to read the whole packet */
-            if self.last_read.elapsed() > time::Duration::new(30, 0) {
+            if self.eof {
+                bail!("Connection closed");
+            } else if self.last_read.elapsed() > time::Duration::new(30, 0) {
                 /* If we haven't read anything for 30 seconds, timeout */
                 self.close()?;
                 bail!("Read timeout");
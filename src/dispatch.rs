@@ -0,0 +1,204 @@
+//! Dispatching packets to handlers registered for specific packet types
+//!
+//! Instead of matching on ClientboundPacket or ServerboundPacket, handlers
+//! can be registered for the packet types they're interested in, and get
+//! passed that packet directly:
+//!
+//! ```rust,no_run
+//! use ozelot::Client;
+//! use ozelot::clientbound::{ChatMessage, ClientboundPacket};
+//! use ozelot::dispatch::{Dispatcher, Flow};
+//! use ozelot::utils;
+//!
+//! let mut client = Client::connect_unauthenticated("localhost", 25565, "bot")
+//!     .unwrap();
+//! let mut dispatcher: Dispatcher<Client, ClientboundPacket> = Dispatcher::new();
+//! let _ = dispatcher.on::<ChatMessage>(|_, p| {
+//!     println!("{}", utils::chat_to_str(p.get_chat())?);
+//!     Ok(Flow::Continue)
+//! });
+//! loop {
+//!     let _ = dispatcher.poll(&mut client).unwrap();
+//! }
+//! ```
+//!
+//! Handlers are called in order of descending priority, and in the order
+//! they were registered for handlers with the same priority. A handler can
+//! consume the packet, in which case no further handlers are called.
+//!
+//! Every packet type implements PacketVariant for the packet enum it belongs
+//! to, this is generated along with the packets themselves.
+use client::Client;
+use clientbound::ClientboundPacket;
+use errors::Result;
+use server::Server;
+use serverbound::ServerboundPacket;
+
+use std::fmt;
+
+/// A packet type that is a variant of the packet enum P
+pub trait PacketVariant<P> {
+    /// Get the packet of this type from the enum, if it is of this type
+    fn from_packet(packet: &P) -> Option<&Self>;
+}
+
+/// What should happen to a packet after a handler has been called with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// Pass the packet on to the next handler
+    Continue,
+    /// Stop dispatching the packet, no further handlers are called with it
+    Consume,
+}
+
+/// Identifies a registered handler, for use with Dispatcher::remove
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandlerId(u64);
+
+/// A handler, already wrapped to take the packet enum
+type Handler<C, P> = Box<dyn FnMut(&mut C, &P) -> Result<Flow> + Send>;
+
+struct Entry<C, P> {
+    id: HandlerId,
+    priority: i32,
+    handler: Handler<C, P>,
+}
+
+/// Dispatches packets of type P to the registered handlers, passing along
+/// a context C, e.g. the Client or Server the packet was read from
+pub struct Dispatcher<C, P> {
+    /* Sorted by descending priority, then by id */
+    handlers: Vec<Entry<C, P>>,
+    next_id: u64,
+}
+impl<C, P> fmt::Debug for Dispatcher<C, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dispatcher")
+            .field("handlers", &self.handlers.len())
+            .finish()
+    }
+}
+impl<C, P> Default for Dispatcher<C, P> {
+    fn default() -> Self {
+        Dispatcher {
+            handlers: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+impl<C, P> Dispatcher<C, P> {
+    /// Create a new Dispatcher without any handlers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a handler for packets of type T, with priority 0
+    ///
+    /// The packet type can be given explicitly, e.g.
+    /// `dispatcher.on::<ChatMessage>(|client, p| ...)`
+    pub fn on<T>(&mut self,
+                 handler: impl FnMut(&mut C, &T) -> Result<Flow> + Send + 'static)
+                 -> HandlerId
+        where T: PacketVariant<P> + 'static,
+              C: 'static,
+              P: 'static
+    {
+        self.on_with_priority(0, handler)
+    }
+
+    /// Register a handler for packets of type T with the given priority.
+    /// Handlers with a higher priority are called first.
+    pub fn on_with_priority<T>(&mut self,
+                               priority: i32,
+                               mut handler: impl FnMut(&mut C, &T) -> Result<Flow> + Send + 'static)
+                               -> HandlerId
+        where T: PacketVariant<P> + 'static,
+              C: 'static,
+              P: 'static
+    {
+        self.on_any(priority, move |context: &mut C, packet: &P| {
+            match T::from_packet(packet) {
+                Some(packet) => handler(context, packet),
+                None => Ok(Flow::Continue),
+            }
+        })
+    }
+
+    /// Register a handler for all packets with the given priority
+    pub fn on_any<F>(&mut self, priority: i32, handler: F) -> HandlerId
+        where F: FnMut(&mut C, &P) -> Result<Flow> + Send + 'static
+    {
+        let id = HandlerId(self.next_id);
+        self.next_id += 1;
+        /* Insert after all handlers with the same or higher priority */
+        let index = self.handlers
+            .iter()
+            .position(|x| x.priority < priority)
+            .unwrap_or(self.handlers.len());
+        self.handlers.insert(index,
+                             Entry {
+                                 id,
+                                 priority,
+                                 handler: Box::new(handler),
+                             });
+        id
+    }
+
+    /// Remove the given handler, returning whether it was registered
+    pub fn remove(&mut self, id: HandlerId) -> bool {
+        let len = self.handlers.len();
+        self.handlers.retain(|x| x.id != id);
+        self.handlers.len() != len
+    }
+
+    /// Get the amount of registered handlers
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    /// Whether there are no registered handlers
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Pass the packet to the handlers, returning whether it was consumed.
+    ///
+    /// If a handler returns an error, no further handlers are called and the
+    /// error is returned.
+    pub fn dispatch(&mut self, context: &mut C, packet: &P) -> Result<bool> {
+        for entry in &mut self.handlers {
+            if (entry.handler)(context, packet)? == Flow::Consume {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Dispatch all the given packets in order, returning those that weren't
+    /// consumed
+    pub fn dispatch_all(&mut self, context: &mut C, packets: Vec<P>) -> Result<Vec<P>> {
+        let mut ret = Vec::new();
+        for packet in packets {
+            if !self.dispatch(context, &packet)? {
+                ret.push(packet);
+            }
+        }
+        Ok(ret)
+    }
+}
+impl Dispatcher<Client, ClientboundPacket> {
+    /// Read packets from the client with Client::read and dispatch them,
+    /// returning those that weren't consumed
+    pub fn poll(&mut self, client: &mut Client) -> Result<Vec<ClientboundPacket>> {
+        let packets = client.read()?;
+        self.dispatch_all(client, packets)
+    }
+}
+impl Dispatcher<Server, ServerboundPacket> {
+    /// Read packets from the server with Server::read and dispatch them,
+    /// returning those that weren't consumed
+    pub fn poll(&mut self, server: &mut Server) -> Result<Vec<ServerboundPacket>> {
+        let packets = server.read()?;
+        self.dispatch_all(server, packets)
+    }
+}
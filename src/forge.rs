@@ -0,0 +1,292 @@
+//! Client-side support for the Forge (FML) login handshake
+//!
+//! A client tells a Forge server that it is modded by appending a marker to
+//! the server address of the Handshake packet. The server then sends its mod
+//! list, registries and configuration as LoginPluginRequests on the
+//! `fml:loginwrapper` channel, wrapping the `fml:handshake` channel, and the
+//! client must answer with its own mod list and acknowledge everything else.
+//!
+//! `ForgeHandshake` is a login plugin handler doing this, presenting a
+//! configurable mod list. The easiest way to use it is with
+//! `Client::connect_unauthenticated_forge` or
+//! `Client::connect_authenticated_forge`.
+//!
+//! Note that this only gets the client through the login, ozelot does not
+//! know anything about the mods' own packets.
+
+use errors::Result;
+use plugin::{LoginPluginHandler, LoginWrapper, PluginCodec};
+use read::*;
+use write::*;
+
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// The marker appended to the server address in the Handshake by Forge
+/// clients for the supported protocol version
+pub const FML_MARKER: &str = "\0FML2\0";
+
+/// The marker used by Forge clients for Minecraft 1.12 and older
+pub const FML_LEGACY_MARKER: &str = "\0FML\0";
+
+/// The channel wrapped inside `fml:loginwrapper` used for the handshake
+pub const FML_HANDSHAKE_CHANNEL: &str = "fml:handshake";
+
+/// A single packet of the `fml:handshake` channel
+#[derive(Debug, Clone, PartialEq)]
+pub enum FmlHandshakePacket {
+    /// The server's mod list, the first packet sent by the server
+    ModList {
+        /// The ids of the mods on the server
+        mods: Vec<String>,
+        /// The server's network channels, as (name, version)
+        channels: Vec<(String, String)>,
+        /// The names of the registries the server will synchronize
+        registries: Vec<String>,
+    },
+    /// The client's response to ModList
+    ModListReply {
+        /// The ids of the mods on the client
+        mods: Vec<String>,
+        /// The client's network channels, as (name, version)
+        channels: Vec<(String, String)>,
+        /// The client's registries, as (name, hash)
+        registries: Vec<(String, String)>,
+    },
+    /// The contents of a single registry
+    ServerRegistry {
+        /// The name of the registry
+        name: String,
+        /// The raw registry snapshot, if any. Ozelot does not attempt to
+        /// parse it.
+        snapshot: Option<Vec<u8>>,
+    },
+    /// A configuration file sent by the server
+    ConfigurationData {
+        /// The name of the configuration file
+        file_name: String,
+        /// The raw contents of the configuration file
+        data: Vec<u8>,
+    },
+    /// The client's acknowledgement of ServerRegistry and ConfigurationData
+    Acknowledgement,
+}
+impl FmlHandshakePacket {
+    /// Decode a packet from the data of the `fml:handshake` channel
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let id = read_varint(&mut r)?;
+        Ok(match id {
+            1 => {
+                FmlHandshakePacket::ModList {
+                    mods: read_string_list(&mut r)?,
+                    channels: read_string_pairs(&mut r)?,
+                    registries: read_string_list(&mut r)?,
+                }
+            },
+            2 => {
+                FmlHandshakePacket::ModListReply {
+                    mods: read_string_list(&mut r)?,
+                    channels: read_string_pairs(&mut r)?,
+                    registries: read_string_pairs(&mut r)?,
+                }
+            },
+            3 => {
+                let name = read_String(&mut r)?;
+                let snapshot = if read_bool(&mut r)? {
+                    Some(read_bytearray_to_end(&mut r)?)
+                } else {
+                    None
+                };
+                FmlHandshakePacket::ServerRegistry {
+                    name,
+                    snapshot,
+                }
+            },
+            4 => {
+                FmlHandshakePacket::ConfigurationData {
+                    file_name: read_String(&mut r)?,
+                    data: read_prefixed_bytearray(&mut r)?,
+                }
+            },
+            99 => FmlHandshakePacket::Acknowledgement,
+            _ => bail!("Unknown fml:handshake packet id {}", id),
+        })
+    }
+
+    /// Encode the packet into the data of the `fml:handshake` channel
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        match *self {
+            FmlHandshakePacket::ModList { ref mods, ref channels, ref registries } => {
+                write_varint(&1, &mut ret)?;
+                write_string_list(mods, &mut ret)?;
+                write_string_pairs(channels, &mut ret)?;
+                write_string_list(registries, &mut ret)?;
+            },
+            FmlHandshakePacket::ModListReply { ref mods, ref channels, ref registries } => {
+                write_varint(&2, &mut ret)?;
+                write_string_list(mods, &mut ret)?;
+                write_string_pairs(channels, &mut ret)?;
+                write_string_pairs(registries, &mut ret)?;
+            },
+            FmlHandshakePacket::ServerRegistry { ref name, ref snapshot } => {
+                write_varint(&3, &mut ret)?;
+                write_String(name, &mut ret)?;
+                write_bool(&snapshot.is_some(), &mut ret)?;
+                if let Some(ref x) = *snapshot {
+                    write_bytearray(x, &mut ret)?;
+                }
+            },
+            FmlHandshakePacket::ConfigurationData { ref file_name, ref data } => {
+                write_varint(&4, &mut ret)?;
+                write_String(file_name, &mut ret)?;
+                write_prefixed_bytearray(data, &mut ret)?;
+            },
+            FmlHandshakePacket::Acknowledgement => {
+                write_varint(&99, &mut ret)?;
+            },
+        }
+        Ok(ret)
+    }
+}
+
+/// What the client has learned about the server during the handshake
+#[derive(Debug, Clone, Default)]
+pub struct ForgeServerInfo {
+    /// The ids of the mods on the server
+    pub mods: Vec<String>,
+    /// The server's network channels, as (name, version)
+    pub channels: Vec<(String, String)>,
+    /// The registries received from the server, with their raw snapshots
+    pub registries: BTreeMap<String, Option<Vec<u8>>>,
+    /// The configuration files received from the server
+    pub configs: BTreeMap<String, Vec<u8>>,
+}
+
+/// A login plugin handler performing the client side of the Forge handshake
+///
+/// Clones share the same state, so keep a clone around to inspect what the
+/// server sent with get_server_info after having registered the handler.
+#[derive(Debug, Clone, Default)]
+pub struct ForgeHandshake {
+    mods: Vec<String>,
+    channels: Vec<(String, String)>,
+    registry_hashes: BTreeMap<String, String>,
+    server: Arc<Mutex<ForgeServerInfo>>,
+}
+impl ForgeHandshake {
+    /// Create a new handshake presenting the given mod ids, e.g.
+    /// `["minecraft", "forge"]`
+    pub fn new(mods: Vec<String>) -> Self {
+        ForgeHandshake {
+            mods,
+            channels: Vec::new(),
+            registry_hashes: BTreeMap::new(),
+            server: Arc::new(Mutex::new(ForgeServerInfo::default())),
+        }
+    }
+
+    /// Present the given network channel with the given version
+    pub fn add_channel(&mut self, name: &str, version: &str) {
+        self.channels.push((name.to_string(), version.to_string()));
+    }
+
+    /// Set the hash the client reports for the given registry. Registries
+    /// without a hash are reported with an empty hash.
+    pub fn set_registry_hash(&mut self, registry: &str, hash: &str) {
+        let _ = self.registry_hashes.insert(registry.to_string(), hash.to_string());
+    }
+
+    /// Get what the server has sent so far
+    pub fn get_server_info(&self) -> ForgeServerInfo {
+        self.server.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Handle a single `fml:handshake` packet, returning the reply
+    fn handle_packet(&mut self, packet: FmlHandshakePacket) -> Result<FmlHandshakePacket> {
+        let mut server = self.server.lock().unwrap_or_else(PoisonError::into_inner);
+        match packet {
+            FmlHandshakePacket::ModList { mods, channels, registries } => {
+                let registries = registries.into_iter()
+                    .map(|x| {
+                        let hash = self.registry_hashes.get(&x).cloned().unwrap_or_default();
+                        (x, hash)
+                    })
+                    .collect();
+                server.mods = mods;
+                server.channels = channels;
+                Ok(FmlHandshakePacket::ModListReply {
+                    mods: self.mods.clone(),
+                    channels: self.channels.clone(),
+                    registries,
+                })
+            },
+            FmlHandshakePacket::ServerRegistry { name, snapshot } => {
+                let _ = server.registries.insert(name, snapshot);
+                Ok(FmlHandshakePacket::Acknowledgement)
+            },
+            FmlHandshakePacket::ConfigurationData { file_name, data } => {
+                let _ = server.configs.insert(file_name, data);
+                Ok(FmlHandshakePacket::Acknowledgement)
+            },
+            x => bail!("Unexpected fml:handshake packet from the server: {:?}", x),
+        }
+    }
+}
+impl LoginPluginHandler for ForgeHandshake {
+    fn handle_request(&mut self, data: &[u8]) -> Result<Option<Vec<u8>>> {
+        let wrapper = LoginWrapper::decode(data)?;
+        if wrapper.channel != FML_HANDSHAKE_CHANNEL {
+            return Ok(None);
+        }
+        let reply = self.handle_packet(FmlHandshakePacket::decode(&wrapper.data)?)?;
+        let wrapper = LoginWrapper {
+            channel: FML_HANDSHAKE_CHANNEL.to_string(),
+            data: reply.encode()?,
+        };
+        Ok(Some(wrapper.encode()?))
+    }
+}
+
+fn read_string_list<R: Read>(r: &mut R) -> Result<Vec<String>> {
+    let count = read_varint(r)?;
+    if count < 0 {
+        bail!("Forge handshake had string list of negative length {}", count);
+    }
+    let mut ret = Vec::new();
+    for _ in 0..count {
+        ret.push(read_String(r)?);
+    }
+    Ok(ret)
+}
+
+fn read_string_pairs<R: Read>(r: &mut R) -> Result<Vec<(String, String)>> {
+    let count = read_varint(r)?;
+    if count < 0 {
+        bail!("Forge handshake had string pair list of negative length {}", count);
+    }
+    let mut ret = Vec::new();
+    for _ in 0..count {
+        ret.push((read_String(r)?, read_String(r)?));
+    }
+    Ok(ret)
+}
+
+fn write_string_list(val: &[String], w: &mut Vec<u8>) -> Result<()> {
+    write_varint(&(val.len() as i32), w)?;
+    for x in val {
+        write_String(x, w)?;
+    }
+    Ok(())
+}
+
+fn write_string_pairs(val: &[(String, String)], w: &mut Vec<u8>) -> Result<()> {
+    write_varint(&(val.len() as i32), w)?;
+    for (a, b) in val {
+        write_String(a, w)?;
+        write_String(b, w)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,230 @@
+//! Support for servers running behind a BungeeCord or Velocity proxy
+//!
+//! When a proxy sits in front of a server, the connection the server sees is
+//! made by the proxy rather than by the player, and in offline mode the
+//! server has no way of knowing the player's real IP or UUID. Proxies solve
+//! this by forwarding the player's information to the server.
+//!
+//! BungeeCord (legacy) forwarding stuffs the information into the server
+//! address field of the Handshake packet, separated by null bytes, see
+//! `serverbound::Handshake::get_bungeecord_forwarding`.
+//!
+//! Velocity (modern) forwarding instead has the server send a
+//! LoginPluginRequest on the `velocity:player_info` channel, to which the
+//! proxy responds with the player's information, signed with a secret shared
+//! between the proxy and the server. See `VelocityForwarding`.
+//!
+//! See also `utils::offline_uuid` for calculating the UUID of a player in
+//! offline mode, i.e. when not running behind a forwarding proxy.
+
+use clientbound::{self, ClientboundPacket};
+use errors::{Result, ResultExt};
+use json::ProfileProperties;
+use read::*;
+use serverbound::LoginPluginResponse;
+use write::*;
+
+use std::io::{Cursor, Read};
+use std::net::IpAddr;
+
+use openssl::hash::MessageDigest;
+use openssl::memcmp;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+
+use serde_json;
+
+/// The name of the login plugin channel used by Velocity modern forwarding
+pub const VELOCITY_CHANNEL: &str = "velocity:player_info";
+
+/// The highest Velocity modern forwarding version supported by ozelot
+pub const VELOCITY_MAX_VERSION: u8 = 1;
+
+/// The player information contained in a BungeeCord forwarding Handshake
+#[derive(Debug, Clone)]
+pub struct BungeeCordForwarding {
+    /// The hostname the player connected to
+    pub host: String,
+    /// The real IP address of the player
+    pub address: String,
+    /// The player's UUID
+    pub uuid: u128,
+    /// The player's profile properties, e.g. skin textures
+    pub properties: Vec<ProfileProperties>,
+}
+impl BungeeCordForwarding {
+    /// Parse BungeeCord forwarding data from the server address field of a
+    /// Handshake packet.
+    ///
+    /// The format is `host\0address\0uuid\0properties`, where the uuid is
+    /// given in hex without dashes, and the properties are a json array.
+    /// The properties may be missing entirely.
+    ///
+    /// Returns Ok(None) if the server address does not contain any
+    /// forwarding data, i.e. if the host isn't followed by an IP address and
+    /// a UUID. This is also the case for the `\0FML2\0` marker appended by
+    /// Forge clients.
+    pub fn parse(server_address: &str) -> Result<Option<Self>> {
+        let mut split = server_address.split('\0');
+        let host = split.next().unwrap_or("").to_string();
+        let address = match split.next() {
+            Some(x) if x.parse::<IpAddr>().is_ok() => x.to_string(),
+            _ => return Ok(None),
+        };
+        let uuid = match split.next().map(|x| x.replace("-", "")) {
+            Some(ref x) if x.len() == 32 && x.chars().all(|c| c.is_ascii_hexdigit()) => {
+                u128::from_str_radix(x, 16).chain_err(|| "Invalid UUID in BungeeCord forwarding data")?
+            },
+            _ => return Ok(None),
+        };
+        let properties = match split.next() {
+            Some(x) if !x.is_empty() => serde_json::from_str(x)?,
+            _ => Vec::new(),
+        };
+
+        Ok(Some(BungeeCordForwarding {
+            host,
+            address,
+            uuid,
+            properties,
+        }))
+    }
+
+    /// Create the server address for a Handshake packet containing this
+    /// forwarding data, as a BungeeCord proxy would do it.
+    pub fn to_server_address(&self) -> Result<String> {
+        let mut ret = format!("{}\0{}\0{:032x}", self.host, self.address, self.uuid);
+        if !self.properties.is_empty() {
+            ret.push('\0');
+            ret.push_str(&serde_json::to_string(&self.properties)?);
+        }
+        Ok(ret)
+    }
+}
+
+/// The player information forwarded by a Velocity proxy using modern
+/// forwarding
+///
+/// To use modern forwarding, the server sends the LoginPluginRequest created
+/// by `VelocityForwarding::new_request` after receiving the LoginStart packet,
+/// and then passes the LoginPluginResponse it gets back to
+/// `VelocityForwarding::verify`.
+#[derive(Debug, Clone)]
+pub struct VelocityForwarding {
+    /// The forwarding version used by the proxy
+    pub version: i32,
+    /// The real IP address of the player
+    pub address: String,
+    /// The player's UUID
+    pub uuid: u128,
+    /// The player's username
+    pub username: String,
+    /// The player's profile properties, e.g. skin textures
+    pub properties: Vec<ProfileProperties>,
+}
+impl VelocityForwarding {
+    /// Create the LoginPluginRequest asking the proxy for the player's
+    /// information, with the given message id.
+    pub fn new_request(message_id: i32) -> ClientboundPacket {
+        clientbound::LoginPluginRequest::new(message_id,
+                                             VELOCITY_CHANNEL.to_string(),
+                                             vec![VELOCITY_MAX_VERSION])
+    }
+
+    /// Verify the signature of the proxy's response using the secret shared
+    /// with the proxy, and parse the forwarded information.
+    ///
+    /// Returns an error if the proxy did not understand the request, or if
+    /// the signature is invalid. In both cases the client should be
+    /// disconnected.
+    pub fn verify(response: &LoginPluginResponse, secret: &[u8]) -> Result<Self> {
+        if !*response.get_successful() {
+            bail!("Velocity forwarding request was not understood, is the server behind a Velocity proxy?");
+        }
+        let data = response.get_data();
+        if data.len() < 32 {
+            bail!("Velocity forwarding response was too short");
+        }
+        let (signature, payload) = data.split_at(32);
+        let expected = hmac_sha256(secret, payload)?;
+        if !memcmp::eq(signature, &expected) {
+            bail!("Velocity forwarding response had an invalid signature");
+        }
+
+        let mut r = Cursor::new(payload);
+        let version = read_varint(&mut r)?;
+        if version < 1 || version > VELOCITY_MAX_VERSION as i32 {
+            bail!("Unsupported Velocity forwarding version {}", version);
+        }
+        let address = read_String(&mut r)?;
+        let uuid = read_u128(&mut r)?;
+        let username = read_String(&mut r)?;
+        let properties = read_properties(&mut r)?;
+
+        Ok(VelocityForwarding {
+            version,
+            address,
+            uuid,
+            username,
+            properties,
+        })
+    }
+
+    /// Create the signed LoginPluginResponse data for this information, as a
+    /// Velocity proxy would do it.
+    pub fn to_response_data(&self, secret: &[u8]) -> Result<Vec<u8>> {
+        let mut payload = Vec::new();
+        write_varint(&self.version, &mut payload)?;
+        write_String(&self.address, &mut payload)?;
+        write_u128(&self.uuid, &mut payload)?;
+        write_String(&self.username, &mut payload)?;
+        write_varint(&(self.properties.len() as i32), &mut payload)?;
+        for property in &self.properties {
+            write_String(&property.name, &mut payload)?;
+            write_String(&property.value, &mut payload)?;
+            if let Some(ref signature) = property.signature {
+                write_bool(&true, &mut payload)?;
+                write_String(signature, &mut payload)?;
+            } else {
+                write_bool(&false, &mut payload)?;
+            }
+        }
+
+        let mut ret = hmac_sha256(secret, &payload)?;
+        ret.extend(payload);
+        Ok(ret)
+    }
+}
+
+/// Read a varint-prefixed list of profile properties, as used in Velocity
+/// forwarding
+fn read_properties<R: Read>(r: &mut R) -> Result<Vec<ProfileProperties>> {
+    let count = read_varint(r)?;
+    if count < 0 {
+        bail!("Velocity forwarding had negative property count {}", count);
+    }
+    let mut ret = Vec::new();
+    for _ in 0..count {
+        let name = read_String(r)?;
+        let value = read_String(r)?;
+        let signature = if read_bool(r)? {
+            Some(read_String(r)?)
+        } else {
+            None
+        };
+        ret.push(ProfileProperties {
+            name,
+            value,
+            signature,
+        });
+    }
+    Ok(ret)
+}
+
+/// Calculate the HMAC-SHA256 of the given data
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let key = PKey::hmac(key)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(data)?;
+    Ok(signer.sign_to_vec()?)
+}
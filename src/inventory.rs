@@ -0,0 +1,493 @@
+//! The player's inventory and the currently open window
+//!
+//! `Inventory` applies the window packets the server sends, and creates the
+//! ClickWindow packets for common operations. The server doesn't tell the
+//! client the result of a click it accepts, so the operations whose outcome
+//! is certain are applied locally right away.
+//!
+//! When the outcome of a click depends on things the client doesn't know,
+//! such as the maximum stack size of an item or which slots a shift-click
+//! moves the item to, the click is sent with an empty clicked item. If the
+//! click changed anything the server then rejects the transaction and sends
+//! the whole window again. The rejected transaction must be acknowledged
+//! before the server accepts more clicks, which `handle_packet` does by
+//! returning the ConfirmTransaction to send.
+//!
+//! Slots are numbered like in the protocol. In the player's own window (id
+//! 0), 0 is the crafting output, 1-4 the crafting grid, 5-8 the armor from
+//! helmet to boots, 9-35 the main inventory, 36-44 the hotbar and 45 the
+//! offhand. Other windows have their own slots first, followed by the main
+//! inventory and the hotbar.
+
+use clientbound::*;
+use errors::Result;
+use serverbound::{ClickWindow, CloseWindow, ConfirmTransaction, HeldItemChange,
+                  ServerboundPacket};
+use slot::Slot;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The id of the player's own inventory window
+pub const PLAYER_WINDOW_ID: u8 = 0;
+
+/// The number of slots in the player's own inventory window
+pub const PLAYER_WINDOW_SIZE: usize = 46;
+
+/// The first hotbar slot in the player's own inventory window
+pub const HOTBAR_START: usize = 36;
+
+/// The first main inventory slot in the player's own inventory window
+pub const MAIN_INVENTORY_START: usize = 9;
+
+/// The number of slots of the main inventory and hotbar, which are at the
+/// end of every window
+const PLAYER_SLOTS: usize = 36;
+
+/// The window id SetSlot uses for the cursor
+const CURSOR_WINDOW_ID: u8 = 255;
+
+/// The window id SetSlot uses for setting a player inventory slot directly
+const INVENTORY_WINDOW_ID: u8 = 254;
+
+/// The slot id of clicks outside the window
+const OUTSIDE_SLOT: i16 = -999;
+
+/// The mode of a ClickWindow packet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickMode {
+    /// Mode 0, a left (button 0) or right (button 1) click
+    PickUp,
+    /// Mode 1, a shift-click
+    QuickMove,
+    /// Mode 2, pressing a number key (button 0-8) to swap with the hotbar
+    Swap,
+    /// Mode 3, a middle click in creative mode
+    Clone,
+    /// Mode 4, dropping one (button 0) or all (button 1) items of a slot
+    Throw,
+    /// Mode 5, dragging items over slots
+    QuickCraft,
+    /// Mode 6, double clicking to pick up all items of a kind
+    PickUpAll,
+}
+impl ClickMode {
+    /// Get the mode's id
+    pub fn to_i32(self) -> i32 {
+        match self {
+            ClickMode::PickUp => 0,
+            ClickMode::QuickMove => 1,
+            ClickMode::Swap => 2,
+            ClickMode::Clone => 3,
+            ClickMode::Throw => 4,
+            ClickMode::QuickCraft => 5,
+            ClickMode::PickUpAll => 6,
+        }
+    }
+
+    /// Get the mode with the given id
+    pub fn from_i32(val: i32) -> Option<Self> {
+        Some(match val {
+            0 => ClickMode::PickUp,
+            1 => ClickMode::QuickMove,
+            2 => ClickMode::Swap,
+            3 => ClickMode::Clone,
+            4 => ClickMode::Throw,
+            5 => ClickMode::QuickCraft,
+            6 => ClickMode::PickUpAll,
+            _ => return None,
+        })
+    }
+}
+
+/// A window, i.e. the player's inventory or an opened container
+#[derive(Debug, Clone, PartialEq)]
+pub struct Window {
+    /// The window id
+    pub id: u8,
+    /// The window type from OpenWindow, None for the player's inventory
+    pub window_type: Option<i32>,
+    /// The raw json of the title
+    pub title: String,
+    /// The window's slots. Empty until the server sends them.
+    pub slots: Vec<Option<Slot>>,
+    /// The values set with WindowProperty, e.g. a furnace's progress
+    pub properties: BTreeMap<i16, i16>,
+}
+impl Window {
+    /// Create a new window without slots
+    pub fn new(id: u8, window_type: Option<i32>, title: &str) -> Self {
+        Window {
+            id,
+            window_type,
+            title: title.to_string(),
+            slots: Vec::new(),
+            properties: BTreeMap::new(),
+        }
+    }
+
+    /// Get the item in the given slot
+    pub fn get_slot(&self, slot: usize) -> Option<&Slot> {
+        self.slots.get(slot).and_then(|x| x.as_ref())
+    }
+
+    /// Get the slot index of the first hotbar slot in this window
+    pub fn get_hotbar_start(&self) -> usize {
+        self.get_player_slots_start() + PLAYER_SLOTS - 9
+    }
+
+    /// Get the slot index where the main inventory starts in this window
+    pub fn get_player_slots_start(&self) -> usize {
+        if self.id == PLAYER_WINDOW_ID {
+            MAIN_INVENTORY_START
+        } else {
+            self.slots.len().saturating_sub(PLAYER_SLOTS)
+        }
+    }
+
+    /// Find the first slot containing the given item id
+    pub fn find_item(&self, item_id: i32) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|x| x.as_ref().is_some_and(|x| x.item_id == item_id))
+    }
+}
+
+/// The player's inventory, the open window and the item on the cursor
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    player: Window,
+    open: Option<Window>,
+    cursor: Option<Slot>,
+    held_slot: u8,
+    next_action: i16,
+    pending: BTreeSet<(u8, i16)>,
+}
+impl Default for Inventory {
+    fn default() -> Self {
+        let mut player = Window::new(PLAYER_WINDOW_ID, None, "");
+        player.slots = vec![None; PLAYER_WINDOW_SIZE];
+        Inventory {
+            player,
+            open: None,
+            cursor: None,
+            held_slot: 0,
+            next_action: 1,
+            pending: BTreeSet::new(),
+        }
+    }
+}
+impl Inventory {
+    /// Create a new, empty inventory
+    pub fn new() -> Self {
+        Inventory::default()
+    }
+
+    /// Apply the packet if it is about windows or the held item, returning
+    /// the packet that must be sent in reply, if any
+    pub fn handle_packet(&mut self,
+                         packet: &ClientboundPacket)
+                         -> Result<Option<ServerboundPacket>> {
+        match *packet {
+            ClientboundPacket::OpenWindow(ref x) => {
+                let id = *x.get_window_id();
+                if !(1..=255).contains(&id) {
+                    bail!("OpenWindow had invalid window id {}", id);
+                }
+                self.open = Some(Window::new(id as u8, Some(*x.get_window_type()), x.get_window_title()));
+                self.next_action = 1;
+            },
+            ClientboundPacket::WindowItems(ref x) => {
+                let items = x.get_items()?;
+                if let Some(window) = self.get_window_mut(*x.get_window_id()) {
+                    window.slots = items;
+                }
+                if *x.get_window_id() == PLAYER_WINDOW_ID {
+                    self.player.slots.resize(PLAYER_WINDOW_SIZE, None);
+                    self.sync_open_slots();
+                } else {
+                    self.sync_player_slots();
+                }
+            },
+            ClientboundPacket::SetSlot(ref x) => {
+                self.handle_set_slot(*x.get_window_id(), *x.get_slot_id(), x.get_slot()?)
+            },
+            ClientboundPacket::WindowProperty(ref x) => {
+                if let Some(window) = self.get_window_mut(*x.get_window_id()) {
+                    let _ = window.properties.insert(*x.get_property(), *x.get_new_value());
+                }
+            },
+            ClientboundPacket::ClientboundConfirmTransaction(ref x) => {
+                let key = (*x.get_window_id(), *x.get_action_id());
+                let _ = self.pending.remove(&key);
+                if !*x.get_accepted() {
+                    return Ok(Some(ConfirmTransaction::new(key.0, key.1, false)));
+                }
+            },
+            ClientboundPacket::ClientboundCloseWindow(ref x)
+                if self.open.as_ref().is_some_and(|w| w.id == *x.get_window_id()) => self.close(),
+            ClientboundPacket::ClientboundHeldItemChange(ref x) if *x.get_slot() < 9 => {
+                self.held_slot = *x.get_slot();
+            },
+            _ => (),
+        }
+        Ok(None)
+    }
+
+    fn handle_set_slot(&mut self, window_id: u8, slot: i16, item: Option<Slot>) {
+        if window_id == CURSOR_WINDOW_ID && slot == -1 {
+            self.cursor = item;
+            return;
+        }
+        if window_id == INVENTORY_WINDOW_ID {
+            /* The slot is an index into the player's inventory, with the
+             * hotbar first and then the main inventory, armor and offhand */
+            let index = match slot {
+                0..=8 => HOTBAR_START + slot as usize,
+                9..=35 => slot as usize,
+                36..=39 => 8 - (slot as usize - 36),
+                40 => 45,
+                _ => return,
+            };
+            self.player.slots[index] = item;
+            self.sync_open_slots();
+            return;
+        }
+        if slot < 0 {
+            return;
+        }
+        if let Some(window) = self.get_window_mut(window_id) {
+            if let Some(x) = window.slots.get_mut(slot as usize) {
+                *x = item;
+            }
+        }
+        if window_id == PLAYER_WINDOW_ID {
+            self.sync_open_slots();
+        } else {
+            self.sync_player_slots();
+        }
+    }
+
+    /// Copy the main inventory and hotbar from the open window to the
+    /// player's window
+    fn sync_player_slots(&mut self) {
+        if let Some(ref window) = self.open {
+            let start = window.get_player_slots_start();
+            if window.slots.len() >= PLAYER_SLOTS {
+                self.player.slots[MAIN_INVENTORY_START..MAIN_INVENTORY_START + PLAYER_SLOTS]
+                    .clone_from_slice(&window.slots[start..]);
+            }
+        }
+    }
+
+    /// Copy the main inventory and hotbar from the player's window to the
+    /// open window
+    fn sync_open_slots(&mut self) {
+        if let Some(ref mut window) = self.open {
+            let start = window.get_player_slots_start();
+            if window.slots.len() >= PLAYER_SLOTS {
+                window.slots[start..]
+                    .clone_from_slice(&self.player.slots[MAIN_INVENTORY_START..MAIN_INVENTORY_START + PLAYER_SLOTS]);
+            }
+        }
+    }
+
+    fn get_window_mut(&mut self, id: u8) -> Option<&mut Window> {
+        if id == PLAYER_WINDOW_ID {
+            return Some(&mut self.player);
+        }
+        match self.open {
+            Some(ref mut window) if window.id == id => Some(window),
+            _ => None,
+        }
+    }
+
+    /// Get the player's own inventory window
+    pub fn get_player_window(&self) -> &Window {
+        &self.player
+    }
+
+    /// Get the opened window, if any
+    pub fn get_open_window(&self) -> Option<&Window> {
+        self.open.as_ref()
+    }
+
+    /// Get the window clicks go to, i.e. the opened window if any, otherwise
+    /// the player's inventory
+    pub fn get_active_window(&self) -> &Window {
+        self.open.as_ref().unwrap_or(&self.player)
+    }
+
+    /// Get the item on the cursor
+    pub fn get_cursor(&self) -> Option<&Slot> {
+        self.cursor.as_ref()
+    }
+
+    /// Get the selected hotbar slot (0-8)
+    pub fn get_held_slot(&self) -> u8 {
+        self.held_slot
+    }
+
+    /// Get the item in the selected hotbar slot
+    pub fn get_held_item(&self) -> Option<&Slot> {
+        self.player.get_slot(HOTBAR_START + self.held_slot as usize)
+    }
+
+    /// Check whether there are clicks the server hasn't confirmed yet
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Select the given hotbar slot (0-8), returning the packet telling the
+    /// server
+    pub fn select_hotbar(&mut self, slot: u8) -> Result<ServerboundPacket> {
+        if slot >= 9 {
+            bail!("Hotbar slot {} is out of range", slot);
+        }
+        self.held_slot = slot;
+        Ok(HeldItemChange::new(i16::from(slot)))
+    }
+
+    /// Close the opened window, returning the packet telling the server. Any
+    /// item on the cursor is dropped by the server.
+    pub fn close_window(&mut self) -> Option<ServerboundPacket> {
+        let id = self.open.as_ref()?.id;
+        self.close();
+        Some(CloseWindow::new(id))
+    }
+
+    fn close(&mut self) {
+        self.open = None;
+        self.cursor = None;
+        self.next_action = 1;
+        self.pending.clear();
+    }
+
+    /// Left click (or right click if `right` is set) the given slot of the
+    /// active window
+    pub fn click(&mut self, slot: usize, right: bool) -> Result<ServerboundPacket> {
+        let clicked = self.get_clicked_slot(slot)?.cloned();
+        let cursor = self.cursor.clone();
+        let predicted = match (clicked.clone(), cursor) {
+            (None, None) => Some((None, None)),
+            (Some(x), None) => {
+                if right {
+                    let taken = (x.count + 1) / 2;
+                    let left = x.count - taken;
+                    let mut cursor = x.clone();
+                    cursor.count = taken;
+                    let mut slot = x;
+                    slot.count = left;
+                    Some((if left > 0 { Some(slot) } else { None }, Some(cursor)))
+                } else {
+                    Some((None, Some(x)))
+                }
+            },
+            (None, Some(x)) => {
+                if right {
+                    let mut slot = x.clone();
+                    slot.count = 1;
+                    let mut cursor = x;
+                    cursor.count -= 1;
+                    Some((Some(slot), if cursor.count > 0 { Some(cursor) } else { None }))
+                } else {
+                    Some((Some(x), None))
+                }
+            },
+            /* Stacking needs the maximum stack size */
+            (Some(ref a), Some(ref b)) if a.item_id == b.item_id && a.nbt == b.nbt => None,
+            (Some(a), Some(b)) => Some((Some(b), Some(a))),
+        };
+
+        let button = if right { 1 } else { 0 };
+        match predicted {
+            Some((slot_item, cursor)) => {
+                self.set_active_slot(slot, slot_item);
+                self.cursor = cursor;
+                self.send_click(slot as i16, button, ClickMode::PickUp, &clicked)
+            },
+            None => self.send_click(slot as i16, button, ClickMode::PickUp, &None),
+        }
+    }
+
+    /// Shift-click the given slot of the active window, moving its items to
+    /// the other part of the window. This isn't applied locally, instead the
+    /// server resends the window if anything moved.
+    pub fn shift_click(&mut self, slot: usize) -> Result<ServerboundPacket> {
+        let _ = self.get_clicked_slot(slot)?;
+        self.send_click(slot as i16, 0, ClickMode::QuickMove, &None)
+    }
+
+    /// Swap the given slot of the active window with the given hotbar slot
+    /// (0-8), like pressing a number key while hovering over the slot
+    pub fn swap_hotbar(&mut self, slot: usize, hotbar: u8) -> Result<ServerboundPacket> {
+        if hotbar >= 9 {
+            bail!("Hotbar slot {} is out of range", hotbar);
+        }
+        let item = self.get_clicked_slot(slot)?.cloned();
+        let hotbar_slot = self.get_active_window().get_hotbar_start() + hotbar as usize;
+        let hotbar_item = self.get_clicked_slot(hotbar_slot)?.cloned();
+        self.set_active_slot(slot, hotbar_item);
+        self.set_active_slot(hotbar_slot, item);
+        self.send_click(slot as i16, hotbar as i8, ClickMode::Swap, &None)
+    }
+
+    /// Drop one item (or the whole stack if `whole_stack` is set) from the
+    /// given slot of the active window
+    pub fn drop(&mut self, slot: usize, whole_stack: bool) -> Result<ServerboundPacket> {
+        let item = match self.get_clicked_slot(slot)?.cloned() {
+            Some(mut x) if !whole_stack && x.count > 1 => {
+                x.count -= 1;
+                Some(x)
+            },
+            _ => None,
+        };
+        if self.cursor.is_none() {
+            self.set_active_slot(slot, item);
+        }
+        let button = if whole_stack { 1 } else { 0 };
+        self.send_click(slot as i16, button, ClickMode::Throw, &None)
+    }
+
+    /// Drop the item on the cursor, or just one of them if `single` is set,
+    /// by clicking outside the window
+    pub fn drop_cursor(&mut self, single: bool) -> Result<ServerboundPacket> {
+        self.cursor = match self.cursor.take() {
+            Some(mut x) if single && x.count > 1 => {
+                x.count -= 1;
+                Some(x)
+            },
+            _ => None,
+        };
+        let button = if single { 1 } else { 0 };
+        self.send_click(OUTSIDE_SLOT, button, ClickMode::PickUp, &None)
+    }
+
+    fn get_clicked_slot(&self, slot: usize) -> Result<Option<&Slot>> {
+        let window = self.get_active_window();
+        if slot >= window.slots.len() {
+            bail!("Slot {} is outside window {} with {} slots", slot, window.id, window.slots.len());
+        }
+        Ok(window.get_slot(slot))
+    }
+
+    fn set_active_slot(&mut self, slot: usize, item: Option<Slot>) {
+        match self.open {
+            Some(ref mut window) => window.slots[slot] = item,
+            None => self.player.slots[slot] = item,
+        }
+        self.sync_player_slots();
+    }
+
+    fn send_click(&mut self,
+                  slot: i16,
+                  button: i8,
+                  mode: ClickMode,
+                  clicked: &Option<Slot>)
+                  -> Result<ServerboundPacket> {
+        let window_id = self.get_active_window().id;
+        let action = self.next_action;
+        self.next_action = self.next_action.wrapping_add(1);
+        let _ = self.pending.insert((window_id, action));
+        ClickWindow::new_click(window_id, slot, button, action, mode.to_i32(), clicked)
+    }
+}
@@ -51,7 +51,7 @@ pub struct Profile {
 /// Represents the properties part of a Profile response
 ///
 /// Used in the UUIDToProfile and SessionHasJoined requests.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ProfileProperties {
     pub name: String,
     pub value: String,
@@ -89,9 +89,9 @@ pub struct AuthenticationResponse {
 #[derive(Debug, Deserialize, Clone)]
 pub struct SessionHasJoinedResponse {
     /// The uuid
-    id: String,
-    name: String,
-    properties: Vec<ProfileProperties>,
+    pub id: String,
+    pub name: String,
+    pub properties: Vec<ProfileProperties>,
 }
 
 
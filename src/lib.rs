@@ -101,6 +101,7 @@
 extern crate byteorder;
 extern crate curl;
 extern crate flate2;
+extern crate mio;
 extern crate netbuf;
 extern crate openssl;
 #[macro_use]
@@ -115,13 +116,39 @@ mod connection;
 #[allow(non_snake_case)]
 mod json;
 mod server;
+pub mod advancements;
+pub mod attributes;
+pub mod bossbar;
+pub mod chunk;
 pub mod clientbound;
+pub mod commands;
+pub mod dispatch;
 pub mod errors;
+pub mod forge;
+pub mod forwarding;
+pub mod inventory;
+pub mod light;
+pub mod limbo;
+pub mod listener;
+pub mod map;
+pub mod metadata;
 #[allow(non_snake_case)]
 pub mod mojang;
+pub mod movement;
+pub mod pathfinding;
+pub mod plugin;
 pub mod read;
+pub mod reconnect;
+pub mod recipes;
+pub mod registry;
+pub mod scoreboard;
 pub mod serverbound;
+pub mod slot;
+pub mod tags;
+pub mod trading;
 pub mod utils;
+pub mod world;
+pub mod worldborder;
 pub mod write;
 #[cfg(test)]
 mod tests;
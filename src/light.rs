@@ -0,0 +1,195 @@
+//! Chunk light data, as sent in the UpdateLight packet
+//!
+//! Light is sent per 16x16x16 section, for the 18 sections from y = -16 to
+//! y = 271, i.e. including one section below and one above the world. Each
+//! section's light is a 2048 byte array of 4 bit values. The masks in the
+//! packet tell which sections have light arrays, and which sections should be
+//! set to all zero.
+
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::io::{Cursor, Read};
+
+/// The number of sections in the light masks
+pub const LIGHT_SECTIONS: usize = 18;
+
+/// The size of a single section's light array in bytes
+pub const LIGHT_ARRAY_SIZE: usize = 2048;
+
+/// A section's light array, 4096 4-bit values ordered by y, then z, then x
+pub type LightArray = Vec<u8>;
+
+/// The light of a chunk column, as sent in the UpdateLight packet
+#[derive(Debug, Clone, PartialEq)]
+pub struct LightData {
+    /// The sky light arrays, indexed by section from y = -16 upwards. None
+    /// for sections that are not updated.
+    pub sky_light: Vec<Option<LightArray>>,
+    /// The block light arrays, indexed by section from y = -16 upwards. None
+    /// for sections that are not updated.
+    pub block_light: Vec<Option<LightArray>>,
+    /// The mask of sections whose sky light should be set to all zero
+    pub empty_sky_light_mask: i32,
+    /// The mask of sections whose block light should be set to all zero
+    pub empty_block_light_mask: i32,
+}
+impl Default for LightData {
+    fn default() -> Self {
+        LightData {
+            sky_light: vec![None; LIGHT_SECTIONS],
+            block_light: vec![None; LIGHT_SECTIONS],
+            empty_sky_light_mask: 0,
+            empty_block_light_mask: 0,
+        }
+    }
+}
+impl LightData {
+    /// Decode the light arrays following the masks in an UpdateLight packet
+    pub fn decode(sky_light_mask: i32,
+                  block_light_mask: i32,
+                  empty_sky_light_mask: i32,
+                  empty_block_light_mask: i32,
+                  data: &[u8])
+                  -> Result<Self> {
+        let mut r = Cursor::new(data);
+        Ok(LightData {
+            sky_light: read_light_arrays(sky_light_mask, &mut r)?,
+            block_light: read_light_arrays(block_light_mask, &mut r)?,
+            empty_sky_light_mask,
+            empty_block_light_mask,
+        })
+    }
+
+    /// Get the mask of sections with sky light arrays
+    pub fn get_sky_light_mask(&self) -> i32 {
+        get_mask(&self.sky_light)
+    }
+
+    /// Get the mask of sections with block light arrays
+    pub fn get_block_light_mask(&self) -> i32 {
+        get_mask(&self.block_light)
+    }
+
+    /// Encode the light arrays, i.e. the data following the masks in an
+    /// UpdateLight packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_light_arrays(&self.sky_light, &mut ret)?;
+        write_light_arrays(&self.block_light, &mut ret)?;
+        Ok(ret)
+    }
+
+    /// Get the sky light at the given block, with x and z relative to the
+    /// chunk (0-15) and y absolute. Returns None if the section's sky light
+    /// is not included, or its array doesn't have LIGHT_ARRAY_SIZE bytes.
+    pub fn get_sky_light(&self, x: usize, y: i32, z: usize) -> Option<u8> {
+        get_light(&self.sky_light, x, y, z)
+    }
+
+    /// Get the block light at the given block, with x and z relative to the
+    /// chunk (0-15) and y absolute. Returns None if the section's block
+    /// light is not included, or its array doesn't have LIGHT_ARRAY_SIZE
+    /// bytes.
+    pub fn get_block_light(&self, x: usize, y: i32, z: usize) -> Option<u8> {
+        get_light(&self.block_light, x, y, z)
+    }
+
+    /// Set the sky light at the given block, adding the section's array if
+    /// missing. Fails if the block is outside the lit sections.
+    pub fn set_sky_light(&mut self, x: usize, y: i32, z: usize, value: u8) -> Result<()> {
+        set_light(&mut self.sky_light, x, y, z, value)
+    }
+
+    /// Set the block light at the given block, adding the section's array if
+    /// missing. Fails if the block is outside the lit sections.
+    pub fn set_block_light(&mut self, x: usize, y: i32, z: usize, value: u8) -> Result<()> {
+        set_light(&mut self.block_light, x, y, z, value)
+    }
+}
+
+fn read_light_arrays<R: Read>(mask: i32, r: &mut R) -> Result<Vec<Option<LightArray>>> {
+    let mut ret = Vec::with_capacity(LIGHT_SECTIONS);
+    for section in 0..LIGHT_SECTIONS {
+        if mask & (1 << section) != 0 {
+            let array = read_prefixed_bytearray(r)?;
+            if array.len() != LIGHT_ARRAY_SIZE {
+                bail!("Light array of section {} has length {}", section, array.len());
+            }
+            ret.push(Some(array));
+        } else {
+            ret.push(None);
+        }
+    }
+    Ok(ret)
+}
+
+fn write_light_arrays(arrays: &[Option<LightArray>], w: &mut Vec<u8>) -> Result<()> {
+    if arrays.len() > LIGHT_SECTIONS {
+        bail!("Light data has {} sections, expected at most {}", arrays.len(), LIGHT_SECTIONS);
+    }
+    for array in arrays.iter().filter_map(|x| x.as_ref()) {
+        if array.len() != LIGHT_ARRAY_SIZE {
+            bail!("Light array has length {}, expected {}", array.len(), LIGHT_ARRAY_SIZE);
+        }
+        write_prefixed_bytearray(array, w)?;
+    }
+    Ok(())
+}
+
+fn get_mask(arrays: &[Option<LightArray>]) -> i32 {
+    arrays.iter()
+        .enumerate()
+        .filter(|&(_, x)| x.is_some())
+        .fold(0, |mask, (section, _)| mask | (1 << section))
+}
+
+/// Get the section index and the nibble index within the section of the
+/// given block
+fn get_index(x: usize, y: i32, z: usize) -> Option<(usize, usize)> {
+    if x >= 16 || z >= 16 || y < -16 {
+        return None;
+    }
+    let section = ((y >> 4) + 1) as usize;
+    if section >= LIGHT_SECTIONS {
+        return None;
+    }
+    Some((section, ((y & 15) as usize) * 256 + z * 16 + x))
+}
+
+fn get_light(arrays: &[Option<LightArray>], x: usize, y: i32, z: usize) -> Option<u8> {
+    let (section, index) = get_index(x, y, z)?;
+    let array = arrays.get(section)?.as_ref()?;
+    if array.len() != LIGHT_ARRAY_SIZE {
+        return None;
+    }
+    let byte = array[index / 2];
+    Some(if index % 2 == 0 { byte & 0x0f } else { byte >> 4 })
+}
+
+fn set_light(arrays: &mut Vec<Option<LightArray>>,
+             x: usize,
+             y: i32,
+             z: usize,
+             value: u8)
+             -> Result<()> {
+    let (section, index) = match get_index(x, y, z) {
+        Some(x) => x,
+        None => bail!("Block ({}, {}, {}) is outside the lit sections", x, y, z),
+    };
+    if arrays.len() < LIGHT_SECTIONS {
+        arrays.resize(LIGHT_SECTIONS, None);
+    }
+    let array = arrays[section].get_or_insert_with(|| vec![0; LIGHT_ARRAY_SIZE]);
+    if array.len() != LIGHT_ARRAY_SIZE {
+        bail!("Light array has length {}, expected {}", array.len(), LIGHT_ARRAY_SIZE);
+    }
+    let byte = &mut array[index / 2];
+    if index % 2 == 0 {
+        *byte = (*byte & 0xf0) | (value & 0x0f);
+    } else {
+        *byte = (*byte & 0x0f) | (value << 4);
+    }
+    Ok(())
+}
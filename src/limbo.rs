@@ -0,0 +1,453 @@
+//! A minimal Play state server, e.g. for parking players while a backend
+//! restarts
+//!
+//! LimboServer builds on ServerListener. It answers server list pings, logs
+//! players in (in offline or online mode), sends them JoinGame, the chunks of
+//! a LimboWorld and their spawn position, keeps the connection alive and
+//! relays chat between the players. Everything else the players send is
+//! returned from poll() for the consumer to handle.
+//!
+//! It is also a convenient server to test Client against.
+use chunk::ChunkColumn;
+use clientbound::{ChatMessage, ChunkData, EncryptionRequest, JoinGame, PlayerAbilities,
+                  PlayerPositionAndLook, StatusPong, StatusResponse, UpdateViewPosition};
+use errors::Result;
+use listener::{ConnectionId, ListenerEvent, PlayerProfile, ServerListener};
+use mojang::SessionHasJoined;
+use serverbound::ServerboundPacket;
+use world::PlayerPosition;
+use {PROTOCOL_VERSION, utils};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use openssl::pkey::Private;
+use openssl::rsa::Rsa;
+
+/// The version name sent in server list pings
+const VERSION_NAME: &str = "1.15.2";
+
+/// Provides the world players are put in
+pub trait LimboWorld {
+    /// Get the chunks to send to a player when they join
+    fn get_chunks(&mut self) -> Result<Vec<ChunkColumn>>;
+    /// Get the position players spawn at
+    fn get_spawn(&self) -> PlayerPosition;
+}
+
+/// A world of nothing but air
+#[derive(Debug, Clone)]
+pub struct VoidWorld {
+    /// How many chunks around the spawn chunk to send
+    pub radius: i32,
+    /// Where players spawn
+    pub spawn: PlayerPosition,
+}
+impl VoidWorld {
+    /// Create a new VoidWorld where players spawn at y = 64, with only the
+    /// spawn chunk sent
+    pub fn new() -> Self {
+        VoidWorld {
+            radius: 0,
+            spawn: PlayerPosition {
+                x: 0.5,
+                y: 64.0,
+                z: 0.5,
+                yaw: 0.0,
+                pitch: 0.0,
+            },
+        }
+    }
+}
+impl Default for VoidWorld {
+    fn default() -> Self {
+        VoidWorld::new()
+    }
+}
+impl LimboWorld for VoidWorld {
+    fn get_chunks(&mut self) -> Result<Vec<ChunkColumn>> {
+        let chunk_x = (self.spawn.x.floor() as i32) >> 4;
+        let chunk_z = (self.spawn.z.floor() as i32) >> 4;
+        let mut ret = Vec::new();
+        for x in chunk_x - self.radius..chunk_x + self.radius + 1 {
+            for z in chunk_z - self.radius..chunk_z + self.radius + 1 {
+                ret.push(ChunkColumn::new(x, z));
+            }
+        }
+        Ok(ret)
+    }
+
+    fn get_spawn(&self) -> PlayerPosition {
+        self.spawn
+    }
+}
+
+/// A flat world made of the given layers of block states, starting at y = 0
+#[derive(Debug, Clone)]
+pub struct FlatWorld {
+    /// How many chunks around 0, 0 to send
+    pub radius: i32,
+    /// The block state of each layer, from the bottom up
+    pub layers: Vec<i32>,
+}
+impl FlatWorld {
+    /// Create a new FlatWorld with the given layers, sending 2 chunks around
+    /// 0, 0 in every direction
+    pub fn new(layers: Vec<i32>) -> Self {
+        FlatWorld { radius: 2, layers }
+    }
+}
+impl LimboWorld for FlatWorld {
+    fn get_chunks(&mut self) -> Result<Vec<ChunkColumn>> {
+        let mut ret = Vec::new();
+        for chunk_x in -self.radius..self.radius + 1 {
+            for chunk_z in -self.radius..self.radius + 1 {
+                let mut chunk = ChunkColumn::new(chunk_x, chunk_z);
+                for (y, &state) in self.layers.iter().enumerate() {
+                    for x in 0..16 {
+                        for z in 0..16 {
+                            chunk.set_block(x, y as i32, z, state)?;
+                        }
+                    }
+                }
+                ret.push(chunk);
+            }
+        }
+        Ok(ret)
+    }
+
+    /// On top of the layers, in the middle of block 0, 0
+    fn get_spawn(&self) -> PlayerPosition {
+        PlayerPosition {
+            x: 0.5,
+            y: self.layers.len() as f64,
+            z: 0.5,
+            yaw: 0.0,
+            pitch: 0.0,
+        }
+    }
+}
+
+/// Decides what to do with chat messages sent by players
+pub trait ChatHandler {
+    /// Handle a message sent by the given player, returning the chat (as
+    /// json) to send to every player, or None to not relay the message
+    fn handle_chat(&mut self, sender: &PlayerProfile, message: &str) -> Option<String>;
+}
+impl<F: FnMut(&PlayerProfile, &str) -> Option<String>> ChatHandler for F {
+    fn handle_chat(&mut self, sender: &PlayerProfile, message: &str) -> Option<String> {
+        self(sender, message)
+    }
+}
+
+/// The default ChatHandler, relaying messages as `<username> message`
+fn relay_chat(sender: &PlayerProfile, message: &str) -> Option<String> {
+    Some(utils::str_to_chat(&format!("<{}> {}", sender.username, message)))
+}
+
+/// Something that happened to a LimboServer
+#[derive(Debug)]
+pub enum LimboEvent {
+    /// The player logged in and has been sent the world
+    Joined(ConnectionId, PlayerProfile),
+    /// The connection was closed, with the profile if the player had logged
+    /// in and the reason
+    Left(ConnectionId, Option<PlayerProfile>, String),
+    /// A play state packet not handled by the LimboServer
+    Packet(ConnectionId, ServerboundPacket),
+}
+
+/// A player waiting for its EncryptionResponse in online mode
+struct PendingLogin {
+    username: String,
+    verify_token: Vec<u8>,
+}
+
+/// A player that has joined
+struct Player {
+    profile: PlayerProfile,
+    entity_id: i32,
+}
+
+/// A minimal server, see the module documentation
+pub struct LimboServer {
+    listener: ServerListener,
+    world: Box<dyn LimboWorld>,
+    chat: Box<dyn ChatHandler>,
+    motd: String,
+    gamemode: u8,
+    /* The key used for encryption in online mode */
+    key: Option<Rsa<Private>>,
+    pending_logins: HashMap<ConnectionId, PendingLogin>,
+    players: HashMap<ConnectionId, Player>,
+    next_entity_id: i32,
+}
+impl fmt::Debug for LimboServer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LimboServer")
+            .field("listener", &self.listener)
+            .field("motd", &self.motd)
+            .field("online_mode", &self.key.is_some())
+            .field("players", &self.players.len())
+            .finish()
+    }
+}
+impl LimboServer {
+    /// Bind to the given address, putting players in the given world.
+    ///
+    /// The server starts in offline mode, with players in adventure mode.
+    pub fn bind<W: LimboWorld + 'static>(addr: &str, world: W) -> Result<Self> {
+        let mut listener = ServerListener::bind(addr)?;
+        listener.enable_keepalive(Duration::from_secs(15), Duration::from_secs(30));
+        Ok(LimboServer {
+            listener,
+            world: Box::new(world),
+            chat: Box::new(relay_chat),
+            motd: "A Minecraft Server".to_string(),
+            gamemode: 2,
+            key: None,
+            pending_logins: HashMap::new(),
+            players: HashMap::new(),
+            next_entity_id: 1,
+        })
+    }
+
+    /// Get the ServerListener, e.g. to set max players or broadcast packets
+    pub fn get_listener(&mut self) -> &mut ServerListener {
+        &mut self.listener
+    }
+
+    /// Set the ChatHandler deciding which messages are relayed
+    pub fn set_chat_handler<H: ChatHandler + 'static>(&mut self, handler: H) {
+        self.chat = Box::new(handler);
+    }
+
+    /// Set the plaintext message shown in the server list
+    pub fn set_motd(&mut self, motd: &str) {
+        self.motd = motd.to_string();
+    }
+
+    /// Set the gamemode players join with, see JoinGame
+    pub fn set_gamemode(&mut self, gamemode: u8) {
+        self.gamemode = gamemode;
+    }
+
+    /// Whether to authenticate players with Mojang and encrypt connections.
+    ///
+    /// Note that authenticating a player blocks poll() for the duration of
+    /// the request to Mojang.
+    pub fn set_online_mode(&mut self, online_mode: bool) {
+        self.key = if online_mode {
+            Some(utils::generate_rsa_key())
+        } else {
+            None
+        };
+    }
+
+    /// Set how often a KeepAlive is sent to each player, and how long they
+    /// have to answer it before being disconnected. Default 15 and 30
+    /// seconds.
+    pub fn set_keepalive(&mut self, interval: Duration, timeout: Duration) {
+        self.listener.enable_keepalive(interval, timeout)
+    }
+
+    /// Get the entity id of the given player
+    pub fn get_entity_id(&self, id: ConnectionId) -> Option<i32> {
+        self.players.get(&id).map(|x| x.entity_id)
+    }
+
+    /// Disconnect the given player with the given plaintext reason
+    pub fn disconnect(&mut self, id: ConnectionId, reason: &str) {
+        self.listener.disconnect(id, reason)
+    }
+
+    /// Accept connections, handle the packets the LimboServer is responsible
+    /// for and send KeepAlives.
+    ///
+    /// This function is nonblocking, except while authenticating players in
+    /// online mode.
+    pub fn poll(&mut self) -> Result<Vec<LimboEvent>> {
+        self.poll_timeout(Some(Duration::from_secs(0)))
+    }
+
+    /// Like poll, but first wait until a connection is ready or the timeout
+    /// has passed, see ServerListener::poll_timeout
+    pub fn poll_timeout(&mut self, timeout: Option<Duration>) -> Result<Vec<LimboEvent>> {
+        let mut ret = Vec::new();
+        for event in self.listener.poll_timeout(timeout)? {
+            match event {
+                ListenerEvent::Packet(id, packet) => {
+                    if let Err(e) = self.handle_packet(id, packet, &mut ret) {
+                        self.listener.disconnect(id, &e.to_string());
+                    }
+                },
+                ListenerEvent::Disconnected(id, reason) => {
+                    let _ = self.pending_logins.remove(&id);
+                    let profile = self.players.remove(&id).map(|x| x.profile);
+                    ret.push(LimboEvent::Left(id, profile, reason));
+                },
+                ListenerEvent::Connected(..) |
+                ListenerEvent::Rejected(..) |
+                ListenerEvent::Latency(..) => (),
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Handle a single packet from the given connection
+    fn handle_packet(&mut self,
+                     id: ConnectionId,
+                     packet: ServerboundPacket,
+                     events: &mut Vec<LimboEvent>)
+                     -> Result<()> {
+        match packet {
+            ServerboundPacket::StatusRequest(..) => {
+                let _: usize = self.listener.send(id, StatusResponse::new(self.get_status()))?;
+            },
+            ServerboundPacket::StatusPing(ref p) => {
+                let _: usize = self.listener.send(id, StatusPong::new(*p.get_id()))?;
+            },
+            ServerboundPacket::LoginStart(ref p) => {
+                let username = p.get_name().clone();
+                let request = match self.key {
+                    Some(ref key) => {
+                        let verify_token = utils::create_shared_secret()[..4].to_vec();
+                        let request = EncryptionRequest::new(String::new(),
+                                                             utils::rsa_key_binary(key),
+                                                             verify_token.clone());
+                        let _ = self.pending_logins
+                            .insert(id,
+                                    PendingLogin {
+                                        username: username.clone(),
+                                        verify_token,
+                                    });
+                        request
+                    },
+                    None => {
+                        return self.join(id, PlayerProfile::offline(&username), events);
+                    },
+                };
+                let _: usize = self.listener.send(id, request)?;
+            },
+            ServerboundPacket::EncryptionResponse(ref p) => {
+                let profile = self.authenticate(id, p)?;
+                self.join(id, profile, events)?;
+            },
+            ServerboundPacket::ChatMessage(ref p) => {
+                let profile = match self.players.get(&id) {
+                    Some(x) => x.profile.clone(),
+                    None => bail!("Got ChatMessage before logging in"),
+                };
+                if let Some(chat) = self.chat.handle_chat(&profile, p.get_message()) {
+                    /* Position 0 is a player chat message */
+                    self.listener.broadcast(ChatMessage::new(chat, 0));
+                }
+            },
+            ServerboundPacket::Handshake(..) |
+            ServerboundPacket::TeleportConfirm(..) => (),
+            packet => events.push(LimboEvent::Packet(id, packet)),
+        }
+        Ok(())
+    }
+
+    /// Finish an online mode login, enabling encryption and checking the
+    /// player has joined with Mojang
+    fn authenticate(&mut self,
+                    id: ConnectionId,
+                    response: &::serverbound::EncryptionResponse)
+                    -> Result<PlayerProfile> {
+        let pending = match self.pending_logins.remove(&id) {
+            Some(x) => x,
+            None => bail!("Got unexpected EncryptionResponse"),
+        };
+        let key = match self.key {
+            Some(ref x) => x,
+            None => bail!("Got EncryptionResponse in offline mode"),
+        };
+        if response.get_decrypted_verify_token(key)? != pending.verify_token {
+            bail!("Invalid verify token");
+        }
+        let shared_secret = response.get_decrypted_shared_secret(key)?;
+        if let Some(peer) = self.listener.get_mut(id) {
+            peer.get_server().enable_encryption(&shared_secret);
+        }
+
+        let joined = SessionHasJoined::new(pending.username,
+                                           "",
+                                           &shared_secret,
+                                           &utils::rsa_key_binary(key))
+                .perform()?;
+        let uuid = match u128::from_str_radix(&joined.id, 16) {
+            Ok(x) => x,
+            Err(_) => bail!("Invalid uuid {} from Mojang", joined.id),
+        };
+        Ok(PlayerProfile {
+               uuid,
+               username: joined.name,
+           })
+    }
+
+    /// Log the player in and send it the world
+    fn join(&mut self,
+            id: ConnectionId,
+            profile: PlayerProfile,
+            events: &mut Vec<LimboEvent>)
+            -> Result<()> {
+        self.listener.login(id, profile.clone())?;
+        let entity_id = self.next_entity_id;
+        self.next_entity_id += 1;
+        let spawn = self.world.get_spawn();
+
+        let mut packets = vec![JoinGame::new(entity_id,
+                                             self.gamemode,
+                                             0,
+                                             0,
+                                             0,
+                                             "flat".to_string(),
+                                             2,
+                                             false,
+                                             true),
+                               PlayerAbilities::new(0, 0.05, 0.1),
+                               UpdateViewPosition::new((spawn.x.floor() as i32) >> 4,
+                                                       (spawn.z.floor() as i32) >> 4)];
+        for chunk in self.world.get_chunks()? {
+            packets.push(ChunkData::new_chunk(&chunk)?);
+        }
+        packets.push(PlayerPositionAndLook::new(spawn.x,
+                                                spawn.y,
+                                                spawn.z,
+                                                spawn.yaw,
+                                                spawn.pitch,
+                                                0,
+                                                1));
+        for packet in packets {
+            let _: usize = self.listener.send(id, packet)?;
+        }
+
+        let _ = self.players.insert(id,
+                                    Player {
+                                        profile: profile.clone(),
+                                        entity_id,
+                                    });
+        events.push(LimboEvent::Joined(id, profile));
+        Ok(())
+    }
+
+    /// Get the json status sent in response to server list pings
+    fn get_status(&self) -> String {
+        json!({
+            "version": {
+                "name": VERSION_NAME,
+                "protocol": PROTOCOL_VERSION,
+            },
+            "players": {
+                "max": self.listener.get_max_players().unwrap_or(0),
+                "online": self.players.len(),
+            },
+            "description": {
+                "text": self.motd,
+            },
+        }).to_string()
+    }
+}
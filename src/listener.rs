@@ -0,0 +1,669 @@
+//! Accepting and managing many client connections
+//!
+//! ServerListener binds a port and drives a Server for every accepted
+//! connection. Call poll_timeout() from the server's main loop, which waits
+//! until a connection has something to read, then accepts any new
+//! connections, reads the connections that are ready, and returns everything
+//! that happened as ListenerEvents. Only the connections the OS reports as
+//! ready are read, so idle connections cost nothing. poll() does the same
+//! without waiting, for main loops that do other work between polls.
+//!
+//! The listener follows the Handshake to put connections in the Status or
+//! Login state, and rejects LoginStarts once max_players is reached, but the
+//! login itself (encryption, compression, forwarding, ...) is left to the
+//! consumer, which finishes it by calling login().
+use clientbound::{ClientboundPacket, LoginDisconnect, LoginSuccess, PlayDisconnect,
+                  PlayerListItem};
+use errors::Result;
+use server::Server;
+use serverbound::ServerboundPacket;
+use {ClientState, utils};
+
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt;
+use std::io;
+use std::mem;
+use std::net::{IpAddr, SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use mio::{Events, Interest, Poll, Token};
+use mio::net;
+
+/// The token of the listener itself, connections use their id plus one
+const LISTENER: Token = Token(0);
+
+/// How long to wait before accepting again after accepting failed, e.g.
+/// because the process ran out of file descriptors
+const ACCEPT_RETRY: Duration = Duration::from_millis(100);
+
+/// Identifies a connection to a ServerListener
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConnectionId(u64);
+impl fmt::Display for ConnectionId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// The profile of a logged in player
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerProfile {
+    /// The player's UUID
+    pub uuid: u128,
+    /// The player's username
+    pub username: String,
+}
+impl PlayerProfile {
+    /// Create the profile the vanilla server uses for the given username in
+    /// offline mode
+    pub fn offline(username: &str) -> Self {
+        PlayerProfile {
+            uuid: utils::offline_uuid(username),
+            username: username.to_string(),
+        }
+    }
+}
+
+/// A single connection accepted by a ServerListener
+pub struct Peer {
+    server: Server,
+    /* Another handle to the server's stream, registered for readiness */
+    source: net::TcpStream,
+    addr: SocketAddr,
+    state: ClientState,
+    /* The username sent in LoginStart */
+    username: Option<String>,
+    profile: Option<PlayerProfile>,
+    connected_at: Instant,
+}
+impl fmt::Debug for Peer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Peer")
+            .field("addr", &self.addr)
+            .field("state", &self.state)
+            .field("username", &self.username)
+            .field("profile", &self.profile)
+            .finish()
+    }
+}
+impl Peer {
+    /// Get the Server for this connection, e.g. to enable encryption or
+    /// compression
+    pub fn get_server(&mut self) -> &mut Server {
+        &mut self.server
+    }
+
+    /// Get the remote address
+    pub fn get_addr(&self) -> &SocketAddr {
+        &self.addr
+    }
+
+    /// Get the current state of the connection
+    pub fn get_state(&self) -> &ClientState {
+        &self.state
+    }
+
+    /// Get the username the client sent in LoginStart, if it has been sent
+    pub fn get_username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// Get the player's profile, only set once logged in
+    pub fn get_profile(&self) -> Option<&PlayerProfile> {
+        self.profile.as_ref()
+    }
+
+    /// Whether the player has logged in, i.e. is in the Play state
+    pub fn is_playing(&self) -> bool {
+        self.state == ClientState::Play
+    }
+
+    /// Get when the connection was accepted
+    pub fn get_connected_at(&self) -> &Instant {
+        &self.connected_at
+    }
+}
+
+/// Something that happened to a ServerListener
+#[derive(Debug)]
+pub enum ListenerEvent {
+    /// A new connection was accepted
+    Connected(ConnectionId),
+    /// A packet was read from the given connection
+    Packet(ConnectionId, ServerboundPacket),
+    /// The connection was closed, with the reason or error. The connection
+    /// no longer exists.
+    Disconnected(ConnectionId, String),
+    /// A connection from the given address was refused because of the rate
+    /// limit
+    Rejected(SocketAddr),
+    /// A new latency was measured for the given connection, see
+    /// enable_keepalive
+    Latency(ConnectionId, Duration),
+}
+
+/// Accepts connections and manages them, see the module documentation
+pub struct ServerListener {
+    listener: TcpListener,
+    /* Another handle to the listener, registered for readiness */
+    source: net::TcpListener,
+    poll: Poll,
+    events: Events,
+    /* Connections that may have something to read */
+    ready: BTreeSet<ConnectionId>,
+    /* Whether the listener may have connections left to accept */
+    accept_ready: bool,
+    peers: BTreeMap<ConnectionId, Peer>,
+    next_id: u64,
+    max_players: Option<usize>,
+    /* Maximum connections per address in the given duration */
+    rate_limit: Option<(usize, Duration)>,
+    /* The recent connection times for each address */
+    recent: HashMap<IpAddr, VecDeque<Instant>>,
+    /* Events from outside of poll() not yet returned by poll() */
+    pending: Vec<ListenerEvent>,
+    /* The KeepAlive interval and timeout given to every connection */
+    keepalive: Option<(Duration, Duration)>,
+    /* Latencies measured by the connections' latency hooks */
+    latencies: Arc<Mutex<Vec<(ConnectionId, Duration)>>>,
+    broadcast_latency: bool,
+}
+impl fmt::Debug for ServerListener {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ServerListener")
+            .field("listener", &self.listener)
+            .field("peers", &self.peers)
+            .field("max_players", &self.max_players)
+            .field("rate_limit", &self.rate_limit)
+            .finish()
+    }
+}
+impl ServerListener {
+    /// Bind to the given address, e.g. `0.0.0.0:25565`
+    pub fn bind(addr: &str) -> Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let poll = Poll::new()?;
+        let mut source = net::TcpListener::from_std(listener.try_clone()?);
+        poll.registry().register(&mut source, LISTENER, Interest::READABLE)?;
+        Ok(ServerListener {
+            listener,
+            source,
+            poll,
+            events: Events::with_capacity(1024),
+            ready: BTreeSet::new(),
+            accept_ready: false,
+            peers: BTreeMap::new(),
+            next_id: 0,
+            max_players: None,
+            rate_limit: None,
+            recent: HashMap::new(),
+            pending: Vec::new(),
+            keepalive: None,
+            latencies: Arc::new(Mutex::new(Vec::new())),
+            broadcast_latency: false,
+        })
+    }
+
+    /// Get the address the listener is bound to
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.source.local_addr()?)
+    }
+
+    /// Set the maximum amount of players. Once this many connections have
+    /// sent a LoginStart, further LoginStarts are disconnected.
+    pub fn set_max_players(&mut self, max_players: Option<usize>) {
+        self.max_players = max_players;
+    }
+
+    /// Get the maximum amount of players, if any
+    pub fn get_max_players(&self) -> Option<usize> {
+        self.max_players
+    }
+
+    /// Only accept max connections from the same address within the given
+    /// duration, further connections are closed immediately. The vanilla
+    /// server allows 1 connection per 4 seconds.
+    pub fn set_rate_limit(&mut self, max: usize, per: Duration) {
+        self.rate_limit = Some((max, per));
+    }
+
+    /// Remove the rate limit
+    pub fn clear_rate_limit(&mut self) {
+        self.rate_limit = None;
+        self.recent.clear();
+    }
+
+    /// Send automatic KeepAlives to every connection in the Play state, see
+    /// Server::enable_keepalive. Connections that time out are disconnected,
+    /// and every measured latency is returned from poll().
+    pub fn enable_keepalive(&mut self, interval: Duration, timeout: Duration) {
+        self.keepalive = Some((interval, timeout));
+        let ids: Vec<ConnectionId> = self.peers.keys().cloned().collect();
+        for id in ids {
+            self.setup_keepalive(id);
+        }
+    }
+
+    /// Stop sending automatic KeepAlives
+    pub fn disable_keepalive(&mut self) {
+        self.keepalive = None;
+        for peer in self.peers.values_mut() {
+            peer.server.disable_keepalive();
+        }
+    }
+
+    /// Whether to send a PlayerListItem latency update to every player when
+    /// a player's latency is measured. Default false.
+    pub fn set_broadcast_latency(&mut self, broadcast_latency: bool) {
+        self.broadcast_latency = broadcast_latency;
+    }
+
+    /// Get the given connection
+    pub fn get(&self, id: ConnectionId) -> Option<&Peer> {
+        self.peers.get(&id)
+    }
+
+    /// Get the given connection mutably
+    pub fn get_mut(&mut self, id: ConnectionId) -> Option<&mut Peer> {
+        self.peers.get_mut(&id)
+    }
+
+    /// Get all the connections
+    pub fn get_peers(&self) -> &BTreeMap<ConnectionId, Peer> {
+        &self.peers
+    }
+
+    /// Get the amount of connections, in any state
+    pub fn get_connection_count(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Get the amount of connections that are logging in or playing, which
+    /// is what max_players applies to
+    pub fn get_player_count(&self) -> usize {
+        self.peers.values().filter(|x| x.username.is_some()).count()
+    }
+
+    /// Find the connection of the player with the given username
+    pub fn find_player(&self, username: &str) -> Option<ConnectionId> {
+        self.peers
+            .iter()
+            .find(|&(_, x)| x.profile.as_ref().map(|x| x.username.as_str()) == Some(username))
+            .map(|(id, _)| *id)
+    }
+
+    /// Accept new connections, and flush outgoing data and read packets
+    /// from the connections that are ready, see poll_timeout.
+    ///
+    /// This function is nonblocking.
+    pub fn poll(&mut self) -> Result<Vec<ListenerEvent>> {
+        self.poll_timeout(Some(Duration::from_secs(0)))
+    }
+
+    /// Wait until a connection is ready or the timeout has passed, then
+    /// accept new connections, and flush outgoing data and read packets from
+    /// the connections that are ready.
+    ///
+    /// A timeout of None waits until something happens. If automatic
+    /// KeepAlives are enabled, this returns at least once every KeepAlive
+    /// interval so they can be sent, possibly without any events.
+    ///
+    /// Errors accepting or reading a single connection don't make this
+    /// fail: a connection that fails is disconnected, and accepting is
+    /// retried shortly after.
+    pub fn poll_timeout(&mut self, timeout: Option<Duration>) -> Result<Vec<ListenerEvent>> {
+        let mut ret = mem::take(&mut self.pending);
+        if let Err(e) = self.wait(timeout, !ret.is_empty()) {
+            self.pending = ret;
+            return Err(e);
+        }
+        for event in &self.events {
+            match event.token() {
+                LISTENER => self.accept_ready = true,
+                Token(x) => {
+                    let _ = self.ready.insert(ConnectionId(x as u64 - 1));
+                },
+            }
+        }
+        if self.accept_ready {
+            self.accept(&mut ret);
+        }
+
+        /* Checking whether a KeepAlive is due needs no syscall */
+        let ids: Vec<ConnectionId> = self.peers.keys().cloned().collect();
+        for id in ids {
+            let res = match self.peers.get_mut(&id) {
+                Some(peer) => peer.server.update_keepalive(),
+                None => continue,
+            };
+            if let Err(e) = res {
+                self.remove(id, e.to_string(), &mut ret);
+            }
+        }
+
+        for id in mem::take(&mut self.ready) {
+            if !self.peers.contains_key(&id) {
+                continue;
+            }
+            if let Err(e) = self.read_peer(id, &mut ret) {
+                self.remove(id, e.to_string(), &mut ret);
+                continue;
+            }
+            /* Readiness is only reported when new data arrives, so keep
+             * reading until everything available has been read */
+            if self.peers.get(&id).map(|x| x.server.is_drained()) == Some(false) {
+                let _ = self.ready.insert(id);
+            }
+        }
+        self.latency(&mut ret);
+
+        Ok(ret)
+    }
+
+    /// Send the given packet to the given connection
+    ///
+    /// If sending fails the connection is dropped, which is reported by the
+    /// next call to poll().
+    pub fn send<T: Borrow<ClientboundPacket>>(&mut self,
+                                              id: ConnectionId,
+                                              packet: T)
+                                              -> Result<usize> {
+        let res = match self.peers.get_mut(&id) {
+            Some(peer) => peer.server.send(packet),
+            None => bail!("No connection with id {}", id),
+        };
+        if let Err(ref e) = res {
+            let mut events = mem::take(&mut self.pending);
+            self.remove(id, e.to_string(), &mut events);
+            self.pending = events;
+        }
+        res
+    }
+
+    /// Send the given packet to every player in the Play state
+    pub fn broadcast<T: Borrow<ClientboundPacket>>(&mut self, packet: T) {
+        self.broadcast_filter(packet, |_| true)
+    }
+
+    /// Send the given packet to every player in the Play state for which the
+    /// filter returns true.
+    ///
+    /// Connections that fail are dropped, as with send().
+    pub fn broadcast_filter<T, F>(&mut self, packet: T, mut filter: F)
+        where T: Borrow<ClientboundPacket>,
+              F: FnMut(&Peer) -> bool
+    {
+        let ids: Vec<ConnectionId> = self.peers
+            .iter()
+            .filter(|&(_, x)| x.is_playing() && filter(x))
+            .map(|(id, _)| *id)
+            .collect();
+        for id in ids {
+            let _ = self.send(id, packet.borrow());
+        }
+    }
+
+    /// Finish logging in the given connection, sending LoginSuccess with the
+    /// given profile and switching to the Play state
+    pub fn login(&mut self, id: ConnectionId, profile: PlayerProfile) -> Result<()> {
+        match self.peers.get(&id) {
+            Some(peer) if peer.state == ClientState::Login => (),
+            Some(peer) => bail!("Connection {} is in state {}, not Login", id, peer.state),
+            None => bail!("No connection with id {}", id),
+        }
+        let _: usize = self.send(id,
+                                 LoginSuccess::new(profile.uuid, profile.username.clone()))?;
+        let peer = self.peers.get_mut(&id).expect("listener peer went missing");
+        peer.server.set_clientstate(ClientState::Play);
+        peer.state = ClientState::Play;
+        peer.profile = Some(profile);
+        Ok(())
+    }
+
+    /// Disconnect the given connection with the given plaintext reason,
+    /// which is shown to the player if it is logging in or playing
+    pub fn disconnect(&mut self, id: ConnectionId, reason: &str) {
+        let mut events = mem::take(&mut self.pending);
+        self.kick(id, reason, &mut events);
+        self.pending = events;
+    }
+
+    /// Wait for readiness events, not waiting at all if there are
+    /// connections left to read or there are already events to return
+    fn wait(&mut self, timeout: Option<Duration>, have_events: bool) -> Result<()> {
+        let mut timeout = if have_events || !self.ready.is_empty() {
+            Some(Duration::from_secs(0))
+        } else {
+            timeout
+        };
+        if let Some((interval, _)) = self.keepalive {
+            timeout = Some(timeout.map_or(interval, |x| x.min(interval)));
+        }
+        if self.accept_ready {
+            timeout = Some(timeout.map_or(ACCEPT_RETRY, |x| x.min(ACCEPT_RETRY)));
+        }
+        match self.poll.poll(&mut self.events, timeout) {
+            Ok(()) => Ok(()),
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {
+                self.events.clear();
+                Ok(())
+            },
+            Err(e) => bail!(e),
+        }
+    }
+
+    /// Accept all pending connections.
+    ///
+    /// If accepting fails for any reason other than a connection that was
+    /// aborted before it could be accepted, e.g. because the process ran out
+    /// of file descriptors, the rest are accepted by a later poll. The
+    /// listener's readiness is only reported for new connections, so it
+    /// must be retried even without one.
+    fn accept(&mut self, events: &mut Vec<ListenerEvent>) {
+        loop {
+            let (stream, addr) = match self.listener.accept() {
+                Ok(x) => x,
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    self.accept_ready = false;
+                    return;
+                },
+                Err(ref e) if e.kind() == io::ErrorKind::ConnectionAborted ||
+                              e.kind() == io::ErrorKind::ConnectionReset ||
+                              e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => return,
+            };
+            if !self.check_rate_limit(addr.ip()) {
+                /* Dropping the stream closes it */
+                events.push(ListenerEvent::Rejected(addr));
+                continue;
+            }
+            let server = match Server::from_tcpstream(stream) {
+                Ok(x) => x,
+                Err(_) => continue,
+            };
+            let id = ConnectionId(self.next_id);
+            let mut source = match server.try_clone_stream() {
+                Ok(x) => net::TcpStream::from_std(x),
+                Err(_) => continue,
+            };
+            let token = Token(id.0 as usize + 1);
+            if self.poll
+                .registry()
+                .register(&mut source, token, Interest::READABLE | Interest::WRITABLE)
+                .is_err() {
+                continue;
+            }
+            self.next_id += 1;
+            /* Anything sent before registering would not be reported */
+            let _ = self.ready.insert(id);
+            let _ = self.peers.insert(id,
+                                      Peer {
+                                          server,
+                                          source,
+                                          addr,
+                                          state: ClientState::Handshake,
+                                          username: None,
+                                          profile: None,
+                                          connected_at: Instant::now(),
+                                      });
+            self.setup_keepalive(id);
+            events.push(ListenerEvent::Connected(id));
+        }
+    }
+
+    /// Enable automatic KeepAlives for the given connection, if enabled for
+    /// the listener
+    fn setup_keepalive(&mut self, id: ConnectionId) {
+        let (interval, timeout) = match self.keepalive {
+            Some(x) => x,
+            None => return,
+        };
+        if let Some(peer) = self.peers.get_mut(&id) {
+            let latencies = self.latencies.clone();
+            peer.server.enable_keepalive(interval, timeout);
+            peer.server.set_latency_hook(move |ping| {
+                latencies.lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push((id, ping))
+            });
+        }
+    }
+
+    /// Report the latencies measured since the last poll, and broadcast
+    /// them if enabled
+    fn latency(&mut self, events: &mut Vec<ListenerEvent>) {
+        let latencies = mem::take(&mut *self.latencies.lock().unwrap_or_else(PoisonError::into_inner));
+        let mut update = Vec::new();
+        for (id, ping) in latencies {
+            if let Some(profile) = self.peers.get(&id).and_then(|x| x.profile.as_ref()) {
+                update.push((profile.uuid, ping.as_millis() as i32));
+            }
+            events.push(ListenerEvent::Latency(id, ping));
+        }
+        if self.broadcast_latency && !update.is_empty() {
+            /* Failing to encode the update must not lose the events */
+            if let Ok(packet) = PlayerListItem::new_latency(&update) {
+                self.broadcast(packet);
+            }
+        }
+    }
+
+    /// Flush the outgoing data of the given connection and read its packets.
+    ///
+    /// Packets are read one at a time, since a Handshake changes how the
+    /// packets after it are read.
+    fn read_peer(&mut self, id: ConnectionId, events: &mut Vec<ListenerEvent>) -> Result<()> {
+        {
+            let peer = self.peers.get_mut(&id).expect("listener peer went missing");
+            peer.server.update_keepalive()?;
+            let _: usize = peer.server.write()?;
+            peer.server.update_inbuf()?;
+        }
+        loop {
+            let packet = match self.peers.get_mut(&id) {
+                Some(peer) => peer.server.read_packet()?,
+                None => return Ok(()),
+            };
+            match packet {
+                /* Answers to automatic KeepAlives were handled by the Server */
+                Some(ServerboundPacket::KeepAlive(..)) if self.keepalive.is_some() => (),
+                Some(packet) => {
+                    if self.handle_packet(id, &packet, events) {
+                        events.push(ListenerEvent::Packet(id, packet));
+                    }
+                },
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Record a connection from the given address, returning whether it is
+    /// within the rate limit
+    fn check_rate_limit(&mut self, ip: IpAddr) -> bool {
+        let (max, per) = match self.rate_limit {
+            Some(x) => x,
+            None => return true,
+        };
+        let now = Instant::now();
+        /* Forget about old connections from all addresses */
+        self.recent.retain(|_, times| {
+            while times.front().map(|x| now.duration_since(*x) >= per) == Some(true) {
+                let _ = times.pop_front();
+            }
+            !times.is_empty()
+        });
+        let times = self.recent.entry(ip).or_default();
+        if times.len() >= max {
+            false
+        } else {
+            times.push_back(now);
+            true
+        }
+    }
+
+    /// Track the state of the connection, returning false if the connection
+    /// was dropped because of the packet
+    fn handle_packet(&mut self,
+                     id: ConnectionId,
+                     packet: &ServerboundPacket,
+                     events: &mut Vec<ListenerEvent>)
+                     -> bool {
+        match *packet {
+            ServerboundPacket::Handshake(ref p) => {
+                let state = match *p.get_next_state() {
+                    1 => ClientState::Status,
+                    2 => ClientState::Login,
+                    x => {
+                        let reason = format!("Invalid next state {} in Handshake", x);
+                        self.remove(id, reason, events);
+                        return false;
+                    },
+                };
+                let peer = self.peers.get_mut(&id).expect("listener peer went missing");
+                peer.server.set_clientstate(state.clone());
+                peer.state = state;
+            },
+            ServerboundPacket::LoginStart(ref p) => {
+                if let Some(max_players) = self.max_players {
+                    if self.get_player_count() >= max_players {
+                        self.kick(id, "The server is full!", events);
+                        return false;
+                    }
+                }
+                let peer = self.peers.get_mut(&id).expect("listener peer went missing");
+                peer.username = Some(p.get_name().clone());
+            },
+            _ => (),
+        }
+        true
+    }
+
+    /// Send a disconnect packet with the given reason if possible, and remove
+    /// the connection
+    fn kick(&mut self, id: ConnectionId, reason: &str, events: &mut Vec<ListenerEvent>) {
+        if let Some(peer) = self.peers.get_mut(&id) {
+            let chat = utils::str_to_chat(reason);
+            let packet = match peer.state {
+                ClientState::Login => Some(LoginDisconnect::new(chat)),
+                ClientState::Play => Some(PlayDisconnect::new(chat)),
+                _ => None,
+            };
+            if let Some(packet) = packet {
+                let _ = peer.server.send(packet);
+            }
+        }
+        self.remove(id, reason.to_string(), events);
+    }
+
+    /// Close and remove the given connection
+    fn remove(&mut self, id: ConnectionId, reason: String, events: &mut Vec<ListenerEvent>) {
+        if let Some(mut peer) = self.peers.remove(&id) {
+            let _ = self.poll.registry().deregister(&mut peer.source);
+            let _ = peer.server.close();
+            events.push(ListenerEvent::Disconnected(id, reason));
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! Map items, as sent in the Map packet
+//!
+//! Each Map packet updates the icons of a map, and optionally a rectangular
+//! patch of its colors. `MapCanvas` applies the patches to a 128x128 buffer
+//! of color ids, which can be turned into RGB(A) pixels using vanilla's color
+//! table.
+
+use clientbound::Map;
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::io::{Cursor, Read};
+
+/// The width and height of a map in pixels
+pub const MAP_SIZE: usize = 128;
+
+/// The RGB values of the base colors, indexed by `color_id / 4`
+const BASE_COLORS: [(u8, u8, u8); 52] = [(0, 0, 0),
+                                         (127, 178, 56),
+                                         (247, 233, 163),
+                                         (199, 199, 199),
+                                         (255, 0, 0),
+                                         (160, 160, 255),
+                                         (167, 167, 167),
+                                         (0, 124, 0),
+                                         (255, 255, 255),
+                                         (164, 168, 184),
+                                         (151, 109, 77),
+                                         (112, 112, 112),
+                                         (64, 64, 255),
+                                         (143, 119, 72),
+                                         (255, 252, 245),
+                                         (216, 127, 51),
+                                         (178, 76, 216),
+                                         (102, 153, 216),
+                                         (229, 229, 51),
+                                         (127, 204, 25),
+                                         (242, 127, 165),
+                                         (76, 76, 76),
+                                         (153, 153, 153),
+                                         (76, 127, 153),
+                                         (127, 63, 178),
+                                         (51, 76, 178),
+                                         (102, 76, 51),
+                                         (102, 127, 51),
+                                         (153, 51, 51),
+                                         (25, 25, 25),
+                                         (250, 238, 77),
+                                         (92, 219, 213),
+                                         (74, 128, 255),
+                                         (0, 217, 58),
+                                         (129, 86, 49),
+                                         (112, 2, 0),
+                                         (209, 177, 161),
+                                         (159, 82, 36),
+                                         (149, 87, 108),
+                                         (112, 108, 138),
+                                         (186, 133, 36),
+                                         (103, 117, 53),
+                                         (160, 77, 78),
+                                         (57, 41, 35),
+                                         (135, 107, 98),
+                                         (87, 92, 92),
+                                         (122, 73, 88),
+                                         (76, 62, 92),
+                                         (76, 50, 35),
+                                         (76, 82, 42),
+                                         (142, 60, 46),
+                                         (37, 22, 16)];
+
+/// The multipliers of the shades, indexed by `color_id % 4`
+const SHADES: [u16; 4] = [180, 220, 255, 135];
+
+/// Get the RGB value of the given map color id, or None if the color is
+/// transparent (base color 0) or unknown
+pub fn color_to_rgb(color: u8) -> Option<(u8, u8, u8)> {
+    let base = (color / 4) as usize;
+    if base == 0 || base >= BASE_COLORS.len() {
+        return None;
+    }
+    let (r, g, b) = BASE_COLORS[base];
+    let shade = SHADES[(color % 4) as usize];
+    let apply = |x: u8| (u16::from(x) * shade / 255) as u8;
+    Some((apply(r), apply(g), apply(b)))
+}
+
+/// An icon shown on a map
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapIcon {
+    /// The raw icon type, e.g. 0 for the white arrow of a player
+    pub icon_type: i32,
+    /// The x coordinate, from -128 for the left edge to 127 for the right
+    pub x: i8,
+    /// The z coordinate, from -128 for the top edge to 127 for the bottom
+    pub z: i8,
+    /// The direction, from 0 to 15, 0 being south and 4 west
+    pub direction: u8,
+    /// The text shown below the icon, as a json chat message
+    pub display_name: Option<String>,
+}
+
+/// An update of a rectangle of a map's colors
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapPatch {
+    /// The width of the rectangle
+    pub columns: u8,
+    /// The height of the rectangle
+    pub rows: u8,
+    /// The x offset of the rectangle's top left corner
+    pub x: u8,
+    /// The z offset of the rectangle's top left corner
+    pub z: u8,
+    /// The color ids, row by row
+    pub data: Vec<u8>,
+}
+
+/// The decoded contents of a Map packet
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapData {
+    /// The id of the map being updated
+    pub map_id: i32,
+    /// The zoom level, from 0 (1 block per pixel) to 4 (16 blocks per pixel)
+    pub scale: i8,
+    /// Whether the icons of players and item frames are shown
+    pub tracking_position: bool,
+    /// Whether the map has been locked in a cartography table
+    pub locked: bool,
+    /// The icons, replacing all previous icons
+    pub icons: Vec<MapIcon>,
+    /// The updated colors, if any
+    pub patch: Option<MapPatch>,
+}
+impl MapData {
+    /// Decode the raw data of a Map packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let map_id = read_varint(&mut r)?;
+        let scale = read_i8(&mut r)?;
+        let tracking_position = read_bool(&mut r)?;
+        let locked = read_bool(&mut r)?;
+
+        let count = read_varint(&mut r)?;
+        if count < 0 {
+            bail!("Map had negative icon count {}", count);
+        }
+        let mut icons = Vec::new();
+        for _ in 0..count {
+            icons.push(MapIcon::read(&mut r)?);
+        }
+
+        let columns = read_u8(&mut r)?;
+        let patch = if columns > 0 {
+            let rows = read_u8(&mut r)?;
+            let x = read_u8(&mut r)?;
+            let z = read_u8(&mut r)?;
+            let data = read_prefixed_bytearray(&mut r)?;
+            if data.len() != columns as usize * rows as usize {
+                bail!("Map patch is {}x{} but has {} colors", columns, rows, data.len());
+            }
+            Some(MapPatch {
+                columns,
+                rows,
+                x,
+                z,
+                data,
+            })
+        } else {
+            None
+        };
+
+        Ok(MapData {
+            map_id,
+            scale,
+            tracking_position,
+            locked,
+            icons,
+            patch,
+        })
+    }
+
+    /// Encode into the raw data of a Map packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_varint(&self.map_id, &mut ret)?;
+        write_i8(&self.scale, &mut ret)?;
+        write_bool(&self.tracking_position, &mut ret)?;
+        write_bool(&self.locked, &mut ret)?;
+        write_varint(&(self.icons.len() as i32), &mut ret)?;
+        for icon in &self.icons {
+            icon.write(&mut ret)?;
+        }
+        match self.patch {
+            Some(ref patch) if patch.columns > 0 => {
+                if patch.data.len() != patch.columns as usize * patch.rows as usize {
+                    bail!("Map patch is {}x{} but has {} colors",
+                          patch.columns,
+                          patch.rows,
+                          patch.data.len());
+                }
+                write_u8(&patch.columns, &mut ret)?;
+                write_u8(&patch.rows, &mut ret)?;
+                write_u8(&patch.x, &mut ret)?;
+                write_u8(&patch.z, &mut ret)?;
+                write_prefixed_bytearray(&patch.data, &mut ret)?;
+            },
+            _ => write_u8(&0, &mut ret)?,
+        }
+        Ok(ret)
+    }
+}
+
+impl MapIcon {
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        let icon_type = read_varint(r)?;
+        let x = read_i8(r)?;
+        let z = read_i8(r)?;
+        let direction = read_u8(r)?;
+        let display_name = if read_bool(r)? {
+            Some(read_String(r)?)
+        } else {
+            None
+        };
+        Ok(MapIcon {
+            icon_type,
+            x,
+            z,
+            direction,
+            display_name,
+        })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_varint(&self.icon_type, w)?;
+        write_i8(&self.x, w)?;
+        write_i8(&self.z, w)?;
+        write_u8(&self.direction, w)?;
+        write_bool(&self.display_name.is_some(), w)?;
+        if let Some(ref x) = self.display_name {
+            write_String(x, w)?;
+        }
+        Ok(())
+    }
+}
+
+/// The contents of a single map, built from successive Map packets
+#[derive(Debug, Clone)]
+pub struct MapCanvas {
+    /// The zoom level of the map
+    pub scale: i8,
+    /// Whether the map has been locked
+    pub locked: bool,
+    /// The current icons
+    pub icons: Vec<MapIcon>,
+    colors: Vec<u8>,
+}
+impl Default for MapCanvas {
+    fn default() -> Self {
+        MapCanvas {
+            scale: 0,
+            locked: false,
+            icons: Vec::new(),
+            colors: vec![0; MAP_SIZE * MAP_SIZE],
+        }
+    }
+}
+impl MapCanvas {
+    /// Create a new, fully transparent canvas
+    pub fn new() -> Self {
+        MapCanvas::default()
+    }
+
+    /// Apply a Map packet
+    pub fn handle_map(&mut self, packet: &Map) -> Result<()> {
+        self.apply(&packet.get_map_data()?);
+        Ok(())
+    }
+
+    /// Apply the decoded contents of a Map packet. Parts of the patch
+    /// outside the map are ignored.
+    pub fn apply(&mut self, data: &MapData) {
+        self.scale = data.scale;
+        self.locked = data.locked;
+        self.icons = data.icons.clone();
+        if let Some(ref patch) = data.patch {
+            for row in 0..patch.rows as usize {
+                for column in 0..patch.columns as usize {
+                    let x = patch.x as usize + column;
+                    let z = patch.z as usize + row;
+                    if x < MAP_SIZE && z < MAP_SIZE {
+                        self.colors[z * MAP_SIZE + x] = patch.data[row * patch.columns as usize + column];
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get the color id of the given pixel, or None if the pixel is outside
+    /// the map
+    pub fn get_color(&self, x: usize, z: usize) -> Option<u8> {
+        if x >= MAP_SIZE {
+            return None;
+        }
+        self.colors.get(z * MAP_SIZE + x).cloned()
+    }
+
+    /// Get the color ids of all the pixels, row by row
+    pub fn get_colors(&self) -> &[u8] {
+        &self.colors
+    }
+
+    /// Get the pixels as RGB values, row by row, three bytes per pixel.
+    /// Transparent pixels are black.
+    pub fn to_rgb(&self) -> Vec<u8> {
+        let mut ret = Vec::with_capacity(self.colors.len() * 3);
+        for &color in &self.colors {
+            let (r, g, b) = color_to_rgb(color).unwrap_or((0, 0, 0));
+            ret.extend_from_slice(&[r, g, b]);
+        }
+        ret
+    }
+
+    /// Get the pixels as RGBA values, row by row, four bytes per pixel.
+    /// Transparent pixels have alpha 0.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        let mut ret = Vec::with_capacity(self.colors.len() * 4);
+        for &color in &self.colors {
+            match color_to_rgb(color) {
+                Some((r, g, b)) => ret.extend_from_slice(&[r, g, b, 255]),
+                None => ret.extend_from_slice(&[0, 0, 0, 0]),
+            }
+        }
+        ret
+    }
+}
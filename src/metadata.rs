@@ -0,0 +1,237 @@
+//! Entity metadata, as sent in the EntityMetadata packet
+//!
+//! The metadata is a list of entries, each with an index, a type and a value.
+//! What each index means depends on the type of entity, see wiki.vg's
+//! Entity_metadata page. Only the entries that changed are sent, so the
+//! entries of successive packets should be merged.
+
+use errors::Result;
+use read::*;
+use slot::*;
+use write::*;
+
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+
+/// The index marking the end of the metadata
+const END_OF_METADATA: u8 = 0xff;
+
+/// Entity metadata, mapping each index to its value
+pub type Metadata = BTreeMap<u8, MetadataValue>;
+
+/// A single metadata value
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValue {
+    /// Type 0
+    Byte(i8),
+    /// Type 1
+    VarInt(i32),
+    /// Type 2
+    Float(f32),
+    /// Type 3
+    String(String),
+    /// Type 4, a json chat message
+    Chat(String),
+    /// Type 5, an optional json chat message
+    OptChat(Option<String>),
+    /// Type 6
+    Slot(Option<Slot>),
+    /// Type 7
+    Boolean(bool),
+    /// Type 8, the rotation around the x, y and z axes in degrees
+    Rotation(f32, f32, f32),
+    /// Type 9
+    Position((i32, i32, i32)),
+    /// Type 10
+    OptPosition(Option<(i32, i32, i32)>),
+    /// Type 11, 0 down, 1 up, 2 north, 3 south, 4 west, 5 east
+    Direction(i32),
+    /// Type 12
+    OptUuid(Option<u128>),
+    /// Type 13, a block state id, None for air
+    OptBlockState(Option<i32>),
+    /// Type 14, raw NBT data, None for TAG_End
+    Nbt(Option<Vec<u8>>),
+    /// Type 15, a particle id and its raw data
+    Particle(i32, Vec<u8>),
+    /// Type 16, the villager type, profession and level
+    VillagerData(i32, i32, i32),
+    /// Type 17
+    OptVarInt(Option<i32>),
+    /// Type 18, the entity's pose, e.g. 0 standing and 5 sneaking
+    Pose(i32),
+}
+
+/// Decode the raw metadata of an EntityMetadata packet
+pub fn decode_metadata(data: &[u8]) -> Result<Metadata> {
+    let mut r = Cursor::new(data);
+    let mut ret = BTreeMap::new();
+    loop {
+        let index = read_u8(&mut r)?;
+        if index == END_OF_METADATA {
+            return Ok(ret);
+        }
+        let _ = ret.insert(index, MetadataValue::read(&mut r)?);
+    }
+}
+
+/// Encode into the raw metadata of an EntityMetadata packet
+pub fn encode_metadata(metadata: &Metadata) -> Result<Vec<u8>> {
+    let mut ret = Vec::new();
+    for (index, value) in metadata {
+        if *index == END_OF_METADATA {
+            bail!("Metadata index {} is reserved", END_OF_METADATA);
+        }
+        write_u8(index, &mut ret)?;
+        value.write(&mut ret)?;
+    }
+    write_u8(&END_OF_METADATA, &mut ret)?;
+    Ok(ret)
+}
+
+impl MetadataValue {
+    /// Get the type id of the value
+    pub fn get_type(&self) -> i32 {
+        match *self {
+            MetadataValue::Byte(_) => 0,
+            MetadataValue::VarInt(_) => 1,
+            MetadataValue::Float(_) => 2,
+            MetadataValue::String(_) => 3,
+            MetadataValue::Chat(_) => 4,
+            MetadataValue::OptChat(_) => 5,
+            MetadataValue::Slot(_) => 6,
+            MetadataValue::Boolean(_) => 7,
+            MetadataValue::Rotation(..) => 8,
+            MetadataValue::Position(_) => 9,
+            MetadataValue::OptPosition(_) => 10,
+            MetadataValue::Direction(_) => 11,
+            MetadataValue::OptUuid(_) => 12,
+            MetadataValue::OptBlockState(_) => 13,
+            MetadataValue::Nbt(_) => 14,
+            MetadataValue::Particle(..) => 15,
+            MetadataValue::VillagerData(..) => 16,
+            MetadataValue::OptVarInt(_) => 17,
+            MetadataValue::Pose(_) => 18,
+        }
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        let value_type = read_varint(r)?;
+        Ok(match value_type {
+            0 => MetadataValue::Byte(read_i8(r)?),
+            1 => MetadataValue::VarInt(read_varint(r)?),
+            2 => MetadataValue::Float(read_f32(r)?),
+            3 => MetadataValue::String(read_String(r)?),
+            4 => MetadataValue::Chat(read_String(r)?),
+            5 => MetadataValue::OptChat(if read_bool(r)? { Some(read_String(r)?) } else { None }),
+            6 => MetadataValue::Slot(read_slot(r)?),
+            7 => MetadataValue::Boolean(read_bool(r)?),
+            8 => MetadataValue::Rotation(read_f32(r)?, read_f32(r)?, read_f32(r)?),
+            9 => MetadataValue::Position(read_position(r)?),
+            10 => {
+                MetadataValue::OptPosition(if read_bool(r)? {
+                                               Some(read_position(r)?)
+                                           } else {
+                                               None
+                                           })
+            },
+            11 => MetadataValue::Direction(read_varint(r)?),
+            12 => MetadataValue::OptUuid(if read_bool(r)? { Some(read_u128(r)?) } else { None }),
+            13 => {
+                let state = read_varint(r)?;
+                MetadataValue::OptBlockState(if state == 0 { None } else { Some(state) })
+            },
+            14 => MetadataValue::Nbt(read_nbt(r)?),
+            15 => {
+                let particle = read_varint(r)?;
+                MetadataValue::Particle(particle, read_particle_data(particle, r)?)
+            },
+            16 => MetadataValue::VillagerData(read_varint(r)?, read_varint(r)?, read_varint(r)?),
+            17 => {
+                let x = read_varint(r)?;
+                MetadataValue::OptVarInt(if x == 0 { None } else { Some(x - 1) })
+            },
+            18 => MetadataValue::Pose(read_varint(r)?),
+            _ => bail!("Unknown metadata type {}", value_type),
+        })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_varint(&self.get_type(), w)?;
+        match *self {
+            MetadataValue::Byte(x) => write_i8(&x, w),
+            MetadataValue::VarInt(x) |
+            MetadataValue::Direction(x) |
+            MetadataValue::Pose(x) => write_varint(&x, w),
+            MetadataValue::Float(x) => write_f32(&x, w),
+            MetadataValue::String(ref x) |
+            MetadataValue::Chat(ref x) => write_String(x, w),
+            MetadataValue::OptChat(ref x) => {
+                write_bool(&x.is_some(), w)?;
+                match *x {
+                    Some(ref x) => write_String(x, w),
+                    None => Ok(()),
+                }
+            },
+            MetadataValue::Slot(ref x) => write_slot(x, w),
+            MetadataValue::Boolean(x) => write_bool(&x, w),
+            MetadataValue::Rotation(x, y, z) => {
+                write_f32(&x, w)?;
+                write_f32(&y, w)?;
+                write_f32(&z, w)
+            },
+            MetadataValue::Position(ref x) => write_position(x, w),
+            MetadataValue::OptPosition(ref x) => {
+                write_bool(&x.is_some(), w)?;
+                match *x {
+                    Some(ref x) => write_position(x, w),
+                    None => Ok(()),
+                }
+            },
+            MetadataValue::OptUuid(x) => {
+                write_bool(&x.is_some(), w)?;
+                match x {
+                    Some(x) => write_u128(&x, w),
+                    None => Ok(()),
+                }
+            },
+            MetadataValue::OptBlockState(x) => write_varint(&x.unwrap_or(0), w),
+            MetadataValue::Nbt(ref x) => {
+                match *x {
+                    Some(ref x) => w.extend_from_slice(x),
+                    None => w.push(0),
+                }
+                Ok(())
+            },
+            MetadataValue::Particle(particle, ref data) => {
+                write_varint(&particle, w)?;
+                w.extend_from_slice(data);
+                Ok(())
+            },
+            MetadataValue::VillagerData(a, b, c) => {
+                write_varint(&a, w)?;
+                write_varint(&b, w)?;
+                write_varint(&c, w)
+            },
+            MetadataValue::OptVarInt(x) => write_varint(&x.map_or(0, |x| x + 1), w),
+        }
+    }
+}
+
+/// Read the data following a particle id, returning it as raw bytes. Only a
+/// few particles have data: block and falling_dust have a block state, dust
+/// has its color and size, and item has a slot.
+fn read_particle_data<R: Read>(particle: i32, r: &mut R) -> Result<Vec<u8>> {
+    let mut ret = Vec::new();
+    match particle {
+        3 | 23 => write_varint(&read_varint(r)?, &mut ret)?,
+        14 => {
+            for _ in 0..4 {
+                write_f32(&read_f32(r)?, &mut ret)?;
+            }
+        },
+        32 => write_slot(&read_slot(r)?, &mut ret)?,
+        _ => (),
+    }
+    Ok(ret)
+}
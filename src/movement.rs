@@ -0,0 +1,379 @@
+//! Moving the player around like the vanilla client does
+//!
+//! The server expects the client to confirm every teleport it receives in
+//! PlayerPositionAndLook, and to send its position every tick (20 times per
+//! second). `Movement` does both: feed it the received packets with
+//! `handle_packet`, and call `poll` regularly to get the packets due.
+//!
+//! Movement is controlled with `walk`, `jump` and `look`. When a `World` is
+//! given, each tick also applies gravity and collision with the world's
+//! blocks. Collision is simplified: every block state is a full cube except
+//! air (0) and the states marked passable with `set_passable`, and blocks in
+//! chunks that aren't loaded are solid, so the player doesn't fall through
+//! the world while it loads.
+
+use clientbound::{ClientboundPacket, PlayerPositionAndLook as Teleport};
+use serverbound::{Player, PlayerLook, PlayerPosition as PlayerPositionPacket,
+                  PlayerPositionAndLook, ServerboundPacket, TeleportConfirm};
+use world::{PlayerPosition, World, RELATIVE_X, RELATIVE_Y, RELATIVE_Z, VELOCITY_FACTOR};
+
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+
+/// The time between two ticks
+pub const TICK: Duration = Duration::from_millis(50);
+
+/// The downwards acceleration in blocks per tick squared
+pub const GRAVITY: f64 = 0.08;
+
+/// The factor the vertical velocity is multiplied by each tick
+pub const VERTICAL_DRAG: f64 = 0.98;
+
+/// The vertical velocity of a jump in blocks per tick
+pub const JUMP_VELOCITY: f64 = 0.42;
+
+/// The width of the player's bounding box
+pub const PLAYER_WIDTH: f64 = 0.6;
+
+/// The height of the player's bounding box
+pub const PLAYER_HEIGHT: f64 = 1.8;
+
+/// The factor the horizontal knockback is multiplied by each tick
+const HORIZONTAL_DRAG: f64 = 0.91;
+
+/// The horizontal velocity below which it's set to 0
+const MIN_VELOCITY: f64 = 0.003;
+
+/// The squared distance the player must move before the position is sent
+const MIN_MOVE_SQUARED: f64 = 0.0009;
+
+/// The number of ticks after which the position is sent even if unchanged
+const POSITION_REMINDER_TICKS: u32 = 20;
+
+/// The most ticks `poll` runs at once, to avoid bursts after stalls
+const MAX_CATCH_UP_TICKS: u32 = 10;
+
+/// Used when comparing positions to block edges
+const EPSILON: f64 = 1e-7;
+
+/// The most the player moves along each axis in one tick, so that a huge
+/// velocity, e.g. from an Explosion packet, can't make the collision checks
+/// go through billions of blocks
+const MAX_MOVE: f64 = 10.0;
+
+/// Keeps track of the player's position and sends it to the server
+#[derive(Debug, Clone)]
+pub struct Movement {
+    position: Option<PlayerPosition>,
+    on_ground: bool,
+    velocity: (f64, f64, f64),
+    walk: (f64, f64),
+    jumping: bool,
+    passable: BTreeSet<i32>,
+    player_entity_id: Option<i32>,
+    last_sent: Option<PlayerPosition>,
+    ticks_since_sent: u32,
+    next_tick: Option<Instant>,
+}
+impl Default for Movement {
+    fn default() -> Self {
+        Movement {
+            position: None,
+            on_ground: false,
+            velocity: (0.0, 0.0, 0.0),
+            walk: (0.0, 0.0),
+            jumping: false,
+            passable: BTreeSet::new(),
+            player_entity_id: None,
+            last_sent: None,
+            ticks_since_sent: 0,
+            next_tick: None,
+        }
+    }
+}
+impl Movement {
+    /// Create a new controller. Nothing is sent until the server has set
+    /// the player's position.
+    pub fn new() -> Self {
+        Movement::default()
+    }
+
+    /// Apply the packet if it affects the player's movement, returning the
+    /// packets that must be sent in reply
+    pub fn handle_packet(&mut self, packet: &ClientboundPacket) -> Vec<ServerboundPacket> {
+        match *packet {
+            ClientboundPacket::JoinGame(ref x) => {
+                self.player_entity_id = Some(*x.get_entity_id());
+            },
+            ClientboundPacket::Respawn(_) => {
+                self.position = None;
+                self.velocity = (0.0, 0.0, 0.0);
+            },
+            ClientboundPacket::PlayerPositionAndLook(ref x) => {
+                return self.teleport(x);
+            },
+            ClientboundPacket::EntityVelocity(ref x)
+                if Some(*x.get_entity_id()) == self.player_entity_id => {
+                self.velocity = (f64::from(*x.get_x_velocity()) / VELOCITY_FACTOR,
+                                 f64::from(*x.get_y_velocity()) / VELOCITY_FACTOR,
+                                 f64::from(*x.get_z_velocity()) / VELOCITY_FACTOR);
+            },
+            ClientboundPacket::Explosion(ref x) => {
+                self.velocity.0 += f64::from(*x.get_motion_x());
+                self.velocity.1 += f64::from(*x.get_motion_y());
+                self.velocity.2 += f64::from(*x.get_motion_z());
+            },
+            _ => (),
+        }
+        Vec::new()
+    }
+
+    fn teleport(&mut self, packet: &Teleport) -> Vec<ServerboundPacket> {
+        let new = self.position.unwrap_or_default().teleported(packet);
+        let flags = *packet.get_flags();
+        let relative = |flag: u8| flags & flag != 0;
+        if !relative(RELATIVE_X) {
+            self.velocity.0 = 0.0;
+        }
+        if !relative(RELATIVE_Y) {
+            self.velocity.1 = 0.0;
+        }
+        if !relative(RELATIVE_Z) {
+            self.velocity.2 = 0.0;
+        }
+        self.position = Some(new);
+        self.on_ground = false;
+        self.last_sent = Some(new);
+        self.ticks_since_sent = 0;
+
+        /* Like vanilla, confirm and then echo the new position */
+        vec![TeleportConfirm::new(*packet.get_teleport_id()),
+             PlayerPositionAndLook::new(new.x, new.y, new.z, new.yaw, new.pitch, false)]
+    }
+
+    /// Get the player's position, known after the server has teleported the
+    /// player
+    pub fn get_position(&self) -> Option<PlayerPosition> {
+        self.position
+    }
+
+    /// Check whether the player is standing on a block
+    pub fn is_on_ground(&self) -> bool {
+        self.on_ground
+    }
+
+    /// Get the velocity in blocks per tick, not including walking
+    pub fn get_velocity(&self) -> (f64, f64, f64) {
+        self.velocity
+    }
+
+    /// Mark the given block state as passable, i.e. not collided with
+    pub fn set_passable(&mut self, state: i32, passable: bool) {
+        if passable {
+            let _ = self.passable.insert(state);
+        } else {
+            let _ = self.passable.remove(&state);
+        }
+    }
+
+    /// Walk with the given velocity in blocks per tick, until changed. A
+    /// vanilla player walks at about 0.2 and sprints at about 0.28 blocks
+    /// per tick.
+    pub fn walk(&mut self, x: f64, z: f64) {
+        self.walk = (x, z);
+    }
+
+    /// Stop walking
+    pub fn stop(&mut self) {
+        self.walk = (0.0, 0.0);
+    }
+
+    /// Jump at the next tick the player is on the ground
+    pub fn jump(&mut self) {
+        self.jumping = true;
+    }
+
+    /// Set the direction the player is looking in, in degrees
+    pub fn look(&mut self, yaw: f32, pitch: f32) {
+        if let Some(ref mut position) = self.position {
+            position.yaw = yaw;
+            position.pitch = pitch;
+        }
+    }
+
+    /// Look at the given point from the player's eyes
+    pub fn look_at(&mut self, x: f64, y: f64, z: f64) {
+        if let Some(position) = self.position {
+            let dx = x - position.x;
+            let dy = y - (position.y + 1.62);
+            let dz = z - position.z;
+            let horizontal = (dx * dx + dz * dz).sqrt();
+            let yaw = (-dx).atan2(dz).to_degrees() as f32;
+            let pitch = (-dy).atan2(horizontal).to_degrees() as f32;
+            self.look(yaw, pitch);
+        }
+    }
+
+    /// Run the ticks that are due at the given instant, returning the
+    /// packets to send. Call this at least 20 times per second.
+    pub fn poll(&mut self, now: Instant, world: Option<&World>) -> Vec<ServerboundPacket> {
+        let mut next_tick = self.next_tick.unwrap_or(now);
+        let mut ret = Vec::new();
+        let mut ticks = 0;
+        while next_tick <= now {
+            if ticks < MAX_CATCH_UP_TICKS {
+                ret.extend(self.tick(world));
+                ticks += 1;
+            }
+            next_tick += TICK;
+        }
+        self.next_tick = Some(next_tick);
+        ret
+    }
+
+    /// Run a single tick, moving the player and returning the movement
+    /// packet to send, if the player's position is known
+    pub fn tick(&mut self, world: Option<&World>) -> Option<ServerboundPacket> {
+        let mut position = self.position?;
+
+        let clamp = |x: f64| x.clamp(-MAX_MOVE, MAX_MOVE);
+        let (mut dx, mut dy, mut dz) = (clamp(self.velocity.0 + self.walk.0),
+                                        clamp(self.velocity.1),
+                                        clamp(self.velocity.2 + self.walk.1));
+        if let Some(world) = world {
+            if self.jumping && self.on_ground {
+                dy = JUMP_VELOCITY;
+            }
+            let wanted_dy = dy;
+            dy = self.collide(world, &position, 1, dy);
+            position.y += dy;
+            dx = self.collide(world, &position, 0, dx);
+            position.x += dx;
+            dz = self.collide(world, &position, 2, dz);
+            position.z += dz;
+
+            self.on_ground = wanted_dy < 0.0 && dy > wanted_dy;
+            self.velocity.1 = if dy != wanted_dy { 0.0 } else { dy };
+            self.velocity.1 = (self.velocity.1 - GRAVITY) * VERTICAL_DRAG;
+        } else {
+            position.x += dx;
+            position.y += dy;
+            position.z += dz;
+            self.velocity.1 = 0.0;
+        }
+        self.jumping = false;
+
+        self.velocity.0 *= HORIZONTAL_DRAG;
+        self.velocity.2 *= HORIZONTAL_DRAG;
+        if self.velocity.0.abs() < MIN_VELOCITY {
+            self.velocity.0 = 0.0;
+        }
+        if self.velocity.2.abs() < MIN_VELOCITY {
+            self.velocity.2 = 0.0;
+        }
+        self.position = Some(position);
+        Some(self.get_update())
+    }
+
+    /// Run a tick that moves the player to the given position without
+    /// applying physics, e.g. to follow a planned path. Returns the movement
+    /// packet to send, if the player's position is known.
+    pub fn move_to(&mut self, x: f64, y: f64, z: f64, on_ground: bool) -> Option<ServerboundPacket> {
+        let position = self.position.as_mut()?;
+        position.x = x;
+        position.y = y;
+        position.z = z;
+        self.on_ground = on_ground;
+        self.velocity = (0.0, 0.0, 0.0);
+        self.jumping = false;
+        Some(self.get_update())
+    }
+
+    /// Create the packet telling the server about the current position,
+    /// choosing the smallest packet that includes all changes
+    fn get_update(&mut self) -> ServerboundPacket {
+        let position = self.position.expect("get_update without a position");
+        let last = self.last_sent.unwrap_or(position);
+        self.ticks_since_sent += 1;
+        let moved = (position.x - last.x).powi(2) + (position.y - last.y).powi(2) +
+                    (position.z - last.z).powi(2) > MIN_MOVE_SQUARED ||
+                    self.ticks_since_sent >= POSITION_REMINDER_TICKS;
+        let rotated = position.yaw != last.yaw || position.pitch != last.pitch;
+
+        let mut sent = last;
+        let ret = if moved && rotated {
+            sent = position;
+            PlayerPositionAndLook::new(position.x,
+                                       position.y,
+                                       position.z,
+                                       position.yaw,
+                                       position.pitch,
+                                       self.on_ground)
+        } else if moved {
+            sent.x = position.x;
+            sent.y = position.y;
+            sent.z = position.z;
+            PlayerPositionPacket::new(position.x, position.y, position.z, self.on_ground)
+        } else if rotated {
+            sent.yaw = position.yaw;
+            sent.pitch = position.pitch;
+            PlayerLook::new(position.yaw, position.pitch, self.on_ground)
+        } else {
+            Player::new(self.on_ground)
+        };
+        if moved {
+            self.ticks_since_sent = 0;
+        }
+        self.last_sent = Some(sent);
+        ret
+    }
+
+    fn is_solid(&self, world: &World, x: i32, y: i32, z: i32) -> bool {
+        if !world.is_loaded(x, z) {
+            return true;
+        }
+        match world.block_at(x, y, z) {
+            Some(state) => state != 0 && !self.passable.contains(&state),
+            None => false,
+        }
+    }
+
+    /// Get how far the player can move along the given axis (0 x, 1 y,
+    /// 2 z) from the given position, up to the given amount
+    fn collide(&self, world: &World, position: &PlayerPosition, axis: usize, amount: f64) -> f64 {
+        if amount == 0.0 {
+            return 0.0;
+        }
+        let half = PLAYER_WIDTH / 2.0;
+        let min = [position.x - half, position.y, position.z - half];
+        let max = [position.x + half, position.y + PLAYER_HEIGHT, position.z + half];
+        let mut swept_min = min;
+        let mut swept_max = max;
+        if amount < 0.0 {
+            swept_min[axis] += amount;
+        } else {
+            swept_max[axis] += amount;
+        }
+
+        let mut allowed = amount;
+        let from: Vec<i32> = swept_min.iter().map(|x| (x + EPSILON).floor() as i32).collect();
+        let to: Vec<i32> = swept_max.iter().map(|x| (x - EPSILON).floor() as i32).collect();
+        for bx in from[0]..=to[0] {
+            for by in from[1]..=to[1] {
+                for bz in from[2]..=to[2] {
+                    if !self.is_solid(world, bx, by, bz) {
+                        continue;
+                    }
+                    let block = [f64::from(bx), f64::from(by), f64::from(bz)];
+                    if amount > 0.0 && block[axis] >= max[axis] - EPSILON {
+                        allowed = allowed.min(block[axis] - max[axis]);
+                    } else if amount < 0.0 && block[axis] + 1.0 <= min[axis] + EPSILON {
+                        allowed = allowed.max(block[axis] + 1.0 - min[axis]);
+                    }
+                }
+            }
+        }
+        /* Don't move backwards if already touching */
+        if amount > 0.0 { allowed.max(0.0) } else { allowed.min(0.0) }
+    }
+}
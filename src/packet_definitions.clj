@@ -142,7 +142,7 @@
      {:name "CraftRecipeRequest"
       :id 24
       :fields [{:name "window_id" :type "u8" :getter "Get the window ID"}
-               {:name "recipe" :type "i32" :getter "Get the recipe ID" :read "varint"}
+               {:name "recipe" :type "String" :getter "Get the recipe identifier"}
                {:name "make_all" :type "bool" :getter "Get if shift was down when the item was clicked"}]}
      {:name "PlayerAbilities"
       :id 25
@@ -529,16 +529,16 @@
 {:name "EntityRelativeMove"
  :id 41
  :fields [{:name "entity_id" :type "i32" :getter "Get the entity ID" :read "varint"}
-          {:name "x" :type "i16" :getter "Get the relative distance moved in the X direction times 128"}
-          {:name "y" :type "i16" :getter "Get the relative distance moved in the Y direction times 128"}
-          {:name "z" :type "i16" :getter "Get the relative distance moved in the Z direction times 128"}
+          {:name "x" :type "i16" :getter "Get the relative distance moved in the X direction times 4096"}
+          {:name "y" :type "i16" :getter "Get the relative distance moved in the Y direction times 4096"}
+          {:name "z" :type "i16" :getter "Get the relative distance moved in the Z direction times 4096"}
           {:name "on_ground" :type "bool" :getter "Get whether the entity is on the ground"}]}
 {:name "EntityLookRelativeMove"
  :id 42
  :fields [{:name "entity_id" :type "i32" :getter "Get the entity ID" :read "varint"}
-          {:name "x" :type "i16" :getter "Get the relative distance moved in the X direction times 128"}
-          {:name "y" :type "i16" :getter "Get the relative distance moved in the Y direction times 128"}
-          {:name "z" :type "i16" :getter "Get the relative distance moved in the Z direction times 128"}
+          {:name "x" :type "i16" :getter "Get the relative distance moved in the X direction times 4096"}
+          {:name "y" :type "i16" :getter "Get the relative distance moved in the Y direction times 4096"}
+          {:name "z" :type "i16" :getter "Get the relative distance moved in the Z direction times 4096"}
           {:name "yaw" :type "i8" :getter "Get the yaw"}
           {:name "pitch" :type "i8" :getter "Get the pitch"}
           {:name "on_ground" :type "bool" :getter "Get whether the entity is on the grouns"}]}
@@ -572,7 +572,7 @@
 {:name "CraftRecipeResponse"
  :id 49
  :fields [{:name "window_id" :type "u8" :getter "Get the window ID"}
-          {:name "recipe" :type "i32" :getter "Get the recipe ID" :read "varint"}
+          {:name "recipe" :type "String" :getter "Get the recipe identifier"}
           ]
  }
 {:name "PlayerAbilities"
@@ -175,6 +175,26 @@
 (spit clientbound-enum-file (enum-impl-packet "ClientboundPacket" clientbound-packets) :append true)
 (spit serverbound-enum-file (enum-impl-packet "ServerboundPacket" serverbound-packets) :append true)
 
+;; Create the PacketVariant impls for the given packets, which is what lets
+;; the dispatch module pick out a single type of packet from the enum
+(defn enum-impl-variants [packet-type packets]
+  (apply str
+         (for [{name :name} packets]
+           (format
+             (long-str ""
+                       "impl PacketVariant<%s> for %s {"
+                       "    fn from_packet(packet: &%s) -> Option<&Self> {"
+                       "        match *packet {"
+                       "            %s::%s(ref x) => Some(x),"
+                       "            _ => None,"
+                       "        }"
+                       "    }"
+                       "}")
+             packet-type name packet-type packet-type name))))
+
+(spit clientbound-enum-file (enum-impl-variants "ClientboundPacket" clientbound-packets) :append true)
+(spit serverbound-enum-file (enum-impl-variants "ServerboundPacket" serverbound-packets) :append true)
+
 ;; Given the fields of a given packet, return a string containing the fields
 ;; in the rust definition format, i.e. %name: %type, and so on
 (defn fields-type-str [fields]
@@ -0,0 +1,503 @@
+//! Planning and following walkable routes through a `World`
+//!
+//! `find_path` runs A* over the positions the player's feet can be at,
+//! connecting them by walking, stepping up a block, dropping down, swimming
+//! and climbing ladders. What each move costs, and what kind each block
+//! state is, comes from a `PathCosts`, of which `DefaultCosts` is a
+//! configurable implementation.
+//!
+//! `PathFollower` then moves a `Movement` along the planned path, moving
+//! vertically and horizontally separately so the server's collision checks
+//! agree with every step.
+
+use movement::Movement;
+use serverbound::ServerboundPacket;
+use world::World;
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+
+/// A block position
+pub type BlockPos = (i32, i32, i32);
+
+/// The four horizontal directions
+const DIRECTIONS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// The furthest a drop is looked for, the height of the world
+const MAX_FALL: i32 = 256;
+
+/// What a block is like, as far as pathfinding is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockKind {
+    /// Can be walked through, e.g. air and flowers
+    Passable,
+    /// Can be stood on and can't be walked through
+    Solid,
+    /// Can be swum through
+    Water,
+    /// Must never be entered
+    Lava,
+    /// Can be climbed
+    Ladder,
+}
+impl BlockKind {
+    /// Check whether the player's body can be in the block
+    pub fn is_enterable(self) -> bool {
+        match self {
+            BlockKind::Passable | BlockKind::Water | BlockKind::Ladder => true,
+            BlockKind::Solid | BlockKind::Lava => false,
+        }
+    }
+}
+
+/// How the player gets from one position to the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathMove {
+    /// The starting position
+    Start,
+    /// Walking to a horizontally adjacent position
+    Walk,
+    /// Jumping onto a block one higher
+    StepUp,
+    /// Walking off an edge and falling the given number of blocks
+    Drop(i32),
+    /// Swimming to an adjacent position in water
+    Swim,
+    /// Climbing a ladder up or down
+    Climb,
+}
+
+/// Decides what blocks are like and how much moves cost
+pub trait PathCosts {
+    /// Get the kind of the given block state
+    fn get_kind(&self, state: i32) -> BlockKind;
+
+    /// Get the cost of moving between the given positions, or None if the
+    /// move shouldn't be made
+    fn get_cost(&self, from: BlockPos, to: BlockPos, movement: PathMove) -> Option<f64>;
+
+    /// Estimate the cost from the given position to the goal. Must not be
+    /// more than the actual cost for the found path to be the cheapest.
+    ///
+    /// By default this is the straight line distance, which is only right
+    /// if every move costs at least the distance it moves.
+    fn estimate(&self, from: BlockPos, goal: BlockPos) -> f64 {
+        distance(from, goal)
+    }
+}
+
+/// Get the straight line distance between two positions
+fn distance(from: BlockPos, to: BlockPos) -> f64 {
+    let dx = f64::from(from.0 - to.0);
+    let dy = f64::from(from.1 - to.1);
+    let dz = f64::from(from.2 - to.2);
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+/// Costs with a fixed price per kind of move. Block states are solid unless
+/// given another kind with `set_kind`, except air (0) which is passable.
+#[derive(Debug, Clone)]
+pub struct DefaultCosts {
+    kinds: BTreeMap<i32, BlockKind>,
+    /// The cost of walking one block
+    pub walk: f64,
+    /// The cost of stepping up one block
+    pub step_up: f64,
+    /// The cost of walking off an edge, plus `fall` per block fallen
+    pub drop: f64,
+    /// The cost per block fallen
+    pub fall: f64,
+    /// The highest drop allowed
+    pub max_drop: i32,
+    /// The cost of swimming one block
+    pub swim: f64,
+    /// The cost of climbing one block
+    pub climb: f64,
+}
+impl Default for DefaultCosts {
+    fn default() -> Self {
+        let mut kinds = BTreeMap::new();
+        let _ = kinds.insert(0, BlockKind::Passable);
+        DefaultCosts {
+            kinds,
+            walk: 1.0,
+            step_up: 2.0,
+            drop: 1.0,
+            fall: 0.5,
+            max_drop: 3,
+            swim: 2.0,
+            climb: 1.5,
+        }
+    }
+}
+impl DefaultCosts {
+    /// Create new costs with the default prices
+    pub fn new() -> Self {
+        DefaultCosts::default()
+    }
+
+    /// Set the kind of the given block state
+    pub fn set_kind(&mut self, state: i32, kind: BlockKind) {
+        let _ = self.kinds.insert(state, kind);
+    }
+
+    /// Get the lowest cost per block moved of any move. Drops and steps
+    /// move diagonally, so they can be cheaper per block than walking.
+    fn get_min_cost_per_block(&self) -> f64 {
+        let drops = (1..=self.max_drop.min(MAX_FALL)).map(|height| {
+            let height = f64::from(height);
+            (self.drop + self.fall * height) / (1.0 + height * height).sqrt()
+        });
+        [self.walk, self.step_up / 2f64.sqrt(), self.swim, self.climb]
+            .iter()
+            .cloned()
+            .chain(drops)
+            .fold(f64::INFINITY, f64::min)
+            .max(0.0)
+    }
+}
+impl PathCosts for DefaultCosts {
+    fn get_kind(&self, state: i32) -> BlockKind {
+        self.kinds.get(&state).cloned().unwrap_or(BlockKind::Solid)
+    }
+
+    fn get_cost(&self, _: BlockPos, _: BlockPos, movement: PathMove) -> Option<f64> {
+        Some(match movement {
+            PathMove::Start => 0.0,
+            PathMove::Walk => self.walk,
+            PathMove::StepUp => self.step_up,
+            PathMove::Drop(height) if height > self.max_drop => return None,
+            PathMove::Drop(height) => self.drop + self.fall * f64::from(height),
+            PathMove::Swim => self.swim,
+            PathMove::Climb => self.climb,
+        })
+    }
+
+    fn estimate(&self, from: BlockPos, goal: BlockPos) -> f64 {
+        distance(from, goal) * self.get_min_cost_per_block()
+    }
+}
+
+/// A position along a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathNode {
+    /// The block the player's feet are in
+    pub position: BlockPos,
+    /// How the player gets here from the previous node
+    pub movement: PathMove,
+    /// Whether the player stands on a solid block here
+    pub on_ground: bool,
+}
+
+/// A planned path
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    /// The nodes from the start to the goal
+    pub nodes: Vec<PathNode>,
+    /// The total cost
+    pub cost: f64,
+}
+
+/// A node in the open set, ordered so the heap pops the lowest estimate
+#[derive(Debug, PartialEq)]
+struct OpenNode {
+    estimate: f64,
+    position: BlockPos,
+}
+impl Eq for OpenNode {}
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.partial_cmp(&self.estimate).unwrap_or(Ordering::Equal)
+    }
+}
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Finds paths through a world
+pub struct Pathfinder<'a, C: PathCosts + 'a> {
+    world: &'a World,
+    costs: &'a C,
+    /// The most positions to visit before giving up
+    pub max_visited: usize,
+}
+impl<'a, C: PathCosts + 'a> Pathfinder<'a, C> {
+    /// Create a new pathfinder
+    pub fn new(world: &'a World, costs: &'a C) -> Self {
+        Pathfinder {
+            world,
+            costs,
+            max_visited: 10000,
+        }
+    }
+
+    /// Get the kind of the block at the given position. Blocks in chunks
+    /// that aren't loaded are solid, and blocks above or below the world
+    /// are passable.
+    pub fn get_kind(&self, pos: BlockPos) -> BlockKind {
+        if !self.world.is_loaded(pos.0, pos.2) {
+            return BlockKind::Solid;
+        }
+        match self.world.block_at(pos.0, pos.1, pos.2) {
+            Some(state) => self.costs.get_kind(state),
+            None => BlockKind::Passable,
+        }
+    }
+
+    /// Check whether the player can be at the given position, returning
+    /// whether they stand on a solid block there
+    fn get_standing(&self, pos: BlockPos) -> Option<bool> {
+        let feet = self.get_kind(pos);
+        let head = self.get_kind((pos.0, pos.1 + 1, pos.2));
+        if !feet.is_enterable() || !head.is_enterable() {
+            return None;
+        }
+        let below = self.get_kind((pos.0, pos.1 - 1, pos.2));
+        if below == BlockKind::Solid {
+            return Some(true);
+        }
+        if feet == BlockKind::Water || feet == BlockKind::Ladder {
+            return Some(false);
+        }
+        None
+    }
+
+    /// Get the positions reachable from the given position in one move
+    fn get_neighbours(&self, pos: BlockPos) -> Vec<(BlockPos, PathMove, bool)> {
+        let mut ret = Vec::new();
+        let feet = self.get_kind(pos);
+        let above_head = self.get_kind((pos.0, pos.1 + 2, pos.2));
+        let can_jump = feet == BlockKind::Water || feet == BlockKind::Ladder ||
+                       self.get_kind((pos.0, pos.1 - 1, pos.2)) == BlockKind::Solid;
+
+        for &(dx, dz) in &DIRECTIONS {
+            let next = (pos.0 + dx, pos.1, pos.2 + dz);
+            if let Some(on_ground) = self.get_standing(next) {
+                let movement = if feet == BlockKind::Water || self.get_kind(next) == BlockKind::Water {
+                    PathMove::Swim
+                } else {
+                    PathMove::Walk
+                };
+                ret.push((next, movement, on_ground));
+                continue;
+            }
+
+            let up = (next.0, next.1 + 1, next.2);
+            if self.get_kind(next) == BlockKind::Solid {
+                if !can_jump || !above_head.is_enterable() {
+                    continue;
+                }
+                if let Some(on_ground) = self.get_standing(up) {
+                    ret.push((up, PathMove::StepUp, on_ground));
+                }
+                continue;
+            }
+
+            /* Walk off the edge and fall until landing */
+            if !self.get_kind(next).is_enterable() ||
+               !self.get_kind((next.0, next.1 + 1, next.2)).is_enterable() {
+                continue;
+            }
+            let mut y = next.1 - 1;
+            while next.1 - y <= MAX_FALL {
+                let kind = self.get_kind((next.0, y, next.2));
+                if !kind.is_enterable() {
+                    break;
+                }
+                let height = next.1 - y;
+                if let Some(on_ground) = self.get_standing((next.0, y, next.2)) {
+                    ret.push(((next.0, y, next.2), PathMove::Drop(height), on_ground));
+                    break;
+                }
+                y -= 1;
+            }
+        }
+
+        /* Swimming and climbing also go straight up and down */
+        if feet == BlockKind::Water || feet == BlockKind::Ladder {
+            let movement = if feet == BlockKind::Water { PathMove::Swim } else { PathMove::Climb };
+            for &dy in &[1, -1] {
+                let next = (pos.0, pos.1 + dy, pos.2);
+                let kind = self.get_kind(next);
+                if kind == BlockKind::Water || kind == BlockKind::Ladder ||
+                   (dy == 1 && kind.is_enterable()) {
+                    if let Some(on_ground) = self.get_standing(next) {
+                        ret.push((next, movement, on_ground));
+                    }
+                }
+            }
+        } else if self.get_kind((pos.0, pos.1 - 1, pos.2)) == BlockKind::Ladder {
+            let next = (pos.0, pos.1 - 1, pos.2);
+            if let Some(on_ground) = self.get_standing(next) {
+                ret.push((next, PathMove::Climb, on_ground));
+            }
+        }
+        ret
+    }
+
+    /// Find the cheapest path from the start to the goal, given as the
+    /// blocks the player's feet are in. Returns None if there is no path,
+    /// or none was found within `max_visited` positions.
+    pub fn find_path(&self, start: BlockPos, goal: BlockPos) -> Option<Path> {
+        let start_on_ground = self.get_standing(start).unwrap_or(false);
+        let mut open = BinaryHeap::new();
+        let mut costs: HashMap<BlockPos, f64> = HashMap::new();
+        let mut came_from: HashMap<BlockPos, (BlockPos, PathMove, bool)> = HashMap::new();
+        let _ = costs.insert(start, 0.0);
+        open.push(OpenNode {
+                      estimate: self.costs.estimate(start, goal),
+                      position: start,
+                  });
+
+        let mut visited = 0;
+        while let Some(OpenNode { estimate, position }) = open.pop() {
+            let cost = costs[&position];
+            if estimate > cost + self.costs.estimate(position, goal) + 1e-9 {
+                /* A cheaper way here was found after this was queued */
+                continue;
+            }
+            if position == goal {
+                return Some(self.build_path(start, start_on_ground, goal, cost, &came_from));
+            }
+            visited += 1;
+            if visited > self.max_visited {
+                return None;
+            }
+
+            for (next, movement, on_ground) in self.get_neighbours(position) {
+                let step = match self.costs.get_cost(position, next, movement) {
+                    Some(x) => x,
+                    None => continue,
+                };
+                let next_cost = cost + step;
+                if costs.get(&next).is_some_and(|&x| x <= next_cost) {
+                    continue;
+                }
+                let _ = costs.insert(next, next_cost);
+                let _ = came_from.insert(next, (position, movement, on_ground));
+                open.push(OpenNode {
+                              estimate: next_cost + self.costs.estimate(next, goal),
+                              position: next,
+                          });
+            }
+        }
+        None
+    }
+
+    fn build_path(&self,
+                  start: BlockPos,
+                  start_on_ground: bool,
+                  goal: BlockPos,
+                  cost: f64,
+                  came_from: &HashMap<BlockPos, (BlockPos, PathMove, bool)>)
+                  -> Path {
+        let mut nodes = Vec::new();
+        let mut position = goal;
+        while position != start {
+            let (previous, movement, on_ground) = came_from[&position];
+            nodes.push(PathNode {
+                           position,
+                           movement,
+                           on_ground,
+                       });
+            position = previous;
+        }
+        nodes.push(PathNode {
+                       position: start,
+                       movement: PathMove::Start,
+                       on_ground: start_on_ground,
+                   });
+        nodes.reverse();
+        Path { nodes, cost }
+    }
+}
+
+/// Find the cheapest path through the world with the given costs, see
+/// `Pathfinder::find_path`
+pub fn find_path<C: PathCosts>(world: &World,
+                               costs: &C,
+                               start: BlockPos,
+                               goal: BlockPos)
+                               -> Option<Path> {
+    Pathfinder::new(world, costs).find_path(start, goal)
+}
+
+/// A point the player moves to in a straight line
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Waypoint {
+    x: f64,
+    y: f64,
+    z: f64,
+    on_ground: bool,
+}
+
+/// Moves the player along a path, one tick at a time
+#[derive(Debug, Clone)]
+pub struct PathFollower {
+    waypoints: Vec<Waypoint>,
+    next: usize,
+    /// How far the player moves per tick, in blocks
+    pub speed: f64,
+}
+impl PathFollower {
+    /// Create a follower for the given path, moving at the given speed in
+    /// blocks per tick. A vanilla player walks at about 0.2 blocks per tick.
+    pub fn new(path: &Path, speed: f64) -> Self {
+        let mut waypoints = Vec::new();
+        let center = |pos: BlockPos, on_ground: bool| {
+            Waypoint {
+                x: f64::from(pos.0) + 0.5,
+                y: f64::from(pos.1),
+                z: f64::from(pos.2) + 0.5,
+                on_ground,
+            }
+        };
+        for (i, node) in path.nodes.iter().enumerate() {
+            if i > 0 {
+                /* Go up before moving over a step, and down after moving off
+                 * an edge, so the player never cuts through a block */
+                let previous = path.nodes[i - 1].position;
+                let pos = node.position;
+                if pos.1 > previous.1 && (pos.0 != previous.0 || pos.2 != previous.2) {
+                    waypoints.push(center((previous.0, pos.1, previous.2), false));
+                } else if pos.1 < previous.1 && (pos.0 != previous.0 || pos.2 != previous.2) {
+                    waypoints.push(center((pos.0, previous.1, pos.2), false));
+                }
+            }
+            waypoints.push(center(node.position, node.on_ground));
+        }
+        PathFollower {
+            waypoints,
+            next: 0,
+            speed,
+        }
+    }
+
+    /// Check whether the end of the path has been reached
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.waypoints.len()
+    }
+
+    /// Move the player further along the path, returning the movement
+    /// packet to send. Returns None once the path is finished, or if the
+    /// player's position isn't known.
+    pub fn tick(&mut self, movement: &mut Movement) -> Option<ServerboundPacket> {
+        let position = movement.get_position()?;
+        let target = *self.waypoints.get(self.next)?;
+        let dx = target.x - position.x;
+        let dy = target.y - position.y;
+        let dz = target.z - position.z;
+        let distance = (dx * dx + dy * dy + dz * dz).sqrt();
+        if distance <= self.speed {
+            self.next += 1;
+            return movement.move_to(target.x, target.y, target.z, target.on_ground);
+        }
+        let factor = self.speed / distance;
+        movement.move_to(position.x + dx * factor,
+                         position.y + dy * factor,
+                         position.z + dz * factor,
+                         false)
+    }
+}
@@ -0,0 +1,454 @@
+//! Plugin channels, i.e. custom data sent by mods, plugins and proxies
+//!
+//! During the Login state, the server can send LoginPluginRequest packets on
+//! a named channel, and the client must always respond with a
+//! LoginPluginResponse with the same message id, marking the request as not
+//! understood if it does not know the channel. The `LoginPluginRegistry` lets
+//! a Client register handlers for specific channels, and takes care of
+//! replying to all other requests, while the `LoginPluginResponseRegistry`
+//! lets a Server register handlers for the responses to its requests.
+//!
+//! In the Play state, plugin messages are sent in both directions without any
+//! replies. The `PluginChannelRouter` passes them to the handlers registered
+//! for their channel, and keeps track of the channels registered by the other
+//! end using `minecraft:register` and `minecraft:unregister`.
+//!
+//! The `PluginCodec` trait is for the payloads of channels with a known
+//! format, allowing them to be decoded into and encoded from Rust types.
+
+use clientbound::{ClientboundPacket, LoginPluginRequest};
+use errors::Result;
+use read::*;
+use serverbound::{LoginPluginResponse, ServerboundPacket};
+use write::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io::Cursor;
+
+/// A payload with a known format sent on a specific plugin channel
+pub trait PluginCodec: Sized {
+    /// The channel (namespaced identifier) the payload is sent on
+    const CHANNEL: &'static str;
+    /// Decode the payload from the raw data of a plugin message
+    fn decode(data: &[u8]) -> Result<Self>;
+    /// Encode the payload into the raw data of a plugin message
+    fn encode(&self) -> Result<Vec<u8>>;
+}
+
+/// A client-side handler for LoginPluginRequests on a given channel
+pub trait LoginPluginHandler {
+    /// Handle the data of a request, returning the data to respond with.
+    ///
+    /// Returning Ok(None) responds that the request was not understood.
+    fn handle_request(&mut self, data: &[u8]) -> Result<Option<Vec<u8>>>;
+}
+impl<F: FnMut(&[u8]) -> Result<Option<Vec<u8>>>> LoginPluginHandler for F {
+    fn handle_request(&mut self, data: &[u8]) -> Result<Option<Vec<u8>>> {
+        self(data)
+    }
+}
+
+/// The login plugin channel handlers of a Client
+///
+/// Requests on channels without a handler are responded to as not
+/// understood, which is also what the vanilla client does.
+#[derive(Default)]
+pub struct LoginPluginRegistry {
+    handlers: BTreeMap<String, Box<dyn LoginPluginHandler + Send>>,
+}
+impl LoginPluginRegistry {
+    /// Create a new registry without any handlers
+    pub fn new() -> Self {
+        LoginPluginRegistry {
+            handlers: BTreeMap::new(),
+        }
+    }
+
+    /// Register the handler for the given channel, replacing any previously
+    /// registered handler for the channel.
+    pub fn register<H: LoginPluginHandler + Send + 'static>(&mut self,
+                                                             channel: &str,
+                                                             handler: H) {
+        let _ = self.handlers.insert(channel.to_string(), Box::new(handler));
+    }
+
+    /// Remove the handler for the given channel, returning whether there was
+    /// one.
+    pub fn unregister(&mut self, channel: &str) -> bool {
+        self.handlers.remove(channel).is_some()
+    }
+
+    /// Get whether a handler is registered for the given channel
+    pub fn is_registered(&self, channel: &str) -> bool {
+        self.handlers.contains_key(channel)
+    }
+
+    /// Pass the given request to the handler registered for its channel, and
+    /// create the response that is to be sent to the server.
+    pub fn handle_request(&mut self,
+                          request: &LoginPluginRequest)
+                          -> Result<ServerboundPacket> {
+        let id = *request.get_id();
+        match self.handlers.get_mut(request.get_identifier()) {
+            Some(handler) => {
+                match handler.handle_request(request.get_data())? {
+                    Some(data) => Ok(LoginPluginResponse::new_understood(id, data)),
+                    None => Ok(LoginPluginResponse::new_not_understood(id)),
+                }
+            },
+            None => Ok(LoginPluginResponse::new_not_understood(id)),
+        }
+    }
+}
+impl fmt::Debug for LoginPluginRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.handlers.keys()).finish()
+    }
+}
+
+/// A server-side handler for the LoginPluginResponses on a given channel
+pub trait LoginPluginResponseHandler {
+    /// Handle a response to a request sent on the channel. If understood is
+    /// false the client did not understand the request and data is empty.
+    fn handle_response(&mut self, understood: bool, data: &[u8]) -> Result<()>;
+}
+impl<F: FnMut(bool, &[u8]) -> Result<()>> LoginPluginResponseHandler for F {
+    fn handle_response(&mut self, understood: bool, data: &[u8]) -> Result<()> {
+        self(understood, data)
+    }
+}
+
+/// The login plugin channel handlers of a Server
+///
+/// Since responses only contain the message id and not the channel, this
+/// also keeps track of which channel each outstanding request was sent on,
+/// so requests must be created with `LoginPluginResponseRegistry::new_request`.
+#[derive(Default)]
+pub struct LoginPluginResponseRegistry {
+    handlers: BTreeMap<String, Box<dyn LoginPluginResponseHandler + Send>>,
+    pending: BTreeMap<i32, String>,
+    next_id: i32,
+}
+impl LoginPluginResponseRegistry {
+    /// Create a new registry without any handlers
+    pub fn new() -> Self {
+        LoginPluginResponseRegistry {
+            handlers: BTreeMap::new(),
+            pending: BTreeMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Register the handler for the given channel, replacing any previously
+    /// registered handler for the channel.
+    pub fn register<H: LoginPluginResponseHandler + Send + 'static>(&mut self,
+                                                                     channel: &str,
+                                                                     handler: H) {
+        let _ = self.handlers.insert(channel.to_string(), Box::new(handler));
+    }
+
+    /// Remove the handler for the given channel, returning whether there was
+    /// one.
+    pub fn unregister(&mut self, channel: &str) -> bool {
+        self.handlers.remove(channel).is_some()
+    }
+
+    /// Create a LoginPluginRequest on the given channel with a new unique
+    /// message id, remembering the channel for when the response arrives.
+    pub fn new_request(&mut self, channel: &str, data: Vec<u8>) -> ClientboundPacket {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        let _ = self.pending.insert(id, channel.to_string());
+        LoginPluginRequest::new(id, channel.to_string(), data)
+    }
+
+    /// Get the channel an outstanding request with the given message id was
+    /// sent on
+    pub fn get_pending_channel(&self, message_id: i32) -> Option<&str> {
+        self.pending.get(&message_id).map(String::as_str)
+    }
+
+    /// Get whether there are requests that have not yet been responded to.
+    ///
+    /// The server should not send LoginSuccess before this returns false.
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Pass the given response to the handler registered for the channel the
+    /// request was sent on, returning the channel.
+    ///
+    /// Returns an error if the message id does not match any outstanding
+    /// request. Responses on channels without a registered handler are
+    /// ignored.
+    pub fn handle_response(&mut self, response: &LoginPluginResponse) -> Result<String> {
+        let id = *response.get_message_id();
+        let channel = match self.pending.remove(&id) {
+            Some(x) => x,
+            None => bail!("Got LoginPluginResponse with unknown message id {}", id),
+        };
+        if let Some(handler) = self.handlers.get_mut(&channel) {
+            handler.handle_response(*response.get_successful(), response.get_data())?;
+        }
+        Ok(channel)
+    }
+}
+impl fmt::Debug for LoginPluginResponseRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LoginPluginResponseRegistry")
+            .field("handlers", &self.handlers.keys().collect::<Vec<_>>())
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+/// The payload of a `velocity:player_info` request, sent by a server to ask
+/// a Velocity proxy for the player's information.
+///
+/// See the forwarding module for the response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VelocityPlayerInfoRequest {
+    /// The highest forwarding version supported by the server, if given
+    pub max_version: Option<u8>,
+}
+impl PluginCodec for VelocityPlayerInfoRequest {
+    const CHANNEL: &'static str = ::forwarding::VELOCITY_CHANNEL;
+    fn decode(data: &[u8]) -> Result<Self> {
+        Ok(VelocityPlayerInfoRequest {
+            max_version: data.first().cloned(),
+        })
+    }
+    fn encode(&self) -> Result<Vec<u8>> {
+        Ok(self.max_version.into_iter().collect())
+    }
+}
+
+/// The payload of a `fml:loginwrapper` message, used by Forge to wrap its
+/// own login channels (e.g. `fml:handshake`) in a single login channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoginWrapper {
+    /// The wrapped channel
+    pub channel: String,
+    /// The wrapped data
+    pub data: Vec<u8>,
+}
+impl PluginCodec for LoginWrapper {
+    const CHANNEL: &'static str = "fml:loginwrapper";
+    fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let channel = read_String(&mut r)?;
+        let data = read_prefixed_bytearray(&mut r)?;
+        Ok(LoginWrapper {
+            channel,
+            data,
+        })
+    }
+    fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_String(&self.channel, &mut ret)?;
+        write_prefixed_bytearray(&self.data, &mut ret)?;
+        Ok(ret)
+    }
+}
+
+/// A handler for play state plugin messages on a given channel
+pub trait PluginMessageHandler {
+    /// Handle the data of a plugin message
+    fn handle_message(&mut self, data: &[u8]) -> Result<()>;
+}
+impl<F: FnMut(&[u8]) -> Result<()>> PluginMessageHandler for F {
+    fn handle_message(&mut self, data: &[u8]) -> Result<()> {
+        self(data)
+    }
+}
+
+/// Routes play state plugin messages to the handlers registered for their
+/// channel, for use by both Client and Server.
+///
+/// This also keeps track of the channels the other end has registered with
+/// `minecraft:register` and `minecraft:unregister`, and of the brand it has
+/// sent with `minecraft:brand`.
+#[derive(Default)]
+pub struct PluginChannelRouter {
+    handlers: BTreeMap<String, Box<dyn PluginMessageHandler + Send>>,
+    remote_channels: BTreeSet<String>,
+    remote_brand: Option<String>,
+}
+impl PluginChannelRouter {
+    /// Create a new router without any handlers
+    pub fn new() -> Self {
+        PluginChannelRouter {
+            handlers: BTreeMap::new(),
+            remote_channels: BTreeSet::new(),
+            remote_brand: None,
+        }
+    }
+
+    /// Register the handler for the given channel, replacing any previously
+    /// registered handler for the channel.
+    pub fn register<H: PluginMessageHandler + Send + 'static>(&mut self,
+                                                              channel: &str,
+                                                              handler: H) {
+        let _ = self.handlers.insert(channel.to_string(), Box::new(handler));
+    }
+
+    /// Remove the handler for the given channel, returning whether there was
+    /// one.
+    pub fn unregister(&mut self, channel: &str) -> bool {
+        self.handlers.remove(channel).is_some()
+    }
+
+    /// Get the channels that have a handler registered
+    pub fn get_channels(&self) -> Vec<String> {
+        self.handlers.keys().cloned().collect()
+    }
+
+    /// Get the channels the other end has said it listens on
+    pub fn get_remote_channels(&self) -> &BTreeSet<String> {
+        &self.remote_channels
+    }
+
+    /// Get whether the other end has said it listens on the given channel
+    pub fn is_remote_registered(&self, channel: &str) -> bool {
+        self.remote_channels.contains(channel)
+    }
+
+    /// Get the brand the other end has sent, if any, e.g. `vanilla`
+    pub fn get_remote_brand(&self) -> Option<&str> {
+        self.remote_brand.as_deref()
+    }
+
+    /// Get whether messages on the given channel are handled by the router,
+    /// i.e. whether the channel has a handler or is one of the channels
+    /// handled by the router itself.
+    pub fn is_handled(&self, channel: &str) -> bool {
+        match channel {
+            Register::CHANNEL | Unregister::CHANNEL | Brand::CHANNEL => true,
+            _ => self.handlers.contains_key(channel),
+        }
+    }
+
+    /// Handle a plugin message received on the given channel.
+    ///
+    /// `minecraft:register`, `minecraft:unregister` and `minecraft:brand` are
+    /// handled by the router itself (and then also passed to any handler
+    /// registered for them), all other messages are passed to the handler
+    /// registered for their channel.
+    ///
+    /// Returns whether the message was handled.
+    pub fn handle_message(&mut self, channel: &str, data: &[u8]) -> Result<bool> {
+        match channel {
+            Register::CHANNEL => {
+                self.remote_channels.extend(Register::decode(data)?.channels);
+            },
+            Unregister::CHANNEL => {
+                for x in Unregister::decode(data)?.channels {
+                    let _ = self.remote_channels.remove(&x);
+                }
+            },
+            Brand::CHANNEL => {
+                self.remote_brand = Some(Brand::decode(data)?.brand);
+            },
+            _ => (),
+        }
+
+        if let Some(handler) = self.handlers.get_mut(channel) {
+            handler.handle_message(data)?;
+        }
+        Ok(self.is_handled(channel))
+    }
+
+    /// Create the `minecraft:register` payload for all the channels that
+    /// have a handler registered
+    pub fn get_register(&self) -> Register {
+        Register {
+            channels: self.get_channels(),
+        }
+    }
+}
+impl fmt::Debug for PluginChannelRouter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PluginChannelRouter")
+            .field("handlers", &self.handlers.keys().collect::<Vec<_>>())
+            .field("remote_channels", &self.remote_channels)
+            .field("remote_brand", &self.remote_brand)
+            .finish()
+    }
+}
+
+/// The payload of a `minecraft:brand` message, i.e. the name of the client
+/// or server software, e.g. `vanilla`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Brand {
+    /// The brand
+    pub brand: String,
+}
+impl PluginCodec for Brand {
+    const CHANNEL: &'static str = "minecraft:brand";
+    fn decode(data: &[u8]) -> Result<Self> {
+        Ok(Brand {
+            brand: read_String(&mut Cursor::new(data))?,
+        })
+    }
+    fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_String(&self.brand, &mut ret)?;
+        Ok(ret)
+    }
+}
+
+/// The payload of a `minecraft:register` message, telling the other end
+/// which channels the sender listens on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Register {
+    /// The channels being registered
+    pub channels: Vec<String>,
+}
+impl PluginCodec for Register {
+    const CHANNEL: &'static str = "minecraft:register";
+    fn decode(data: &[u8]) -> Result<Self> {
+        Ok(Register {
+            channels: decode_channel_list(data)?,
+        })
+    }
+    fn encode(&self) -> Result<Vec<u8>> {
+        Ok(encode_channel_list(&self.channels))
+    }
+}
+
+/// The payload of a `minecraft:unregister` message, telling the other end
+/// which channels the sender no longer listens on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unregister {
+    /// The channels being unregistered
+    pub channels: Vec<String>,
+}
+impl PluginCodec for Unregister {
+    const CHANNEL: &'static str = "minecraft:unregister";
+    fn decode(data: &[u8]) -> Result<Self> {
+        Ok(Unregister {
+            channels: decode_channel_list(data)?,
+        })
+    }
+    fn encode(&self) -> Result<Vec<u8>> {
+        Ok(encode_channel_list(&self.channels))
+    }
+}
+
+/// Decode a list of null-separated channel names, as used by
+/// `minecraft:register` and `minecraft:unregister`
+fn decode_channel_list(data: &[u8]) -> Result<Vec<String>> {
+    let mut ret = Vec::new();
+    for x in data.split(|&x| x == 0) {
+        if !x.is_empty() {
+            ret.push(String::from_utf8(x.to_vec())?);
+        }
+    }
+    Ok(ret)
+}
+
+/// Encode a list of channel names as null-separated strings
+fn encode_channel_list(channels: &[String]) -> Vec<u8> {
+    channels.join("\0").into_bytes()
+}
@@ -0,0 +1,366 @@
+//! The recipes sent in the DeclareRecipes packet, and the recipe book
+//!
+//! `RecipeBook` keeps track of which recipes the player has unlocked based on
+//! the UnlockRecipes packets, and creates the CraftRecipeRequest packets used
+//! to craft them from the recipe book.
+
+use clientbound::UnlockRecipes;
+use errors::Result;
+use read::*;
+use serverbound::{CraftRecipeRequest, RecipeBookData, ServerboundPacket};
+use slot::*;
+use write::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Cursor, Read};
+
+/// An ingredient, i.e. the list of items any of which may be used
+pub type Ingredient = Vec<Option<Slot>>;
+
+/// A single recipe
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recipe {
+    /// The recipe identifier, e.g. `minecraft:oak_planks`
+    pub id: String,
+    /// The type and contents of the recipe
+    pub data: RecipeData,
+}
+
+/// The different types of recipes
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecipeData {
+    /// `minecraft:crafting_shapeless`
+    Shapeless {
+        /// The recipe group, used for grouping recipes in the recipe book
+        group: String,
+        /// The ingredients, in any order
+        ingredients: Vec<Ingredient>,
+        /// The crafted item
+        result: Option<Slot>,
+    },
+    /// `minecraft:crafting_shaped`
+    Shaped {
+        /// The width of the pattern
+        width: i32,
+        /// The height of the pattern
+        height: i32,
+        /// The recipe group, used for grouping recipes in the recipe book
+        group: String,
+        /// The ingredients, row by row, width * height of them
+        ingredients: Vec<Ingredient>,
+        /// The crafted item
+        result: Option<Slot>,
+    },
+    /// `minecraft:smelting`
+    Smelting(CookingRecipe),
+    /// `minecraft:blasting`
+    Blasting(CookingRecipe),
+    /// `minecraft:smoking`
+    Smoking(CookingRecipe),
+    /// `minecraft:campfire_cooking`
+    CampfireCooking(CookingRecipe),
+    /// `minecraft:stonecutting`
+    Stonecutting {
+        /// The recipe group
+        group: String,
+        /// The input item
+        ingredient: Ingredient,
+        /// The resulting item
+        result: Option<Slot>,
+    },
+    /// One of the `minecraft:crafting_special_*` recipes which are hardcoded
+    /// in the client and carry no data, e.g.
+    /// `minecraft:crafting_special_armordye`. Contains the full type
+    /// identifier.
+    Special(String),
+}
+impl RecipeData {
+    /// Get the type identifier of the recipe
+    pub fn get_type(&self) -> &str {
+        match *self {
+            RecipeData::Shapeless { .. } => "minecraft:crafting_shapeless",
+            RecipeData::Shaped { .. } => "minecraft:crafting_shaped",
+            RecipeData::Smelting(..) => "minecraft:smelting",
+            RecipeData::Blasting(..) => "minecraft:blasting",
+            RecipeData::Smoking(..) => "minecraft:smoking",
+            RecipeData::CampfireCooking(..) => "minecraft:campfire_cooking",
+            RecipeData::Stonecutting { .. } => "minecraft:stonecutting",
+            RecipeData::Special(ref x) => x,
+        }
+    }
+
+    /// Get the item produced by the recipe, if known
+    pub fn get_result(&self) -> Option<&Slot> {
+        match *self {
+            RecipeData::Shapeless { ref result, .. } |
+            RecipeData::Shaped { ref result, .. } |
+            RecipeData::Stonecutting { ref result, .. } => result.as_ref(),
+            RecipeData::Smelting(ref x) |
+            RecipeData::Blasting(ref x) |
+            RecipeData::Smoking(ref x) |
+            RecipeData::CampfireCooking(ref x) => x.result.as_ref(),
+            RecipeData::Special(..) => None,
+        }
+    }
+}
+
+/// A smelting, blasting, smoking or campfire cooking recipe
+#[derive(Debug, Clone, PartialEq)]
+pub struct CookingRecipe {
+    /// The recipe group
+    pub group: String,
+    /// The item being cooked
+    pub ingredient: Ingredient,
+    /// The resulting item
+    pub result: Option<Slot>,
+    /// The experience gained
+    pub experience: f32,
+    /// The cooking time in ticks
+    pub cooking_time: i32,
+}
+impl CookingRecipe {
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        Ok(CookingRecipe {
+            group: read_String(r)?,
+            ingredient: read_slot_array(r)?,
+            result: read_slot(r)?,
+            experience: read_f32(r)?,
+            cooking_time: read_varint(r)?,
+        })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_String(&self.group, w)?;
+        write_slot_array(&self.ingredient, w)?;
+        write_slot(&self.result, w)?;
+        write_f32(&self.experience, w)?;
+        write_varint(&self.cooking_time, w)
+    }
+}
+
+impl Recipe {
+    /// Decode the raw data of a DeclareRecipes packet
+    pub fn decode_list(data: &[u8]) -> Result<Vec<Recipe>> {
+        let mut r = Cursor::new(data);
+        let count = read_varint(&mut r)?;
+        if count < 0 {
+            bail!("DeclareRecipes had negative recipe count {}", count);
+        }
+        let mut ret = Vec::new();
+        for _ in 0..count {
+            ret.push(Recipe::read(&mut r)?);
+        }
+        Ok(ret)
+    }
+
+    /// Encode the recipes into the raw data of a DeclareRecipes packet
+    pub fn encode_list(recipes: &[Recipe]) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_varint(&(recipes.len() as i32), &mut ret)?;
+        for recipe in recipes {
+            recipe.write(&mut ret)?;
+        }
+        Ok(ret)
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        let type_ = read_String(r)?;
+        let id = read_String(r)?;
+        let data = match type_.as_str() {
+            "minecraft:crafting_shapeless" => {
+                let group = read_String(r)?;
+                let count = read_varint(r)?;
+                if count < 0 {
+                    bail!("Shapeless recipe {} has negative ingredient count {}", id, count);
+                }
+                let mut ingredients = Vec::new();
+                for _ in 0..count {
+                    ingredients.push(read_slot_array(r)?);
+                }
+                RecipeData::Shapeless {
+                    group,
+                    ingredients,
+                    result: read_slot(r)?,
+                }
+            },
+            "minecraft:crafting_shaped" => {
+                let width = read_varint(r)?;
+                let height = read_varint(r)?;
+                let group = read_String(r)?;
+                let count = match width.checked_mul(height) {
+                    Some(x) if x >= 0 => x,
+                    _ => bail!("Shaped recipe {} has invalid size {}x{}", id, width, height),
+                };
+                let mut ingredients = Vec::new();
+                for _ in 0..count {
+                    ingredients.push(read_slot_array(r)?);
+                }
+                RecipeData::Shaped {
+                    width,
+                    height,
+                    group,
+                    ingredients,
+                    result: read_slot(r)?,
+                }
+            },
+            "minecraft:smelting" => RecipeData::Smelting(CookingRecipe::read(r)?),
+            "minecraft:blasting" => RecipeData::Blasting(CookingRecipe::read(r)?),
+            "minecraft:smoking" => RecipeData::Smoking(CookingRecipe::read(r)?),
+            "minecraft:campfire_cooking" => RecipeData::CampfireCooking(CookingRecipe::read(r)?),
+            "minecraft:stonecutting" => {
+                RecipeData::Stonecutting {
+                    group: read_String(r)?,
+                    ingredient: read_slot_array(r)?,
+                    result: read_slot(r)?,
+                }
+            },
+            x if x.starts_with("minecraft:crafting_special_") => RecipeData::Special(type_.clone()),
+            x => bail!("Recipe {} has unknown type {}", id, x),
+        };
+        Ok(Recipe {
+            id,
+            data,
+        })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_String(self.data.get_type(), w)?;
+        write_String(&self.id, w)?;
+        match self.data {
+            RecipeData::Shapeless { ref group, ref ingredients, ref result } => {
+                write_String(group, w)?;
+                write_varint(&(ingredients.len() as i32), w)?;
+                for x in ingredients {
+                    write_slot_array(x, w)?;
+                }
+                write_slot(result, w)?;
+            },
+            RecipeData::Shaped { width, height, ref group, ref ingredients, ref result } => {
+                if ingredients.len() as i32 != width * height {
+                    bail!("Shaped recipe {} has {} ingredients, expected {}x{}",
+                          self.id,
+                          ingredients.len(),
+                          width,
+                          height);
+                }
+                write_varint(&width, w)?;
+                write_varint(&height, w)?;
+                write_String(group, w)?;
+                for x in ingredients {
+                    write_slot_array(x, w)?;
+                }
+                write_slot(result, w)?;
+            },
+            RecipeData::Smelting(ref x) |
+            RecipeData::Blasting(ref x) |
+            RecipeData::Smoking(ref x) |
+            RecipeData::CampfireCooking(ref x) => x.write(w)?,
+            RecipeData::Stonecutting { ref group, ref ingredient, ref result } => {
+                write_String(group, w)?;
+                write_slot_array(ingredient, w)?;
+                write_slot(result, w)?;
+            },
+            RecipeData::Special(..) => (),
+        }
+        Ok(())
+    }
+}
+
+/// Keeps track of the known recipes, and which of them the player has
+/// unlocked
+#[derive(Debug, Clone, Default)]
+pub struct RecipeBook {
+    recipes: BTreeMap<String, Recipe>,
+    unlocked: BTreeSet<String>,
+    highlighted: BTreeSet<String>,
+}
+impl RecipeBook {
+    /// Create a new, empty recipe book
+    pub fn new() -> Self {
+        RecipeBook::default()
+    }
+
+    /// Replace the known recipes with the given ones, as sent in the
+    /// DeclareRecipes packet
+    pub fn set_recipes(&mut self, recipes: Vec<Recipe>) {
+        self.recipes = recipes.into_iter().map(|x| (x.id.clone(), x)).collect();
+    }
+
+    /// Update the unlocked and highlighted recipes from an UnlockRecipes
+    /// packet
+    pub fn handle_unlock(&mut self, packet: &UnlockRecipes) -> Result<()> {
+        match *packet.get_action() {
+            0 => {
+                self.unlocked = packet.get_recipes().iter().cloned().collect();
+                self.highlighted = packet.get_recipes2().iter().cloned().collect();
+            },
+            1 => {
+                for x in packet.get_recipes() {
+                    let _ = self.unlocked.insert(x.clone());
+                    let _ = self.highlighted.insert(x.clone());
+                }
+            },
+            2 => {
+                for x in packet.get_recipes() {
+                    let _ = self.unlocked.remove(x);
+                    let _ = self.highlighted.remove(x);
+                }
+            },
+            x => bail!("UnlockRecipes has invalid action {}", x),
+        }
+        Ok(())
+    }
+
+    /// Get the recipe with the given identifier, if known
+    pub fn get_recipe(&self, id: &str) -> Option<&Recipe> {
+        self.recipes.get(id)
+    }
+
+    /// Get all the known recipes
+    pub fn get_recipes(&self) -> Vec<&Recipe> {
+        self.recipes.values().collect()
+    }
+
+    /// Get the identifiers of all the unlocked recipes
+    pub fn get_unlocked(&self) -> &BTreeSet<String> {
+        &self.unlocked
+    }
+
+    /// Get the identifiers of the unlocked recipes that are highlighted as
+    /// new in the recipe book
+    pub fn get_highlighted(&self) -> &BTreeSet<String> {
+        &self.highlighted
+    }
+
+    /// Check whether the given recipe is unlocked
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains(id)
+    }
+
+    /// Check whether the given recipe is highlighted as new
+    pub fn is_highlighted(&self, id: &str) -> bool {
+        self.highlighted.contains(id)
+    }
+
+    /// Mark the given recipe as seen, removing its highlight, returning the
+    /// RecipeBookData packet telling the server about it
+    pub fn mark_seen(&mut self, id: &str) -> ServerboundPacket {
+        let _ = self.highlighted.remove(id);
+        RecipeBookData::new_raw(Some(id.to_string()), None)
+    }
+
+    /// Create the CraftRecipeRequest packet for crafting the given recipe in
+    /// the given window, crafting as many as possible if make_all is true.
+    ///
+    /// Fails if the recipe is not unlocked.
+    pub fn new_craft_request(&self,
+                             window_id: u8,
+                             id: &str,
+                             make_all: bool)
+                             -> Result<ServerboundPacket> {
+        if !self.is_unlocked(id) {
+            bail!("Recipe {} is not unlocked", id);
+        }
+        Ok(CraftRecipeRequest::new(window_id, id.to_string(), make_all))
+    }
+}
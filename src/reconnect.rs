@@ -0,0 +1,435 @@
+//! A Client wrapper that survives disconnects
+//!
+//! ReconnectingClient owns a Client, and when the connection is lost, be it
+//! through a PlayDisconnect, a read timeout or any other error, it drops the
+//! connection and tries to log in again after an exponentially increasing
+//! delay. For authenticated sessions the access token is refreshed before
+//! every reconnect.
+//!
+//! Everything that happens to the connection is reported as SessionEvents,
+//! interleaved with the received packets, so consumers can rebuild any state
+//! (e.g. a World) when they see Connected.
+//!
+//! Note that logging in is blocking, so read() may block for the duration of
+//! a login attempt.
+use client::Client;
+use clientbound::ClientboundPacket;
+use errors::Result;
+use json::AuthenticationResponse;
+use mojang;
+use serverbound::{ClientStatus, ServerboundPacket};
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::mem;
+use std::time::{Duration, Instant};
+
+/// Exponential backoff for reconnect attempts
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    /// The delay before the first reconnect attempt
+    pub initial: Duration,
+    /// The maximum delay between attempts
+    pub max: Duration,
+    /// What the delay is multiplied by after every failed attempt
+    pub multiplier: f64,
+    /// How many consecutive failed attempts before giving up, None for
+    /// retrying forever
+    pub max_attempts: Option<u32>,
+    attempts: u32,
+}
+impl Backoff {
+    /// Create a new Backoff with the given initial and max delays, doubling
+    /// the delay after every attempt and never giving up
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Backoff {
+            initial,
+            max,
+            multiplier: 2.0,
+            max_attempts: None,
+            attempts: 0,
+        }
+    }
+
+    /// Get the delay before the next attempt, counting it as an attempt, or
+    /// None if max_attempts has been reached
+    pub fn next_delay(&mut self) -> Option<Duration> {
+        if let Some(max_attempts) = self.max_attempts {
+            if self.attempts >= max_attempts {
+                return None;
+            }
+        }
+        let delay = self.initial.as_secs_f64() *
+                    self.multiplier.powi(self.attempts as i32);
+        self.attempts += 1;
+        Some(Duration::from_secs_f64(delay.min(self.max.as_secs_f64())))
+    }
+
+    /// Get the amount of attempts since the last reset
+    pub fn get_attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Reset the delay back to the initial delay, done when a connection
+    /// succeeds
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+    }
+}
+impl Default for Backoff {
+    /// 1 second initial delay, doubling up to 5 minutes
+    fn default() -> Self {
+        Backoff::new(Duration::from_secs(1), Duration::from_secs(300))
+    }
+}
+
+/// How to log in to the server
+#[derive(Debug, Clone)]
+pub enum Credentials {
+    /// Log in unauthenticated with the given username, see
+    /// Client::connect_unauthenticated
+    Offline(String),
+    /// Log in with the given Mojang authentication, see
+    /// Client::connect_authenticated
+    Online(AuthenticationResponse),
+}
+
+/// Something that happened to a ReconnectingClient's session
+#[derive(Debug)]
+pub enum SessionEvent {
+    /// Logged in to the server. Any state from a previous connection should
+    /// be discarded.
+    Connected,
+    /// A packet read from the server
+    Packet(ClientboundPacket),
+    /// The connection was lost, with the disconnect reason or error
+    Disconnected(String),
+    /// A login attempt failed with the given error
+    ConnectFailed(String),
+    /// The next login attempt will be made after the given delay
+    Reconnecting(Duration),
+    /// The player died and a respawn was requested
+    Respawned,
+    /// The maximum amount of attempts was reached, no further attempts will
+    /// be made
+    GaveUp,
+}
+
+/// The default time without receiving anything after which the connection
+/// is considered lost, the same as vanilla
+const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Refreshes the authentication before a reconnect
+type Refresher =
+    Box<dyn FnMut(&AuthenticationResponse) -> Result<AuthenticationResponse> + Send>;
+/// Sets up a newly connected Client
+type Setup = Box<dyn FnMut(&mut Client) -> Result<()> + Send>;
+
+/// A Client that reconnects when the connection is lost, see the module
+/// documentation
+pub struct ReconnectingClient {
+    host: String,
+    port: u16,
+    credentials: Credentials,
+    refresher: Refresher,
+    setup: Option<Setup>,
+    client: Option<Client>,
+    backoff: Backoff,
+    /* When to make the next login attempt, None if connected or closed */
+    next_attempt: Option<Instant>,
+    read_timeout: Option<Duration>,
+    /* When a packet was last received, or when we connected */
+    last_read: Instant,
+    closed: bool,
+    auto_respawn: bool,
+    /* Events from send() and write() not yet returned by read() */
+    pending: Vec<SessionEvent>,
+}
+impl fmt::Debug for ReconnectingClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ReconnectingClient")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("connected", &self.client.is_some())
+            .field("backoff", &self.backoff)
+            .field("closed", &self.closed)
+            .field("auto_respawn", &self.auto_respawn)
+            .finish()
+    }
+}
+impl ReconnectingClient {
+    /// Create a new ReconnectingClient for the given server. The first login
+    /// attempt is made on the first call to read().
+    pub fn new(host: &str, port: u16, credentials: Credentials) -> Self {
+        ReconnectingClient {
+            host: host.to_string(),
+            port,
+            credentials,
+            refresher: Box::new(default_refresh),
+            setup: None,
+            client: None,
+            backoff: Backoff::default(),
+            next_attempt: Some(Instant::now()),
+            read_timeout: Some(DEFAULT_READ_TIMEOUT),
+            last_read: Instant::now(),
+            closed: false,
+            auto_respawn: false,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Set the backoff used between login attempts
+    pub fn set_backoff(&mut self, backoff: Backoff) {
+        self.backoff = backoff;
+    }
+
+    /// Set how long the server may not send anything before the connection
+    /// is considered lost, None for no timeout. Default 30 seconds.
+    pub fn set_read_timeout(&mut self, timeout: Option<Duration>) {
+        self.read_timeout = timeout;
+    }
+
+    /// Whether to automatically request a respawn with ClientStatus when an
+    /// UpdateHealth with health 0 is received. Default false.
+    pub fn set_auto_respawn(&mut self, new: bool) {
+        self.auto_respawn = new;
+    }
+
+    /// Set the function used to refresh the authentication before every
+    /// reconnect of an Online session.
+    ///
+    /// By default the access token is validated with Mojang, and refreshed if
+    /// it is no longer valid.
+    pub fn set_refresher<F>(&mut self, refresher: F)
+        where F: FnMut(&AuthenticationResponse) -> Result<AuthenticationResponse> + Send + 'static
+    {
+        self.refresher = Box::new(refresher);
+    }
+
+    /// Set a function to be called with every newly connected Client, e.g.
+    /// to register plugin channels or send the client brand. If it fails the
+    /// login attempt counts as failed.
+    pub fn set_setup<F>(&mut self, setup: F)
+        where F: FnMut(&mut Client) -> Result<()> + Send + 'static
+    {
+        self.setup = Some(Box::new(setup));
+    }
+
+    /// Get the credentials used for logging in, which are updated when the
+    /// authentication is refreshed
+    pub fn get_credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+
+    /// Get the current connection, if connected
+    pub fn get_client(&mut self) -> Option<&mut Client> {
+        self.client.as_mut()
+    }
+
+    /// Whether there currently is a connection to the server
+    pub fn is_connected(&self) -> bool {
+        self.client.is_some()
+    }
+
+    /// Whether the session has been closed, either with close() or by giving
+    /// up after too many failed attempts
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Read any new packets, and (re)connect if it is time to.
+    ///
+    /// This function is nonblocking, except while logging in.
+    pub fn read(&mut self) -> Result<Vec<SessionEvent>> {
+        let mut ret = mem::take(&mut self.pending);
+        if self.closed {
+            return Ok(ret);
+        }
+
+        if self.client.is_none() {
+            match self.next_attempt {
+                Some(ref x) if *x <= Instant::now() => (),
+                _ => return Ok(ret),
+            }
+            match self.connect() {
+                Ok(client) => {
+                    self.client = Some(client);
+                    self.next_attempt = None;
+                    self.last_read = Instant::now();
+                    self.backoff.reset();
+                    ret.push(SessionEvent::Connected);
+                },
+                Err(e) => {
+                    ret.push(SessionEvent::ConnectFailed(e.to_string()));
+                    self.schedule(&mut ret);
+                    return Ok(ret);
+                },
+            }
+        }
+
+        let packets = match self.client.as_mut().map(|x| x.read()) {
+            Some(Ok(packets)) => packets,
+            Some(Err(e)) => {
+                self.disconnected(e.to_string(), &mut ret);
+                return Ok(ret);
+            },
+            None => return Ok(ret),
+        };
+
+        if !packets.is_empty() {
+            self.last_read = Instant::now();
+        } else if let Some(timeout) = self.read_timeout {
+            if self.last_read.elapsed() >= timeout {
+                self.disconnected("Timed out".to_string(), &mut ret);
+                return Ok(ret);
+            }
+        }
+
+        for packet in packets {
+            let mut disconnect = None;
+            let mut respawn = false;
+            match packet {
+                ClientboundPacket::PlayDisconnect(ref p) => {
+                    disconnect = Some(p.get_reason().clone());
+                },
+                ClientboundPacket::UpdateHealth(ref p) => {
+                    respawn = self.auto_respawn && *p.get_health() <= 0.0;
+                },
+                _ => (),
+            }
+            ret.push(SessionEvent::Packet(packet));
+            if let Some(reason) = disconnect {
+                self.disconnected(reason, &mut ret);
+                break;
+            }
+            if respawn {
+                /* Action 0 is perform respawn. Not sent with self.send, as that
+                 * would report the disconnect on a failure as well */
+                let res = match self.client {
+                    Some(ref mut client) => client.send(ClientStatus::new(0)),
+                    None => break,
+                };
+                match res {
+                    Ok(_) => ret.push(SessionEvent::Respawned),
+                    Err(e) => {
+                        self.disconnected(e.to_string(), &mut ret);
+                        break;
+                    },
+                }
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Send the given packet, see Client::send
+    ///
+    /// If sending fails, the connection is dropped and a reconnect scheduled,
+    /// which will be reported by the next call to read().
+    pub fn send<T: Borrow<ServerboundPacket>>(&mut self, packet: T) -> Result<usize> {
+        let res = match self.client {
+            Some(ref mut client) => client.send(packet),
+            None => bail!("ReconnectingClient is not connected"),
+        };
+        if let Err(ref e) = res {
+            self.drop_client(e.to_string());
+        }
+        res
+    }
+
+    /// Write from the outgoing buffer, see Client::write
+    pub fn write(&mut self) -> Result<usize> {
+        let res = match self.client {
+            Some(ref mut client) => client.write(),
+            None => bail!("ReconnectingClient is not connected"),
+        };
+        if let Err(ref e) = res {
+            self.drop_client(e.to_string());
+        }
+        res
+    }
+
+    /// Close the connection, and stop reconnecting
+    pub fn close(&mut self) -> Result<()> {
+        self.closed = true;
+        self.next_attempt = None;
+        match self.client.take() {
+            Some(mut client) => client.close(),
+            None => Ok(()),
+        }
+    }
+
+    /// Drop the connection after an error outside of read(), the events are
+    /// returned by the next call to read()
+    fn drop_client(&mut self, reason: String) {
+        if self.client.is_some() {
+            let mut events = mem::take(&mut self.pending);
+            self.disconnected(reason, &mut events);
+            self.pending = events;
+        }
+    }
+
+    /// Handle losing the connection
+    fn disconnected(&mut self, reason: String, events: &mut Vec<SessionEvent>) {
+        if let Some(mut client) = self.client.take() {
+            let _ = client.close();
+        }
+        events.push(SessionEvent::Disconnected(reason));
+        self.schedule(events);
+    }
+
+    /// Schedule the next login attempt, or give up
+    fn schedule(&mut self, events: &mut Vec<SessionEvent>) {
+        match self.backoff.next_delay() {
+            Some(delay) => {
+                self.next_attempt = Some(Instant::now() + delay);
+                events.push(SessionEvent::Reconnecting(delay));
+            },
+            None => {
+                self.closed = true;
+                self.next_attempt = None;
+                events.push(SessionEvent::GaveUp);
+            },
+        }
+    }
+
+    /// Make a single login attempt
+    fn connect(&mut self) -> Result<Client> {
+        let mut client = match self.credentials {
+            Credentials::Offline(ref username) => {
+                Client::connect_unauthenticated(&self.host, self.port, username)?
+            },
+            Credentials::Online(ref mut auth) => {
+                /* Every attempt but the first follows a lost connection or
+                 * a failed attempt, either of which may be due to the
+                 * access token having expired */
+                if self.backoff.get_attempts() > 0 {
+                    *auth = (self.refresher)(auth)?;
+                }
+                Client::connect_authenticated(&self.host, self.port, auth)?
+            },
+        };
+        if let Some(ref mut setup) = self.setup {
+            setup(&mut client)?;
+        }
+        Ok(client)
+    }
+}
+
+/// Validate the access token, refreshing it if it is no longer valid
+fn default_refresh(auth: &AuthenticationResponse) -> Result<AuthenticationResponse> {
+    let validate = mojang::AuthenticateValidate::new(auth.accessToken.clone(),
+                                                     auth.clientToken.clone());
+    if validate.perform().is_ok() {
+        return Ok(auth.clone());
+    }
+    match auth.clientToken {
+        Some(ref client_token) => {
+            mojang::AuthenticateRefresh::new(auth.accessToken.clone(),
+                                             client_token.clone(),
+                                             false)
+                    .perform()
+        },
+        None => bail!("Access token is invalid and there is no client token to refresh it with"),
+    }
+}
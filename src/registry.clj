@@ -0,0 +1,67 @@
+#!/usr/bin/env clojure
+(ns registry
+  (:use [clojure.java.io]))
+(load-file "registry_definitions.clj")
+
+;;;; What this file does is given the registries of protocol 578, create
+;;;; .registry-578.generated.rs, which contains them as constants for
+;;;; Registry::v578 to build a Registry from.
+;;;;
+;;;; The block states aren't written out one by one, there are over 11000 of
+;;;; them. Instead each block is written with its properties, and
+;;;; Registry::v578 assigns the state ids the same way the game does.
+
+(defn long-str [& x] (clojure.string/join "\n" x))
+
+(def registry-file "./.registry-578.generated.rs")
+(def warning (long-str
+               "/* This file is automatically generated by registry.clj"
+               "Do not manually edit this file, if you wish to make"
+               "changes here, then edit and rerun registry.clj */\n\n"))
+
+(defn quote-str [s] (str "\"" s "\""))
+(defn namespaced [s] (quote-str (str "minecraft:" s)))
+
+;; Given a block, provide it as a (name, properties, default values) tuple,
+;; with the properties sorted by name like the game does
+(defn block-definition [[name properties defaults]]
+  (let [properties (sort-by first properties)]
+    (format "    (%s, &[%s], &[%s]),\n"
+            (namespaced name)
+            (clojure.string/join
+              ", "
+              (for [[property values] properties]
+                (format "(%s, &[%s])"
+                        (quote-str property)
+                        (clojure.string/join ", " (map quote-str values)))))
+            (clojure.string/join
+              ", "
+              (for [[property values] properties]
+                (quote-str (get defaults property (first values))))))))
+
+;; Given a name, a doc comment and some names in id order, provide the
+;; constant holding them
+(defn names-definition [name doc names]
+  (str (format "/// %s\nconst %s: &[&str] = &[\n" doc name)
+       (apply str
+              (for [n names]
+                (format "    %s,\n" (namespaced n))))
+       "];\n\n"))
+
+(spit registry-file
+      (str warning
+           "/// The blocks in id order, as (name, properties, default values)\n"
+           "const BLOCKS_578: &[BlockDefinition] = &[\n"
+           (apply str (map block-definition blocks))
+           "];\n\n"
+           (names-definition "ITEMS_578" "The items in id order" items)
+           (names-definition "ENTITIES_578" "The entity types in id order" entities)
+           (names-definition "PARTICLES_578" "The particle types in id order" particles)
+           (names-definition "SOUNDS_578" "The sound events in id order" sounds)
+           (names-definition "ENCHANTMENTS_578" "The enchantments in id order" enchantments)
+           "/// The biomes, as (id, name)\n"
+           "const BIOMES_578: &[(i32, &str)] = &[\n"
+           (apply str
+                  (for [[id n] biomes]
+                    (format "    (%d, %s),\n" id (namespaced n))))
+           "];\n"))
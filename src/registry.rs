@@ -0,0 +1,357 @@
+//! Lookup tables between numeric ids and namespaced names
+//!
+//! The protocol refers to block states, items, entity types, particles,
+//! sounds, biomes and enchantments by numeric ids, which change between
+//! Minecraft versions. `Registry` maps these ids to their names (and block
+//! state properties) and back.
+//!
+//! `Registry::v578` gives the tables of the supported version, which are
+//! generated from registry_definitions.clj by registry.clj. They can also be
+//! built from the reports written by the vanilla server's data generator,
+//! which for the supported version can be created with
+//!
+//! ```text
+//! java -cp minecraft_server.1.15.2.jar net.minecraft.data.Main --reports
+//! ```
+//!
+//! and are then found in `generated/reports/blocks.json` and
+//! `generated/reports/registries.json`. Using the reports of the server a
+//! client connects to also gives the correct ids for modded servers.
+//!
+//! Block states are written like in commands, e.g.
+//! `minecraft:oak_stairs[facing=north,half=top]`. Properties are always
+//! listed in alphabetical order.
+
+use errors::{Result, ResultExt};
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// A block of the generated tables, as (name, properties, default values)
+type BlockDefinition = (&'static str,
+                        &'static [(&'static str, &'static [&'static str])],
+                        &'static [&'static str]);
+
+/* See registry.clj for information about this include */
+include!("./.registry-578.generated.rs");
+
+/// The shape of each block in blocks.json
+#[derive(Deserialize)]
+struct BlockReport {
+    states: Vec<BlockStateReport>,
+}
+
+/// The shape of each state in blocks.json
+#[derive(Deserialize)]
+struct BlockStateReport {
+    id: i32,
+    #[serde(default)]
+    default: bool,
+    #[serde(default)]
+    properties: BTreeMap<String, String>,
+}
+
+/// The shape of each registry in registries.json
+#[derive(Deserialize)]
+struct RegistryReport {
+    entries: BTreeMap<String, RegistryEntryReport>,
+}
+
+/// The shape of each entry of a registry in registries.json
+#[derive(Deserialize)]
+struct RegistryEntryReport {
+    protocol_id: i32,
+}
+
+/// A single block state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockState {
+    /// The block state id, as used in chunk data and BlockChange
+    pub id: i32,
+    /// The name of the block, e.g. `minecraft:oak_stairs`
+    pub name: String,
+    /// The values of the block's properties in this state
+    pub properties: BTreeMap<String, String>,
+    /// Whether this is the block's default state
+    pub default: bool,
+}
+impl BlockState {
+    /// Get the value of the given property, if the block has it
+    pub fn get_property(&self, property: &str) -> Option<&str> {
+        self.properties.get(property).map(|x| x.as_str())
+    }
+}
+impl fmt::Display for BlockState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.properties.is_empty() {
+            let properties: Vec<String> = self.properties
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            write!(f, "[{}]", properties.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a block state string such as `minecraft:oak_stairs[facing=north]`
+/// into the block name and properties. A missing namespace is taken to be
+/// `minecraft`.
+pub fn parse_block_state(val: &str) -> Result<(String, BTreeMap<String, String>)> {
+    let (name, properties) = match val.find('[') {
+        Some(i) => {
+            if !val.ends_with(']') {
+                bail!("Block state {} is missing the closing ]", val);
+            }
+            (&val[..i], &val[i + 1..val.len() - 1])
+        },
+        None => (val, ""),
+    };
+    let name = if name.contains(':') {
+        name.to_string()
+    } else {
+        format!("minecraft:{}", name)
+    };
+
+    let mut ret = BTreeMap::new();
+    for property in properties.split(',').filter(|x| !x.is_empty()) {
+        let mut split = property.splitn(2, '=');
+        match (split.next(), split.next()) {
+            (Some(k), Some(v)) => {
+                let _ = ret.insert(k.trim().to_string(), v.trim().to_string());
+            },
+            _ => bail!("Block state {} has invalid property {}", val, property),
+        }
+    }
+    Ok((name, ret))
+}
+
+/// A simple mapping between numeric ids and names
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdRegistry {
+    names: BTreeMap<i32, String>,
+    ids: BTreeMap<String, i32>,
+}
+impl IdRegistry {
+    /// Create a new, empty registry
+    pub fn new() -> Self {
+        IdRegistry::default()
+    }
+
+    /// Add a mapping between the given id and name
+    pub fn insert(&mut self, id: i32, name: &str) {
+        let _ = self.names.insert(id, name.to_string());
+        let _ = self.ids.insert(name.to_string(), id);
+    }
+
+    /// Get the name of the given id
+    pub fn get_name(&self, id: i32) -> Option<&str> {
+        self.names.get(&id).map(|x| x.as_str())
+    }
+
+    /// Get the id of the given name. A missing namespace is taken to be
+    /// `minecraft`.
+    pub fn get_id(&self, name: &str) -> Option<i32> {
+        match self.ids.get(name) {
+            Some(x) => Some(*x),
+            None if !name.contains(':') => self.ids.get(&format!("minecraft:{}", name)).cloned(),
+            None => None,
+        }
+    }
+
+    /// Get the number of entries
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Check whether the registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Iterate over the entries in order of id
+    pub fn iter(&self) -> impl Iterator<Item = (i32, &str)> {
+        self.names.iter().map(|(&id, name)| (id, name.as_str()))
+    }
+}
+
+/// All the registries used by the protocol
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    block_states: BTreeMap<i32, BlockState>,
+    block_state_ids: BTreeMap<String, i32>,
+    default_states: BTreeMap<String, i32>,
+    /// The block ids, as used in block tags
+    pub blocks: IdRegistry,
+    /// The item ids, as used in slots
+    pub items: IdRegistry,
+    /// The entity type ids, as used in SpawnMob and SpawnObject
+    pub entities: IdRegistry,
+    /// The particle ids, as used in Particle
+    pub particles: IdRegistry,
+    /// The sound ids, as used in SoundEffect
+    pub sounds: IdRegistry,
+    /// The biome ids, as used in chunk data
+    pub biomes: IdRegistry,
+    /// The enchantment ids, as used in the enchanting table window
+    pub enchantments: IdRegistry,
+}
+impl Registry {
+    /// Create a new, empty registry
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Build the registry of protocol 578, i.e. Minecraft 1.15.2. Servers
+    /// with mods that add blocks or items need `from_reports` instead.
+    pub fn v578() -> Self {
+        let mut ret = Registry::new();
+
+        let mut id = 0;
+        for (block_id, &(name, properties, default)) in BLOCKS_578.iter().enumerate() {
+            ret.blocks.insert(block_id as i32, name);
+            // Like in the game, a block has a state for every combination of
+            // its property values, with the last property changing fastest
+            let count: usize = properties.iter().map(|&(_, x)| x.len()).product();
+            for i in 0..count {
+                let mut values = vec![""; properties.len()];
+                let mut rest = i;
+                for (j, &(_, x)) in properties.iter().enumerate().rev() {
+                    values[j] = x[rest % x.len()];
+                    rest /= x.len();
+                }
+                ret.insert_block_state(BlockState {
+                    id,
+                    name: name.to_string(),
+                    properties: properties.iter()
+                        .zip(&values)
+                        .map(|(&(k, _), v)| (k.to_string(), v.to_string()))
+                        .collect(),
+                    default: values[..] == *default,
+                });
+                id += 1;
+            }
+        }
+
+        insert_names(&mut ret.items, ITEMS_578);
+        insert_names(&mut ret.entities, ENTITIES_578);
+        insert_names(&mut ret.particles, PARTICLES_578);
+        insert_names(&mut ret.sounds, SOUNDS_578);
+        insert_names(&mut ret.enchantments, ENCHANTMENTS_578);
+        for &(id, name) in BIOMES_578 {
+            ret.biomes.insert(id, name);
+        }
+        ret
+    }
+
+    /// Build the registry from the contents of the data generator's
+    /// blocks.json and registries.json reports
+    pub fn from_reports(blocks_json: &str, registries_json: &str) -> Result<Self> {
+        let mut ret = Registry::new();
+
+        let blocks: BTreeMap<String, BlockReport> = ::serde_json::from_str(blocks_json)
+            .chain_err(|| "while parsing blocks.json")?;
+        for (name, block) in blocks {
+            for state in block.states {
+                ret.insert_block_state(BlockState {
+                    id: state.id,
+                    name: name.clone(),
+                    properties: state.properties,
+                    default: state.default,
+                });
+            }
+        }
+
+        let registries: BTreeMap<String, RegistryReport> = ::serde_json::from_str(registries_json)
+            .chain_err(|| "while parsing registries.json")?;
+        for (name, registry) in registries {
+            let target = match name.as_str() {
+                "minecraft:block" => &mut ret.blocks,
+                "minecraft:item" => &mut ret.items,
+                "minecraft:entity_type" => &mut ret.entities,
+                "minecraft:particle_type" => &mut ret.particles,
+                "minecraft:sound_event" => &mut ret.sounds,
+                "minecraft:biome" => &mut ret.biomes,
+                "minecraft:enchantment" => &mut ret.enchantments,
+                _ => continue,
+            };
+            for (entry, x) in registry.entries {
+                target.insert(x.protocol_id, &entry);
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Build the registry from the blocks.json and registries.json files in
+    /// the given directory, e.g. `generated/reports`
+    pub fn from_report_dir<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let read = |file: &str| -> Result<String> {
+            let path = dir.as_ref().join(file);
+            let mut ret = String::new();
+            let _: usize = File::open(&path)
+                .and_then(|mut x| x.read_to_string(&mut ret))
+                .chain_err(|| format!("while reading {}", path.display()))?;
+            Ok(ret)
+        };
+        Registry::from_reports(&read("blocks.json")?, &read("registries.json")?)
+    }
+
+    /// Add a block state
+    pub fn insert_block_state(&mut self, state: BlockState) {
+        let _ = self.block_state_ids.insert(state.to_string(), state.id);
+        if state.default {
+            let _ = self.default_states.insert(state.name.clone(), state.id);
+        }
+        let _ = self.block_states.insert(state.id, state);
+    }
+
+    /// Get the block state with the given id
+    pub fn get_block_state(&self, id: i32) -> Option<&BlockState> {
+        self.block_states.get(&id)
+    }
+
+    /// Get the number of known block states
+    pub fn get_block_state_count(&self) -> usize {
+        self.block_states.len()
+    }
+
+    /// Get the id of the default state of the given block
+    pub fn get_default_state_id(&self, block: &str) -> Option<i32> {
+        let (name, _) = parse_block_state(block).ok()?;
+        self.default_states.get(&name).cloned()
+    }
+
+    /// Get the id of the given block state, e.g.
+    /// `minecraft:oak_stairs[facing=north,half=top]`. Properties that are
+    /// not given take their value from the block's default state.
+    pub fn get_block_state_id(&self, val: &str) -> Option<i32> {
+        let (name, properties) = parse_block_state(val).ok()?;
+        let default = self.block_states.get(self.default_states.get(&name)?)?;
+        let mut full = default.clone();
+        for (k, v) in properties {
+            match full.properties.get_mut(&k) {
+                Some(x) => *x = v,
+                None => return None,
+            }
+        }
+        self.block_state_ids.get(&full.to_string()).cloned()
+    }
+
+    /// Get the block id of the given block state id, i.e. the id used in
+    /// block tags
+    pub fn get_block_id(&self, state_id: i32) -> Option<i32> {
+        let state = self.block_states.get(&state_id)?;
+        self.blocks.get_id(&state.name)
+    }
+}
+
+/// Add the given names to the registry, with the index as the id
+fn insert_names(registry: &mut IdRegistry, names: &[&str]) {
+    for (id, name) in names.iter().enumerate() {
+        registry.insert(id as i32, name);
+    }
+}
@@ -0,0 +1,2771 @@
+;;;;;; This file contains only the definitions for the registries of protocol
+;;;;;; 578 (Minecraft 1.15.2), see registry.clj.
+
+(defn int-property [name from to]
+  [name (mapv str (range from (inc to)))])
+
+(defn bool-property [name]
+  [name ["true" "false"]])
+
+;; Block state properties, as [name values] with the values in id order
+(def axis ["axis" ["x" "y" "z"]])
+(def horizontal-axis ["axis" ["x" "z"]])
+(def facing ["facing" ["north" "east" "south" "west" "up" "down"]])
+(def horizontal-facing ["facing" ["north" "south" "west" "east"]])
+(def hopper-facing ["facing" ["down" "north" "south" "west" "east"]])
+(def half ["half" ["top" "bottom"]])
+(def double-block-half ["half" ["upper" "lower"]])
+(def face ["face" ["floor" "wall" "ceiling"]])
+(def stairs-shape ["shape" ["straight" "inner_left" "inner_right" "outer_left" "outer_right"]])
+(def straight-rail-shape ["shape" ["north_south" "east_west" "ascending_east" "ascending_west" "ascending_north" "ascending_south"]])
+(def rail-shape ["shape" ["north_south" "east_west" "ascending_east" "ascending_west" "ascending_north" "ascending_south" "south_east" "south_west" "north_west" "north_east"]])
+(def slab-type ["type" ["top" "bottom" "double"]])
+(def chest-type ["type" ["single" "left" "right"]])
+(def piston-type ["type" ["normal" "sticky"]])
+(def bed-part ["part" ["head" "foot"]])
+(def hinge ["hinge" ["left" "right"]])
+(def instrument ["instrument" ["harp" "basedrum" "snare" "hat" "bass" "flute" "bell" "guitar" "chime" "xylophone" "iron_xylophone" "cow_bell" "didgeridoo" "bit" "banjo" "pling"]])
+(def wire-east ["east" ["up" "side" "none"]])
+(def wire-north ["north" ["up" "side" "none"]])
+(def wire-south ["south" ["up" "side" "none"]])
+(def wire-west ["west" ["up" "side" "none"]])
+(def comparator-mode ["mode" ["compare" "subtract"]])
+(def structure-mode ["mode" ["save" "load" "corner" "data"]])
+(def bell-attachment ["attachment" ["floor" "ceiling" "single_wall" "double_wall"]])
+(def bamboo-leaves ["leaves" ["none" "small" "large"]])
+(def age-2 (int-property "age" 0 2))
+(def age-3 (int-property "age" 0 3))
+(def age-5 (int-property "age" 0 5))
+(def age-7 (int-property "age" 0 7))
+(def age-15 (int-property "age" 0 15))
+(def kelp-age (int-property "age" 0 25))
+(def bamboo-age (int-property "age" 0 1))
+(def sapling-stage (int-property "stage" 0 1))
+(def fluid-level (int-property "level" 0 15))
+(def cauldron-level (int-property "level" 0 3))
+(def composter-level (int-property "level" 0 8))
+(def honey-level (int-property "honey_level" 0 5))
+(def leaves-distance (int-property "distance" 1 7))
+(def scaffolding-distance (int-property "distance" 0 7))
+(def note (int-property "note" 0 24))
+(def power (int-property "power" 0 15))
+(def rotation (int-property "rotation" 0 15))
+(def moisture (int-property "moisture" 0 7))
+(def layers (int-property "layers" 1 8))
+(def bites (int-property "bites" 0 6))
+(def repeater-delay (int-property "delay" 1 4))
+(def eggs (int-property "eggs" 1 4))
+(def hatch (int-property "hatch" 0 2))
+(def pickles (int-property "pickles" 1 4))
+(def attached (bool-property "attached"))
+(def bottom (bool-property "bottom"))
+(def conditional (bool-property "conditional"))
+(def disarmed (bool-property "disarmed"))
+(def drag (bool-property "drag"))
+(def down (bool-property "down"))
+(def east (bool-property "east"))
+(def enabled (bool-property "enabled"))
+(def extended (bool-property "extended"))
+(def eye (bool-property "eye"))
+(def hanging (bool-property "hanging"))
+(def has-book (bool-property "has_book"))
+(def has-bottle-0 (bool-property "has_bottle_0"))
+(def has-bottle-1 (bool-property "has_bottle_1"))
+(def has-bottle-2 (bool-property "has_bottle_2"))
+(def has-record (bool-property "has_record"))
+(def in-wall (bool-property "in_wall"))
+(def inverted (bool-property "inverted"))
+(def lit (bool-property "lit"))
+(def locked (bool-property "locked"))
+(def north (bool-property "north"))
+(def occupied (bool-property "occupied"))
+(def open (bool-property "open"))
+(def persistent (bool-property "persistent"))
+(def powered (bool-property "powered"))
+(def short (bool-property "short"))
+(def signal-fire (bool-property "signal_fire"))
+(def snowy (bool-property "snowy"))
+(def south (bool-property "south"))
+(def triggered (bool-property "triggered"))
+(def unstable (bool-property "unstable"))
+(def up (bool-property "up"))
+(def waterlogged (bool-property "waterlogged"))
+(def west (bool-property "west"))
+
+;; The blocks in id order, as [name properties defaults]. A block has a state
+;; for every combination of its properties' values, and defaults gives the
+;; values of its default state that aren't the property's first value.
+(def blocks
+  [["air" [] {}]
+   ["stone" [] {}]
+   ["granite" [] {}]
+   ["polished_granite" [] {}]
+   ["diorite" [] {}]
+   ["polished_diorite" [] {}]
+   ["andesite" [] {}]
+   ["polished_andesite" [] {}]
+   ["grass_block" [snowy] {"snowy" "false"}]
+   ["dirt" [] {}]
+   ["coarse_dirt" [] {}]
+   ["podzol" [snowy] {"snowy" "false"}]
+   ["cobblestone" [] {}]
+   ["oak_planks" [] {}]
+   ["spruce_planks" [] {}]
+   ["birch_planks" [] {}]
+   ["jungle_planks" [] {}]
+   ["acacia_planks" [] {}]
+   ["dark_oak_planks" [] {}]
+   ["oak_sapling" [sapling-stage] {}]
+   ["spruce_sapling" [sapling-stage] {}]
+   ["birch_sapling" [sapling-stage] {}]
+   ["jungle_sapling" [sapling-stage] {}]
+   ["acacia_sapling" [sapling-stage] {}]
+   ["dark_oak_sapling" [sapling-stage] {}]
+   ["bedrock" [] {}]
+   ["water" [fluid-level] {}]
+   ["lava" [fluid-level] {}]
+   ["sand" [] {}]
+   ["red_sand" [] {}]
+   ["gravel" [] {}]
+   ["gold_ore" [] {}]
+   ["iron_ore" [] {}]
+   ["coal_ore" [] {}]
+   ["oak_log" [axis] {"axis" "y"}]
+   ["spruce_log" [axis] {"axis" "y"}]
+   ["birch_log" [axis] {"axis" "y"}]
+   ["jungle_log" [axis] {"axis" "y"}]
+   ["acacia_log" [axis] {"axis" "y"}]
+   ["dark_oak_log" [axis] {"axis" "y"}]
+   ["stripped_spruce_log" [axis] {"axis" "y"}]
+   ["stripped_birch_log" [axis] {"axis" "y"}]
+   ["stripped_jungle_log" [axis] {"axis" "y"}]
+   ["stripped_acacia_log" [axis] {"axis" "y"}]
+   ["stripped_dark_oak_log" [axis] {"axis" "y"}]
+   ["stripped_oak_log" [axis] {"axis" "y"}]
+   ["oak_wood" [axis] {"axis" "y"}]
+   ["spruce_wood" [axis] {"axis" "y"}]
+   ["birch_wood" [axis] {"axis" "y"}]
+   ["jungle_wood" [axis] {"axis" "y"}]
+   ["acacia_wood" [axis] {"axis" "y"}]
+   ["dark_oak_wood" [axis] {"axis" "y"}]
+   ["stripped_oak_wood" [axis] {"axis" "y"}]
+   ["stripped_spruce_wood" [axis] {"axis" "y"}]
+   ["stripped_birch_wood" [axis] {"axis" "y"}]
+   ["stripped_jungle_wood" [axis] {"axis" "y"}]
+   ["stripped_acacia_wood" [axis] {"axis" "y"}]
+   ["stripped_dark_oak_wood" [axis] {"axis" "y"}]
+   ["oak_leaves" [leaves-distance persistent] {"distance" "7" "persistent" "false"}]
+   ["spruce_leaves" [leaves-distance persistent] {"distance" "7" "persistent" "false"}]
+   ["birch_leaves" [leaves-distance persistent] {"distance" "7" "persistent" "false"}]
+   ["jungle_leaves" [leaves-distance persistent] {"distance" "7" "persistent" "false"}]
+   ["acacia_leaves" [leaves-distance persistent] {"distance" "7" "persistent" "false"}]
+   ["dark_oak_leaves" [leaves-distance persistent] {"distance" "7" "persistent" "false"}]
+   ["sponge" [] {}]
+   ["wet_sponge" [] {}]
+   ["glass" [] {}]
+   ["lapis_ore" [] {}]
+   ["lapis_block" [] {}]
+   ["dispenser" [facing triggered] {"triggered" "false"}]
+   ["sandstone" [] {}]
+   ["chiseled_sandstone" [] {}]
+   ["cut_sandstone" [] {}]
+   ["note_block" [instrument note powered] {"powered" "false"}]
+   ["white_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["orange_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["magenta_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["light_blue_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["yellow_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["lime_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["pink_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["gray_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["light_gray_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["cyan_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["purple_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["blue_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["brown_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["green_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["red_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["black_bed" [horizontal-facing occupied bed-part] {"occupied" "false" "part" "foot"}]
+   ["powered_rail" [powered straight-rail-shape] {"powered" "false"}]
+   ["detector_rail" [powered straight-rail-shape] {"powered" "false"}]
+   ["sticky_piston" [extended facing] {"extended" "false"}]
+   ["cobweb" [] {}]
+   ["grass" [] {}]
+   ["fern" [] {}]
+   ["dead_bush" [] {}]
+   ["seagrass" [] {}]
+   ["tall_seagrass" [double-block-half] {"half" "lower"}]
+   ["piston" [extended facing] {"extended" "false"}]
+   ["piston_head" [facing short piston-type] {"short" "false"}]
+   ["white_wool" [] {}]
+   ["orange_wool" [] {}]
+   ["magenta_wool" [] {}]
+   ["light_blue_wool" [] {}]
+   ["yellow_wool" [] {}]
+   ["lime_wool" [] {}]
+   ["pink_wool" [] {}]
+   ["gray_wool" [] {}]
+   ["light_gray_wool" [] {}]
+   ["cyan_wool" [] {}]
+   ["purple_wool" [] {}]
+   ["blue_wool" [] {}]
+   ["brown_wool" [] {}]
+   ["green_wool" [] {}]
+   ["red_wool" [] {}]
+   ["black_wool" [] {}]
+   ["moving_piston" [facing piston-type] {}]
+   ["dandelion" [] {}]
+   ["poppy" [] {}]
+   ["blue_orchid" [] {}]
+   ["allium" [] {}]
+   ["azure_bluet" [] {}]
+   ["red_tulip" [] {}]
+   ["orange_tulip" [] {}]
+   ["white_tulip" [] {}]
+   ["pink_tulip" [] {}]
+   ["oxeye_daisy" [] {}]
+   ["cornflower" [] {}]
+   ["wither_rose" [] {}]
+   ["lily_of_the_valley" [] {}]
+   ["brown_mushroom" [] {}]
+   ["red_mushroom" [] {}]
+   ["gold_block" [] {}]
+   ["iron_block" [] {}]
+   ["bricks" [] {}]
+   ["tnt" [unstable] {"unstable" "false"}]
+   ["bookshelf" [] {}]
+   ["mossy_cobblestone" [] {}]
+   ["obsidian" [] {}]
+   ["torch" [] {}]
+   ["wall_torch" [horizontal-facing] {}]
+   ["fire" [age-15 east north south up west] {"east" "false" "north" "false" "south" "false" "up" "false" "west" "false"}]
+   ["spawner" [] {}]
+   ["oak_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["chest" [horizontal-facing chest-type waterlogged] {"waterlogged" "false"}]
+   ["redstone_wire" [wire-east wire-north power wire-south wire-west] {"east" "none" "north" "none" "south" "none" "west" "none"}]
+   ["diamond_ore" [] {}]
+   ["diamond_block" [] {}]
+   ["crafting_table" [] {}]
+   ["wheat" [age-7] {}]
+   ["farmland" [moisture] {}]
+   ["furnace" [horizontal-facing lit] {"lit" "false"}]
+   ["oak_sign" [rotation waterlogged] {"waterlogged" "false"}]
+   ["spruce_sign" [rotation waterlogged] {"waterlogged" "false"}]
+   ["birch_sign" [rotation waterlogged] {"waterlogged" "false"}]
+   ["acacia_sign" [rotation waterlogged] {"waterlogged" "false"}]
+   ["jungle_sign" [rotation waterlogged] {"waterlogged" "false"}]
+   ["dark_oak_sign" [rotation waterlogged] {"waterlogged" "false"}]
+   ["oak_door" [horizontal-facing double-block-half hinge open powered] {"half" "lower" "open" "false" "powered" "false"}]
+   ["ladder" [horizontal-facing waterlogged] {"waterlogged" "false"}]
+   ["rail" [rail-shape] {}]
+   ["cobblestone_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["oak_wall_sign" [horizontal-facing waterlogged] {"waterlogged" "false"}]
+   ["spruce_wall_sign" [horizontal-facing waterlogged] {"waterlogged" "false"}]
+   ["birch_wall_sign" [horizontal-facing waterlogged] {"waterlogged" "false"}]
+   ["acacia_wall_sign" [horizontal-facing waterlogged] {"waterlogged" "false"}]
+   ["jungle_wall_sign" [horizontal-facing waterlogged] {"waterlogged" "false"}]
+   ["dark_oak_wall_sign" [horizontal-facing waterlogged] {"waterlogged" "false"}]
+   ["lever" [face horizontal-facing powered] {"face" "wall" "powered" "false"}]
+   ["stone_pressure_plate" [powered] {"powered" "false"}]
+   ["iron_door" [horizontal-facing double-block-half hinge open powered] {"half" "lower" "open" "false" "powered" "false"}]
+   ["oak_pressure_plate" [powered] {"powered" "false"}]
+   ["spruce_pressure_plate" [powered] {"powered" "false"}]
+   ["birch_pressure_plate" [powered] {"powered" "false"}]
+   ["jungle_pressure_plate" [powered] {"powered" "false"}]
+   ["acacia_pressure_plate" [powered] {"powered" "false"}]
+   ["dark_oak_pressure_plate" [powered] {"powered" "false"}]
+   ["redstone_ore" [lit] {"lit" "false"}]
+   ["redstone_torch" [lit] {}]
+   ["redstone_wall_torch" [horizontal-facing lit] {}]
+   ["stone_button" [face horizontal-facing powered] {"face" "wall" "powered" "false"}]
+   ["snow" [layers] {}]
+   ["ice" [] {}]
+   ["snow_block" [] {}]
+   ["cactus" [age-15] {}]
+   ["clay" [] {}]
+   ["sugar_cane" [age-15] {}]
+   ["jukebox" [has-record] {"has_record" "false"}]
+   ["oak_fence" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["pumpkin" [] {}]
+   ["netherrack" [] {}]
+   ["soul_sand" [] {}]
+   ["glowstone" [] {}]
+   ["nether_portal" [horizontal-axis] {}]
+   ["carved_pumpkin" [horizontal-facing] {}]
+   ["jack_o_lantern" [horizontal-facing] {}]
+   ["cake" [bites] {}]
+   ["repeater" [repeater-delay horizontal-facing locked powered] {"locked" "false" "powered" "false"}]
+   ["white_stained_glass" [] {}]
+   ["orange_stained_glass" [] {}]
+   ["magenta_stained_glass" [] {}]
+   ["light_blue_stained_glass" [] {}]
+   ["yellow_stained_glass" [] {}]
+   ["lime_stained_glass" [] {}]
+   ["pink_stained_glass" [] {}]
+   ["gray_stained_glass" [] {}]
+   ["light_gray_stained_glass" [] {}]
+   ["cyan_stained_glass" [] {}]
+   ["purple_stained_glass" [] {}]
+   ["blue_stained_glass" [] {}]
+   ["brown_stained_glass" [] {}]
+   ["green_stained_glass" [] {}]
+   ["red_stained_glass" [] {}]
+   ["black_stained_glass" [] {}]
+   ["oak_trapdoor" [horizontal-facing half open powered waterlogged] {"half" "bottom" "open" "false" "powered" "false" "waterlogged" "false"}]
+   ["spruce_trapdoor" [horizontal-facing half open powered waterlogged] {"half" "bottom" "open" "false" "powered" "false" "waterlogged" "false"}]
+   ["birch_trapdoor" [horizontal-facing half open powered waterlogged] {"half" "bottom" "open" "false" "powered" "false" "waterlogged" "false"}]
+   ["jungle_trapdoor" [horizontal-facing half open powered waterlogged] {"half" "bottom" "open" "false" "powered" "false" "waterlogged" "false"}]
+   ["acacia_trapdoor" [horizontal-facing half open powered waterlogged] {"half" "bottom" "open" "false" "powered" "false" "waterlogged" "false"}]
+   ["dark_oak_trapdoor" [horizontal-facing half open powered waterlogged] {"half" "bottom" "open" "false" "powered" "false" "waterlogged" "false"}]
+   ["stone_bricks" [] {}]
+   ["mossy_stone_bricks" [] {}]
+   ["cracked_stone_bricks" [] {}]
+   ["chiseled_stone_bricks" [] {}]
+   ["infested_stone" [] {}]
+   ["infested_cobblestone" [] {}]
+   ["infested_stone_bricks" [] {}]
+   ["infested_mossy_stone_bricks" [] {}]
+   ["infested_cracked_stone_bricks" [] {}]
+   ["infested_chiseled_stone_bricks" [] {}]
+   ["brown_mushroom_block" [down east north south up west] {}]
+   ["red_mushroom_block" [down east north south up west] {}]
+   ["mushroom_stem" [down east north south up west] {}]
+   ["iron_bars" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["melon" [] {}]
+   ["attached_pumpkin_stem" [horizontal-facing] {}]
+   ["attached_melon_stem" [horizontal-facing] {}]
+   ["pumpkin_stem" [age-7] {}]
+   ["melon_stem" [age-7] {}]
+   ["vine" [east north south up west] {"east" "false" "north" "false" "south" "false" "up" "false" "west" "false"}]
+   ["oak_fence_gate" [horizontal-facing in-wall open powered] {"in_wall" "false" "open" "false" "powered" "false"}]
+   ["brick_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["stone_brick_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["mycelium" [snowy] {"snowy" "false"}]
+   ["lily_pad" [] {}]
+   ["nether_bricks" [] {}]
+   ["nether_brick_fence" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["nether_brick_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["nether_wart" [age-3] {}]
+   ["enchanting_table" [] {}]
+   ["brewing_stand" [has-bottle-0 has-bottle-1 has-bottle-2] {"has_bottle_0" "false" "has_bottle_1" "false" "has_bottle_2" "false"}]
+   ["cauldron" [cauldron-level] {}]
+   ["end_portal" [] {}]
+   ["end_portal_frame" [eye horizontal-facing] {"eye" "false"}]
+   ["end_stone" [] {}]
+   ["dragon_egg" [] {}]
+   ["redstone_lamp" [lit] {"lit" "false"}]
+   ["cocoa" [age-2 horizontal-facing] {}]
+   ["sandstone_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["emerald_ore" [] {}]
+   ["ender_chest" [horizontal-facing waterlogged] {"waterlogged" "false"}]
+   ["tripwire_hook" [attached horizontal-facing powered] {"attached" "false" "powered" "false"}]
+   ["tripwire" [attached disarmed east north powered south west] {"attached" "false" "disarmed" "false" "east" "false" "north" "false" "powered" "false" "south" "false" "west" "false"}]
+   ["emerald_block" [] {}]
+   ["spruce_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["birch_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["jungle_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["command_block" [conditional facing] {"conditional" "false"}]
+   ["beacon" [] {}]
+   ["cobblestone_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["mossy_cobblestone_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["flower_pot" [] {}]
+   ["potted_oak_sapling" [] {}]
+   ["potted_spruce_sapling" [] {}]
+   ["potted_birch_sapling" [] {}]
+   ["potted_jungle_sapling" [] {}]
+   ["potted_acacia_sapling" [] {}]
+   ["potted_dark_oak_sapling" [] {}]
+   ["potted_fern" [] {}]
+   ["potted_dandelion" [] {}]
+   ["potted_poppy" [] {}]
+   ["potted_blue_orchid" [] {}]
+   ["potted_allium" [] {}]
+   ["potted_azure_bluet" [] {}]
+   ["potted_red_tulip" [] {}]
+   ["potted_orange_tulip" [] {}]
+   ["potted_white_tulip" [] {}]
+   ["potted_pink_tulip" [] {}]
+   ["potted_oxeye_daisy" [] {}]
+   ["potted_cornflower" [] {}]
+   ["potted_lily_of_the_valley" [] {}]
+   ["potted_wither_rose" [] {}]
+   ["potted_red_mushroom" [] {}]
+   ["potted_brown_mushroom" [] {}]
+   ["potted_dead_bush" [] {}]
+   ["potted_cactus" [] {}]
+   ["carrots" [age-7] {}]
+   ["potatoes" [age-7] {}]
+   ["oak_button" [face horizontal-facing powered] {"face" "wall" "powered" "false"}]
+   ["spruce_button" [face horizontal-facing powered] {"face" "wall" "powered" "false"}]
+   ["birch_button" [face horizontal-facing powered] {"face" "wall" "powered" "false"}]
+   ["jungle_button" [face horizontal-facing powered] {"face" "wall" "powered" "false"}]
+   ["acacia_button" [face horizontal-facing powered] {"face" "wall" "powered" "false"}]
+   ["dark_oak_button" [face horizontal-facing powered] {"face" "wall" "powered" "false"}]
+   ["skeleton_skull" [rotation] {}]
+   ["skeleton_wall_skull" [horizontal-facing] {}]
+   ["wither_skeleton_skull" [rotation] {}]
+   ["wither_skeleton_wall_skull" [horizontal-facing] {}]
+   ["zombie_head" [rotation] {}]
+   ["zombie_wall_head" [horizontal-facing] {}]
+   ["player_head" [rotation] {}]
+   ["player_wall_head" [horizontal-facing] {}]
+   ["creeper_head" [rotation] {}]
+   ["creeper_wall_head" [horizontal-facing] {}]
+   ["dragon_head" [rotation] {}]
+   ["dragon_wall_head" [horizontal-facing] {}]
+   ["anvil" [horizontal-facing] {}]
+   ["chipped_anvil" [horizontal-facing] {}]
+   ["damaged_anvil" [horizontal-facing] {}]
+   ["trapped_chest" [horizontal-facing chest-type waterlogged] {"waterlogged" "false"}]
+   ["light_weighted_pressure_plate" [power] {}]
+   ["heavy_weighted_pressure_plate" [power] {}]
+   ["comparator" [horizontal-facing comparator-mode powered] {"powered" "false"}]
+   ["daylight_detector" [inverted power] {"inverted" "false"}]
+   ["redstone_block" [] {}]
+   ["nether_quartz_ore" [] {}]
+   ["hopper" [enabled hopper-facing] {}]
+   ["quartz_block" [] {}]
+   ["chiseled_quartz_block" [] {}]
+   ["quartz_pillar" [axis] {"axis" "y"}]
+   ["quartz_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["activator_rail" [powered straight-rail-shape] {"powered" "false"}]
+   ["dropper" [facing triggered] {"triggered" "false"}]
+   ["white_terracotta" [] {}]
+   ["orange_terracotta" [] {}]
+   ["magenta_terracotta" [] {}]
+   ["light_blue_terracotta" [] {}]
+   ["yellow_terracotta" [] {}]
+   ["lime_terracotta" [] {}]
+   ["pink_terracotta" [] {}]
+   ["gray_terracotta" [] {}]
+   ["light_gray_terracotta" [] {}]
+   ["cyan_terracotta" [] {}]
+   ["purple_terracotta" [] {}]
+   ["blue_terracotta" [] {}]
+   ["brown_terracotta" [] {}]
+   ["green_terracotta" [] {}]
+   ["red_terracotta" [] {}]
+   ["black_terracotta" [] {}]
+   ["white_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["orange_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["magenta_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["light_blue_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["yellow_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["lime_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["pink_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["gray_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["light_gray_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["cyan_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["purple_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["blue_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["brown_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["green_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["red_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["black_stained_glass_pane" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["acacia_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["dark_oak_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["slime_block" [] {}]
+   ["barrier" [] {}]
+   ["iron_trapdoor" [horizontal-facing half open powered waterlogged] {"half" "bottom" "open" "false" "powered" "false" "waterlogged" "false"}]
+   ["prismarine" [] {}]
+   ["prismarine_bricks" [] {}]
+   ["dark_prismarine" [] {}]
+   ["prismarine_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["prismarine_brick_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["dark_prismarine_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["prismarine_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["prismarine_brick_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["dark_prismarine_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["sea_lantern" [] {}]
+   ["hay_block" [axis] {"axis" "y"}]
+   ["white_carpet" [] {}]
+   ["orange_carpet" [] {}]
+   ["magenta_carpet" [] {}]
+   ["light_blue_carpet" [] {}]
+   ["yellow_carpet" [] {}]
+   ["lime_carpet" [] {}]
+   ["pink_carpet" [] {}]
+   ["gray_carpet" [] {}]
+   ["light_gray_carpet" [] {}]
+   ["cyan_carpet" [] {}]
+   ["purple_carpet" [] {}]
+   ["blue_carpet" [] {}]
+   ["brown_carpet" [] {}]
+   ["green_carpet" [] {}]
+   ["red_carpet" [] {}]
+   ["black_carpet" [] {}]
+   ["terracotta" [] {}]
+   ["coal_block" [] {}]
+   ["packed_ice" [] {}]
+   ["sunflower" [double-block-half] {"half" "lower"}]
+   ["lilac" [double-block-half] {"half" "lower"}]
+   ["rose_bush" [double-block-half] {"half" "lower"}]
+   ["peony" [double-block-half] {"half" "lower"}]
+   ["tall_grass" [double-block-half] {"half" "lower"}]
+   ["large_fern" [double-block-half] {"half" "lower"}]
+   ["white_banner" [rotation] {}]
+   ["orange_banner" [rotation] {}]
+   ["magenta_banner" [rotation] {}]
+   ["light_blue_banner" [rotation] {}]
+   ["yellow_banner" [rotation] {}]
+   ["lime_banner" [rotation] {}]
+   ["pink_banner" [rotation] {}]
+   ["gray_banner" [rotation] {}]
+   ["light_gray_banner" [rotation] {}]
+   ["cyan_banner" [rotation] {}]
+   ["purple_banner" [rotation] {}]
+   ["blue_banner" [rotation] {}]
+   ["brown_banner" [rotation] {}]
+   ["green_banner" [rotation] {}]
+   ["red_banner" [rotation] {}]
+   ["black_banner" [rotation] {}]
+   ["white_wall_banner" [horizontal-facing] {}]
+   ["orange_wall_banner" [horizontal-facing] {}]
+   ["magenta_wall_banner" [horizontal-facing] {}]
+   ["light_blue_wall_banner" [horizontal-facing] {}]
+   ["yellow_wall_banner" [horizontal-facing] {}]
+   ["lime_wall_banner" [horizontal-facing] {}]
+   ["pink_wall_banner" [horizontal-facing] {}]
+   ["gray_wall_banner" [horizontal-facing] {}]
+   ["light_gray_wall_banner" [horizontal-facing] {}]
+   ["cyan_wall_banner" [horizontal-facing] {}]
+   ["purple_wall_banner" [horizontal-facing] {}]
+   ["blue_wall_banner" [horizontal-facing] {}]
+   ["brown_wall_banner" [horizontal-facing] {}]
+   ["green_wall_banner" [horizontal-facing] {}]
+   ["red_wall_banner" [horizontal-facing] {}]
+   ["black_wall_banner" [horizontal-facing] {}]
+   ["red_sandstone" [] {}]
+   ["chiseled_red_sandstone" [] {}]
+   ["cut_red_sandstone" [] {}]
+   ["red_sandstone_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["oak_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["spruce_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["birch_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["jungle_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["acacia_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["dark_oak_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["stone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["smooth_stone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["sandstone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["cut_sandstone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["petrified_oak_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["cobblestone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["brick_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["stone_brick_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["nether_brick_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["quartz_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["red_sandstone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["cut_red_sandstone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["purpur_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["smooth_stone" [] {}]
+   ["smooth_sandstone" [] {}]
+   ["smooth_quartz" [] {}]
+   ["smooth_red_sandstone" [] {}]
+   ["spruce_fence_gate" [horizontal-facing in-wall open powered] {"in_wall" "false" "open" "false" "powered" "false"}]
+   ["birch_fence_gate" [horizontal-facing in-wall open powered] {"in_wall" "false" "open" "false" "powered" "false"}]
+   ["jungle_fence_gate" [horizontal-facing in-wall open powered] {"in_wall" "false" "open" "false" "powered" "false"}]
+   ["acacia_fence_gate" [horizontal-facing in-wall open powered] {"in_wall" "false" "open" "false" "powered" "false"}]
+   ["dark_oak_fence_gate" [horizontal-facing in-wall open powered] {"in_wall" "false" "open" "false" "powered" "false"}]
+   ["spruce_fence" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["birch_fence" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["jungle_fence" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["acacia_fence" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["dark_oak_fence" [east north south waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["spruce_door" [horizontal-facing double-block-half hinge open powered] {"half" "lower" "open" "false" "powered" "false"}]
+   ["birch_door" [horizontal-facing double-block-half hinge open powered] {"half" "lower" "open" "false" "powered" "false"}]
+   ["jungle_door" [horizontal-facing double-block-half hinge open powered] {"half" "lower" "open" "false" "powered" "false"}]
+   ["acacia_door" [horizontal-facing double-block-half hinge open powered] {"half" "lower" "open" "false" "powered" "false"}]
+   ["dark_oak_door" [horizontal-facing double-block-half hinge open powered] {"half" "lower" "open" "false" "powered" "false"}]
+   ["end_rod" [facing] {"facing" "up"}]
+   ["chorus_plant" [down east north south up west] {"down" "false" "east" "false" "north" "false" "south" "false" "up" "false" "west" "false"}]
+   ["chorus_flower" [age-5] {}]
+   ["purpur_block" [] {}]
+   ["purpur_pillar" [axis] {"axis" "y"}]
+   ["purpur_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["end_stone_bricks" [] {}]
+   ["beetroots" [age-3] {}]
+   ["grass_path" [] {}]
+   ["end_gateway" [] {}]
+   ["repeating_command_block" [conditional facing] {"conditional" "false"}]
+   ["chain_command_block" [conditional facing] {"conditional" "false"}]
+   ["frosted_ice" [age-3] {}]
+   ["magma_block" [] {}]
+   ["nether_wart_block" [] {}]
+   ["red_nether_bricks" [] {}]
+   ["bone_block" [axis] {"axis" "y"}]
+   ["structure_void" [] {}]
+   ["observer" [facing powered] {"facing" "south" "powered" "false"}]
+   ["shulker_box" [facing] {"facing" "up"}]
+   ["white_shulker_box" [facing] {"facing" "up"}]
+   ["orange_shulker_box" [facing] {"facing" "up"}]
+   ["magenta_shulker_box" [facing] {"facing" "up"}]
+   ["light_blue_shulker_box" [facing] {"facing" "up"}]
+   ["yellow_shulker_box" [facing] {"facing" "up"}]
+   ["lime_shulker_box" [facing] {"facing" "up"}]
+   ["pink_shulker_box" [facing] {"facing" "up"}]
+   ["gray_shulker_box" [facing] {"facing" "up"}]
+   ["light_gray_shulker_box" [facing] {"facing" "up"}]
+   ["cyan_shulker_box" [facing] {"facing" "up"}]
+   ["purple_shulker_box" [facing] {"facing" "up"}]
+   ["blue_shulker_box" [facing] {"facing" "up"}]
+   ["brown_shulker_box" [facing] {"facing" "up"}]
+   ["green_shulker_box" [facing] {"facing" "up"}]
+   ["red_shulker_box" [facing] {"facing" "up"}]
+   ["black_shulker_box" [facing] {"facing" "up"}]
+   ["white_glazed_terracotta" [horizontal-facing] {}]
+   ["orange_glazed_terracotta" [horizontal-facing] {}]
+   ["magenta_glazed_terracotta" [horizontal-facing] {}]
+   ["light_blue_glazed_terracotta" [horizontal-facing] {}]
+   ["yellow_glazed_terracotta" [horizontal-facing] {}]
+   ["lime_glazed_terracotta" [horizontal-facing] {}]
+   ["pink_glazed_terracotta" [horizontal-facing] {}]
+   ["gray_glazed_terracotta" [horizontal-facing] {}]
+   ["light_gray_glazed_terracotta" [horizontal-facing] {}]
+   ["cyan_glazed_terracotta" [horizontal-facing] {}]
+   ["purple_glazed_terracotta" [horizontal-facing] {}]
+   ["blue_glazed_terracotta" [horizontal-facing] {}]
+   ["brown_glazed_terracotta" [horizontal-facing] {}]
+   ["green_glazed_terracotta" [horizontal-facing] {}]
+   ["red_glazed_terracotta" [horizontal-facing] {}]
+   ["black_glazed_terracotta" [horizontal-facing] {}]
+   ["white_concrete" [] {}]
+   ["orange_concrete" [] {}]
+   ["magenta_concrete" [] {}]
+   ["light_blue_concrete" [] {}]
+   ["yellow_concrete" [] {}]
+   ["lime_concrete" [] {}]
+   ["pink_concrete" [] {}]
+   ["gray_concrete" [] {}]
+   ["light_gray_concrete" [] {}]
+   ["cyan_concrete" [] {}]
+   ["purple_concrete" [] {}]
+   ["blue_concrete" [] {}]
+   ["brown_concrete" [] {}]
+   ["green_concrete" [] {}]
+   ["red_concrete" [] {}]
+   ["black_concrete" [] {}]
+   ["white_concrete_powder" [] {}]
+   ["orange_concrete_powder" [] {}]
+   ["magenta_concrete_powder" [] {}]
+   ["light_blue_concrete_powder" [] {}]
+   ["yellow_concrete_powder" [] {}]
+   ["lime_concrete_powder" [] {}]
+   ["pink_concrete_powder" [] {}]
+   ["gray_concrete_powder" [] {}]
+   ["light_gray_concrete_powder" [] {}]
+   ["cyan_concrete_powder" [] {}]
+   ["purple_concrete_powder" [] {}]
+   ["blue_concrete_powder" [] {}]
+   ["brown_concrete_powder" [] {}]
+   ["green_concrete_powder" [] {}]
+   ["red_concrete_powder" [] {}]
+   ["black_concrete_powder" [] {}]
+   ["kelp" [kelp-age] {}]
+   ["kelp_plant" [] {}]
+   ["dried_kelp_block" [] {}]
+   ["turtle_egg" [eggs hatch] {}]
+   ["dead_tube_coral_block" [] {}]
+   ["dead_brain_coral_block" [] {}]
+   ["dead_bubble_coral_block" [] {}]
+   ["dead_fire_coral_block" [] {}]
+   ["dead_horn_coral_block" [] {}]
+   ["tube_coral_block" [] {}]
+   ["brain_coral_block" [] {}]
+   ["bubble_coral_block" [] {}]
+   ["fire_coral_block" [] {}]
+   ["horn_coral_block" [] {}]
+   ["dead_tube_coral" [waterlogged] {}]
+   ["dead_brain_coral" [waterlogged] {}]
+   ["dead_bubble_coral" [waterlogged] {}]
+   ["dead_fire_coral" [waterlogged] {}]
+   ["dead_horn_coral" [waterlogged] {}]
+   ["tube_coral" [waterlogged] {}]
+   ["brain_coral" [waterlogged] {}]
+   ["bubble_coral" [waterlogged] {}]
+   ["fire_coral" [waterlogged] {}]
+   ["horn_coral" [waterlogged] {}]
+   ["dead_tube_coral_fan" [waterlogged] {}]
+   ["dead_brain_coral_fan" [waterlogged] {}]
+   ["dead_bubble_coral_fan" [waterlogged] {}]
+   ["dead_fire_coral_fan" [waterlogged] {}]
+   ["dead_horn_coral_fan" [waterlogged] {}]
+   ["tube_coral_fan" [waterlogged] {}]
+   ["brain_coral_fan" [waterlogged] {}]
+   ["bubble_coral_fan" [waterlogged] {}]
+   ["fire_coral_fan" [waterlogged] {}]
+   ["horn_coral_fan" [waterlogged] {}]
+   ["dead_tube_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["dead_brain_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["dead_bubble_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["dead_fire_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["dead_horn_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["tube_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["brain_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["bubble_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["fire_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["horn_coral_wall_fan" [horizontal-facing waterlogged] {}]
+   ["sea_pickle" [pickles waterlogged] {}]
+   ["blue_ice" [] {}]
+   ["conduit" [waterlogged] {}]
+   ["bamboo_sapling" [] {}]
+   ["bamboo" [bamboo-age bamboo-leaves sapling-stage] {}]
+   ["potted_bamboo" [] {}]
+   ["void_air" [] {}]
+   ["cave_air" [] {}]
+   ["bubble_column" [drag] {}]
+   ["polished_granite_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["smooth_red_sandstone_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["mossy_stone_brick_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["polished_diorite_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["mossy_cobblestone_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["end_stone_brick_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["stone_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["smooth_sandstone_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["smooth_quartz_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["granite_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["andesite_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["red_nether_brick_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["polished_andesite_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["diorite_stairs" [horizontal-facing half stairs-shape waterlogged] {"half" "bottom" "waterlogged" "false"}]
+   ["polished_granite_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["smooth_red_sandstone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["mossy_stone_brick_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["polished_diorite_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["mossy_cobblestone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["end_stone_brick_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["smooth_sandstone_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["smooth_quartz_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["granite_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["andesite_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["red_nether_brick_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["polished_andesite_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["diorite_slab" [slab-type waterlogged] {"type" "bottom" "waterlogged" "false"}]
+   ["brick_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["prismarine_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["red_sandstone_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["mossy_stone_brick_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["granite_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["stone_brick_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["nether_brick_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["andesite_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["red_nether_brick_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["sandstone_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["end_stone_brick_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["diorite_wall" [east north south up waterlogged west] {"east" "false" "north" "false" "south" "false" "waterlogged" "false" "west" "false"}]
+   ["scaffolding" [bottom scaffolding-distance waterlogged] {"bottom" "false" "distance" "7" "waterlogged" "false"}]
+   ["loom" [horizontal-facing] {}]
+   ["barrel" [facing open] {"open" "false"}]
+   ["smoker" [horizontal-facing lit] {"lit" "false"}]
+   ["blast_furnace" [horizontal-facing lit] {"lit" "false"}]
+   ["cartography_table" [] {}]
+   ["fletching_table" [] {}]
+   ["grindstone" [face horizontal-facing] {"face" "wall"}]
+   ["lectern" [horizontal-facing has-book powered] {"has_book" "false" "powered" "false"}]
+   ["smithing_table" [] {}]
+   ["stonecutter" [horizontal-facing] {}]
+   ["bell" [bell-attachment horizontal-facing powered] {"powered" "false"}]
+   ["lantern" [hanging] {"hanging" "false"}]
+   ["campfire" [horizontal-facing lit signal-fire waterlogged] {"signal_fire" "false" "waterlogged" "false"}]
+   ["sweet_berry_bush" [age-3] {}]
+   ["structure_block" [structure-mode] {}]
+   ["jigsaw" [facing] {"facing" "up"}]
+   ["composter" [composter-level] {}]
+   ["bee_nest" [horizontal-facing honey-level] {}]
+   ["beehive" [horizontal-facing honey-level] {}]
+   ["honey_block" [] {}]
+   ["honeycomb_block" [] {}]])
+
+;; The items in id order
+(def items
+  ["air"
+   "stone"
+   "granite"
+   "polished_granite"
+   "diorite"
+   "polished_diorite"
+   "andesite"
+   "polished_andesite"
+   "grass_block"
+   "dirt"
+   "coarse_dirt"
+   "podzol"
+   "cobblestone"
+   "oak_planks"
+   "spruce_planks"
+   "birch_planks"
+   "jungle_planks"
+   "acacia_planks"
+   "dark_oak_planks"
+   "oak_sapling"
+   "spruce_sapling"
+   "birch_sapling"
+   "jungle_sapling"
+   "acacia_sapling"
+   "dark_oak_sapling"
+   "bedrock"
+   "sand"
+   "red_sand"
+   "gravel"
+   "gold_ore"
+   "iron_ore"
+   "coal_ore"
+   "oak_log"
+   "spruce_log"
+   "birch_log"
+   "jungle_log"
+   "acacia_log"
+   "dark_oak_log"
+   "stripped_oak_log"
+   "stripped_spruce_log"
+   "stripped_birch_log"
+   "stripped_jungle_log"
+   "stripped_acacia_log"
+   "stripped_dark_oak_log"
+   "stripped_oak_wood"
+   "stripped_spruce_wood"
+   "stripped_birch_wood"
+   "stripped_jungle_wood"
+   "stripped_acacia_wood"
+   "stripped_dark_oak_wood"
+   "oak_wood"
+   "spruce_wood"
+   "birch_wood"
+   "jungle_wood"
+   "acacia_wood"
+   "dark_oak_wood"
+   "oak_leaves"
+   "spruce_leaves"
+   "birch_leaves"
+   "jungle_leaves"
+   "acacia_leaves"
+   "dark_oak_leaves"
+   "sponge"
+   "wet_sponge"
+   "glass"
+   "lapis_ore"
+   "lapis_block"
+   "dispenser"
+   "sandstone"
+   "chiseled_sandstone"
+   "cut_sandstone"
+   "note_block"
+   "powered_rail"
+   "detector_rail"
+   "sticky_piston"
+   "cobweb"
+   "grass"
+   "fern"
+   "dead_bush"
+   "seagrass"
+   "sea_pickle"
+   "piston"
+   "white_wool"
+   "orange_wool"
+   "magenta_wool"
+   "light_blue_wool"
+   "yellow_wool"
+   "lime_wool"
+   "pink_wool"
+   "gray_wool"
+   "light_gray_wool"
+   "cyan_wool"
+   "purple_wool"
+   "blue_wool"
+   "brown_wool"
+   "green_wool"
+   "red_wool"
+   "black_wool"
+   "dandelion"
+   "poppy"
+   "blue_orchid"
+   "allium"
+   "azure_bluet"
+   "red_tulip"
+   "orange_tulip"
+   "white_tulip"
+   "pink_tulip"
+   "oxeye_daisy"
+   "cornflower"
+   "lily_of_the_valley"
+   "wither_rose"
+   "brown_mushroom"
+   "red_mushroom"
+   "gold_block"
+   "iron_block"
+   "oak_slab"
+   "spruce_slab"
+   "birch_slab"
+   "jungle_slab"
+   "acacia_slab"
+   "dark_oak_slab"
+   "stone_slab"
+   "smooth_stone_slab"
+   "sandstone_slab"
+   "cut_sandstone_slab"
+   "petrified_oak_slab"
+   "cobblestone_slab"
+   "brick_slab"
+   "stone_brick_slab"
+   "nether_brick_slab"
+   "quartz_slab"
+   "red_sandstone_slab"
+   "cut_red_sandstone_slab"
+   "purpur_slab"
+   "prismarine_slab"
+   "prismarine_brick_slab"
+   "dark_prismarine_slab"
+   "smooth_quartz"
+   "smooth_red_sandstone"
+   "smooth_sandstone"
+   "smooth_stone"
+   "bricks"
+   "tnt"
+   "bookshelf"
+   "mossy_cobblestone"
+   "obsidian"
+   "torch"
+   "end_rod"
+   "chorus_plant"
+   "chorus_flower"
+   "purpur_block"
+   "purpur_pillar"
+   "purpur_stairs"
+   "spawner"
+   "oak_stairs"
+   "chest"
+   "diamond_ore"
+   "diamond_block"
+   "crafting_table"
+   "farmland"
+   "furnace"
+   "ladder"
+   "rail"
+   "cobblestone_stairs"
+   "lever"
+   "stone_pressure_plate"
+   "oak_pressure_plate"
+   "spruce_pressure_plate"
+   "birch_pressure_plate"
+   "jungle_pressure_plate"
+   "acacia_pressure_plate"
+   "dark_oak_pressure_plate"
+   "redstone_ore"
+   "redstone_torch"
+   "stone_button"
+   "snow"
+   "ice"
+   "snow_block"
+   "cactus"
+   "clay"
+   "jukebox"
+   "oak_fence"
+   "spruce_fence"
+   "birch_fence"
+   "jungle_fence"
+   "acacia_fence"
+   "dark_oak_fence"
+   "pumpkin"
+   "carved_pumpkin"
+   "netherrack"
+   "soul_sand"
+   "glowstone"
+   "jack_o_lantern"
+   "oak_trapdoor"
+   "spruce_trapdoor"
+   "birch_trapdoor"
+   "jungle_trapdoor"
+   "acacia_trapdoor"
+   "dark_oak_trapdoor"
+   "infested_stone"
+   "infested_cobblestone"
+   "infested_stone_bricks"
+   "infested_mossy_stone_bricks"
+   "infested_cracked_stone_bricks"
+   "infested_chiseled_stone_bricks"
+   "stone_bricks"
+   "mossy_stone_bricks"
+   "cracked_stone_bricks"
+   "chiseled_stone_bricks"
+   "brown_mushroom_block"
+   "red_mushroom_block"
+   "mushroom_stem"
+   "iron_bars"
+   "glass_pane"
+   "melon"
+   "vine"
+   "oak_fence_gate"
+   "spruce_fence_gate"
+   "birch_fence_gate"
+   "jungle_fence_gate"
+   "acacia_fence_gate"
+   "dark_oak_fence_gate"
+   "brick_stairs"
+   "stone_brick_stairs"
+   "mycelium"
+   "lily_pad"
+   "nether_bricks"
+   "nether_brick_fence"
+   "nether_brick_stairs"
+   "enchanting_table"
+   "end_portal_frame"
+   "end_stone"
+   "end_stone_bricks"
+   "dragon_egg"
+   "redstone_lamp"
+   "sandstone_stairs"
+   "emerald_ore"
+   "ender_chest"
+   "tripwire_hook"
+   "emerald_block"
+   "spruce_stairs"
+   "birch_stairs"
+   "jungle_stairs"
+   "command_block"
+   "beacon"
+   "cobblestone_wall"
+   "mossy_cobblestone_wall"
+   "brick_wall"
+   "prismarine_wall"
+   "red_sandstone_wall"
+   "mossy_stone_brick_wall"
+   "granite_wall"
+   "stone_brick_wall"
+   "nether_brick_wall"
+   "andesite_wall"
+   "red_nether_brick_wall"
+   "sandstone_wall"
+   "end_stone_brick_wall"
+   "diorite_wall"
+   "oak_button"
+   "spruce_button"
+   "birch_button"
+   "jungle_button"
+   "acacia_button"
+   "dark_oak_button"
+   "anvil"
+   "chipped_anvil"
+   "damaged_anvil"
+   "trapped_chest"
+   "light_weighted_pressure_plate"
+   "heavy_weighted_pressure_plate"
+   "daylight_detector"
+   "redstone_block"
+   "nether_quartz_ore"
+   "hopper"
+   "chiseled_quartz_block"
+   "quartz_block"
+   "quartz_pillar"
+   "quartz_stairs"
+   "activator_rail"
+   "dropper"
+   "white_terracotta"
+   "orange_terracotta"
+   "magenta_terracotta"
+   "light_blue_terracotta"
+   "yellow_terracotta"
+   "lime_terracotta"
+   "pink_terracotta"
+   "gray_terracotta"
+   "light_gray_terracotta"
+   "cyan_terracotta"
+   "purple_terracotta"
+   "blue_terracotta"
+   "brown_terracotta"
+   "green_terracotta"
+   "red_terracotta"
+   "black_terracotta"
+   "barrier"
+   "iron_trapdoor"
+   "hay_block"
+   "white_carpet"
+   "orange_carpet"
+   "magenta_carpet"
+   "light_blue_carpet"
+   "yellow_carpet"
+   "lime_carpet"
+   "pink_carpet"
+   "gray_carpet"
+   "light_gray_carpet"
+   "cyan_carpet"
+   "purple_carpet"
+   "blue_carpet"
+   "brown_carpet"
+   "green_carpet"
+   "red_carpet"
+   "black_carpet"
+   "terracotta"
+   "coal_block"
+   "packed_ice"
+   "acacia_stairs"
+   "dark_oak_stairs"
+   "slime_block"
+   "grass_path"
+   "sunflower"
+   "lilac"
+   "rose_bush"
+   "peony"
+   "tall_grass"
+   "large_fern"
+   "white_stained_glass"
+   "orange_stained_glass"
+   "magenta_stained_glass"
+   "light_blue_stained_glass"
+   "yellow_stained_glass"
+   "lime_stained_glass"
+   "pink_stained_glass"
+   "gray_stained_glass"
+   "light_gray_stained_glass"
+   "cyan_stained_glass"
+   "purple_stained_glass"
+   "blue_stained_glass"
+   "brown_stained_glass"
+   "green_stained_glass"
+   "red_stained_glass"
+   "black_stained_glass"
+   "white_stained_glass_pane"
+   "orange_stained_glass_pane"
+   "magenta_stained_glass_pane"
+   "light_blue_stained_glass_pane"
+   "yellow_stained_glass_pane"
+   "lime_stained_glass_pane"
+   "pink_stained_glass_pane"
+   "gray_stained_glass_pane"
+   "light_gray_stained_glass_pane"
+   "cyan_stained_glass_pane"
+   "purple_stained_glass_pane"
+   "blue_stained_glass_pane"
+   "brown_stained_glass_pane"
+   "green_stained_glass_pane"
+   "red_stained_glass_pane"
+   "black_stained_glass_pane"
+   "prismarine"
+   "prismarine_bricks"
+   "dark_prismarine"
+   "prismarine_stairs"
+   "prismarine_brick_stairs"
+   "dark_prismarine_stairs"
+   "sea_lantern"
+   "red_sandstone"
+   "chiseled_red_sandstone"
+   "cut_red_sandstone"
+   "red_sandstone_stairs"
+   "repeating_command_block"
+   "chain_command_block"
+   "magma_block"
+   "nether_wart_block"
+   "red_nether_bricks"
+   "bone_block"
+   "structure_void"
+   "observer"
+   "shulker_box"
+   "white_shulker_box"
+   "orange_shulker_box"
+   "magenta_shulker_box"
+   "light_blue_shulker_box"
+   "yellow_shulker_box"
+   "lime_shulker_box"
+   "pink_shulker_box"
+   "gray_shulker_box"
+   "light_gray_shulker_box"
+   "cyan_shulker_box"
+   "purple_shulker_box"
+   "blue_shulker_box"
+   "brown_shulker_box"
+   "green_shulker_box"
+   "red_shulker_box"
+   "black_shulker_box"
+   "white_glazed_terracotta"
+   "orange_glazed_terracotta"
+   "magenta_glazed_terracotta"
+   "light_blue_glazed_terracotta"
+   "yellow_glazed_terracotta"
+   "lime_glazed_terracotta"
+   "pink_glazed_terracotta"
+   "gray_glazed_terracotta"
+   "light_gray_glazed_terracotta"
+   "cyan_glazed_terracotta"
+   "purple_glazed_terracotta"
+   "blue_glazed_terracotta"
+   "brown_glazed_terracotta"
+   "green_glazed_terracotta"
+   "red_glazed_terracotta"
+   "black_glazed_terracotta"
+   "white_concrete"
+   "orange_concrete"
+   "magenta_concrete"
+   "light_blue_concrete"
+   "yellow_concrete"
+   "lime_concrete"
+   "pink_concrete"
+   "gray_concrete"
+   "light_gray_concrete"
+   "cyan_concrete"
+   "purple_concrete"
+   "blue_concrete"
+   "brown_concrete"
+   "green_concrete"
+   "red_concrete"
+   "black_concrete"
+   "white_concrete_powder"
+   "orange_concrete_powder"
+   "magenta_concrete_powder"
+   "light_blue_concrete_powder"
+   "yellow_concrete_powder"
+   "lime_concrete_powder"
+   "pink_concrete_powder"
+   "gray_concrete_powder"
+   "light_gray_concrete_powder"
+   "cyan_concrete_powder"
+   "purple_concrete_powder"
+   "blue_concrete_powder"
+   "brown_concrete_powder"
+   "green_concrete_powder"
+   "red_concrete_powder"
+   "black_concrete_powder"
+   "turtle_egg"
+   "dead_tube_coral_block"
+   "dead_brain_coral_block"
+   "dead_bubble_coral_block"
+   "dead_fire_coral_block"
+   "dead_horn_coral_block"
+   "tube_coral_block"
+   "brain_coral_block"
+   "bubble_coral_block"
+   "fire_coral_block"
+   "horn_coral_block"
+   "tube_coral"
+   "brain_coral"
+   "bubble_coral"
+   "fire_coral"
+   "horn_coral"
+   "dead_brain_coral"
+   "dead_bubble_coral"
+   "dead_fire_coral"
+   "dead_horn_coral"
+   "dead_tube_coral"
+   "tube_coral_fan"
+   "brain_coral_fan"
+   "bubble_coral_fan"
+   "fire_coral_fan"
+   "horn_coral_fan"
+   "dead_tube_coral_fan"
+   "dead_brain_coral_fan"
+   "dead_bubble_coral_fan"
+   "dead_fire_coral_fan"
+   "dead_horn_coral_fan"
+   "blue_ice"
+   "conduit"
+   "polished_granite_stairs"
+   "smooth_red_sandstone_stairs"
+   "mossy_stone_brick_stairs"
+   "polished_diorite_stairs"
+   "mossy_cobblestone_stairs"
+   "end_stone_brick_stairs"
+   "stone_stairs"
+   "smooth_sandstone_stairs"
+   "smooth_quartz_stairs"
+   "granite_stairs"
+   "andesite_stairs"
+   "red_nether_brick_stairs"
+   "polished_andesite_stairs"
+   "diorite_stairs"
+   "polished_granite_slab"
+   "smooth_red_sandstone_slab"
+   "mossy_stone_brick_slab"
+   "polished_diorite_slab"
+   "mossy_cobblestone_slab"
+   "end_stone_brick_slab"
+   "smooth_sandstone_slab"
+   "smooth_quartz_slab"
+   "granite_slab"
+   "andesite_slab"
+   "red_nether_brick_slab"
+   "polished_andesite_slab"
+   "diorite_slab"
+   "scaffolding"
+   "iron_door"
+   "oak_door"
+   "spruce_door"
+   "birch_door"
+   "jungle_door"
+   "acacia_door"
+   "dark_oak_door"
+   "repeater"
+   "comparator"
+   "structure_block"
+   "jigsaw"
+   "composter"
+   "turtle_helmet"
+   "scute"
+   "iron_shovel"
+   "iron_pickaxe"
+   "iron_axe"
+   "flint_and_steel"
+   "apple"
+   "bow"
+   "arrow"
+   "coal"
+   "charcoal"
+   "diamond"
+   "iron_ingot"
+   "gold_ingot"
+   "iron_sword"
+   "wooden_sword"
+   "wooden_shovel"
+   "wooden_pickaxe"
+   "wooden_axe"
+   "stone_sword"
+   "stone_shovel"
+   "stone_pickaxe"
+   "stone_axe"
+   "diamond_sword"
+   "diamond_shovel"
+   "diamond_pickaxe"
+   "diamond_axe"
+   "stick"
+   "bowl"
+   "mushroom_stew"
+   "golden_sword"
+   "golden_shovel"
+   "golden_pickaxe"
+   "golden_axe"
+   "string"
+   "feather"
+   "gunpowder"
+   "wooden_hoe"
+   "stone_hoe"
+   "iron_hoe"
+   "diamond_hoe"
+   "golden_hoe"
+   "wheat_seeds"
+   "wheat"
+   "bread"
+   "leather_helmet"
+   "leather_chestplate"
+   "leather_leggings"
+   "leather_boots"
+   "chainmail_helmet"
+   "chainmail_chestplate"
+   "chainmail_leggings"
+   "chainmail_boots"
+   "iron_helmet"
+   "iron_chestplate"
+   "iron_leggings"
+   "iron_boots"
+   "diamond_helmet"
+   "diamond_chestplate"
+   "diamond_leggings"
+   "diamond_boots"
+   "golden_helmet"
+   "golden_chestplate"
+   "golden_leggings"
+   "golden_boots"
+   "flint"
+   "porkchop"
+   "cooked_porkchop"
+   "painting"
+   "golden_apple"
+   "enchanted_golden_apple"
+   "oak_sign"
+   "spruce_sign"
+   "birch_sign"
+   "jungle_sign"
+   "acacia_sign"
+   "dark_oak_sign"
+   "bucket"
+   "water_bucket"
+   "lava_bucket"
+   "minecart"
+   "saddle"
+   "redstone"
+   "snowball"
+   "oak_boat"
+   "leather"
+   "milk_bucket"
+   "pufferfish_bucket"
+   "salmon_bucket"
+   "cod_bucket"
+   "tropical_fish_bucket"
+   "brick"
+   "clay_ball"
+   "sugar_cane"
+   "kelp"
+   "dried_kelp_block"
+   "bamboo"
+   "paper"
+   "book"
+   "slime_ball"
+   "chest_minecart"
+   "furnace_minecart"
+   "egg"
+   "compass"
+   "fishing_rod"
+   "clock"
+   "glowstone_dust"
+   "cod"
+   "salmon"
+   "tropical_fish"
+   "pufferfish"
+   "cooked_cod"
+   "cooked_salmon"
+   "ink_sac"
+   "red_dye"
+   "green_dye"
+   "cocoa_beans"
+   "lapis_lazuli"
+   "purple_dye"
+   "cyan_dye"
+   "light_gray_dye"
+   "gray_dye"
+   "pink_dye"
+   "lime_dye"
+   "yellow_dye"
+   "light_blue_dye"
+   "magenta_dye"
+   "orange_dye"
+   "bone_meal"
+   "blue_dye"
+   "brown_dye"
+   "black_dye"
+   "white_dye"
+   "bone"
+   "sugar"
+   "cake"
+   "white_bed"
+   "orange_bed"
+   "magenta_bed"
+   "light_blue_bed"
+   "yellow_bed"
+   "lime_bed"
+   "pink_bed"
+   "gray_bed"
+   "light_gray_bed"
+   "cyan_bed"
+   "purple_bed"
+   "blue_bed"
+   "brown_bed"
+   "green_bed"
+   "red_bed"
+   "black_bed"
+   "cookie"
+   "filled_map"
+   "shears"
+   "melon_slice"
+   "dried_kelp"
+   "pumpkin_seeds"
+   "melon_seeds"
+   "beef"
+   "cooked_beef"
+   "chicken"
+   "cooked_chicken"
+   "rotten_flesh"
+   "ender_pearl"
+   "blaze_rod"
+   "ghast_tear"
+   "gold_nugget"
+   "nether_wart"
+   "potion"
+   "glass_bottle"
+   "spider_eye"
+   "fermented_spider_eye"
+   "blaze_powder"
+   "magma_cream"
+   "brewing_stand"
+   "cauldron"
+   "ender_eye"
+   "glistering_melon_slice"
+   "bat_spawn_egg"
+   "bee_spawn_egg"
+   "blaze_spawn_egg"
+   "cat_spawn_egg"
+   "cave_spider_spawn_egg"
+   "chicken_spawn_egg"
+   "cod_spawn_egg"
+   "cow_spawn_egg"
+   "creeper_spawn_egg"
+   "dolphin_spawn_egg"
+   "donkey_spawn_egg"
+   "drowned_spawn_egg"
+   "elder_guardian_spawn_egg"
+   "enderman_spawn_egg"
+   "endermite_spawn_egg"
+   "evoker_spawn_egg"
+   "fox_spawn_egg"
+   "ghast_spawn_egg"
+   "guardian_spawn_egg"
+   "horse_spawn_egg"
+   "husk_spawn_egg"
+   "llama_spawn_egg"
+   "magma_cube_spawn_egg"
+   "mooshroom_spawn_egg"
+   "mule_spawn_egg"
+   "ocelot_spawn_egg"
+   "panda_spawn_egg"
+   "parrot_spawn_egg"
+   "phantom_spawn_egg"
+   "pig_spawn_egg"
+   "pillager_spawn_egg"
+   "polar_bear_spawn_egg"
+   "pufferfish_spawn_egg"
+   "rabbit_spawn_egg"
+   "ravager_spawn_egg"
+   "salmon_spawn_egg"
+   "sheep_spawn_egg"
+   "shulker_spawn_egg"
+   "silverfish_spawn_egg"
+   "skeleton_spawn_egg"
+   "skeleton_horse_spawn_egg"
+   "slime_spawn_egg"
+   "spider_spawn_egg"
+   "squid_spawn_egg"
+   "stray_spawn_egg"
+   "trader_llama_spawn_egg"
+   "tropical_fish_spawn_egg"
+   "turtle_spawn_egg"
+   "vex_spawn_egg"
+   "villager_spawn_egg"
+   "vindicator_spawn_egg"
+   "wandering_trader_spawn_egg"
+   "witch_spawn_egg"
+   "wither_skeleton_spawn_egg"
+   "wolf_spawn_egg"
+   "zombie_spawn_egg"
+   "zombie_horse_spawn_egg"
+   "zombie_pigman_spawn_egg"
+   "zombie_villager_spawn_egg"
+   "experience_bottle"
+   "fire_charge"
+   "writable_book"
+   "written_book"
+   "emerald"
+   "item_frame"
+   "flower_pot"
+   "carrot"
+   "potato"
+   "baked_potato"
+   "poisonous_potato"
+   "map"
+   "golden_carrot"
+   "skeleton_skull"
+   "wither_skeleton_skull"
+   "player_head"
+   "zombie_head"
+   "creeper_head"
+   "dragon_head"
+   "carrot_on_a_stick"
+   "nether_star"
+   "pumpkin_pie"
+   "firework_rocket"
+   "firework_star"
+   "enchanted_book"
+   "nether_brick"
+   "quartz"
+   "tnt_minecart"
+   "hopper_minecart"
+   "prismarine_shard"
+   "prismarine_crystals"
+   "rabbit"
+   "cooked_rabbit"
+   "rabbit_stew"
+   "rabbit_foot"
+   "rabbit_hide"
+   "armor_stand"
+   "iron_horse_armor"
+   "golden_horse_armor"
+   "diamond_horse_armor"
+   "leather_horse_armor"
+   "lead"
+   "name_tag"
+   "command_block_minecart"
+   "mutton"
+   "cooked_mutton"
+   "white_banner"
+   "orange_banner"
+   "magenta_banner"
+   "light_blue_banner"
+   "yellow_banner"
+   "lime_banner"
+   "pink_banner"
+   "gray_banner"
+   "light_gray_banner"
+   "cyan_banner"
+   "purple_banner"
+   "blue_banner"
+   "brown_banner"
+   "green_banner"
+   "red_banner"
+   "black_banner"
+   "end_crystal"
+   "chorus_fruit"
+   "popped_chorus_fruit"
+   "beetroot"
+   "beetroot_seeds"
+   "beetroot_soup"
+   "dragon_breath"
+   "splash_potion"
+   "spectral_arrow"
+   "tipped_arrow"
+   "lingering_potion"
+   "shield"
+   "elytra"
+   "spruce_boat"
+   "birch_boat"
+   "jungle_boat"
+   "acacia_boat"
+   "dark_oak_boat"
+   "totem_of_undying"
+   "shulker_shell"
+   "iron_nugget"
+   "knowledge_book"
+   "debug_stick"
+   "music_disc_13"
+   "music_disc_cat"
+   "music_disc_blocks"
+   "music_disc_chirp"
+   "music_disc_far"
+   "music_disc_mall"
+   "music_disc_mellohi"
+   "music_disc_stal"
+   "music_disc_strad"
+   "music_disc_ward"
+   "music_disc_11"
+   "music_disc_wait"
+   "trident"
+   "phantom_membrane"
+   "nautilus_shell"
+   "heart_of_the_sea"
+   "crossbow"
+   "suspicious_stew"
+   "loom"
+   "flower_banner_pattern"
+   "creeper_banner_pattern"
+   "skull_banner_pattern"
+   "mojang_banner_pattern"
+   "globe_banner_pattern"
+   "barrel"
+   "smoker"
+   "blast_furnace"
+   "cartography_table"
+   "fletching_table"
+   "grindstone"
+   "lectern"
+   "smithing_table"
+   "stonecutter"
+   "bell"
+   "lantern"
+   "sweet_berries"
+   "campfire"
+   "honeycomb"
+   "bee_nest"
+   "beehive"
+   "honey_bottle"
+   "honey_block"
+   "honeycomb_block"])
+
+;; The entity types in id order
+(def entities
+  ["area_effect_cloud"
+   "armor_stand"
+   "arrow"
+   "bat"
+   "bee"
+   "blaze"
+   "boat"
+   "cat"
+   "cave_spider"
+   "chicken"
+   "cod"
+   "cow"
+   "creeper"
+   "donkey"
+   "dolphin"
+   "dragon_fireball"
+   "drowned"
+   "elder_guardian"
+   "end_crystal"
+   "ender_dragon"
+   "enderman"
+   "endermite"
+   "evoker_fangs"
+   "evoker"
+   "experience_orb"
+   "eye_of_ender"
+   "falling_block"
+   "firework_rocket"
+   "fox"
+   "ghast"
+   "giant"
+   "guardian"
+   "horse"
+   "husk"
+   "illusioner"
+   "item"
+   "item_frame"
+   "fireball"
+   "leash_knot"
+   "llama"
+   "llama_spit"
+   "magma_cube"
+   "minecart"
+   "chest_minecart"
+   "command_block_minecart"
+   "furnace_minecart"
+   "hopper_minecart"
+   "spawner_minecart"
+   "tnt_minecart"
+   "mule"
+   "mooshroom"
+   "ocelot"
+   "painting"
+   "panda"
+   "parrot"
+   "pig"
+   "pufferfish"
+   "zombie_pigman"
+   "polar_bear"
+   "tnt"
+   "rabbit"
+   "salmon"
+   "sheep"
+   "shulker"
+   "shulker_bullet"
+   "silverfish"
+   "skeleton"
+   "skeleton_horse"
+   "slime"
+   "small_fireball"
+   "snow_golem"
+   "snowball"
+   "spectral_arrow"
+   "spider"
+   "squid"
+   "stray"
+   "trader_llama"
+   "tropical_fish"
+   "turtle"
+   "egg"
+   "ender_pearl"
+   "experience_bottle"
+   "potion"
+   "trident"
+   "vex"
+   "villager"
+   "iron_golem"
+   "vindicator"
+   "pillager"
+   "wandering_trader"
+   "witch"
+   "wither"
+   "wither_skeleton"
+   "wither_skull"
+   "wolf"
+   "zombie"
+   "zombie_horse"
+   "zombie_villager"
+   "phantom"
+   "ravager"
+   "lightning_bolt"
+   "player"
+   "fishing_bobber"])
+
+;; The particle types in id order
+(def particles
+  ["ambient_entity_effect"
+   "angry_villager"
+   "barrier"
+   "block"
+   "bubble"
+   "cloud"
+   "crit"
+   "damage_indicator"
+   "dragon_breath"
+   "dripping_lava"
+   "falling_lava"
+   "landing_lava"
+   "dripping_water"
+   "falling_water"
+   "dust"
+   "effect"
+   "elder_guardian"
+   "enchanted_hit"
+   "enchant"
+   "end_rod"
+   "entity_effect"
+   "explosion_emitter"
+   "explosion"
+   "falling_dust"
+   "firework"
+   "fishing"
+   "flame"
+   "flash"
+   "happy_villager"
+   "composter"
+   "heart"
+   "instant_effect"
+   "item"
+   "item_slime"
+   "item_snowball"
+   "large_smoke"
+   "lava"
+   "mycelium"
+   "note"
+   "poof"
+   "portal"
+   "rain"
+   "smoke"
+   "sneeze"
+   "spit"
+   "squid_ink"
+   "sweep_attack"
+   "totem_of_undying"
+   "underwater"
+   "splash"
+   "witch"
+   "bubble_pop"
+   "current_down"
+   "bubble_column_up"
+   "nautilus"
+   "dolphin"
+   "campfire_cosy_smoke"
+   "campfire_signal_smoke"
+   "dripping_honey"
+   "falling_honey"
+   "landing_honey"
+   "falling_nectar"])
+
+;; The sound events in id order
+(def sounds
+  ["ambient.cave"
+   "ambient.underwater.enter"
+   "ambient.underwater.exit"
+   "ambient.underwater.loop"
+   "ambient.underwater.loop.additions"
+   "ambient.underwater.loop.additions.rare"
+   "ambient.underwater.loop.additions.ultra_rare"
+   "block.anvil.break"
+   "block.anvil.destroy"
+   "block.anvil.fall"
+   "block.anvil.hit"
+   "block.anvil.land"
+   "block.anvil.place"
+   "block.anvil.step"
+   "block.anvil.use"
+   "item.armor.equip_chain"
+   "item.armor.equip_diamond"
+   "item.armor.equip_elytra"
+   "item.armor.equip_generic"
+   "item.armor.equip_gold"
+   "item.armor.equip_iron"
+   "item.armor.equip_leather"
+   "item.armor.equip_turtle"
+   "entity.armor_stand.break"
+   "entity.armor_stand.fall"
+   "entity.armor_stand.hit"
+   "entity.armor_stand.place"
+   "entity.arrow.hit"
+   "entity.arrow.hit_player"
+   "entity.arrow.shoot"
+   "item.axe.strip"
+   "block.bamboo.break"
+   "block.bamboo.fall"
+   "block.bamboo.hit"
+   "block.bamboo.place"
+   "block.bamboo.step"
+   "block.bamboo_sapling.break"
+   "block.bamboo_sapling.hit"
+   "block.bamboo_sapling.place"
+   "block.barrel.close"
+   "block.barrel.open"
+   "entity.bat.ambient"
+   "entity.bat.death"
+   "entity.bat.hurt"
+   "entity.bat.loop"
+   "entity.bat.takeoff"
+   "block.beacon.activate"
+   "block.beacon.ambient"
+   "block.beacon.deactivate"
+   "block.beacon.power_select"
+   "entity.bee.death"
+   "entity.bee.hurt"
+   "entity.bee.loop_aggressive"
+   "entity.bee.loop"
+   "entity.bee.sting"
+   "entity.bee.pollinate"
+   "block.beehive.drip"
+   "block.beehive.enter"
+   "block.beehive.exit"
+   "block.beehive.shear"
+   "block.beehive.work"
+   "block.bell.use"
+   "block.bell.resonate"
+   "entity.blaze.ambient"
+   "entity.blaze.burn"
+   "entity.blaze.death"
+   "entity.blaze.hurt"
+   "entity.blaze.shoot"
+   "entity.boat.paddle_land"
+   "entity.boat.paddle_water"
+   "item.book.page_turn"
+   "item.book.put"
+   "entity.fishing_bobber.retrieve"
+   "entity.fishing_bobber.splash"
+   "entity.fishing_bobber.throw"
+   "block.blastfurnace.fire_crackle"
+   "item.bottle.empty"
+   "item.bottle.fill"
+   "item.bottle.fill_dragonbreath"
+   "block.brewing_stand.brew"
+   "block.bubble_column.bubble_pop"
+   "block.bubble_column.upwards_ambient"
+   "block.bubble_column.upwards_inside"
+   "block.bubble_column.whirlpool_ambient"
+   "block.bubble_column.whirlpool_inside"
+   "item.bucket.empty"
+   "item.bucket.empty_fish"
+   "item.bucket.empty_lava"
+   "item.bucket.fill"
+   "item.bucket.fill_fish"
+   "item.bucket.fill_lava"
+   "block.campfire.crackle"
+   "entity.cat.ambient"
+   "entity.cat.stray_ambient"
+   "entity.cat.death"
+   "entity.cat.eat"
+   "entity.cat.hiss"
+   "entity.cat.beg_for_food"
+   "entity.cat.hurt"
+   "entity.cat.purr"
+   "entity.cat.purreow"
+   "block.chest.close"
+   "block.chest.locked"
+   "block.chest.open"
+   "entity.chicken.ambient"
+   "entity.chicken.death"
+   "entity.chicken.egg"
+   "entity.chicken.hurt"
+   "entity.chicken.step"
+   "block.chorus_flower.death"
+   "block.chorus_flower.grow"
+   "item.chorus_fruit.teleport"
+   "block.wool.break"
+   "block.wool.fall"
+   "block.wool.hit"
+   "block.wool.place"
+   "block.wool.step"
+   "entity.cod.ambient"
+   "entity.cod.death"
+   "entity.cod.flop"
+   "entity.cod.hurt"
+   "block.comparator.click"
+   "block.composter.empty"
+   "block.composter.fill"
+   "block.composter.fill_success"
+   "block.composter.ready"
+   "block.conduit.activate"
+   "block.conduit.ambient"
+   "block.conduit.ambient.short"
+   "block.conduit.attack.target"
+   "block.conduit.deactivate"
+   "block.coral_block.break"
+   "block.coral_block.fall"
+   "block.coral_block.hit"
+   "block.coral_block.place"
+   "block.coral_block.step"
+   "entity.cow.ambient"
+   "entity.cow.death"
+   "entity.cow.hurt"
+   "entity.cow.milk"
+   "entity.cow.step"
+   "entity.creeper.death"
+   "entity.creeper.hurt"
+   "entity.creeper.primed"
+   "block.crop.break"
+   "item.crop.plant"
+   "item.crossbow.hit"
+   "item.crossbow.loading_end"
+   "item.crossbow.loading_middle"
+   "item.crossbow.loading_start"
+   "item.crossbow.quick_charge_1"
+   "item.crossbow.quick_charge_2"
+   "item.crossbow.quick_charge_3"
+   "item.crossbow.shoot"
+   "block.dispenser.dispense"
+   "block.dispenser.fail"
+   "block.dispenser.launch"
+   "entity.dolphin.ambient"
+   "entity.dolphin.ambient_water"
+   "entity.dolphin.attack"
+   "entity.dolphin.death"
+   "entity.dolphin.eat"
+   "entity.dolphin.hurt"
+   "entity.dolphin.jump"
+   "entity.dolphin.play"
+   "entity.dolphin.splash"
+   "entity.dolphin.swim"
+   "entity.donkey.ambient"
+   "entity.donkey.angry"
+   "entity.donkey.chest"
+   "entity.donkey.death"
+   "entity.donkey.hurt"
+   "entity.drowned.ambient"
+   "entity.drowned.ambient_water"
+   "entity.drowned.death"
+   "entity.drowned.death_water"
+   "entity.drowned.hurt"
+   "entity.drowned.hurt_water"
+   "entity.drowned.shoot"
+   "entity.drowned.step"
+   "entity.drowned.swim"
+   "entity.egg.throw"
+   "entity.elder_guardian.ambient"
+   "entity.elder_guardian.ambient_land"
+   "entity.elder_guardian.curse"
+   "entity.elder_guardian.death"
+   "entity.elder_guardian.death_land"
+   "entity.elder_guardian.flop"
+   "entity.elder_guardian.hurt"
+   "entity.elder_guardian.hurt_land"
+   "item.elytra.flying"
+   "block.enchantment_table.use"
+   "block.ender_chest.close"
+   "block.ender_chest.open"
+   "entity.ender_dragon.ambient"
+   "entity.ender_dragon.death"
+   "entity.dragon_fireball.explode"
+   "entity.ender_dragon.flap"
+   "entity.ender_dragon.growl"
+   "entity.ender_dragon.hurt"
+   "entity.ender_dragon.shoot"
+   "entity.ender_eye.death"
+   "entity.ender_eye.launch"
+   "entity.enderman.ambient"
+   "entity.enderman.death"
+   "entity.enderman.hurt"
+   "entity.enderman.scream"
+   "entity.enderman.stare"
+   "entity.enderman.teleport"
+   "entity.endermite.ambient"
+   "entity.endermite.death"
+   "entity.endermite.hurt"
+   "entity.endermite.step"
+   "entity.ender_pearl.throw"
+   "block.end_gateway.spawn"
+   "block.end_portal_frame.fill"
+   "block.end_portal.spawn"
+   "entity.evoker.ambient"
+   "entity.evoker.cast_spell"
+   "entity.evoker.celebrate"
+   "entity.evoker.death"
+   "entity.evoker_fangs.attack"
+   "entity.evoker.hurt"
+   "entity.evoker.prepare_attack"
+   "entity.evoker.prepare_summon"
+   "entity.evoker.prepare_wololo"
+   "entity.experience_bottle.throw"
+   "entity.experience_orb.pickup"
+   "block.fence_gate.close"
+   "block.fence_gate.open"
+   "item.firecharge.use"
+   "entity.firework_rocket.blast"
+   "entity.firework_rocket.blast_far"
+   "entity.firework_rocket.large_blast"
+   "entity.firework_rocket.large_blast_far"
+   "entity.firework_rocket.launch"
+   "entity.firework_rocket.shoot"
+   "entity.firework_rocket.twinkle"
+   "entity.firework_rocket.twinkle_far"
+   "block.fire.ambient"
+   "block.fire.extinguish"
+   "entity.fish.swim"
+   "item.flintandsteel.use"
+   "entity.fox.aggro"
+   "entity.fox.ambient"
+   "entity.fox.bite"
+   "entity.fox.death"
+   "entity.fox.eat"
+   "entity.fox.hurt"
+   "entity.fox.screech"
+   "entity.fox.sleep"
+   "entity.fox.sniff"
+   "entity.fox.spit"
+   "block.furnace.fire_crackle"
+   "entity.generic.big_fall"
+   "entity.generic.burn"
+   "entity.generic.death"
+   "entity.generic.drink"
+   "entity.generic.eat"
+   "entity.generic.explode"
+   "entity.generic.extinguish_fire"
+   "entity.generic.hurt"
+   "entity.generic.small_fall"
+   "entity.generic.splash"
+   "entity.generic.swim"
+   "entity.ghast.ambient"
+   "entity.ghast.death"
+   "entity.ghast.hurt"
+   "entity.ghast.scream"
+   "entity.ghast.shoot"
+   "entity.ghast.warn"
+   "block.glass.break"
+   "block.glass.fall"
+   "block.glass.hit"
+   "block.glass.place"
+   "block.glass.step"
+   "block.grass.break"
+   "block.grass.fall"
+   "block.grass.hit"
+   "block.grass.place"
+   "block.grass.step"
+   "block.gravel.break"
+   "block.gravel.fall"
+   "block.gravel.hit"
+   "block.gravel.place"
+   "block.gravel.step"
+   "block.grindstone.use"
+   "entity.guardian.ambient"
+   "entity.guardian.ambient_land"
+   "entity.guardian.attack"
+   "entity.guardian.death"
+   "entity.guardian.death_land"
+   "entity.guardian.flop"
+   "entity.guardian.hurt"
+   "entity.guardian.hurt_land"
+   "item.hoe.till"
+   "block.honey_block.break"
+   "block.honey_block.fall"
+   "block.honey_block.hit"
+   "block.honey_block.place"
+   "block.honey_block.slide"
+   "block.honey_block.step"
+   "item.honey_bottle.drink"
+   "entity.horse.ambient"
+   "entity.horse.angry"
+   "entity.horse.armor"
+   "entity.horse.breathe"
+   "entity.horse.death"
+   "entity.horse.eat"
+   "entity.horse.gallop"
+   "entity.horse.hurt"
+   "entity.horse.jump"
+   "entity.horse.land"
+   "entity.horse.saddle"
+   "entity.horse.step"
+   "entity.horse.step_wood"
+   "entity.hostile.big_fall"
+   "entity.hostile.death"
+   "entity.hostile.hurt"
+   "entity.hostile.small_fall"
+   "entity.hostile.splash"
+   "entity.hostile.swim"
+   "entity.husk.ambient"
+   "entity.husk.converted_to_zombie"
+   "entity.husk.death"
+   "entity.husk.hurt"
+   "entity.husk.step"
+   "entity.ravager.ambient"
+   "entity.ravager.attack"
+   "entity.ravager.celebrate"
+   "entity.ravager.death"
+   "entity.ravager.hurt"
+   "entity.ravager.step"
+   "entity.ravager.stunned"
+   "entity.ravager.roar"
+   "entity.illusioner.ambient"
+   "entity.illusioner.cast_spell"
+   "entity.illusioner.death"
+   "entity.illusioner.hurt"
+   "entity.illusioner.mirror_move"
+   "entity.illusioner.prepare_blindness"
+   "entity.illusioner.prepare_mirror"
+   "block.iron_door.close"
+   "block.iron_door.open"
+   "entity.iron_golem.attack"
+   "entity.iron_golem.damage"
+   "entity.iron_golem.death"
+   "entity.iron_golem.hurt"
+   "entity.iron_golem.repair"
+   "entity.iron_golem.step"
+   "block.iron_trapdoor.close"
+   "block.iron_trapdoor.open"
+   "entity.item_frame.add_item"
+   "entity.item_frame.break"
+   "entity.item_frame.place"
+   "entity.item_frame.remove_item"
+   "entity.item_frame.rotate_item"
+   "entity.item.break"
+   "entity.item.pickup"
+   "block.ladder.break"
+   "block.ladder.fall"
+   "block.ladder.hit"
+   "block.ladder.place"
+   "block.ladder.step"
+   "block.lantern.break"
+   "block.lantern.fall"
+   "block.lantern.hit"
+   "block.lantern.place"
+   "block.lantern.step"
+   "block.lava.ambient"
+   "block.lava.extinguish"
+   "block.lava.pop"
+   "entity.leash_knot.break"
+   "entity.leash_knot.place"
+   "block.lever.click"
+   "entity.lightning_bolt.impact"
+   "entity.lightning_bolt.thunder"
+   "entity.lingering_potion.throw"
+   "entity.llama.ambient"
+   "entity.llama.angry"
+   "entity.llama.chest"
+   "entity.llama.death"
+   "entity.llama.eat"
+   "entity.llama.hurt"
+   "entity.llama.spit"
+   "entity.llama.step"
+   "entity.llama.swag"
+   "entity.magma_cube.death"
+   "entity.magma_cube.hurt"
+   "entity.magma_cube.jump"
+   "entity.magma_cube.squish"
+   "block.metal.break"
+   "block.metal.fall"
+   "block.metal.hit"
+   "block.metal.place"
+   "block.metal_pressure_plate.click_off"
+   "block.metal_pressure_plate.click_on"
+   "block.metal.step"
+   "entity.minecart.inside"
+   "entity.minecart.riding"
+   "entity.mooshroom.convert"
+   "entity.mooshroom.eat"
+   "entity.mooshroom.milk"
+   "entity.mooshroom.suspicious_milk"
+   "entity.mooshroom.shear"
+   "entity.mule.ambient"
+   "entity.mule.chest"
+   "entity.mule.death"
+   "entity.mule.hurt"
+   "music.creative"
+   "music.credits"
+   "music.dragon"
+   "music.end"
+   "music.game"
+   "music.menu"
+   "music.nether"
+   "music.under_water"
+   "block.nether_wart.break"
+   "item.nether_wart.plant"
+   "block.note_block.basedrum"
+   "block.note_block.bass"
+   "block.note_block.bell"
+   "block.note_block.chime"
+   "block.note_block.flute"
+   "block.note_block.guitar"
+   "block.note_block.harp"
+   "block.note_block.hat"
+   "block.note_block.pling"
+   "block.note_block.snare"
+   "block.note_block.xylophone"
+   "block.note_block.iron_xylophone"
+   "block.note_block.cow_bell"
+   "block.note_block.didgeridoo"
+   "block.note_block.bit"
+   "block.note_block.banjo"
+   "entity.ocelot.hurt"
+   "entity.ocelot.ambient"
+   "entity.ocelot.death"
+   "entity.painting.break"
+   "entity.painting.place"
+   "entity.panda.pre_sneeze"
+   "entity.panda.sneeze"
+   "entity.panda.ambient"
+   "entity.panda.death"
+   "entity.panda.eat"
+   "entity.panda.step"
+   "entity.panda.cant_breed"
+   "entity.panda.aggressive_ambient"
+   "entity.panda.worried_ambient"
+   "entity.panda.hurt"
+   "entity.panda.bite"
+   "entity.parrot.ambient"
+   "entity.parrot.death"
+   "entity.parrot.eat"
+   "entity.parrot.fly"
+   "entity.parrot.hurt"
+   "entity.parrot.imitate.blaze"
+   "entity.parrot.imitate.creeper"
+   "entity.parrot.imitate.drowned"
+   "entity.parrot.imitate.elder_guardian"
+   "entity.parrot.imitate.ender_dragon"
+   "entity.parrot.imitate.enderman"
+   "entity.parrot.imitate.endermite"
+   "entity.parrot.imitate.evoker"
+   "entity.parrot.imitate.ghast"
+   "entity.parrot.imitate.guardian"
+   "entity.parrot.imitate.husk"
+   "entity.parrot.imitate.illusioner"
+   "entity.parrot.imitate.magma_cube"
+   "entity.parrot.imitate.panda"
+   "entity.parrot.imitate.phantom"
+   "entity.parrot.imitate.pillager"
+   "entity.parrot.imitate.polar_bear"
+   "entity.parrot.imitate.ravager"
+   "entity.parrot.imitate.shulker"
+   "entity.parrot.imitate.silverfish"
+   "entity.parrot.imitate.skeleton"
+   "entity.parrot.imitate.slime"
+   "entity.parrot.imitate.spider"
+   "entity.parrot.imitate.stray"
+   "entity.parrot.imitate.vex"
+   "entity.parrot.imitate.vindicator"
+   "entity.parrot.imitate.witch"
+   "entity.parrot.imitate.wither"
+   "entity.parrot.imitate.wither_skeleton"
+   "entity.parrot.imitate.wolf"
+   "entity.parrot.imitate.zombie"
+   "entity.parrot.imitate.zombie_pigman"
+   "entity.parrot.imitate.zombie_villager"
+   "entity.parrot.step"
+   "entity.phantom.ambient"
+   "entity.phantom.bite"
+   "entity.phantom.death"
+   "entity.phantom.flap"
+   "entity.phantom.hurt"
+   "entity.phantom.swoop"
+   "entity.pig.ambient"
+   "entity.pig.death"
+   "entity.pig.hurt"
+   "entity.pig.saddle"
+   "entity.pig.step"
+   "entity.pillager.ambient"
+   "entity.pillager.celebrate"
+   "entity.pillager.death"
+   "entity.pillager.hurt"
+   "block.piston.contract"
+   "block.piston.extend"
+   "entity.player.attack.crit"
+   "entity.player.attack.knockback"
+   "entity.player.attack.nodamage"
+   "entity.player.attack.strong"
+   "entity.player.attack.sweep"
+   "entity.player.attack.weak"
+   "entity.player.big_fall"
+   "entity.player.breath"
+   "entity.player.burp"
+   "entity.player.death"
+   "entity.player.hurt"
+   "entity.player.hurt_drown"
+   "entity.player.hurt_on_fire"
+   "entity.player.hurt_sweet_berry_bush"
+   "entity.player.levelup"
+   "entity.player.small_fall"
+   "entity.player.splash"
+   "entity.player.splash.high_speed"
+   "entity.player.swim"
+   "entity.polar_bear.ambient"
+   "entity.polar_bear.ambient_baby"
+   "entity.polar_bear.death"
+   "entity.polar_bear.hurt"
+   "entity.polar_bear.step"
+   "entity.polar_bear.warning"
+   "block.portal.ambient"
+   "block.portal.travel"
+   "block.portal.trigger"
+   "entity.puffer_fish.ambient"
+   "entity.puffer_fish.blow_out"
+   "entity.puffer_fish.blow_up"
+   "entity.puffer_fish.death"
+   "entity.puffer_fish.flop"
+   "entity.puffer_fish.hurt"
+   "entity.puffer_fish.sting"
+   "block.pumpkin.carve"
+   "entity.rabbit.ambient"
+   "entity.rabbit.attack"
+   "entity.rabbit.death"
+   "entity.rabbit.hurt"
+   "entity.rabbit.jump"
+   "event.raid.horn"
+   "music_disc.11"
+   "music_disc.13"
+   "music_disc.blocks"
+   "music_disc.cat"
+   "music_disc.chirp"
+   "music_disc.far"
+   "music_disc.mall"
+   "music_disc.mellohi"
+   "music_disc.stal"
+   "music_disc.strad"
+   "music_disc.wait"
+   "music_disc.ward"
+   "block.redstone_torch.burnout"
+   "entity.salmon.ambient"
+   "entity.salmon.death"
+   "entity.salmon.flop"
+   "entity.salmon.hurt"
+   "block.sand.break"
+   "block.sand.fall"
+   "block.sand.hit"
+   "block.sand.place"
+   "block.sand.step"
+   "block.scaffolding.break"
+   "block.scaffolding.fall"
+   "block.scaffolding.hit"
+   "block.scaffolding.place"
+   "block.scaffolding.step"
+   "entity.sheep.ambient"
+   "entity.sheep.death"
+   "entity.sheep.hurt"
+   "entity.sheep.shear"
+   "entity.sheep.step"
+   "item.shield.block"
+   "item.shield.break"
+   "item.shovel.flatten"
+   "entity.shulker.ambient"
+   "block.shulker_box.close"
+   "block.shulker_box.open"
+   "entity.shulker_bullet.hit"
+   "entity.shulker_bullet.hurt"
+   "entity.shulker.close"
+   "entity.shulker.death"
+   "entity.shulker.hurt"
+   "entity.shulker.hurt_closed"
+   "entity.shulker.open"
+   "entity.shulker.shoot"
+   "entity.shulker.teleport"
+   "entity.silverfish.ambient"
+   "entity.silverfish.death"
+   "entity.silverfish.hurt"
+   "entity.silverfish.step"
+   "entity.skeleton.ambient"
+   "entity.skeleton.death"
+   "entity.skeleton_horse.ambient"
+   "entity.skeleton_horse.death"
+   "entity.skeleton_horse.hurt"
+   "entity.skeleton_horse.swim"
+   "entity.skeleton_horse.ambient_water"
+   "entity.skeleton_horse.gallop_water"
+   "entity.skeleton_horse.jump_water"
+   "entity.skeleton_horse.step_water"
+   "entity.skeleton.hurt"
+   "entity.skeleton.shoot"
+   "entity.skeleton.step"
+   "entity.slime.attack"
+   "entity.slime.death"
+   "entity.slime.hurt"
+   "entity.slime.jump"
+   "entity.slime.squish"
+   "block.slime_block.break"
+   "block.slime_block.fall"
+   "block.slime_block.hit"
+   "block.slime_block.place"
+   "block.slime_block.step"
+   "entity.magma_cube.death_small"
+   "entity.magma_cube.hurt_small"
+   "entity.magma_cube.squish_small"
+   "entity.slime.death_small"
+   "entity.slime.hurt_small"
+   "entity.slime.jump_small"
+   "entity.slime.squish_small"
+   "block.smoker.smoke"
+   "entity.snowball.throw"
+   "block.snow.break"
+   "block.snow.fall"
+   "entity.snow_golem.ambient"
+   "entity.snow_golem.death"
+   "entity.snow_golem.hurt"
+   "entity.snow_golem.shoot"
+   "block.snow.hit"
+   "block.snow.place"
+   "block.snow.step"
+   "entity.spider.ambient"
+   "entity.spider.death"
+   "entity.spider.hurt"
+   "entity.spider.step"
+   "entity.splash_potion.break"
+   "entity.splash_potion.throw"
+   "entity.squid.ambient"
+   "entity.squid.death"
+   "entity.squid.hurt"
+   "entity.squid.squirt"
+   "block.stone.break"
+   "block.stone_button.click_off"
+   "block.stone_button.click_on"
+   "block.stone.fall"
+   "block.stone.hit"
+   "block.stone.place"
+   "block.stone_pressure_plate.click_off"
+   "block.stone_pressure_plate.click_on"
+   "block.stone.step"
+   "entity.stray.ambient"
+   "entity.stray.death"
+   "entity.stray.hurt"
+   "entity.stray.step"
+   "block.sweet_berry_bush.break"
+   "block.sweet_berry_bush.place"
+   "item.sweet_berries.pick_from_bush"
+   "enchant.thorns.hit"
+   "entity.tnt.primed"
+   "item.totem.use"
+   "item.trident.hit"
+   "item.trident.hit_ground"
+   "item.trident.return"
+   "item.trident.riptide_1"
+   "item.trident.riptide_2"
+   "item.trident.riptide_3"
+   "item.trident.throw"
+   "item.trident.thunder"
+   "block.tripwire.attach"
+   "block.tripwire.click_off"
+   "block.tripwire.click_on"
+   "block.tripwire.detach"
+   "entity.tropical_fish.ambient"
+   "entity.tropical_fish.death"
+   "entity.tropical_fish.flop"
+   "entity.tropical_fish.hurt"
+   "entity.turtle.ambient_land"
+   "entity.turtle.death"
+   "entity.turtle.death_baby"
+   "entity.turtle.egg_break"
+   "entity.turtle.egg_crack"
+   "entity.turtle.egg_hatch"
+   "entity.turtle.hurt"
+   "entity.turtle.hurt_baby"
+   "entity.turtle.lay_egg"
+   "entity.turtle.shamble"
+   "entity.turtle.shamble_baby"
+   "entity.turtle.swim"
+   "ui.button.click"
+   "ui.loom.select_pattern"
+   "ui.loom.take_result"
+   "ui.cartography_table.take_result"
+   "ui.stonecutter.take_result"
+   "ui.stonecutter.select_recipe"
+   "ui.toast.challenge_complete"
+   "ui.toast.in"
+   "ui.toast.out"
+   "entity.vex.ambient"
+   "entity.vex.charge"
+   "entity.vex.death"
+   "entity.vex.hurt"
+   "entity.villager.ambient"
+   "entity.villager.celebrate"
+   "entity.villager.death"
+   "entity.villager.hurt"
+   "entity.villager.no"
+   "entity.villager.trade"
+   "entity.villager.yes"
+   "entity.villager.work_armorer"
+   "entity.villager.work_butcher"
+   "entity.villager.work_cartographer"
+   "entity.villager.work_cleric"
+   "entity.villager.work_farmer"
+   "entity.villager.work_fisherman"
+   "entity.villager.work_fletcher"
+   "entity.villager.work_leatherworker"
+   "entity.villager.work_librarian"
+   "entity.villager.work_mason"
+   "entity.villager.work_shepherd"
+   "entity.villager.work_toolsmith"
+   "entity.villager.work_weaponsmith"
+   "entity.vindicator.ambient"
+   "entity.vindicator.celebrate"
+   "entity.vindicator.death"
+   "entity.vindicator.hurt"
+   "entity.wandering_trader.ambient"
+   "entity.wandering_trader.death"
+   "entity.wandering_trader.disappeared"
+   "entity.wandering_trader.drink_milk"
+   "entity.wandering_trader.drink_potion"
+   "entity.wandering_trader.hurt"
+   "entity.wandering_trader.no"
+   "entity.wandering_trader.reappeared"
+   "entity.wandering_trader.trade"
+   "entity.wandering_trader.yes"
+   "block.water.ambient"
+   "weather.rain"
+   "weather.rain.above"
+   "block.wet_grass.break"
+   "block.wet_grass.fall"
+   "block.wet_grass.hit"
+   "block.wet_grass.place"
+   "block.wet_grass.step"
+   "entity.witch.ambient"
+   "entity.witch.celebrate"
+   "entity.witch.death"
+   "entity.witch.drink"
+   "entity.witch.hurt"
+   "entity.witch.throw"
+   "entity.wither.ambient"
+   "entity.wither.break_block"
+   "entity.wither.death"
+   "entity.wither.hurt"
+   "entity.wither.shoot"
+   "entity.wither_skeleton.ambient"
+   "entity.wither_skeleton.death"
+   "entity.wither_skeleton.hurt"
+   "entity.wither_skeleton.step"
+   "entity.wither.spawn"
+   "entity.wolf.ambient"
+   "entity.wolf.death"
+   "entity.wolf.growl"
+   "entity.wolf.howl"
+   "entity.wolf.hurt"
+   "entity.wolf.pant"
+   "entity.wolf.shake"
+   "entity.wolf.step"
+   "entity.wolf.whine"
+   "block.wooden_door.close"
+   "block.wooden_door.open"
+   "block.wooden_trapdoor.close"
+   "block.wooden_trapdoor.open"
+   "block.wood.break"
+   "block.wooden_button.click_off"
+   "block.wooden_button.click_on"
+   "block.wood.fall"
+   "block.wood.hit"
+   "block.wood.place"
+   "block.wooden_pressure_plate.click_off"
+   "block.wooden_pressure_plate.click_on"
+   "block.wood.step"
+   "entity.zombie.ambient"
+   "entity.zombie.attack_wooden_door"
+   "entity.zombie.attack_iron_door"
+   "entity.zombie.break_wooden_door"
+   "entity.zombie.converted_to_drowned"
+   "entity.zombie.death"
+   "entity.zombie.destroy_egg"
+   "entity.zombie_horse.ambient"
+   "entity.zombie_horse.death"
+   "entity.zombie_horse.hurt"
+   "entity.zombie.hurt"
+   "entity.zombie.infect"
+   "entity.zombie_pigman.ambient"
+   "entity.zombie_pigman.angry"
+   "entity.zombie_pigman.death"
+   "entity.zombie_pigman.hurt"
+   "entity.zombie.step"
+   "entity.zombie_villager.ambient"
+   "entity.zombie_villager.converted"
+   "entity.zombie_villager.cure"
+   "entity.zombie_villager.death"
+   "entity.zombie_villager.hurt"
+   "entity.zombie_villager.step"])
+
+;; The enchantments in id order
+(def enchantments
+  ["protection"
+   "fire_protection"
+   "feather_falling"
+   "blast_protection"
+   "projectile_protection"
+   "respiration"
+   "aqua_affinity"
+   "thorns"
+   "depth_strider"
+   "frost_walker"
+   "binding_curse"
+   "sharpness"
+   "smite"
+   "bane_of_arthropods"
+   "knockback"
+   "fire_aspect"
+   "looting"
+   "sweeping"
+   "efficiency"
+   "silk_touch"
+   "unbreaking"
+   "fortune"
+   "power"
+   "punch"
+   "flame"
+   "infinity"
+   "luck_of_the_sea"
+   "lure"
+   "loyalty"
+   "impaling"
+   "riptide"
+   "channeling"
+   "multishot"
+   "quick_charge"
+   "piercing"
+   "mending"
+   "vanishing_curse"])
+
+;; The biomes, as [id name]
+(def biomes
+  [[0 "ocean"]
+   [1 "plains"]
+   [2 "desert"]
+   [3 "mountains"]
+   [4 "forest"]
+   [5 "taiga"]
+   [6 "swamp"]
+   [7 "river"]
+   [8 "nether"]
+   [9 "the_end"]
+   [10 "frozen_ocean"]
+   [11 "frozen_river"]
+   [12 "snowy_tundra"]
+   [13 "snowy_mountains"]
+   [14 "mushroom_fields"]
+   [15 "mushroom_field_shore"]
+   [16 "beach"]
+   [17 "desert_hills"]
+   [18 "wooded_hills"]
+   [19 "taiga_hills"]
+   [20 "mountain_edge"]
+   [21 "jungle"]
+   [22 "jungle_hills"]
+   [23 "jungle_edge"]
+   [24 "deep_ocean"]
+   [25 "stone_shore"]
+   [26 "snowy_beach"]
+   [27 "birch_forest"]
+   [28 "birch_forest_hills"]
+   [29 "dark_forest"]
+   [30 "snowy_taiga"]
+   [31 "snowy_taiga_hills"]
+   [32 "giant_tree_taiga"]
+   [33 "giant_tree_taiga_hills"]
+   [34 "wooded_mountains"]
+   [35 "savanna"]
+   [36 "savanna_plateau"]
+   [37 "badlands"]
+   [38 "wooded_badlands_plateau"]
+   [39 "badlands_plateau"]
+   [40 "small_end_islands"]
+   [41 "end_midlands"]
+   [42 "end_highlands"]
+   [43 "end_barrens"]
+   [44 "warm_ocean"]
+   [45 "lukewarm_ocean"]
+   [46 "cold_ocean"]
+   [47 "deep_warm_ocean"]
+   [48 "deep_lukewarm_ocean"]
+   [49 "deep_cold_ocean"]
+   [50 "deep_frozen_ocean"]
+   [127 "the_void"]
+   [129 "sunflower_plains"]
+   [130 "desert_lakes"]
+   [131 "gravelly_mountains"]
+   [132 "flower_forest"]
+   [133 "taiga_mountains"]
+   [134 "swamp_hills"]
+   [140 "ice_spikes"]
+   [149 "modified_jungle"]
+   [151 "modified_jungle_edge"]
+   [155 "tall_birch_forest"]
+   [156 "tall_birch_hills"]
+   [157 "dark_forest_hills"]
+   [158 "snowy_taiga_mountains"]
+   [160 "giant_spruce_taiga"]
+   [161 "giant_spruce_taiga_hills"]
+   [162 "modified_gravelly_mountains"]
+   [163 "shattered_savanna"]
+   [164 "shattered_savanna_plateau"]
+   [165 "eroded_badlands"]
+   [166 "modified_wooded_badlands_plateau"]
+   [167 "modified_badlands_plateau"]
+   [168 "bamboo_jungle"]
+   [169 "bamboo_jungle_hills"]])
@@ -0,0 +1,395 @@
+//! Teams and the scoreboard
+//!
+//! The Teams packet is decoded into a `TeamsAction` for one of its five
+//! modes. `Scoreboard` keeps track of the objectives, scores, teams and
+//! display slots by applying the Teams, ScoreboardObjective, UpdateScore and
+//! DisplayScoreboard packets, which is what's needed to read sidebars and
+//! team membership.
+
+use clientbound::{ClientboundPacket, DisplayScoreboard, ScoreboardObjective, Teams,
+                  UpdateScore};
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Cursor, Read};
+
+/// The DisplayScoreboard position of the player list
+pub const DISPLAY_LIST: u8 = 0;
+/// The DisplayScoreboard position of the sidebar
+pub const DISPLAY_SIDEBAR: u8 = 1;
+/// The DisplayScoreboard position below players' names
+pub const DISPLAY_BELOW_NAME: u8 = 2;
+
+/// The properties of a team, sent when creating or updating it
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamInfo {
+    /// The display name, as a json chat message
+    pub display_name: String,
+    /// The raw friendly flags, 0x01 allows friendly fire and 0x02 lets
+    /// players see invisible teammates
+    pub friendly_flags: u8,
+    /// The name tag visibility, `always`, `hideForOtherTeams`,
+    /// `hideForOwnTeam` or `never`
+    pub name_tag_visibility: String,
+    /// The collision rule, `always`, `pushOtherTeams`, `pushOwnTeam` or
+    /// `never`
+    pub collision_rule: String,
+    /// The raw team color enum, 0-15 for the chat colors, 21 for reset
+    pub color: i32,
+    /// The prefix shown before team members' names, as a json chat message
+    pub prefix: String,
+    /// The suffix shown after team members' names, as a json chat message
+    pub suffix: String,
+}
+impl TeamInfo {
+    /// Whether members of the team can hurt each other
+    pub fn allows_friendly_fire(&self) -> bool {
+        self.friendly_flags & 0x01 != 0
+    }
+
+    /// Whether members of the team can see invisible teammates
+    pub fn sees_invisible_teammates(&self) -> bool {
+        self.friendly_flags & 0x02 != 0
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        Ok(TeamInfo {
+            display_name: read_String(r)?,
+            friendly_flags: read_u8(r)?,
+            name_tag_visibility: read_String(r)?,
+            collision_rule: read_String(r)?,
+            color: read_varint(r)?,
+            prefix: read_String(r)?,
+            suffix: read_String(r)?,
+        })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_String(&self.display_name, w)?;
+        write_u8(&self.friendly_flags, w)?;
+        write_String(&self.name_tag_visibility, w)?;
+        write_String(&self.collision_rule, w)?;
+        write_varint(&self.color, w)?;
+        write_String(&self.prefix, w)?;
+        write_String(&self.suffix, w)
+    }
+}
+
+/// What a Teams packet does to the team
+#[derive(Debug, Clone, PartialEq)]
+pub enum TeamsAction {
+    /// Mode 0, create the team with the given entities as members
+    Create(TeamInfo, Vec<String>),
+    /// Mode 1, remove the team
+    Remove,
+    /// Mode 2, update the team's properties
+    Update(TeamInfo),
+    /// Mode 3, add the given entities to the team
+    AddEntities(Vec<String>),
+    /// Mode 4, remove the given entities from the team
+    RemoveEntities(Vec<String>),
+}
+
+/// The decoded contents of a Teams packet
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamsUpdate {
+    /// The name of the team
+    pub team: String,
+    /// What is done to the team
+    pub action: TeamsAction,
+}
+impl TeamsUpdate {
+    /// Decode the raw data of a Teams packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let team = read_String(&mut r)?;
+        let mode = read_u8(&mut r)?;
+        let action = match mode {
+            0 => {
+                let info = TeamInfo::read(&mut r)?;
+                TeamsAction::Create(info, read_entities(&mut r)?)
+            },
+            1 => TeamsAction::Remove,
+            2 => TeamsAction::Update(TeamInfo::read(&mut r)?),
+            3 => TeamsAction::AddEntities(read_entities(&mut r)?),
+            4 => TeamsAction::RemoveEntities(read_entities(&mut r)?),
+            _ => bail!("Teams packet had invalid mode {}", mode),
+        };
+        Ok(TeamsUpdate {
+            team,
+            action,
+        })
+    }
+
+    /// Encode into the raw data of a Teams packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_String(&self.team, &mut ret)?;
+        match self.action {
+            TeamsAction::Create(ref info, ref entities) => {
+                write_u8(&0, &mut ret)?;
+                info.write(&mut ret)?;
+                write_entities(entities, &mut ret)?;
+            },
+            TeamsAction::Remove => write_u8(&1, &mut ret)?,
+            TeamsAction::Update(ref info) => {
+                write_u8(&2, &mut ret)?;
+                info.write(&mut ret)?;
+            },
+            TeamsAction::AddEntities(ref entities) => {
+                write_u8(&3, &mut ret)?;
+                write_entities(entities, &mut ret)?;
+            },
+            TeamsAction::RemoveEntities(ref entities) => {
+                write_u8(&4, &mut ret)?;
+                write_entities(entities, &mut ret)?;
+            },
+        }
+        Ok(ret)
+    }
+}
+
+fn read_entities<R: Read>(r: &mut R) -> Result<Vec<String>> {
+    let count = read_varint(r)?;
+    if count < 0 {
+        bail!("Teams had negative entity count {}", count);
+    }
+    let mut ret = Vec::new();
+    for _ in 0..count {
+        ret.push(read_String(r)?);
+    }
+    Ok(ret)
+}
+
+fn write_entities(val: &[String], w: &mut Vec<u8>) -> Result<()> {
+    write_varint(&(val.len() as i32), w)?;
+    for x in val {
+        write_String(x, w)?;
+    }
+    Ok(())
+}
+
+/// A scoreboard objective and its scores
+#[derive(Debug, Clone, PartialEq)]
+pub struct Objective {
+    /// The unique name of the objective
+    pub name: String,
+    /// The text displayed for the objective, as a json chat message
+    pub display_name: String,
+    /// The type of the objective, `integer` or `hearts`
+    pub objective_type: String,
+    /// The scores, by entity name (player name or entity UUID)
+    pub scores: BTreeMap<String, i32>,
+}
+
+/// A team and its members
+#[derive(Debug, Clone, PartialEq)]
+pub struct Team {
+    /// The name of the team
+    pub name: String,
+    /// The properties of the team
+    pub info: TeamInfo,
+    /// The members, by entity name (player name or entity UUID)
+    pub entities: BTreeSet<String>,
+}
+
+/// Keeps track of the scoreboard objectives, scores, teams and display
+/// positions
+#[derive(Debug, Clone, Default)]
+pub struct Scoreboard {
+    objectives: BTreeMap<String, Objective>,
+    teams: BTreeMap<String, Team>,
+    display: BTreeMap<u8, String>,
+}
+impl Scoreboard {
+    /// Create a new, empty scoreboard
+    pub fn new() -> Self {
+        Scoreboard::default()
+    }
+
+    /// Apply the packet if it is a Teams, ScoreboardObjective, UpdateScore
+    /// or DisplayScoreboard packet, returning whether it was applied
+    pub fn handle_packet(&mut self, packet: &ClientboundPacket) -> Result<bool> {
+        match *packet {
+            ClientboundPacket::Teams(ref x) => self.handle_teams(x)?,
+            ClientboundPacket::ScoreboardObjective(ref x) => self.handle_objective(x)?,
+            ClientboundPacket::UpdateScore(ref x) => self.handle_score(x)?,
+            ClientboundPacket::DisplayScoreboard(ref x) => self.handle_display(x),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Apply a Teams packet
+    pub fn handle_teams(&mut self, packet: &Teams) -> Result<()> {
+        let update = packet.get_update()?;
+        match update.action {
+            TeamsAction::Create(info, entities) => {
+                for entity in &entities {
+                    self.leave_teams(entity);
+                }
+                let team = Team {
+                    name: update.team.clone(),
+                    info,
+                    entities: entities.into_iter().collect(),
+                };
+                let _ = self.teams.insert(update.team, team);
+            },
+            TeamsAction::Remove => {
+                let _ = self.teams.remove(&update.team);
+            },
+            TeamsAction::Update(info) => {
+                match self.teams.get_mut(&update.team) {
+                    Some(team) => team.info = info,
+                    None => bail!("Tried updating unknown team {}", update.team),
+                }
+            },
+            TeamsAction::AddEntities(entities) => {
+                if !self.teams.contains_key(&update.team) {
+                    bail!("Tried adding entities to unknown team {}", update.team);
+                }
+                for entity in &entities {
+                    self.leave_teams(entity);
+                }
+                if let Some(team) = self.teams.get_mut(&update.team) {
+                    team.entities.extend(entities);
+                }
+            },
+            TeamsAction::RemoveEntities(entities) => {
+                match self.teams.get_mut(&update.team) {
+                    Some(team) => {
+                        for entity in &entities {
+                            let _ = team.entities.remove(entity);
+                        }
+                    },
+                    None => bail!("Tried removing entities from unknown team {}", update.team),
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Remove the entity from any team it is in, since an entity can only be
+    /// in a single team at a time
+    fn leave_teams(&mut self, entity: &str) {
+        for team in self.teams.values_mut() {
+            let _ = team.entities.remove(entity);
+        }
+    }
+
+    /// Apply a ScoreboardObjective packet
+    pub fn handle_objective(&mut self, packet: &ScoreboardObjective) -> Result<()> {
+        let name = packet.get_name();
+        match *packet.get_mode() {
+            0 => {
+                let objective = Objective {
+                    name: name.clone(),
+                    display_name: packet.get_value().clone().unwrap_or_default(),
+                    objective_type: packet.get_objective_type().clone().unwrap_or_default(),
+                    scores: BTreeMap::new(),
+                };
+                let _ = self.objectives.insert(name.clone(), objective);
+            },
+            1 => {
+                let _ = self.objectives.remove(name);
+                self.display.retain(|_, x| x != name);
+            },
+            2 => {
+                match self.objectives.get_mut(name) {
+                    Some(objective) => {
+                        if let Some(ref x) = *packet.get_value() {
+                            objective.display_name = x.clone();
+                        }
+                        if let Some(ref x) = *packet.get_objective_type() {
+                            objective.objective_type = x.clone();
+                        }
+                    },
+                    None => bail!("Tried updating unknown objective {}", name),
+                }
+            },
+            x => bail!("ScoreboardObjective had invalid mode {}", x),
+        }
+        Ok(())
+    }
+
+    /// Apply an UpdateScore packet
+    pub fn handle_score(&mut self, packet: &UpdateScore) -> Result<()> {
+        let entity = packet.get_name();
+        let objective_name = packet.get_objective_name();
+        match (*packet.get_action(), *packet.get_value()) {
+            (0, Some(value)) => {
+                match self.objectives.get_mut(objective_name) {
+                    Some(objective) => {
+                        let _ = objective.scores.insert(entity.clone(), value);
+                    },
+                    None => bail!("Tried updating score of unknown objective {}", objective_name),
+                }
+            },
+            (1, _) => {
+                if objective_name.is_empty() {
+                    for objective in self.objectives.values_mut() {
+                        let _ = objective.scores.remove(entity);
+                    }
+                } else if let Some(objective) = self.objectives.get_mut(objective_name) {
+                    let _ = objective.scores.remove(entity);
+                }
+            },
+            (x, _) => bail!("UpdateScore had invalid action {}", x),
+        }
+        Ok(())
+    }
+
+    /// Apply a DisplayScoreboard packet
+    pub fn handle_display(&mut self, packet: &DisplayScoreboard) {
+        let position = *packet.get_position();
+        if packet.get_name().is_empty() {
+            let _ = self.display.remove(&position);
+        } else {
+            let _ = self.display.insert(position, packet.get_name().clone());
+        }
+    }
+
+    /// Get the objective with the given name, if any
+    pub fn get_objective(&self, name: &str) -> Option<&Objective> {
+        self.objectives.get(name)
+    }
+
+    /// Get all the objectives
+    pub fn get_objectives(&self) -> &BTreeMap<String, Objective> {
+        &self.objectives
+    }
+
+    /// Get the objective shown in the given display position, if any
+    pub fn get_displayed(&self, position: u8) -> Option<&Objective> {
+        self.display.get(&position).and_then(|x| self.objectives.get(x))
+    }
+
+    /// Get the entries of the sidebar, highest scores first, along with the
+    /// sidebar's objective. Entries with equal scores are ordered by name.
+    pub fn get_sidebar(&self) -> Option<(&Objective, Vec<(&str, i32)>)> {
+        let objective = self.get_displayed(DISPLAY_SIDEBAR)?;
+        let mut entries: Vec<(&str, i32)> = objective.scores
+            .iter()
+            .map(|(name, &score)| (name.as_str(), score))
+            .collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        Some((objective, entries))
+    }
+
+    /// Get the team with the given name, if any
+    pub fn get_team(&self, name: &str) -> Option<&Team> {
+        self.teams.get(name)
+    }
+
+    /// Get all the teams
+    pub fn get_teams(&self) -> &BTreeMap<String, Team> {
+        &self.teams
+    }
+
+    /// Get the team the given entity is in, if any
+    pub fn get_team_of(&self, entity: &str) -> Option<&Team> {
+        self.teams.values().find(|x| x.entities.contains(entity))
+    }
+}
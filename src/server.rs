@@ -1,36 +1,64 @@
-use ClientState;
-use clientbound::ClientboundPacket;
+use {ClientState, utils};
+use clientbound::{self, ClientboundPacket, ClientboundPluginMessage, PlayDisconnect};
 use connection::Connection;
 use errors::Result;
+use plugin::{Brand, LoginPluginResponseHandler, LoginPluginResponseRegistry,
+             PluginChannelRouter, PluginMessageHandler};
 use serverbound::ServerboundPacket;
 
-use std::net::TcpStream;
+use std::net::{SocketAddr, TcpStream};
 use std::borrow::Borrow;
+use std::time::{Duration, Instant};
+
+/// The state of automatic KeepAlives, see Server::enable_keepalive
+struct KeepAliveState {
+    interval: Duration,
+    timeout: Duration,
+    /* When the last KeepAlive was sent */
+    last_sent: Instant,
+    /* The id of the last KeepAlive sent, if it hasn't been answered */
+    pending: Option<i64>,
+    next_id: i64,
+}
 
 /// Represents a single client connection, from the point of view of a server
 pub struct Server {
     conn: Connection<ServerboundPacket, ClientboundPacket>,
+    login_plugins: LoginPluginResponseRegistry,
+    plugin_channels: PluginChannelRouter,
+    keepalive: Option<KeepAliveState>,
+    ping: Option<Duration>,
+    latency_hook: Option<Box<dyn FnMut(Duration) + Send>>,
 }
 impl Server {
     /// Create a new connection from an existing TcpStream
     pub fn from_tcpstream(stream: TcpStream) -> Result<Self> {
         Ok(Server {
                conn: Connection::from_tcpstream(stream)?,
+               login_plugins: LoginPluginResponseRegistry::new(),
+               plugin_channels: PluginChannelRouter::new(),
+               keepalive: None,
+               ping: None,
+               latency_hook: None,
            })
     }
 
     /// Try to read some packets from the client.
     ///
     /// This function is nonblocking.
+    ///
+    /// If automatic KeepAlives are enabled, this also sends them, and the
+    /// client's responses are not returned.
     pub fn read(&mut self) -> Result<Vec<ServerboundPacket>> {
+        self.update_keepalive()?;
         self.update_inbuf()?;
 
         let mut ret = Vec::new();
         loop {
-            if let Some(packet) = self.read_packet()? {
-                ret.push(packet);
-            } else {
-                break;
+            match self.read_packet()? {
+                Some(ServerboundPacket::KeepAlive(..)) if self.keepalive.is_some() => (),
+                Some(packet) => ret.push(packet),
+                None => break,
             }
         }
         Ok(ret)
@@ -52,6 +80,147 @@ impl Server {
         self.conn.write()
     }
 
+    /// Automatically send KeepAlives while in the Play state, one every
+    /// interval, and disconnect the client if one isn't answered within the
+    /// timeout. The vanilla server uses 15 and 30 seconds.
+    ///
+    /// KeepAlives are sent by read(), or by update_keepalive if you only use
+    /// read_packet.
+    pub fn enable_keepalive(&mut self, interval: Duration, timeout: Duration) {
+        self.keepalive = Some(KeepAliveState {
+            interval,
+            timeout,
+            last_sent: Instant::now(),
+            pending: None,
+            next_id: 0,
+        });
+    }
+
+    /// Stop sending KeepAlives automatically
+    pub fn disable_keepalive(&mut self) {
+        self.keepalive = None;
+    }
+
+    /// Get the latency of the connection, as measured by the last answered
+    /// automatic KeepAlive
+    pub fn get_ping(&self) -> Option<Duration> {
+        self.ping
+    }
+
+    /// Set a function to be called with the new latency every time an
+    /// automatic KeepAlive is answered, e.g. to send a PlayerListItem latency
+    /// update to every player
+    pub fn set_latency_hook<F: FnMut(Duration) + Send + 'static>(&mut self, hook: F) {
+        self.latency_hook = Some(Box::new(hook));
+    }
+
+    /// Send a KeepAlive if automatic KeepAlives are enabled and one is due.
+    ///
+    /// If the last KeepAlive hasn't been answered in time, the client is
+    /// disconnected and an error returned.
+    pub fn update_keepalive(&mut self) -> Result<()> {
+        if *self.conn.get_clientstate() != ClientState::Play {
+            return Ok(());
+        }
+        let id = match self.keepalive {
+            Some(ref mut keepalive) => {
+                let elapsed = keepalive.last_sent.elapsed();
+                match keepalive.pending {
+                    Some(_) if elapsed >= keepalive.timeout => None,
+                    Some(_) => return Ok(()),
+                    None if elapsed >= keepalive.interval => {
+                        let id = keepalive.next_id;
+                        keepalive.next_id += 1;
+                        keepalive.pending = Some(id);
+                        keepalive.last_sent = Instant::now();
+                        Some(id)
+                    },
+                    None => return Ok(()),
+                }
+            },
+            None => return Ok(()),
+        };
+        match id {
+            Some(id) => {
+                let _: usize = self.send(clientbound::KeepAlive::new(id))?;
+                Ok(())
+            },
+            None => {
+                let _ = self.send(PlayDisconnect::new(utils::str_to_chat("Timed out")));
+                let _ = self.close();
+                bail!("Timed out");
+            },
+        }
+    }
+
+    /// Send a LoginPluginRequest on the given channel to the client.
+    ///
+    /// The response will be passed to the handler registered for the channel
+    /// with register_login_plugin when it is read. Returns the message id
+    /// used for the request.
+    pub fn send_login_plugin_request(&mut self,
+                                     channel: &str,
+                                     data: Vec<u8>)
+                                     -> Result<i32> {
+        let packet = self.login_plugins.new_request(channel, data);
+        let id = match packet {
+            ClientboundPacket::LoginPluginRequest(ref p) => *p.get_id(),
+            _ => unreachable!("new_request did not return a LoginPluginRequest"),
+        };
+        let _: usize = self.send(packet)?;
+        Ok(id)
+    }
+
+    /// Register a handler for the responses to LoginPluginRequests sent on
+    /// the given channel with send_login_plugin_request.
+    pub fn register_login_plugin<H: LoginPluginResponseHandler + Send + 'static>(&mut self,
+                                                                                 channel: &str,
+                                                                                 handler: H) {
+        self.login_plugins.register(channel, handler)
+    }
+
+    /// Get whether there are LoginPluginRequests sent with
+    /// send_login_plugin_request that the client has not yet responded to.
+    ///
+    /// LoginSuccess should not be sent before all requests have been
+    /// responded to.
+    pub fn has_pending_login_plugin_requests(&self) -> bool {
+        self.login_plugins.has_pending()
+    }
+
+    /// Register a handler for plugin messages on the given channel.
+    ///
+    /// The `minecraft:register`, `minecraft:unregister` and `minecraft:brand`
+    /// channels are handled automatically. Use send_plugin_register to tell
+    /// the client about the registered channels.
+    pub fn register_plugin_channel<H: PluginMessageHandler + Send + 'static>(&mut self,
+                                                                             channel: &str,
+                                                                             handler: H) {
+        self.plugin_channels.register(channel, handler)
+    }
+
+    /// Get the plugin channel router, e.g. to see which channels the client
+    /// has registered or which brand it has sent
+    pub fn get_plugin_channels(&self) -> &PluginChannelRouter {
+        &self.plugin_channels
+    }
+
+    /// Send a `minecraft:register` message to the client, containing all the
+    /// channels registered with register_plugin_channel
+    pub fn send_plugin_register(&mut self) -> Result<usize> {
+        let register = self.plugin_channels.get_register();
+        self.send(ClientboundPluginMessage::new_encoded(&register)?)
+    }
+
+    /// Send the server's brand to the client, which is shown in the client's
+    /// debug screen
+    pub fn send_brand(&mut self, brand: &str) -> Result<usize> {
+        let brand = Brand {
+            brand: brand.to_string(),
+        };
+        self.send(ClientboundPluginMessage::new_encoded(&brand)?)
+    }
+
     /// Attempt to close this connection, disconnecting the client
     ///
     /// All future sends and reads to this connection will fail.
@@ -59,6 +228,25 @@ impl Server {
         self.conn.close()
     }
 
+    /// Get the address of the connected client.
+    ///
+    /// If the server is running behind a proxy, this will be the address of
+    /// the proxy. See the forwarding module for getting the player's real
+    /// address in that case.
+    pub fn peer_addr(&self) -> Result<SocketAddr> {
+        self.conn.peer_addr()
+    }
+
+    /// Get another handle to the TcpStream, see Connection::try_clone_stream
+    pub(crate) fn try_clone_stream(&self) -> Result<TcpStream> {
+        self.conn.try_clone_stream()
+    }
+
+    /// See Connection::is_drained
+    pub(crate) fn is_drained(&self) -> bool {
+        self.conn.is_drained()
+    }
+
     /// Change the client state of this connection
     pub fn set_clientstate(&mut self, new_state: ClientState) {
         self.conn.set_clientstate(new_state)
@@ -102,7 +290,44 @@ impl Server {
     /// You MUST be sure that server.update_inbuf() has been called before this,
     /// this function will not attempt to read from the TcpStream, only from the
     /// internal buffer.
+    ///
+    /// LoginPluginResponses to requests sent with send_login_plugin_request
+    /// and PluginMessages are passed to the registered handlers before being
+    /// returned.
+    ///
+    /// Responses to automatic KeepAlives are used to measure the ping, see
+    /// enable_keepalive.
     pub fn read_packet(&mut self) -> Result<Option<ServerboundPacket>> {
-        self.conn.read_packet()
+        let packet = self.conn.read_packet()?;
+
+        match packet {
+            Some(ServerboundPacket::LoginPluginResponse(ref p))
+                if self.login_plugins.get_pending_channel(*p.get_message_id()).is_some() => {
+                let _: String = self.login_plugins.handle_response(p)?;
+            },
+            Some(ServerboundPacket::PluginMessage(ref p)) => {
+                let _: bool = self.plugin_channels
+                    .handle_message(p.get_channel(), p.get_data())?;
+            },
+            Some(ServerboundPacket::KeepAlive(ref p)) => self.handle_keepalive(*p.get_id()),
+            _ => (),
+        }
+
+        Ok(packet)
+    }
+
+    /// Handle the client's response to an automatic KeepAlive
+    fn handle_keepalive(&mut self, id: i64) {
+        let ping = match self.keepalive {
+            Some(ref mut keepalive) if keepalive.pending == Some(id) => {
+                keepalive.pending = None;
+                keepalive.last_sent.elapsed()
+            },
+            _ => return,
+        };
+        self.ping = Some(ping);
+        if let Some(ref mut hook) = self.latency_hook {
+            hook(ping);
+        }
     }
 }
@@ -4,8 +4,12 @@
 //! about each of the packets.
 
 use connection::Packet;
+use dispatch::PacketVariant;
 use errors::Result;
+use forwarding::BungeeCordForwarding;
+use plugin::PluginCodec;
 use read::*;
+use slot::*;
 use write::*;
 use {ClientState, utils};
 
@@ -31,6 +35,22 @@ impl Handshake {
             _ => None,
         }
     }
+
+    /// Get the hostname the client connected to, without any data appended
+    /// to it by proxies or mod loaders.
+    pub fn get_hostname(&self) -> &str {
+        self.server_address.split('\0').next().unwrap_or("")
+    }
+
+    /// Parse the BungeeCord IP forwarding data contained in the server
+    /// address, if any.
+    ///
+    /// Returns Ok(None) if the server address contains no forwarding data,
+    /// i.e. if the client did not connect through a BungeeCord proxy with IP
+    /// forwarding enabled.
+    pub fn get_bungeecord_forwarding(&self) -> Result<Option<BungeeCordForwarding>> {
+        BungeeCordForwarding::parse(&self.server_address)
+    }
 }
 
 impl EncryptionResponse {
@@ -61,6 +81,38 @@ impl EncryptionResponse {
     }
 }
 
+impl LoginPluginResponse {
+    /// Create a response telling the server that the request with the given
+    /// message id was understood, responding with the given data
+    pub fn new_understood(message_id: i32, data: Vec<u8>) -> ServerboundPacket {
+        LoginPluginResponse::new(message_id, true, data)
+    }
+
+    /// Create a response telling the server that the request with the given
+    /// message id was not understood
+    pub fn new_not_understood(message_id: i32) -> ServerboundPacket {
+        LoginPluginResponse::new(message_id, false, Vec::new())
+    }
+}
+
+impl PluginMessage {
+    /// Decode the data of this plugin message using the given codec.
+    ///
+    /// Returns Ok(None) if the message is not on the codec's channel.
+    pub fn decode_data<C: PluginCodec>(&self) -> Result<Option<C>> {
+        if self.channel == C::CHANNEL {
+            Ok(Some(C::decode(&self.data)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Create a new plugin message with the payload encoded using its codec
+    pub fn new_encoded<C: PluginCodec>(payload: &C) -> Result<ServerboundPacket> {
+        Ok(PluginMessage::new(C::CHANNEL.to_string(), payload.encode()?))
+    }
+}
+
 impl StatusRequest {
     fn to_u8(&self) -> Result<Vec<u8>> {
         let mut ret = Vec::new();
@@ -186,3 +238,24 @@ impl AdvancementTab {
     }
 }
 
+
+impl ClickWindow {
+    /// Decode the clicked item, i.e. what the client expects the click to
+    /// return
+    pub fn get_clicked_item(&self) -> Result<Option<Slot>> {
+        read_slot(&mut &self.slot[..])
+    }
+
+    /// Create a new ClickWindow packet with the given clicked item
+    pub fn new_click(window_id: u8,
+                     slot_id: i16,
+                     button: i8,
+                     action: i16,
+                     mode: i32,
+                     clicked: &Option<Slot>)
+                     -> Result<ServerboundPacket> {
+        let mut slot = Vec::new();
+        write_slot(clicked, &mut slot)?;
+        Ok(ClickWindow::new(window_id, slot_id, button, action, mode, slot))
+    }
+}
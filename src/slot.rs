@@ -0,0 +1,166 @@
+//! The Slot datatype, i.e. an item stack as sent over the network
+//!
+//! Ozelot does not parse NBT, so the NBT data of a slot is kept as raw bytes.
+//! It still has to be walked to find out where it ends, which is what
+//! `read_nbt` does.
+
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::io::Read;
+
+/// The maximum nesting depth of NBT lists and compounds, same as vanilla
+const MAX_NBT_DEPTH: usize = 512;
+
+/// A non-empty slot. Empty slots are represented by None.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slot {
+    /// The item id
+    pub item_id: i32,
+    /// The number of items in the stack
+    pub count: i8,
+    /// The raw NBT data, including the root tag's type and name, if any
+    pub nbt: Option<Vec<u8>>,
+}
+impl Slot {
+    /// Create a new slot without NBT data
+    pub fn new(item_id: i32, count: i8) -> Self {
+        Slot {
+            item_id,
+            count,
+            nbt: None,
+        }
+    }
+}
+
+/// Read a single slot from the Reader, returning None for an empty slot
+pub fn read_slot<R: Read>(reader: &mut R) -> Result<Option<Slot>> {
+    if !read_bool(reader)? {
+        return Ok(None);
+    }
+    Ok(Some(Slot {
+        item_id: read_varint(reader)?,
+        count: read_i8(reader)?,
+        nbt: read_nbt(reader)?,
+    }))
+}
+
+/// Write a single slot, None being an empty slot
+pub fn write_slot(val: &Option<Slot>, writer: &mut Vec<u8>) -> Result<()> {
+    match *val {
+        Some(ref slot) => {
+            write_bool(&true, writer)?;
+            write_varint(&slot.item_id, writer)?;
+            write_i8(&slot.count, writer)?;
+            match slot.nbt {
+                Some(ref nbt) => writer.extend_from_slice(nbt),
+                None => write_u8(&0, writer)?,
+            }
+        },
+        None => write_bool(&false, writer)?,
+    }
+    Ok(())
+}
+
+/// Read a varint-prefixed array of slots
+pub fn read_slot_array<R: Read>(reader: &mut R) -> Result<Vec<Option<Slot>>> {
+    let count = read_varint(reader)?;
+    if count < 0 {
+        bail!("Slot array had negative length {}", count);
+    }
+    let mut ret = Vec::new();
+    for _ in 0..count {
+        ret.push(read_slot(reader)?);
+    }
+    Ok(ret)
+}
+
+/// Write a varint-prefixed array of slots
+pub fn write_slot_array(val: &[Option<Slot>], writer: &mut Vec<u8>) -> Result<()> {
+    write_varint(&(val.len() as i32), writer)?;
+    for x in val {
+        write_slot(x, writer)?;
+    }
+    Ok(())
+}
+
+/// Read a single named NBT tag (normally a compound) without parsing it,
+/// returning its raw bytes, or None if it is a TAG_End
+pub fn read_nbt<R: Read>(reader: &mut R) -> Result<Option<Vec<u8>>> {
+    let tag = read_u8(reader)?;
+    if tag == 0 {
+        return Ok(None);
+    }
+    let mut ret = vec![tag];
+    copy_nbt_string(reader, &mut ret)?;
+    copy_nbt_payload(tag, reader, &mut ret, 0)?;
+    Ok(Some(ret))
+}
+
+/// Copy exactly len bytes from the reader to the buffer
+fn copy_bytes<R: Read>(reader: &mut R, len: usize, buf: &mut Vec<u8>) -> Result<()> {
+    let read = reader.by_ref().take(len as u64).read_to_end(buf)?;
+    if read != len {
+        bail!("Unexpected end of NBT data");
+    }
+    Ok(())
+}
+
+fn copy_nbt_string<R: Read>(reader: &mut R, buf: &mut Vec<u8>) -> Result<()> {
+    let len = read_u16(reader)?;
+    write_u16(&len, buf)?;
+    copy_bytes(reader, len as usize, buf)
+}
+
+/// Copy a length-prefixed array with elements of the given size
+fn copy_nbt_array<R: Read>(reader: &mut R, size: usize, buf: &mut Vec<u8>) -> Result<()> {
+    let len = read_i32(reader)?;
+    if len < 0 {
+        bail!("NBT array had negative length {}", len);
+    }
+    write_i32(&len, buf)?;
+    copy_bytes(reader, len as usize * size, buf)
+}
+
+fn copy_nbt_payload<R: Read>(tag: u8,
+                             reader: &mut R,
+                             buf: &mut Vec<u8>,
+                             depth: usize)
+                             -> Result<()> {
+    if depth > MAX_NBT_DEPTH {
+        bail!("NBT data is nested too deeply");
+    }
+    match tag {
+        1 => copy_bytes(reader, 1, buf)?,
+        2 => copy_bytes(reader, 2, buf)?,
+        3 | 5 => copy_bytes(reader, 4, buf)?,
+        4 | 6 => copy_bytes(reader, 8, buf)?,
+        7 => copy_nbt_array(reader, 1, buf)?,
+        8 => copy_nbt_string(reader, buf)?,
+        9 => {
+            let element = read_u8(reader)?;
+            let len = read_i32(reader)?;
+            buf.push(element);
+            write_i32(&len, buf)?;
+            for _ in 0..len {
+                copy_nbt_payload(element, reader, buf, depth + 1)?;
+            }
+        },
+        10 => {
+            loop {
+                let tag = read_u8(reader)?;
+                buf.push(tag);
+                if tag == 0 {
+                    break;
+                }
+                copy_nbt_string(reader, buf)?;
+                copy_nbt_payload(tag, reader, buf, depth + 1)?;
+            }
+        },
+        11 => copy_nbt_array(reader, 4, buf)?,
+        12 => copy_nbt_array(reader, 8, buf)?,
+        _ => bail!("Invalid NBT tag type {}", tag),
+    }
+    Ok(())
+}
@@ -0,0 +1,168 @@
+//! The block, item, fluid and entity tags sent in the Tags packet
+//!
+//! A tag is a named set of numeric ids, e.g. `minecraft:logs` contains the
+//! ids of all the log blocks. Servers (and data packs and mods) may change
+//! which ids are in which tags, so bots should check the tags rather than
+//! hardcode lists of ids.
+//!
+//! Note that block tags contain block ids, not block state ids. Use
+//! `TagRegistry::is_block_state_in_tag` to look up a block state, such as
+//! one from chunk data.
+
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Cursor, Read, Write};
+
+/// A map from tag identifier to the ids in the tag
+pub type TagMap = BTreeMap<String, Vec<i32>>;
+
+/// The contents of a Tags packet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TagList {
+    /// The block tags, containing block ids
+    pub blocks: TagMap,
+    /// The item tags, containing item ids
+    pub items: TagMap,
+    /// The fluid tags, containing fluid ids
+    pub fluids: TagMap,
+    /// The entity tags, containing entity type ids
+    pub entities: TagMap,
+}
+impl TagList {
+    /// Decode the raw data of a Tags packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        Ok(TagList {
+            blocks: read_tag_map(&mut r)?,
+            items: read_tag_map(&mut r)?,
+            fluids: read_tag_map(&mut r)?,
+            entities: read_tag_map(&mut r)?,
+        })
+    }
+
+    /// Encode into the raw data of a Tags packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        write_tag_map(&self.blocks, &mut ret)?;
+        write_tag_map(&self.items, &mut ret)?;
+        write_tag_map(&self.fluids, &mut ret)?;
+        write_tag_map(&self.entities, &mut ret)?;
+        Ok(ret)
+    }
+}
+
+fn read_tag_map<R: Read>(r: &mut R) -> Result<TagMap> {
+    let count = read_varint(r)?;
+    let mut ret = BTreeMap::new();
+    for _ in 0..count {
+        let name = read_String(r)?;
+        let ids = read_prefixed_varintarray(r)?;
+        let _ = ret.insert(name, ids);
+    }
+    Ok(ret)
+}
+
+fn write_tag_map<W: Write>(val: &TagMap, w: &mut W) -> Result<()> {
+    write_varint(&(val.len() as i32), w)?;
+    for (name, ids) in val {
+        write_String(name, w)?;
+        write_prefixed_varintarray(ids, w)?;
+    }
+    Ok(())
+}
+
+/// The kinds of tags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TagKind {
+    Block,
+    Item,
+    Fluid,
+    Entity,
+}
+
+/// Keeps track of the tags sent by the server, for quick membership lookups
+#[derive(Debug, Clone, Default)]
+pub struct TagRegistry {
+    tags: BTreeMap<TagKind, BTreeMap<String, BTreeSet<i32>>>,
+}
+impl TagRegistry {
+    /// Create a new, empty registry
+    pub fn new() -> Self {
+        TagRegistry::default()
+    }
+
+    /// Replace all the tags with the given ones, as is done by the client
+    /// when it receives a Tags packet
+    pub fn update(&mut self, list: &TagList) {
+        self.tags.clear();
+        for &(kind, map) in &[(TagKind::Block, &list.blocks),
+                              (TagKind::Item, &list.items),
+                              (TagKind::Fluid, &list.fluids),
+                              (TagKind::Entity, &list.entities)] {
+            let sets = map.iter()
+                .map(|(name, ids)| (name.clone(), ids.iter().cloned().collect()))
+                .collect();
+            let _ = self.tags.insert(kind, sets);
+        }
+    }
+
+    /// Check whether the given id is in the given tag, e.g.
+    /// `is_tagged(TagKind::Block, 34, "minecraft:logs")`. Returns false if
+    /// the tag doesn't exist.
+    pub fn is_tagged(&self, kind: TagKind, id: i32, tag: &str) -> bool {
+        self.get_tag(kind, tag).is_some_and(|x| x.contains(&id))
+    }
+
+    /// Check whether the given block id is in the given block tag
+    pub fn is_block_in_tag(&self, block_id: i32, tag: &str) -> bool {
+        self.is_tagged(TagKind::Block, block_id, tag)
+    }
+
+    /// Check whether the block of the given block state id is in the given
+    /// block tag. `block_of` gives the block id of a block state id, as
+    /// these depend on the game version. Returns false if it gives None.
+    pub fn is_block_state_in_tag<F: Fn(i32) -> Option<i32>>(&self,
+                                                             block_of: F,
+                                                             state: i32,
+                                                             tag: &str)
+                                                             -> bool {
+        block_of(state).is_some_and(|x| self.is_block_in_tag(x, tag))
+    }
+
+    /// Check whether the given item id is in the given item tag
+    pub fn is_item_in_tag(&self, item_id: i32, tag: &str) -> bool {
+        self.is_tagged(TagKind::Item, item_id, tag)
+    }
+
+    /// Check whether the given fluid id is in the given fluid tag
+    pub fn is_fluid_in_tag(&self, fluid_id: i32, tag: &str) -> bool {
+        self.is_tagged(TagKind::Fluid, fluid_id, tag)
+    }
+
+    /// Check whether the given entity type id is in the given entity tag
+    pub fn is_entity_in_tag(&self, entity_type: i32, tag: &str) -> bool {
+        self.is_tagged(TagKind::Entity, entity_type, tag)
+    }
+
+    /// Get the ids in the given tag, if it exists
+    pub fn get_tag(&self, kind: TagKind, tag: &str) -> Option<&BTreeSet<i32>> {
+        self.tags.get(&kind).and_then(|x| x.get(tag))
+    }
+
+    /// Get the names of all the tags of the given kind containing the given
+    /// id
+    pub fn get_tags_of(&self, kind: TagKind, id: i32) -> Vec<&str> {
+        match self.tags.get(&kind) {
+            Some(map) => {
+                map.iter()
+                    .filter(|&(_, ids)| ids.contains(&id))
+                    .map(|(name, _)| name.as_str())
+                    .collect()
+            },
+            None => Vec::new(),
+        }
+    }
+}
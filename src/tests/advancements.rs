@@ -0,0 +1,89 @@
+//! Tests the advancements, i.e. the file advancements.rs
+use advancements::*;
+use clientbound::{Advancements, ClientboundPacket};
+use slot::Slot;
+
+use std::collections::BTreeMap;
+
+fn progress(criteria: &[(&str, Option<i64>)]) -> AdvancementProgress {
+    criteria.iter().map(|&(name, time)| (name.to_string(), time)).collect()
+}
+
+fn initial_data() -> AdvancementsData {
+    let root = Advancement {
+        parent: None,
+        display: Some(AdvancementDisplay {
+            title: r#"{"text":"Minecraft"}"#.to_string(),
+            description: r#"{"text":"The heart and story of the game"}"#.to_string(),
+            icon: Some(Slot::new(9, 1)),
+            frame: FrameType::Task,
+            background: Some("minecraft:textures/gui/advancements/backgrounds/stone.png".to_string()),
+            show_toast: false,
+            hidden: false,
+            x: 0.0,
+            y: 0.0,
+        }),
+        criteria: vec!["crafting_table".to_string()],
+        requirements: vec![vec!["crafting_table".to_string()]],
+    };
+    let iron = Advancement {
+        parent: Some("minecraft:story/root".to_string()),
+        display: None,
+        criteria: vec!["iron".to_string(), "gold".to_string(), "diamond".to_string()],
+        requirements: vec![vec!["iron".to_string(), "gold".to_string()],
+                           vec!["diamond".to_string()]],
+    };
+    let mut advancements = BTreeMap::new();
+    let _ = advancements.insert("minecraft:story/root".to_string(), root);
+    let _ = advancements.insert("minecraft:story/ores".to_string(), iron);
+    let mut progresses = BTreeMap::new();
+    let _ = progresses.insert("minecraft:story/root".to_string(),
+                              progress(&[("crafting_table", Some(1_500_000_000_000))]));
+    let _ = progresses.insert("minecraft:story/ores".to_string(),
+                              progress(&[("iron", None), ("gold", Some(1)), ("diamond", None)]));
+    AdvancementsData {
+        reset: true,
+        advancements,
+        removed: Vec::new(),
+        progress: progresses,
+    }
+}
+
+#[test]
+fn advancements_roundtrip() {
+    let data = initial_data();
+    match Advancements::new_advancements(&data).unwrap() {
+        ClientboundPacket::Advancements(ref x) => assert_eq!(x.get_advancements().unwrap(), data),
+        _ => panic!("Expected Advancements"),
+    }
+}
+
+#[test]
+fn advancement_tracker() {
+    let mut tracker = AdvancementTracker::new();
+    assert_eq!(tracker.apply(&initial_data()), vec!["minecraft:story/root"]);
+    assert!(!tracker.is_done("minecraft:story/ores"));
+    assert_eq!(tracker.get_criterion_time("minecraft:story/ores", "gold"), Some(1));
+    assert_eq!(tracker.get_criterion_time("minecraft:story/ores", "iron"), None);
+
+    let mut update = AdvancementsData::default();
+    let _ = update.progress.insert("minecraft:story/ores".to_string(),
+                                   progress(&[("iron", None), ("gold", Some(1)), ("diamond", Some(2))]));
+    assert_eq!(tracker.apply(&update), vec!["minecraft:story/ores"]);
+    assert_eq!(tracker.apply(&update), Vec::<String>::new());
+
+    let update = AdvancementsData {
+        removed: vec!["minecraft:story/root".to_string()],
+        ..AdvancementsData::default()
+    };
+    let _ = tracker.apply(&update);
+    assert!(tracker.get_advancement("minecraft:story/root").is_none());
+    assert_eq!(tracker.get_done(), vec!["minecraft:story/ores"]);
+
+    let reset = AdvancementsData {
+        reset: true,
+        ..AdvancementsData::default()
+    };
+    let _ = tracker.apply(&reset);
+    assert!(tracker.get_advancements().is_empty());
+}
@@ -0,0 +1,38 @@
+//! Tests the entity attributes, i.e. the file attributes.rs
+use attributes::*;
+use clientbound::{ClientboundPacket, EntityProperties};
+
+#[test]
+fn entity_properties() {
+    let mut speed = EntityProperty::new("generic.movementSpeed", 0.1);
+    speed.modifiers.push(AttributeModifier {
+        uuid: SPRINTING_SPEED_BOOST_UUID,
+        amount: 0.3,
+        operation: ModifierOperation::MultiplyTotal,
+    });
+    speed.modifiers.push(AttributeModifier {
+        uuid: 1,
+        amount: 0.4,
+        operation: ModifierOperation::MultiplyBase,
+    });
+    speed.modifiers.push(AttributeModifier {
+        uuid: 2,
+        amount: 0.1,
+        operation: ModifierOperation::Add,
+    });
+    let properties = vec![speed, EntityProperty::new("generic.maxHealth", 20.0)];
+
+    match EntityProperties::new_properties(42, &properties).unwrap() {
+        ClientboundPacket::EntityProperties(ref x) => {
+            assert_eq!(*x.get_entity_id(), 42);
+            assert_eq!(x.get_properties().unwrap(), properties);
+        },
+        _ => panic!("Expected EntityProperties"),
+    }
+
+    /* (0.1 + 0.1) * (1 + 0.4) * (1 + 0.3) */
+    let speed = &properties[0];
+    assert!((speed.get_effective_value() - 0.364).abs() < 1e-9);
+    assert_eq!(speed.get_modifier(SPRINTING_SPEED_BOOST_UUID).unwrap().amount, 0.3);
+    assert_eq!(properties[1].get_effective_value(), 20.0);
+}
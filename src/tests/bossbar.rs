@@ -0,0 +1,48 @@
+//! Tests the boss bars, i.e. the file bossbar.rs
+use bossbar::*;
+use clientbound::{BossBar, ClientboundPacket};
+
+fn unwrap(packet: ClientboundPacket) -> BossBar {
+    match packet {
+        ClientboundPacket::BossBar(x) => x,
+        _ => panic!("Expected BossBar"),
+    }
+}
+
+#[test]
+fn bossbar() {
+    let uuid = 0x0123456789abcdef0123456789abcdef;
+    let title = r#"{"text":"Wither"}"#.to_string();
+    let add = unwrap(BossBar::new_add(uuid,
+                                      title.clone(),
+                                      1.0,
+                                      BossBarColor::Purple,
+                                      BossBarDivision::Notches10,
+                                      FLAG_DARKEN_SKY)
+                         .unwrap());
+    let update = add.get_update().unwrap();
+    assert_eq!(update.uuid, uuid);
+
+    let mut tracker = BossBarTracker::new();
+    tracker.handle_bossbar(&add).unwrap();
+    let packets = vec![BossBar::new_health(uuid, 0.5),
+                       BossBar::new_title(uuid, "{\"text\":\"Boss\"}".to_string()),
+                       BossBar::new_style(uuid, BossBarColor::Red, BossBarDivision::None),
+                       BossBar::new_flags(uuid, FLAG_DARKEN_SKY | FLAG_CREATE_FOG)];
+    for packet in packets {
+        tracker.handle_bossbar(&unwrap(packet.unwrap())).unwrap();
+    }
+    assert_eq!(tracker.get_bar(uuid),
+               Some(&BossBarState {
+                   title: "{\"text\":\"Boss\"}".to_string(),
+                   health: 0.5,
+                   color: BossBarColor::Red,
+                   division: BossBarDivision::None,
+                   flags: FLAG_DARKEN_SKY | FLAG_CREATE_FOG,
+               }));
+    assert_eq!(BossBarDivision::Notches10.get_notches(), 10);
+
+    tracker.handle_bossbar(&unwrap(BossBar::new_remove(uuid).unwrap())).unwrap();
+    assert!(tracker.get_bars().is_empty());
+    assert!(tracker.handle_bossbar(&unwrap(BossBar::new_health(uuid, 0.1).unwrap())).is_err());
+}
@@ -0,0 +1,83 @@
+//! Tests the command graph, i.e. the file commands.rs
+use clientbound::{ClientboundPacket, DeclareCommands};
+use commands::*;
+
+fn node(kind: NodeKind, executable: bool, children: Vec<usize>) -> CommandNode {
+    CommandNode {
+        kind,
+        executable,
+        children,
+        redirect: None,
+        suggestions: None,
+    }
+}
+
+/// A graph with `/tp <target> <destination>`, `/time set <time>`,
+/// `/time query` and `/execute run ...` redirecting to the root
+fn example_graph() -> CommandGraph {
+    let entity = |name: &str, flags| {
+        NodeKind::Argument {
+            name: name.to_string(),
+            parser: "minecraft:entity".to_string(),
+            properties: ParserProperties::Entity(flags),
+        }
+    };
+    let mut run = node(NodeKind::Literal("run".to_string()), false, vec![]);
+    run.redirect = Some(0);
+    let mut time = node(NodeKind::Argument {
+                            name: "time".to_string(),
+                            parser: "brigadier:integer".to_string(),
+                            properties: ParserProperties::Integer(Some(0), None),
+                        },
+                        true,
+                        vec![]);
+    time.suggestions = Some("minecraft:ask_server".to_string());
+    CommandGraph {
+        nodes: vec![node(NodeKind::Root, false, vec![1, 4, 8]),
+                    node(NodeKind::Literal("tp".to_string()), false, vec![2]),
+                    node(entity("target", 0), false, vec![3]),
+                    node(entity("destination", 1), true, vec![]),
+                    node(NodeKind::Literal("time".to_string()), false, vec![5, 7]),
+                    node(NodeKind::Literal("set".to_string()), false, vec![6]),
+                    time,
+                    node(NodeKind::Literal("query".to_string()), true, vec![]),
+                    node(NodeKind::Literal("execute".to_string()), false, vec![9]),
+                    run],
+        root: 0,
+    }
+}
+
+#[test]
+fn command_graph_roundtrip() {
+    let graph = example_graph();
+    let packet = DeclareCommands::new_graph(&graph).unwrap();
+    match packet {
+        ClientboundPacket::DeclareCommands(ref x) => assert_eq!(x.get_graph().unwrap(), graph),
+        _ => panic!("Expected DeclareCommands"),
+    }
+
+    let mut broken = graph;
+    broken.nodes[1].children.push(42);
+    assert!(broken.encode().is_err());
+}
+
+#[test]
+fn command_graph_paths() {
+    assert_eq!(example_graph().get_paths(),
+               vec!["tp <target> <destination>",
+                    "time set <time>",
+                    "time query",
+                    "execute run -> /"]);
+}
+
+#[test]
+fn command_graph_complete() {
+    let graph = example_graph();
+    assert_eq!(graph.complete(""), vec!["execute", "time", "tp"]);
+    assert_eq!(graph.complete("t"), vec!["time", "tp"]);
+    assert_eq!(graph.complete("time "), vec!["query", "set"]);
+    assert_eq!(graph.complete("time s"), vec!["set"]);
+    assert_eq!(graph.complete("tp Notch "), Vec::<String>::new());
+    assert_eq!(graph.complete("execute run ti"), vec!["time"]);
+    assert_eq!(graph.complete("nope "), Vec::<String>::new());
+}
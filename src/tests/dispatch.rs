@@ -0,0 +1,82 @@
+//! Tests the packet dispatcher, i.e. the file dispatch.rs
+use clientbound::{self, ClientboundPacket, KeepAlive};
+use dispatch::*;
+use serverbound::{self, ServerboundPacket};
+
+#[test]
+fn dispatch_order_and_consume() {
+    let mut dispatcher: Dispatcher<Vec<String>, ClientboundPacket> = Dispatcher::new();
+    let _ = dispatcher.on::<clientbound::ChatMessage>(|log, p| {
+        log.push(format!("chat {}", p.get_chat()));
+        Ok(Flow::Continue)
+    });
+    let _ = dispatcher.on_with_priority::<clientbound::ChatMessage>(10, |log, p| {
+        log.push("filter".to_string());
+        if p.get_chat().contains("spam") {
+            Ok(Flow::Consume)
+        } else {
+            Ok(Flow::Continue)
+        }
+    });
+    let any = dispatcher.on_any(-10, |log, p| {
+        log.push(format!("any {}", p));
+        Ok(Flow::Continue)
+    });
+    let _ = dispatcher.on::<KeepAlive>(|log, p| {
+        log.push(format!("keepalive {}", p.get_id()));
+        Ok(Flow::Consume)
+    });
+    assert_eq!(dispatcher.len(), 4);
+
+    let packets = vec![clientbound::ChatMessage::new("hello".to_string(), 0),
+                       clientbound::ChatMessage::new("spam".to_string(), 0),
+                       KeepAlive::new(5)];
+    let mut log = Vec::new();
+    let remaining = dispatcher.dispatch_all(&mut log, packets).unwrap();
+    assert_eq!(remaining, vec![clientbound::ChatMessage::new("hello".to_string(), 0)]);
+    assert_eq!(log,
+               vec!["filter",
+                    "chat hello",
+                    "any ClientboundPacket of type ChatMessage",
+                    "filter",
+                    "keepalive 5"]);
+
+    assert!(dispatcher.remove(any));
+    assert!(!dispatcher.remove(any));
+    let mut log = Vec::new();
+    assert!(!dispatcher.dispatch(&mut log, &clientbound::ChatMessage::new("hi".to_string(), 0))
+                .unwrap());
+    assert_eq!(log, vec!["filter", "chat hi"]);
+}
+
+#[test]
+fn dispatch_errors_and_serverbound() {
+    let mut dispatcher: Dispatcher<u32, ServerboundPacket> = Dispatcher::new();
+    let _ = dispatcher.on::<serverbound::ChatMessage>(|count, p| {
+        if p.get_message().is_empty() {
+            bail!("Empty message");
+        }
+        *count += 1;
+        Ok(Flow::Continue)
+    });
+    let _ = dispatcher.on::<serverbound::ChatMessage>(|count, _| {
+        *count += 10;
+        Ok(Flow::Continue)
+    });
+
+    let mut count = 0;
+    assert!(!dispatcher.dispatch(&mut count, &serverbound::ChatMessage::new("a".to_string()))
+                .unwrap());
+    assert!(!dispatcher.dispatch(&mut count, &serverbound::KeepAlive::new(1)).unwrap());
+    assert_eq!(count, 11);
+    assert!(dispatcher.dispatch(&mut count, &serverbound::ChatMessage::new(String::new()))
+                .is_err());
+    assert_eq!(count, 11);
+}
+
+#[test]
+fn dispatcher_is_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Dispatcher<(), ClientboundPacket>>();
+    assert_send::<Dispatcher<(), ServerboundPacket>>();
+}
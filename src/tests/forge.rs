@@ -0,0 +1,54 @@
+//! Tests the Forge handshake, i.e. the file forge.rs
+use forge::*;
+use plugin::{LoginPluginHandler, LoginWrapper, PluginCodec};
+
+/// Wrap the given handshake packet like the server would, pass it to the
+/// handler and unwrap the reply
+fn exchange(forge: &mut ForgeHandshake, packet: FmlHandshakePacket) -> FmlHandshakePacket {
+    let request = LoginWrapper {
+        channel: FML_HANDSHAKE_CHANNEL.to_string(),
+        data: packet.encode().unwrap(),
+    };
+    let reply = forge.handle_request(&request.encode().unwrap()).unwrap().unwrap();
+    let reply = LoginWrapper::decode(&reply).unwrap();
+    assert_eq!(reply.channel, FML_HANDSHAKE_CHANNEL);
+    FmlHandshakePacket::decode(&reply.data).unwrap()
+}
+
+#[test]
+fn handshake() {
+    let mut forge = ForgeHandshake::new(vec!["minecraft".to_string(), "forge".to_string()]);
+    forge.add_channel("fml:handshake", "FML2");
+    forge.set_registry_hash("minecraft:item", "abc");
+    let info = forge.clone();
+
+    let modlist = FmlHandshakePacket::ModList {
+        mods: vec!["minecraft".to_string(), "forge".to_string(), "jei".to_string()],
+        channels: vec![("fml:handshake".to_string(), "FML2".to_string())],
+        registries: vec!["minecraft:block".to_string(), "minecraft:item".to_string()],
+    };
+    assert_eq!(exchange(&mut forge, modlist),
+               FmlHandshakePacket::ModListReply {
+                   mods: vec!["minecraft".to_string(), "forge".to_string()],
+                   channels: vec![("fml:handshake".to_string(), "FML2".to_string())],
+                   registries: vec![("minecraft:block".to_string(), "".to_string()),
+                                    ("minecraft:item".to_string(), "abc".to_string())],
+               });
+
+    let registry = FmlHandshakePacket::ServerRegistry {
+        name: "minecraft:block".to_string(),
+        snapshot: Some(vec![1, 2, 3]),
+    };
+    assert_eq!(exchange(&mut forge, registry), FmlHandshakePacket::Acknowledgement);
+
+    let config = FmlHandshakePacket::ConfigurationData {
+        file_name: "forge-server.toml".to_string(),
+        data: b"a = 1".to_vec(),
+    };
+    assert_eq!(exchange(&mut forge, config), FmlHandshakePacket::Acknowledgement);
+
+    let server = info.get_server_info();
+    assert_eq!(server.mods.len(), 3);
+    assert_eq!(server.registries.get("minecraft:block"), Some(&Some(vec![1, 2, 3])));
+    assert_eq!(server.configs.get("forge-server.toml"), Some(&b"a = 1".to_vec()));
+}
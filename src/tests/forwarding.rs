@@ -0,0 +1,57 @@
+//! Tests the parsing of proxy forwarding data, i.e. the file forwarding.rs
+use forwarding::*;
+use json::ProfileProperties;
+use serverbound::{Handshake, LoginPluginResponse, ServerboundPacket};
+
+#[test]
+fn bungeecord() {
+    let address = "mc.example.com\u{0}192.0.2.7\u{0}069a79f444e94726a5befca90e38aaf5\u{0}[{\"name\":\"textures\",\"value\":\"abc\",\"signature\":\"def\"}]";
+    let handshake = match Handshake::new(578, address.to_string(), 25565, 2) {
+        ServerboundPacket::Handshake(x) => x,
+        _ => unreachable!(),
+    };
+    assert_eq!(handshake.get_hostname(), "mc.example.com");
+
+    let forwarding = handshake.get_bungeecord_forwarding().unwrap().unwrap();
+    assert_eq!(forwarding.host, "mc.example.com");
+    assert_eq!(forwarding.address, "192.0.2.7");
+    assert_eq!(forwarding.uuid, 0x069a79f444e94726a5befca90e38aaf5);
+    assert_eq!(forwarding.properties.len(), 1);
+    assert_eq!(forwarding.properties[0].signature, Some("def".to_string()));
+    assert_eq!(forwarding.to_server_address().unwrap(), address);
+
+    assert!(BungeeCordForwarding::parse("mc.example.com").unwrap().is_none());
+    /* A Forge client that isn't behind a proxy */
+    let forge = "mc.example.com\u{0}FML2\u{0}";
+    assert!(BungeeCordForwarding::parse(forge).unwrap().is_none());
+    assert!(BungeeCordForwarding::parse("mc.example.com\u{0}192.0.2.7\u{0}").unwrap().is_none());
+}
+
+#[test]
+fn velocity() {
+    let secret = b"hunter2";
+    let forwarding = VelocityForwarding {
+        version: 1,
+        address: "192.0.2.7".to_string(),
+        uuid: 0x069a79f444e94726a5befca90e38aaf5,
+        username: "Notch".to_string(),
+        properties: vec![ProfileProperties {
+            name: "textures".to_string(),
+            value: "abc".to_string(),
+            signature: None,
+        }],
+    };
+    let data = forwarding.to_response_data(secret).unwrap();
+
+    let response = match LoginPluginResponse::new(3, true, data.clone()) {
+        ServerboundPacket::LoginPluginResponse(x) => x,
+        _ => unreachable!(),
+    };
+    let verified = VelocityForwarding::verify(&response, secret).unwrap();
+    assert_eq!(verified.address, forwarding.address);
+    assert_eq!(verified.uuid, forwarding.uuid);
+    assert_eq!(verified.username, forwarding.username);
+    assert_eq!(verified.properties.len(), 1);
+
+    assert!(VelocityForwarding::verify(&response, b"wrong secret").is_err());
+}
@@ -0,0 +1,110 @@
+//! Tests the inventory tracker, i.e. the file inventory.rs
+use clientbound::*;
+use inventory::*;
+use serverbound::{ClickWindow, ServerboundPacket};
+use slot::Slot;
+
+fn get_click(packet: ServerboundPacket) -> ClickWindow {
+    match packet {
+        ServerboundPacket::ClickWindow(x) => x,
+        _ => panic!("Expected ClickWindow"),
+    }
+}
+
+fn apply(inventory: &mut Inventory, packet: &ClientboundPacket) {
+    assert!(inventory.handle_packet(packet).unwrap().is_none());
+}
+
+#[test]
+fn player_inventory() {
+    let mut inventory = Inventory::new();
+    let mut items = vec![None; PLAYER_WINDOW_SIZE];
+    items[36] = Some(Slot::new(1, 64));
+    items[9] = Some(Slot::new(2, 10));
+    items[10] = Some(Slot::new(1, 3));
+    apply(&mut inventory, &WindowItems::new_items(0, &items).unwrap());
+    apply(&mut inventory, &ClientboundHeldItemChange::new(0));
+    assert_eq!(inventory.get_held_item(), Some(&Slot::new(1, 64)));
+
+    /* Picking up a stack sends what the slot contained */
+    let click = get_click(inventory.click(36, false).unwrap());
+    assert_eq!((*click.get_window_id(), *click.get_slot_id(), *click.get_button()), (0, 36, 0));
+    assert_eq!((*click.get_id(), *click.get_mode()), (1, 0));
+    assert_eq!(click.get_clicked_item().unwrap(), Some(Slot::new(1, 64)));
+    assert_eq!(inventory.get_cursor(), Some(&Slot::new(1, 64)));
+    assert!(inventory.get_held_item().is_none());
+
+    /* Right clicking an empty slot places one item */
+    let click = get_click(inventory.click(37, true).unwrap());
+    assert_eq!(*click.get_id(), 2);
+    assert_eq!(click.get_clicked_item().unwrap(), None);
+    assert_eq!(inventory.get_player_window().get_slot(37), Some(&Slot::new(1, 1)));
+    assert_eq!(inventory.get_cursor(), Some(&Slot::new(1, 63)));
+
+    /* Stacking onto the same item isn't predicted */
+    let click = get_click(inventory.click(10, false).unwrap());
+    assert_eq!(click.get_clicked_item().unwrap(), None);
+    assert_eq!(inventory.get_player_window().get_slot(10), Some(&Slot::new(1, 3)));
+
+    /* The server rejects it, gets an acknowledgement and resends */
+    let reply = inventory.handle_packet(&ClientboundConfirmTransaction::new(0, 3, false)).unwrap();
+    match reply {
+        Some(ServerboundPacket::ConfirmTransaction(x)) => {
+            assert_eq!((*x.get_window_id(), *x.get_id(), *x.get_accepted()), (0, 3, false));
+        },
+        _ => panic!("Expected ConfirmTransaction"),
+    }
+    apply(&mut inventory, &SetSlot::new_slot(0, 10, &Some(Slot::new(1, 64))).unwrap());
+    apply(&mut inventory, &SetSlot::new_slot(255, -1, &Some(Slot::new(1, 2))).unwrap());
+    assert_eq!(inventory.get_cursor(), Some(&Slot::new(1, 2)));
+    assert!(inventory.handle_packet(&ClientboundConfirmTransaction::new(0, 2, true)).unwrap().is_none());
+    assert!(inventory.has_pending());
+
+    let _ = inventory.drop_cursor(false).unwrap();
+    assert!(inventory.get_cursor().is_none());
+
+    /* Number keys swap with the hotbar */
+    let click = get_click(inventory.swap_hotbar(9, 2).unwrap());
+    assert_eq!((*click.get_button(), *click.get_mode()), (2, 2));
+    assert_eq!(inventory.get_player_window().get_slot(38), Some(&Slot::new(2, 10)));
+    assert!(inventory.get_player_window().get_slot(9).is_none());
+
+    let _ = inventory.drop(38, false).unwrap();
+    assert_eq!(inventory.get_player_window().get_slot(38), Some(&Slot::new(2, 9)));
+    let _ = inventory.drop(38, true).unwrap();
+    assert!(inventory.get_player_window().get_slot(38).is_none());
+
+    assert!(inventory.click(46, false).is_err());
+    assert!(inventory.swap_hotbar(9, 9).is_err());
+}
+
+#[test]
+fn open_window() {
+    let mut inventory = Inventory::new();
+    apply(&mut inventory, &OpenWindow::new(3, 2, "{\"text\":\"Chest\"}".to_string()));
+    let mut items = vec![None; 27 + 36];
+    items[0] = Some(Slot::new(5, 1));
+    items[27] = Some(Slot::new(6, 2));
+    apply(&mut inventory, &WindowItems::new_items(3, &items).unwrap());
+
+    /* The player's slots are at the end of the window */
+    let window = inventory.get_open_window().unwrap();
+    assert_eq!(window.get_player_slots_start(), 27);
+    assert_eq!(window.get_hotbar_start(), 54);
+    assert_eq!(inventory.get_player_window().get_slot(9), Some(&Slot::new(6, 2)));
+
+    let click = get_click(inventory.shift_click(0).unwrap());
+    assert_eq!((*click.get_window_id(), *click.get_mode(), *click.get_id()), (3, 1, 1));
+    assert_eq!(click.get_clicked_item().unwrap(), None);
+
+    let _ = inventory.swap_hotbar(27, 0).unwrap();
+    assert_eq!(inventory.get_player_window().get_slot(36), Some(&Slot::new(6, 2)));
+    assert_eq!(inventory.get_open_window().unwrap().get_slot(54), Some(&Slot::new(6, 2)));
+
+    match inventory.close_window() {
+        Some(ServerboundPacket::CloseWindow(x)) => assert_eq!(*x.get_window_id(), 3),
+        _ => panic!("Expected CloseWindow"),
+    }
+    assert!(inventory.get_open_window().is_none());
+    assert!(inventory.close_window().is_none());
+}
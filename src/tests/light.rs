@@ -0,0 +1,44 @@
+//! Tests the light data, i.e. the file light.rs
+use clientbound::{ClientboundPacket, UpdateLight};
+use light::*;
+
+#[test]
+fn update_light() {
+    let mut light = LightData::default();
+    light.set_sky_light(0, -16, 0, 15).unwrap();
+    light.set_sky_light(1, 100, 2, 7).unwrap();
+    light.set_block_light(15, 255, 15, 12).unwrap();
+    light.set_block_light(14, 255, 15, 3).unwrap();
+    light.empty_block_light_mask = 1 << 17;
+    assert!(light.set_sky_light(0, 272, 0, 1).is_err());
+    assert!(light.set_sky_light(16, 0, 0, 1).is_err());
+
+    /* Section 0 is y = -16, section 7 is y = 96..111 */
+    assert_eq!(light.get_sky_light_mask(), 1 << 0 | 1 << 7);
+    assert_eq!(light.get_block_light_mask(), 1 << 16);
+
+    let packet = match UpdateLight::new_light_data(-3, 4, &light).unwrap() {
+        ClientboundPacket::UpdateLight(x) => x,
+        _ => panic!("Expected UpdateLight"),
+    };
+    assert_eq!(*packet.get_sky_light_mask(), 1 << 0 | 1 << 7);
+    let decoded = packet.get_light_data().unwrap();
+    assert_eq!(decoded, light);
+
+    assert_eq!(decoded.get_sky_light(0, -16, 0), Some(15));
+    assert_eq!(decoded.get_sky_light(1, 100, 2), Some(7));
+    assert_eq!(decoded.get_sky_light(0, 100, 2), Some(0));
+    assert_eq!(decoded.get_sky_light(0, 50, 0), None);
+    assert_eq!(decoded.get_block_light(15, 255, 15), Some(12));
+    assert_eq!(decoded.get_block_light(14, 255, 15), Some(3));
+    assert_eq!(decoded.get_block_light(0, 0, 0), None);
+    assert_eq!(decoded.get_block_light(0, -17, 0), None);
+
+    assert!(LightData::decode(1, 0, 0, 0, &[1, 0]).is_err());
+
+    /* A user constructed array that is too short */
+    let mut short = LightData::default();
+    short.sky_light[1] = Some(vec![0xff; 16]);
+    assert_eq!(short.get_sky_light(15, 15, 15), None);
+    assert!(short.set_sky_light(15, 15, 15, 1).is_err());
+}
@@ -0,0 +1,151 @@
+//! Tests the limbo server, i.e. the file limbo.rs, with a Client connecting
+//! to it
+use clientbound::ClientboundPacket;
+use limbo::*;
+use listener::PlayerProfile;
+use serverbound::{self, StatusPing, StatusRequest};
+use world::World;
+use {Client, ClientState, PROTOCOL_VERSION, utils};
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Poll the server until an event matching the predicate, returning all
+/// events read
+fn poll_until<F: Fn(&LimboEvent) -> bool>(server: &mut LimboServer, f: F) -> Vec<LimboEvent> {
+    let timeout = Instant::now();
+    let mut ret = Vec::new();
+    loop {
+        assert!(timeout.elapsed() < Duration::from_secs(10), "{:?}", ret);
+        let events = server.poll_timeout(Some(Duration::from_millis(100))).unwrap();
+        let done = events.iter().any(&f);
+        ret.extend(events);
+        if done {
+            return ret;
+        }
+    }
+}
+
+#[test]
+fn limbo_join_and_chat() {
+    let mut world = FlatWorld::new(vec![1, 2, 2, 3]);
+    world.radius = 1;
+    let mut server = LimboServer::bind("127.0.0.1:0", world).unwrap();
+    let port = server.get_listener().local_addr().unwrap().port();
+
+    let client = thread::spawn(move || {
+        let mut client = Client::connect_unauthenticated("127.0.0.1", port, "bot").unwrap();
+        let mut world = World::new();
+        let timeout = Instant::now();
+        while world.player_position().is_none() {
+            assert!(timeout.elapsed() < Duration::from_secs(10));
+            for packet in client.read().unwrap() {
+                let _: bool = world.handle_packet(&packet).unwrap();
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(world.get_chunk_count(), 9);
+        assert_eq!(world.block_at(0, 0, 0), Some(1));
+        assert_eq!(world.block_at(-10, 2, 20), Some(2));
+        assert_eq!(world.block_at(5, 3, 5), Some(3));
+        assert_eq!(world.block_at(5, 4, 5), Some(0));
+        assert_eq!(world.player_position().unwrap().y, 4.0);
+
+        let _: usize = client.send(serverbound::ChatMessage::new("hi".to_string())).unwrap();
+        loop {
+            assert!(timeout.elapsed() < Duration::from_secs(10));
+            for packet in client.read().unwrap() {
+                if let ClientboundPacket::ChatMessage(ref p) = packet {
+                    return utils::chat_to_str(p.get_chat()).unwrap();
+                }
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    });
+
+    let events = poll_until(&mut server, |x| matches!(*x, LimboEvent::Left(..)));
+    match events[0] {
+        LimboEvent::Joined(id, ref profile) => {
+            assert_eq!(*profile, PlayerProfile::offline("bot"));
+            assert!(server.get_entity_id(id).is_none());
+        },
+        ref x => panic!("Expected Joined, got {:?}", x),
+    }
+    match *events.last().unwrap() {
+        LimboEvent::Left(_, ref profile, _) => {
+            assert_eq!(*profile, Some(PlayerProfile::offline("bot")));
+        },
+        _ => unreachable!(),
+    }
+    assert_eq!(client.join().unwrap(), "<bot> hi");
+}
+
+#[test]
+fn limbo_status() {
+    let mut server = LimboServer::bind("127.0.0.1:0", VoidWorld::new()).unwrap();
+    server.set_motd("Waiting for the backend");
+    let port = server.get_listener().local_addr().unwrap().port();
+
+    let mut client = Client::connect_tcp("127.0.0.1", port).unwrap();
+    let handshake = serverbound::Handshake::new(PROTOCOL_VERSION,
+                                                "127.0.0.1".to_string(),
+                                                port,
+                                                1);
+    let _: usize = client.send(handshake).unwrap();
+    client.set_clientstate(ClientState::Status);
+    let _: usize = client.send(StatusRequest::new_raw()).unwrap();
+    let _: usize = client.send(StatusPing::new(42)).unwrap();
+
+    let timeout = Instant::now();
+    let mut packets = Vec::new();
+    while packets.len() < 2 {
+        assert!(timeout.elapsed() < Duration::from_secs(10));
+        assert!(server.poll().unwrap().is_empty());
+        packets.extend(client.read().unwrap());
+        thread::sleep(Duration::from_millis(5));
+    }
+    match packets[0] {
+        ClientboundPacket::StatusResponse(ref p) => {
+            assert!(p.get_json().contains("Waiting for the backend"));
+            assert!(p.get_json().contains("578"));
+        },
+        ref x => panic!("Expected StatusResponse, got {:?}", x),
+    }
+    match packets[1] {
+        ClientboundPacket::StatusPong(ref p) => assert_eq!(*p.get_id(), 42),
+        ref x => panic!("Expected StatusPong, got {:?}", x),
+    }
+}
+
+#[test]
+fn limbo_keepalive_timeout() {
+    let mut server = LimboServer::bind("127.0.0.1:0", VoidWorld::new()).unwrap();
+    server.set_keepalive(Duration::from_millis(50), Duration::from_millis(200));
+    let port = server.get_listener().local_addr().unwrap().port();
+
+    let client = thread::spawn(move || {
+        let mut client = Client::connect_unauthenticated("127.0.0.1", port, "afk").unwrap();
+        /* Stop answering KeepAlives */
+        client.set_auto_handle(false);
+        let timeout = Instant::now();
+        loop {
+            assert!(timeout.elapsed() < Duration::from_secs(10));
+            for packet in client.read().unwrap() {
+                if let ClientboundPacket::PlayDisconnect(ref p) = packet {
+                    return utils::chat_to_str(p.get_reason()).unwrap();
+                }
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    });
+
+    let events = poll_until(&mut server, |x| matches!(*x, LimboEvent::Left(..)));
+    match *events.last().unwrap() {
+        LimboEvent::Left(_, Some(ref profile), ref reason) => {
+            assert_eq!(profile.username, "afk");
+            assert_eq!(reason, "Timed out");
+        },
+        ref x => panic!("Expected Left, got {:?}", x),
+    }
+    assert_eq!(client.join().unwrap(), "Timed out");
+}
@@ -0,0 +1,200 @@
+//! Tests the server listener, i.e. the file listener.rs
+use clientbound::{self, ClientboundPacket, PlayerAbilities};
+use listener::*;
+use read::{read_u128, read_varint};
+use serverbound::ServerboundPacket;
+use {Client, ClientState, utils};
+
+use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Poll the listener until an event matching the predicate, returning all
+/// events read
+fn poll_until<F: Fn(&ListenerEvent) -> bool>(listener: &mut ServerListener,
+                                            f: F)
+                                            -> Vec<ListenerEvent> {
+    let timeout = Instant::now();
+    let mut ret = Vec::new();
+    loop {
+        assert!(timeout.elapsed() < Duration::from_secs(10), "{:?}", ret);
+        let events = listener.poll_timeout(Some(Duration::from_millis(100))).unwrap();
+        let done = events.iter().any(&f);
+        ret.extend(events);
+        if done {
+            return ret;
+        }
+    }
+}
+
+/// Log in the next player to send LoginStart
+fn accept_login(listener: &mut ServerListener) -> ConnectionId {
+    let events = poll_until(listener, |x| {
+        matches!(*x, ListenerEvent::Packet(_, ServerboundPacket::LoginStart(..)))
+    });
+    let (id, name) = match *events.last().unwrap() {
+        ListenerEvent::Packet(id, ServerboundPacket::LoginStart(ref p)) => {
+            (id, p.get_name().clone())
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(listener.get(id).unwrap().get_username(), Some(name.as_str()));
+    listener.login(id, PlayerProfile::offline(&name)).unwrap();
+    let _: usize = listener.send(id, PlayerAbilities::new(0, 0.05, 0.1)).unwrap();
+    id
+}
+
+#[test]
+fn listener_login_broadcast_and_max_players() {
+    let mut listener = ServerListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    listener.set_max_players(Some(1));
+
+    let alice = thread::spawn(move || {
+        let mut client = Client::connect_unauthenticated("127.0.0.1", port, "alice").unwrap();
+        let timeout = Instant::now();
+        loop {
+            assert!(timeout.elapsed() < Duration::from_secs(10));
+            for packet in client.read().unwrap() {
+                if let ClientboundPacket::ChatMessage(ref p) = packet {
+                    return p.get_chat().clone();
+                }
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    });
+    let id = accept_login(&mut listener);
+    assert!(listener.get(id).unwrap().is_playing());
+    assert_eq!(listener.get(id).unwrap().get_profile(),
+               Some(&PlayerProfile::offline("alice")));
+    assert_eq!(listener.find_player("alice"), Some(id));
+    assert_eq!(listener.get_player_count(), 1);
+
+    /* The server is full */
+    let bob = thread::spawn(move || {
+        match Client::connect_unauthenticated("127.0.0.1", port, "bob") {
+            Ok(_) => panic!("bob logged in to a full server"),
+            Err(e) => e.to_string(),
+        }
+    });
+    let events = poll_until(&mut listener, |x| matches!(*x, ListenerEvent::Disconnected(..)));
+    match *events.last().unwrap() {
+        ListenerEvent::Disconnected(bob_id, ref reason) => {
+            assert!(bob_id != id);
+            assert_eq!(reason, "The server is full!");
+        },
+        _ => unreachable!(),
+    }
+    assert!(bob.join().unwrap().contains("The server is full!"));
+    assert_eq!(listener.get_connection_count(), 1);
+
+    /* Only players in the Play state get broadcasts */
+    let chat = utils::str_to_chat("hello");
+    listener.broadcast(clientbound::ChatMessage::new(chat.clone(), 0));
+    assert_eq!(alice.join().unwrap(), chat);
+
+    let _ = poll_until(&mut listener, |x| match *x {
+        ListenerEvent::Disconnected(x, _) => x == id,
+        _ => false,
+    });
+    assert_eq!(listener.get_player_count(), 0);
+}
+
+#[test]
+fn listener_rate_limit() {
+    let mut listener = ServerListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    listener.set_rate_limit(1, Duration::from_secs(60));
+
+    let mut first = Client::connect_tcp("127.0.0.1", port).unwrap();
+    let _ = poll_until(&mut listener, |x| matches!(*x, ListenerEvent::Connected(..)));
+    let _second = Client::connect_tcp("127.0.0.1", port).unwrap();
+    let _ = poll_until(&mut listener, |x| matches!(*x, ListenerEvent::Rejected(..)));
+    assert_eq!(listener.get_connection_count(), 1);
+
+    /* The first connection is put in the Status state by its Handshake */
+    let handshake = ::serverbound::Handshake::new(::PROTOCOL_VERSION,
+                                                  "127.0.0.1".to_string(),
+                                                  port,
+                                                  1);
+    let _: usize = first.send(handshake).unwrap();
+    let events = poll_until(&mut listener, |x| matches!(*x, ListenerEvent::Packet(..)));
+    let id = match *events.last().unwrap() {
+        ListenerEvent::Packet(id, _) => id,
+        _ => unreachable!(),
+    };
+    assert_eq!(*listener.get(id).unwrap().get_state(), ClientState::Status);
+
+    listener.clear_rate_limit();
+    let _third = Client::connect_tcp("127.0.0.1", port).unwrap();
+    let _ = poll_until(&mut listener, |x| matches!(*x, ListenerEvent::Connected(..)));
+    assert_eq!(listener.get_connection_count(), 2);
+}
+
+#[test]
+fn listener_keepalive_latency() {
+    let mut listener = ServerListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    listener.enable_keepalive(Duration::from_millis(20), Duration::from_secs(5));
+    listener.set_broadcast_latency(true);
+
+    let alice = thread::spawn(move || {
+        let mut client = Client::connect_unauthenticated("127.0.0.1", port, "alice").unwrap();
+        let timeout = Instant::now();
+        loop {
+            assert!(timeout.elapsed() < Duration::from_secs(10));
+            for packet in client.read().unwrap() {
+                if let ClientboundPacket::PlayerListItem(ref p) = packet {
+                    return p.get_data().clone();
+                }
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    });
+    let id = accept_login(&mut listener);
+    let events = poll_until(&mut listener, |x| matches!(*x, ListenerEvent::Latency(..)));
+    let ping = match *events.last().unwrap() {
+        ListenerEvent::Latency(x, ping) => {
+            assert_eq!(x, id);
+            ping
+        },
+        _ => unreachable!(),
+    };
+    assert!(ping < Duration::from_secs(5));
+    assert_eq!(listener.get_mut(id).unwrap().get_server().get_ping(), Some(ping));
+    /* The KeepAlive answers are not returned as packets */
+    assert!(!events.iter().any(|x| matches!(*x, ListenerEvent::Packet(..))));
+
+    /* Update latency, for 1 player, with alice's uuid */
+    let data = alice.join().unwrap();
+    let mut r = &data[..];
+    assert_eq!(read_varint(&mut r).unwrap(), 2);
+    assert_eq!(read_varint(&mut r).unwrap(), 1);
+    assert_eq!(read_u128(&mut r).unwrap(), utils::offline_uuid("alice"));
+}
+
+#[test]
+fn listener_poll_timeout() {
+    let mut listener = ServerListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    /* Nothing happens, so the full timeout is waited */
+    let start = Instant::now();
+    assert!(listener.poll_timeout(Some(Duration::from_millis(50))).unwrap().is_empty());
+    assert!(start.elapsed() >= Duration::from_millis(40));
+
+    /* A new connection ends the wait */
+    let connect = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        TcpStream::connect(("127.0.0.1", port)).unwrap()
+    });
+    let events = listener.poll_timeout(None).unwrap();
+    assert!(matches!(events[..], [ListenerEvent::Connected(..)]));
+    let stream = connect.join().unwrap();
+
+    /* As does the connection closing */
+    drop(stream);
+    let events = listener.poll_timeout(Some(Duration::from_secs(10))).unwrap();
+    assert!(matches!(events[..], [ListenerEvent::Disconnected(..)]));
+    assert_eq!(listener.get_connection_count(), 0);
+}
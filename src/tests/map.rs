@@ -0,0 +1,68 @@
+//! Tests the maps, i.e. the file map.rs
+use clientbound::{ClientboundPacket, Map};
+use map::*;
+
+#[test]
+fn map() {
+    let data = MapData {
+        map_id: 7,
+        scale: 2,
+        tracking_position: true,
+        locked: false,
+        icons: vec![MapIcon {
+                        icon_type: 0,
+                        x: -10,
+                        z: 20,
+                        direction: 8,
+                        display_name: None,
+                    },
+                    MapIcon {
+                        icon_type: 9,
+                        x: 127,
+                        z: -128,
+                        direction: 0,
+                        display_name: Some(r#"{"text":"Base"}"#.to_string()),
+                    }],
+        patch: Some(MapPatch {
+            columns: 2,
+            rows: 3,
+            x: 127,
+            z: 1,
+            data: vec![4, 5, 6, 7, 0, 1],
+        }),
+    };
+    let packet = match Map::new_map_data(&data).unwrap() {
+        ClientboundPacket::Map(x) => x,
+        _ => panic!("Expected Map"),
+    };
+    assert_eq!(packet.get_map_data().unwrap(), data);
+
+    let mut canvas = MapCanvas::new();
+    canvas.handle_map(&packet).unwrap();
+    assert_eq!(canvas.icons.len(), 2);
+    assert_eq!(canvas.get_color(127, 1), Some(4));
+    assert_eq!(canvas.get_color(127, 2), Some(6));
+    assert_eq!(canvas.get_color(127, 3), Some(0));
+    assert_eq!(canvas.get_color(0, 1), Some(0));
+    assert_eq!(canvas.get_color(128, 0), None);
+    assert_eq!(canvas.get_color(0, 128), None);
+
+    /* Grass, in its four shades */
+    assert_eq!(color_to_rgb(4), Some((89, 125, 39)));
+    assert_eq!(color_to_rgb(5), Some((109, 153, 48)));
+    assert_eq!(color_to_rgb(6), Some((127, 178, 56)));
+    assert_eq!(color_to_rgb(7), Some((67, 94, 29)));
+    assert_eq!(color_to_rgb(2), None);
+    assert_eq!(color_to_rgb(255), None);
+
+    let rgba = canvas.to_rgba();
+    assert_eq!(rgba.len(), MAP_SIZE * MAP_SIZE * 4);
+    let pixel = (2 * MAP_SIZE + 127) * 4;
+    assert_eq!(rgba[pixel..pixel + 4], [127, 178, 56, 255]);
+    assert_eq!(rgba[0..4], [0, 0, 0, 0]);
+    assert_eq!(canvas.to_rgb().len(), MAP_SIZE * MAP_SIZE * 3);
+
+    let mut broken = data;
+    broken.patch.as_mut().unwrap().rows = 4;
+    assert!(broken.encode().is_err());
+}
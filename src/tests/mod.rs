@@ -1 +1,24 @@
+mod advancements;
+mod attributes;
+mod bossbar;
+mod commands;
 mod datatypes;
+mod dispatch;
+mod forge;
+mod forwarding;
+mod inventory;
+mod light;
+mod limbo;
+mod listener;
+mod map;
+mod movement;
+mod pathfinding;
+mod plugin;
+mod recipes;
+mod reconnect;
+mod registry;
+mod scoreboard;
+mod tags;
+mod trading;
+mod world;
+mod worldborder;
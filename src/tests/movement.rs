@@ -0,0 +1,117 @@
+//! Tests the movement controller, i.e. the file movement.rs
+use chunk::ChunkColumn;
+use clientbound::{ClientboundPacket, Explosion, PlayerPositionAndLook};
+use movement::*;
+use serverbound::ServerboundPacket;
+use world::World;
+
+use std::time::{Duration, Instant};
+
+/// A world with a stone floor at y = 64 and a wall at x = 12
+fn get_world() -> World {
+    let mut world = World::new();
+    let mut chunk = ChunkColumn::new(0, 0);
+    for x in 0..16 {
+        for z in 0..16 {
+            chunk.set_block(x, 64, z, 1).unwrap();
+            chunk.set_block(12, 65, z, 1).unwrap();
+            chunk.set_block(12, 66, z, 1).unwrap();
+        }
+    }
+    world.insert_chunk(chunk);
+    world
+}
+
+fn teleport(movement: &mut Movement, x: f64, y: f64, z: f64, flags: u8, id: i32) {
+    let packet: ClientboundPacket = PlayerPositionAndLook::new(x, y, z, 0.0, 0.0, flags, id);
+    let reply = movement.handle_packet(&packet);
+    assert_eq!(reply.len(), 2);
+    match reply[0] {
+        ServerboundPacket::TeleportConfirm(ref x) => assert_eq!(*x.get_id(), id),
+        _ => panic!("Expected TeleportConfirm"),
+    }
+}
+
+#[test]
+fn teleport_and_updates() {
+    let mut movement = Movement::new();
+    assert!(movement.tick(None).is_none());
+
+    teleport(&mut movement, 8.5, 70.0, 8.5, 0, 1);
+    teleport(&mut movement, 1.0, -2.0, 0.0, 0x01 | 0x02, 2);
+    let position = movement.get_position().unwrap();
+    assert_eq!((position.x, position.y, position.z), (9.5, 68.0, 0.0));
+
+    /* Without changes only the on ground flag is sent */
+    match movement.tick(None) {
+        Some(ServerboundPacket::Player(_)) => (),
+        x => panic!("Expected Player, got {:?}", x),
+    }
+    movement.look(90.0, 0.0);
+    match movement.tick(None) {
+        Some(ServerboundPacket::PlayerLook(ref x)) => assert_eq!(*x.get_yaw(), 90.0),
+        x => panic!("Expected PlayerLook, got {:?}", x),
+    }
+    movement.walk(0.1, 0.0);
+    match movement.tick(None) {
+        Some(ServerboundPacket::PlayerPosition(ref x)) => assert_eq!(*x.get_x(), 9.6),
+        x => panic!("Expected PlayerPosition, got {:?}", x),
+    }
+
+    /* poll runs one tick per 50 ms */
+    let start = Instant::now();
+    assert_eq!(movement.poll(start, None).len(), 1);
+    assert_eq!(movement.poll(start + Duration::from_millis(120), None).len(), 2);
+    assert_eq!(movement.poll(start + Duration::from_millis(140), None).len(), 0);
+}
+
+#[test]
+fn gravity_and_collision() {
+    let world = get_world();
+    let mut movement = Movement::new();
+    teleport(&mut movement, 8.5, 70.0, 8.5, 0, 1);
+
+    for _ in 0..40 {
+        let _ = movement.tick(Some(&world));
+    }
+    let position = movement.get_position().unwrap();
+    assert_eq!(position.y, 65.0);
+    assert!(movement.is_on_ground());
+
+    movement.walk(0.25, 0.0);
+    for _ in 0..40 {
+        let _ = movement.tick(Some(&world));
+    }
+    let position = movement.get_position().unwrap();
+    assert!((position.x - 11.7).abs() < 1e-6, "x is {}", position.x);
+    assert_eq!(position.y, 65.0);
+
+    /* Jumping leaves the ground */
+    movement.stop();
+    movement.jump();
+    let _ = movement.tick(Some(&world));
+    assert!(!movement.is_on_ground());
+    assert!(movement.get_position().unwrap().y > 65.0);
+
+    /* Passable blocks are walked through */
+    movement.set_passable(1, true);
+    movement.walk(0.25, 0.0);
+    for _ in 0..4 {
+        let _ = movement.tick(Some(&world));
+    }
+    assert!(movement.get_position().unwrap().x > 12.0);
+}
+
+#[test]
+fn huge_knockback() {
+    let world = get_world();
+    let mut movement = Movement::new();
+    teleport(&mut movement, 8.5, 65.0, 8.5, 0, 1);
+
+    let explosion = Explosion::new_raw(8.0, 65.0, 8.0, 4.0, Vec::new(), 0.0, 1e30, 1e30);
+    assert!(movement.handle_packet(&explosion).is_empty());
+    let _ = movement.tick(Some(&world));
+    let position = movement.get_position().unwrap();
+    assert!(position.y <= 75.0, "y is {}", position.y);
+    assert!(position.z <= 18.5, "z is {}", position.z);
+}
@@ -0,0 +1,190 @@
+//! Tests the pathfinding, i.e. the file pathfinding.rs
+use chunk::ChunkColumn;
+use clientbound::{ClientboundPacket, PlayerPositionAndLook};
+use movement::Movement;
+use pathfinding::*;
+use world::World;
+
+const STONE: i32 = 1;
+const WATER: i32 = 2;
+const LAVA: i32 = 3;
+const LADDER: i32 = 4;
+
+fn get_costs() -> DefaultCosts {
+    let mut costs = DefaultCosts::new();
+    costs.set_kind(WATER, BlockKind::Water);
+    costs.set_kind(LAVA, BlockKind::Lava);
+    costs.set_kind(LADDER, BlockKind::Ladder);
+    costs
+}
+
+/// A single chunk with a stone floor at y = 64, after applying the given
+/// changes
+fn get_world(blocks: &[(i32, i32, i32, i32)]) -> World {
+    let mut world = World::new();
+    let mut chunk = ChunkColumn::new(0, 0);
+    for x in 0..16 {
+        for z in 0..16 {
+            chunk.set_block(x, 64, z, STONE).unwrap();
+        }
+    }
+    for &(x, y, z, state) in blocks {
+        chunk.set_block(x as usize, y, z as usize, state).unwrap();
+    }
+    world.insert_chunk(chunk);
+    world
+}
+
+fn get_moves(path: &Path) -> Vec<PathMove> {
+    path.nodes.iter().map(|x| x.movement).collect()
+}
+
+#[test]
+fn walk_and_avoid_lava() {
+    let world = get_world(&[]);
+    let path = find_path(&world, &get_costs(), (1, 65, 1), (10, 65, 1)).unwrap();
+    assert_eq!(path.nodes.len(), 10);
+    assert_eq!(path.cost, 9.0);
+    assert_eq!(path.nodes[0].movement, PathMove::Start);
+    assert!(path.nodes.iter().all(|x| x.on_ground && x.position.1 == 65));
+
+    /* A lava trench with a single bridge at z = 15 */
+    let lava: Vec<_> = (0..15).map(|z| (5, 64, z, LAVA)).collect();
+    let world = get_world(&lava);
+    let path = find_path(&world, &get_costs(), (1, 65, 1), (10, 65, 1)).unwrap();
+    for node in &path.nodes {
+        assert!(node.position.0 != 5 || node.position.2 == 15);
+    }
+    assert_eq!(path.nodes.last().unwrap().position, (10, 65, 1));
+
+    /* Without the bridge there is no path */
+    let lava: Vec<_> = (0..16).map(|z| (5, 64, z, LAVA)).collect();
+    let world = get_world(&lava);
+    assert!(find_path(&world, &get_costs(), (1, 65, 1), (10, 65, 1)).is_none());
+
+    /* Nor into an unloaded chunk */
+    assert!(find_path(&get_world(&[]), &get_costs(), (1, 65, 1), (20, 65, 1)).is_none());
+}
+
+#[test]
+fn steps_drops_swimming_and_ladders() {
+    /* A single block wall across the floor */
+    let wall: Vec<_> = (0..16).map(|z| (3, 65, z, STONE)).collect();
+    let world = get_world(&wall);
+    let path = find_path(&world, &get_costs(), (1, 65, 1), (5, 65, 1)).unwrap();
+    assert_eq!(get_moves(&path),
+               vec![PathMove::Start,
+                    PathMove::Walk,
+                    PathMove::StepUp,
+                    PathMove::Drop(1),
+                    PathMove::Walk]);
+
+    /* A pool across the floor must be swum through */
+    let mut pool = Vec::new();
+    for z in 0..16 {
+        for x in 3..6 {
+            pool.push((x, 64, z, WATER));
+            pool.push((x, 63, z, WATER));
+        }
+    }
+    let world = get_world(&pool);
+    let path = find_path(&world, &get_costs(), (1, 65, 1), (7, 65, 1)).unwrap();
+    let moves = get_moves(&path);
+    assert!(moves.contains(&PathMove::Swim));
+    assert!(moves.contains(&PathMove::StepUp));
+
+    /* A wall too high to jump, with a ladder */
+    let mut wall = Vec::new();
+    for z in 0..16 {
+        for x in 8..16 {
+            for y in 65..71 {
+                wall.push((x, y, z, STONE));
+            }
+        }
+    }
+    let world = get_world(&wall);
+    assert!(find_path(&world, &get_costs(), (1, 65, 1), (10, 71, 1)).is_none());
+    for y in 65..71 {
+        wall.push((7, y, 1, LADDER));
+    }
+    let world = get_world(&wall);
+    let path = find_path(&world, &get_costs(), (1, 65, 1), (10, 71, 1)).unwrap();
+    let climbs = get_moves(&path).iter().filter(|&&x| x == PathMove::Climb).count();
+    assert_eq!(climbs, 5);
+
+    /* A more expensive drop isn't taken */
+    let mut costs = get_costs();
+    costs.max_drop = 0;
+    let wall: Vec<_> = (0..16).map(|z| (3, 65, z, STONE)).collect();
+    let world = get_world(&wall);
+    assert!(find_path(&world, &costs, (1, 65, 1), (5, 65, 1)).is_none());
+}
+
+#[test]
+fn cheaper_with_drops() {
+    /* Jumping off a pillar is cheaper than walking its distance */
+    let costs = get_costs();
+    assert!(costs.estimate((4, 68, 1), (5, 65, 1)) <= 2.5);
+
+    /* A pillar with stairs down its side, the drop is still cheaper */
+    let blocks = [(4, 65, 1, STONE),
+                  (4, 66, 1, STONE),
+                  (4, 67, 1, STONE),
+                  (3, 65, 1, STONE),
+                  (3, 66, 1, STONE),
+                  (2, 65, 1, STONE)];
+    let world = get_world(&blocks);
+    let path = find_path(&world, &costs, (4, 68, 1), (7, 65, 1)).unwrap();
+    assert_eq!(get_moves(&path),
+               vec![PathMove::Start,
+                    PathMove::Drop(3),
+                    PathMove::Walk,
+                    PathMove::Walk]);
+    assert_eq!(path.cost, 4.5);
+
+    /* Chains of drops are found too */
+    let mut blocks = Vec::new();
+    for x in 0..4 {
+        for y in 65..65 + 3 * (4 - x) {
+            blocks.push((x, y, 1, STONE));
+        }
+    }
+    let world = get_world(&blocks);
+    let path = find_path(&world, &costs, (0, 77, 1), (4, 65, 1)).unwrap();
+    assert_eq!(get_moves(&path),
+               vec![PathMove::Start,
+                    PathMove::Drop(3),
+                    PathMove::Drop(3),
+                    PathMove::Drop(3),
+                    PathMove::Drop(3)]);
+    assert_eq!(path.cost, 10.0);
+}
+
+#[test]
+fn follow_path() {
+    let wall: Vec<_> = (0..16).map(|z| (3, 65, z, STONE)).collect();
+    let world = get_world(&wall);
+    let path = find_path(&world, &get_costs(), (1, 65, 1), (5, 65, 1)).unwrap();
+
+    let mut movement = Movement::new();
+    let teleport: ClientboundPacket = PlayerPositionAndLook::new(1.5, 65.0, 1.5, 0.0, 0.0, 0, 1);
+    let _ = movement.handle_packet(&teleport);
+    let mut follower = PathFollower::new(&path, 0.25);
+    let mut positions = Vec::new();
+    while !follower.is_finished() {
+        assert!(follower.tick(&mut movement).is_some());
+        positions.push(movement.get_position().unwrap());
+        assert!(positions.len() < 100);
+    }
+    assert!(follower.tick(&mut movement).is_none());
+
+    /* The player is never inside the wall */
+    for position in &positions {
+        if position.y < 66.0 {
+            assert!(position.x <= 2.7 || position.x >= 4.3, "inside the wall at {:?}", position);
+        }
+    }
+    let last = positions.last().unwrap();
+    assert_eq!((last.x, last.y, last.z), (5.5, 65.0, 1.5));
+    assert!(movement.is_on_ground());
+}
@@ -0,0 +1,115 @@
+//! Tests the plugin channel handling, i.e. the file plugin.rs
+use client::Client;
+use clientbound::{ClientboundPacket, LoginPluginRequest};
+use plugin::*;
+use server::Server;
+use serverbound::ServerboundPacket;
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn login_plugin_roundtrip() {
+    let mut client = LoginPluginRegistry::new();
+    client.register("ozelot:echo", |data: &[u8]| Ok(Some(data.to_vec())));
+
+    let understood = Arc::new(Mutex::new(None));
+    let understood2 = understood.clone();
+    let mut server = LoginPluginResponseRegistry::new();
+    server.register("ozelot:echo", move |x: bool, data: &[u8]| {
+        assert_eq!(data, &[1, 2, 3][..]);
+        *understood2.lock().unwrap() = Some(x);
+        Ok(())
+    });
+
+    let request = match server.new_request("ozelot:echo", vec![1, 2, 3]) {
+        ClientboundPacket::LoginPluginRequest(x) => x,
+        _ => unreachable!(),
+    };
+    assert!(server.has_pending());
+    let response = match client.handle_request(&request).unwrap() {
+        ServerboundPacket::LoginPluginResponse(x) => x,
+        _ => unreachable!(),
+    };
+    assert_eq!(server.handle_response(&response).unwrap(), "ozelot:echo");
+    assert_eq!(*understood.lock().unwrap(), Some(true));
+    assert!(!server.has_pending());
+    assert!(server.handle_response(&response).is_err());
+}
+
+#[test]
+fn login_plugin_not_understood() {
+    let mut client = LoginPluginRegistry::new();
+    let request = match LoginPluginRequest::new(7, "ozelot:unknown".to_string(), vec![1]) {
+        ClientboundPacket::LoginPluginRequest(x) => x,
+        _ => unreachable!(),
+    };
+    let response = match client.handle_request(&request).unwrap() {
+        ServerboundPacket::LoginPluginResponse(x) => x,
+        _ => unreachable!(),
+    };
+    assert_eq!(*response.get_message_id(), 7);
+    assert!(!*response.get_successful());
+    assert!(response.get_data().is_empty());
+}
+
+#[test]
+fn login_wrapper() {
+    let wrapper = LoginWrapper {
+        channel: "fml:handshake".to_string(),
+        data: vec![5, 0, 0],
+    };
+    let request = match LoginPluginRequest::new_encoded(1, &wrapper).unwrap() {
+        ClientboundPacket::LoginPluginRequest(x) => x,
+        _ => unreachable!(),
+    };
+    assert_eq!(request.decode_data::<LoginWrapper>().unwrap(), Some(wrapper));
+    assert_eq!(request.decode_data::<VelocityPlayerInfoRequest>().unwrap(), None);
+}
+
+#[test]
+fn plugin_channel_router() {
+    let received = Arc::new(AtomicUsize::new(0));
+    let received2 = received.clone();
+    let mut router = PluginChannelRouter::new();
+    router.register("ozelot:count", move |data: &[u8]| {
+        let _ = received2.fetch_add(data.len(), Ordering::SeqCst);
+        Ok(())
+    });
+
+    let register = Register {
+        channels: vec!["ozelot:a".to_string(), "ozelot:b".to_string()],
+    };
+    assert_eq!(register.encode().unwrap(), b"ozelot:a\0ozelot:b".to_vec());
+    assert!(router.handle_message(Register::CHANNEL, &register.encode().unwrap()).unwrap());
+    assert!(router.is_remote_registered("ozelot:b"));
+
+    let unregister = Unregister {
+        channels: vec!["ozelot:b".to_string()],
+    };
+    assert!(router.handle_message(Unregister::CHANNEL, &unregister.encode().unwrap()).unwrap());
+    assert!(router.is_remote_registered("ozelot:a"));
+    assert!(!router.is_remote_registered("ozelot:b"));
+
+    let brand = Brand {
+        brand: "vanilla".to_string(),
+    };
+    assert!(router.handle_message(Brand::CHANNEL, &brand.encode().unwrap()).unwrap());
+    assert_eq!(router.get_remote_brand(), Some("vanilla"));
+
+    assert!(router.handle_message("ozelot:count", &[1, 2, 3]).unwrap());
+    assert!(!router.handle_message("ozelot:other", &[1, 2, 3]).unwrap());
+    assert_eq!(received.load(Ordering::SeqCst), 3);
+    assert_eq!(router.get_register().channels, vec!["ozelot:count".to_string()]);
+}
+
+/// Handlers are stored on the Client and Server, which must stay Send
+#[test]
+fn connections_are_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Client>();
+    assert_send::<Server>();
+    assert_send::<LoginPluginRegistry>();
+    assert_send::<LoginPluginResponseRegistry>();
+    assert_send::<PluginChannelRouter>();
+}
@@ -0,0 +1,134 @@
+//! Tests the recipes and recipe book, i.e. the files recipes.rs and slot.rs
+use clientbound::{ClientboundPacket, DeclareRecipes, UnlockRecipes};
+use recipes::*;
+use serverbound::ServerboundPacket;
+use slot::*;
+
+use std::io::Cursor;
+
+fn example_recipes() -> Vec<Recipe> {
+    let planks = Some(Slot::new(13, 1));
+    let mut named = Slot::new(598, 1);
+    /* A compound named "" containing the string "Name": "x" */
+    named.nbt = Some(vec![10, 0, 0, 8, 0, 4, b'N', b'a', b'm', b'e', 0, 1, b'x', 0]);
+    vec![Recipe {
+             id: "minecraft:crafting_table".to_string(),
+             data: RecipeData::Shaped {
+                 width: 2,
+                 height: 2,
+                 group: String::new(),
+                 ingredients: vec![vec![planks.clone()]; 4],
+                 result: Some(Slot::new(183, 1)),
+             },
+         },
+         Recipe {
+             id: "minecraft:oak_planks".to_string(),
+             data: RecipeData::Shapeless {
+                 group: "planks".to_string(),
+                 ingredients: vec![vec![Some(Slot::new(33, 1)), Some(Slot::new(57, 1))]],
+                 result: Some(Slot::new(13, 4)),
+             },
+         },
+         Recipe {
+             id: "minecraft:charcoal".to_string(),
+             data: RecipeData::Smelting(CookingRecipe {
+                 group: String::new(),
+                 ingredient: vec![Some(Slot::new(33, 1))],
+                 result: Some(named),
+                 experience: 0.15,
+                 cooking_time: 200,
+             }),
+         },
+         Recipe {
+             id: "minecraft:stone_slab_from_stonecutting".to_string(),
+             data: RecipeData::Stonecutting {
+                 group: String::new(),
+                 ingredient: vec![Some(Slot::new(1, 1))],
+                 result: Some(Slot::new(500, 2)),
+             },
+         },
+         Recipe {
+             id: "minecraft:armor_dye".to_string(),
+             data: RecipeData::Special("minecraft:crafting_special_armordye".to_string()),
+         }]
+}
+
+#[test]
+fn declare_recipes() {
+    let recipes = example_recipes();
+    match DeclareRecipes::new_recipes(&recipes).unwrap() {
+        ClientboundPacket::DeclareRecipes(ref x) => assert_eq!(x.get_recipes().unwrap(), recipes),
+        _ => panic!("Expected DeclareRecipes"),
+    }
+}
+
+#[test]
+fn slot_nbt() {
+    /* A compound containing a list of two compounds each containing an int,
+     * followed by a trailing byte that must not be consumed */
+    let nbt = vec![10, 0, 0, 9, 0, 1, b'l', 10, 0, 0, 0, 2, 3, 0, 1, b'a', 0, 0, 0, 1, 0, 3,
+                   0, 1, b'a', 0, 0, 0, 2, 0, 0];
+    let mut data = vec![1, 42, 3];
+    data.extend_from_slice(&nbt);
+    data.push(0xff);
+    let mut r = Cursor::new(&data);
+    let slot = read_slot(&mut r).unwrap().unwrap();
+    assert_eq!(slot.item_id, 42);
+    assert_eq!(slot.count, 3);
+    assert_eq!(slot.nbt, Some(nbt));
+    assert_eq!(r.position() as usize, data.len() - 1);
+
+    let mut written = Vec::new();
+    write_slot(&Some(slot), &mut written).unwrap();
+    assert_eq!(written[..], data[..data.len() - 1]);
+
+    assert_eq!(read_slot(&mut Cursor::new(&[0])).unwrap(), None);
+    assert!(read_slot(&mut Cursor::new(&[1, 42, 3, 10, 0, 0, 3, 0])).is_err());
+}
+
+fn unlock(action: i32, recipes: &[&str], recipes2: &[&str]) -> UnlockRecipes {
+    let packet = UnlockRecipes::new_raw(action,
+                                        false,
+                                        false,
+                                        false,
+                                        false,
+                                        recipes.iter().map(|x| x.to_string()).collect(),
+                                        recipes2.iter().map(|x| x.to_string()).collect());
+    match packet {
+        ClientboundPacket::UnlockRecipes(x) => x,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn recipe_book() {
+    let mut book = RecipeBook::new();
+    book.set_recipes(example_recipes());
+    assert!(book.get_recipe("minecraft:charcoal").is_some());
+
+    book.handle_unlock(&unlock(0,
+                               &["minecraft:crafting_table", "minecraft:oak_planks"],
+                               &["minecraft:oak_planks"]))
+        .unwrap();
+    assert!(book.is_unlocked("minecraft:crafting_table"));
+    assert!(!book.is_highlighted("minecraft:crafting_table"));
+    assert!(book.is_highlighted("minecraft:oak_planks"));
+    assert!(book.new_craft_request(0, "minecraft:charcoal", false).is_err());
+
+    book.handle_unlock(&unlock(1, &["minecraft:charcoal"], &[])).unwrap();
+    assert!(book.is_highlighted("minecraft:charcoal"));
+    match book.new_craft_request(1, "minecraft:charcoal", true).unwrap() {
+        ServerboundPacket::CraftRecipeRequest(ref x) => {
+            assert_eq!(*x.get_window_id(), 1);
+            assert_eq!(x.get_recipe(), "minecraft:charcoal");
+            assert!(*x.get_make_all());
+        },
+        _ => panic!("Expected CraftRecipeRequest"),
+    }
+    let _ = book.mark_seen("minecraft:charcoal");
+    assert!(!book.is_highlighted("minecraft:charcoal"));
+
+    book.handle_unlock(&unlock(2, &["minecraft:oak_planks"], &[])).unwrap();
+    assert!(!book.is_unlocked("minecraft:oak_planks"));
+    assert!(!book.is_highlighted("minecraft:oak_planks"));
+}
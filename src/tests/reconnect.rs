@@ -0,0 +1,174 @@
+//! Tests the reconnecting client, i.e. the file reconnect.rs
+use clientbound::{LoginSuccess, PlayDisconnect, PlayerAbilities, UpdateHealth};
+use reconnect::*;
+use serverbound::ServerboundPacket;
+use {ClientState, Server};
+
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[test]
+fn backoff() {
+    let mut backoff = Backoff::new(Duration::from_secs(1), Duration::from_secs(5));
+    backoff.max_attempts = Some(4);
+    assert_eq!(backoff.next_delay(), Some(Duration::from_secs(1)));
+    assert_eq!(backoff.next_delay(), Some(Duration::from_secs(2)));
+    assert_eq!(backoff.next_delay(), Some(Duration::from_secs(4)));
+    assert_eq!(backoff.next_delay(), Some(Duration::from_secs(5)));
+    assert_eq!(backoff.get_attempts(), 4);
+    assert_eq!(backoff.next_delay(), None);
+    backoff.reset();
+    assert_eq!(backoff.next_delay(), Some(Duration::from_secs(1)));
+}
+
+/// Read from the server until a packet is received
+fn wait_for(server: &mut Server) -> ServerboundPacket {
+    let timeout = Instant::now();
+    loop {
+        assert!(timeout.elapsed() < Duration::from_secs(10));
+        server.update_inbuf().unwrap();
+        if let Some(packet) = server.read_packet().unwrap() {
+            return packet;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+/// Accept a connection and log the client in
+fn accept(listener: &TcpListener) -> Server {
+    let (stream, _) = listener.accept().unwrap();
+    let mut server = Server::from_tcpstream(stream).unwrap();
+    match wait_for(&mut server) {
+        ServerboundPacket::Handshake(..) => server.set_clientstate(ClientState::Login),
+        x => panic!("Expected Handshake, got {:?}", x),
+    }
+    match wait_for(&mut server) {
+        ServerboundPacket::LoginStart(ref p) => {
+            let _: usize = server.send(LoginSuccess::new(1, p.get_name().clone())).unwrap();
+        },
+        x => panic!("Expected LoginStart, got {:?}", x),
+    }
+    server.set_clientstate(ClientState::Play);
+    let _: usize = server.send(PlayerAbilities::new(0, 0.05, 0.1)).unwrap();
+    server
+}
+
+/// Read from the client until an event matching the predicate, returning all
+/// events read
+fn read_until<F: Fn(&SessionEvent) -> bool>(client: &mut ReconnectingClient,
+                                            f: F)
+                                            -> Vec<SessionEvent> {
+    let timeout = Instant::now();
+    let mut ret = Vec::new();
+    loop {
+        assert!(timeout.elapsed() < Duration::from_secs(10), "{:?}", ret);
+        let events = client.read().unwrap();
+        let done = events.iter().any(&f);
+        ret.extend(events);
+        if done {
+            return ret;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn reconnect_and_respawn() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let server = thread::spawn(move || {
+        let mut server = accept(&listener);
+        let _: usize = server.send(UpdateHealth::new(0.0, 20, 5.0)).unwrap();
+        match wait_for(&mut server) {
+            ServerboundPacket::ClientStatus(ref p) => assert_eq!(*p.get_action(), 0),
+            x => panic!("Expected ClientStatus, got {:?}", x),
+        }
+        let _: usize = server.send(PlayDisconnect::new("\"Restarting\"".to_string()))
+            .unwrap();
+
+        /* The client logs in again, and is dropped without a reason */
+        let mut server = accept(&listener);
+        drop(listener);
+        server.close().unwrap();
+    });
+
+    let mut client = ReconnectingClient::new("127.0.0.1",
+                                             port,
+                                             Credentials::Offline("bot".to_string()));
+    let mut backoff = Backoff::new(Duration::from_millis(10), Duration::from_millis(50));
+    backoff.max_attempts = Some(2);
+    client.set_backoff(backoff);
+    client.set_auto_respawn(true);
+
+    let events = read_until(&mut client, |x| matches!(*x, SessionEvent::Reconnecting(..)));
+    match events[0] {
+        SessionEvent::Connected => (),
+        ref x => panic!("Expected Connected, got {:?}", x),
+    }
+    assert!(events.iter().any(|x| matches!(*x, SessionEvent::Respawned)));
+    let disconnects: Vec<_> = events.iter()
+        .filter_map(|x| match *x {
+            SessionEvent::Disconnected(ref reason) => Some(reason.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(disconnects, vec!["\"Restarting\"".to_string()]);
+    assert!(!client.is_connected());
+
+    /* Reconnects, then loses the connection, then fails to reconnect twice
+     * as the listener is gone */
+    let events = read_until(&mut client, |x| matches!(*x, SessionEvent::GaveUp));
+    server.join().unwrap();
+    let names: Vec<_> = events.iter()
+        .filter_map(|x| match *x {
+            SessionEvent::Connected => Some("connected"),
+            SessionEvent::Disconnected(..) => Some("disconnected"),
+            SessionEvent::ConnectFailed(..) => Some("failed"),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(names, vec!["connected", "disconnected", "failed", "failed"]);
+    assert!(client.is_closed());
+    assert!(!client.is_connected());
+    assert!(client.read().unwrap().is_empty());
+}
+
+#[test]
+fn reconnecting_client_is_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<ReconnectingClient>();
+}
+
+#[test]
+fn read_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (done_tx, done_rx) = mpsc::channel();
+
+    /* The server goes silent after logging the client in */
+    let server = thread::spawn(move || {
+        let _server = accept(&listener);
+        done_rx.recv().unwrap();
+    });
+
+    let mut client = ReconnectingClient::new("127.0.0.1",
+                                             port,
+                                             Credentials::Offline("bot".to_string()));
+    client.set_read_timeout(Some(Duration::from_millis(200)));
+    let events = read_until(&mut client, |x| matches!(*x, SessionEvent::Disconnected(..)));
+    done_tx.send(()).unwrap();
+    server.join().unwrap();
+
+    assert!(matches!(events[0], SessionEvent::Connected));
+    let disconnects: Vec<_> = events.iter()
+        .filter_map(|x| match *x {
+            SessionEvent::Disconnected(ref reason) => Some(reason.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(disconnects, vec!["Timed out".to_string()]);
+    assert!(!client.is_connected());
+}
@@ -0,0 +1,117 @@
+//! Tests the registries, i.e. the file registry.rs
+use registry::*;
+
+/* Excerpts of the data generator's reports for 1.15.2 */
+const BLOCKS_JSON: &str = r#"{
+    "minecraft:air": {"states": [{"id": 0, "default": true}]},
+    "minecraft:stone": {"states": [{"id": 1, "default": true}]},
+    "minecraft:oak_log": {
+        "properties": {"axis": ["x", "y", "z"]},
+        "states": [
+            {"properties": {"axis": "x"}, "id": 72},
+            {"properties": {"axis": "y"}, "id": 73, "default": true},
+            {"properties": {"axis": "z"}, "id": 74}
+        ]
+    },
+    "minecraft:oak_slab": {
+        "properties": {"type": ["top", "bottom", "double"], "waterlogged": ["true", "false"]},
+        "states": [
+            {"properties": {"type": "top", "waterlogged": "true"}, "id": 7764},
+            {"properties": {"type": "top", "waterlogged": "false"}, "id": 7765},
+            {"properties": {"type": "bottom", "waterlogged": "true"}, "id": 7766},
+            {"properties": {"type": "bottom", "waterlogged": "false"}, "id": 7767, "default": true},
+            {"properties": {"type": "double", "waterlogged": "true"}, "id": 7768},
+            {"properties": {"type": "double", "waterlogged": "false"}, "id": 7769}
+        ]
+    }
+}"#;
+
+const REGISTRIES_JSON: &str = r#"{
+    "minecraft:block": {"default": "minecraft:air", "protocol_id": 3, "entries": {
+        "minecraft:air": {"protocol_id": 0},
+        "minecraft:stone": {"protocol_id": 1},
+        "minecraft:oak_log": {"protocol_id": 34}
+    }},
+    "minecraft:item": {"default": "minecraft:air", "protocol_id": 6, "entries": {
+        "minecraft:air": {"protocol_id": 0},
+        "minecraft:stone": {"protocol_id": 1}
+    }},
+    "minecraft:entity_type": {"default": "minecraft:pig", "protocol_id": 5, "entries": {
+        "minecraft:pig": {"protocol_id": 55}
+    }},
+    "minecraft:enchantment": {"protocol_id": 4, "entries": {
+        "minecraft:protection": {"protocol_id": 0}
+    }},
+    "minecraft:custom_stat": {"protocol_id": 9, "entries": {
+        "minecraft:jump": {"protocol_id": 20}
+    }}
+}"#;
+
+#[test]
+fn registry() {
+    let registry = Registry::from_reports(BLOCKS_JSON, REGISTRIES_JSON).unwrap();
+    assert_eq!(registry.get_block_state_count(), 11);
+
+    let state = registry.get_block_state(7765).unwrap();
+    assert_eq!(state.name, "minecraft:oak_slab");
+    assert_eq!(state.get_property("type"), Some("top"));
+    assert_eq!(state.to_string(), "minecraft:oak_slab[type=top,waterlogged=false]");
+    assert_eq!(registry.get_block_state(1).unwrap().to_string(), "minecraft:stone");
+
+    assert_eq!(registry.get_block_state_id("minecraft:oak_slab[waterlogged=false,type=top]"),
+               Some(7765));
+    assert_eq!(registry.get_block_state_id("oak_slab[type=double]"), Some(7769));
+    assert_eq!(registry.get_block_state_id("minecraft:oak_slab"), Some(7767));
+    assert_eq!(registry.get_block_state_id("minecraft:oak_slab[facing=up]"), None);
+    assert_eq!(registry.get_block_state_id("minecraft:oak_log[axis=w]"), None);
+    assert_eq!(registry.get_block_state_id("minecraft:nonexistent"), None);
+    assert_eq!(registry.get_default_state_id("minecraft:oak_log"), Some(73));
+    assert_eq!(registry.get_block_id(74), Some(34));
+
+    assert_eq!(registry.items.get_name(1), Some("minecraft:stone"));
+    assert_eq!(registry.items.get_id("stone"), Some(1));
+    assert_eq!(registry.entities.get_id("minecraft:pig"), Some(55));
+    assert_eq!(registry.enchantments.get_name(0), Some("minecraft:protection"));
+    assert!(registry.biomes.is_empty());
+
+    assert!(parse_block_state("minecraft:oak_slab[type=top").is_err());
+    assert!(parse_block_state("minecraft:oak_slab[type]").is_err());
+    assert!(Registry::from_reports("[]", REGISTRIES_JSON).is_err());
+}
+
+#[test]
+fn registry_v578() {
+    let registry = Registry::v578();
+    assert_eq!(registry.get_block_state_count(), 11337);
+    assert_eq!(registry.blocks.len(), 680);
+
+    /* Everything in the excerpts matches */
+    let reports = Registry::from_reports(BLOCKS_JSON, REGISTRIES_JSON).unwrap();
+    for id in &[0, 1, 72, 73, 74, 7764, 7765, 7766, 7767, 7768, 7769] {
+        assert_eq!(registry.get_block_state(*id), reports.get_block_state(*id));
+    }
+    for (id, name) in reports.blocks.iter() {
+        assert_eq!(registry.blocks.get_name(id), Some(name));
+    }
+    for (id, name) in reports.items.iter() {
+        assert_eq!(registry.items.get_name(id), Some(name));
+    }
+    assert_eq!(registry.entities.get_id("minecraft:pig"), Some(55));
+    assert_eq!(registry.enchantments.get_name(0), Some("minecraft:protection"));
+
+    assert_eq!(registry.get_block_state_id("minecraft:water[level=0]"), Some(34));
+    assert_eq!(registry.get_block_state_id("minecraft:note_block"), Some(249));
+    assert_eq!(registry.get_block_state_id("minecraft:redstone_wire"), Some(3216));
+    assert_eq!(registry.get_block_state_id("minecraft:redstone_wire[east=up,north=up,south=up,west=up]"),
+               Some(2056));
+    assert_eq!(registry.get_block_state_id("minecraft:oak_stairs[facing=east,half=top,shape=outer_right]"),
+               Some(2021));
+    assert_eq!(registry.get_default_state_id("minecraft:cave_air"), Some(9130));
+    assert_eq!(registry.get_block_state(11336).unwrap().to_string(), "minecraft:honeycomb_block");
+    assert_eq!(registry.get_block_id(9130), registry.blocks.get_id("minecraft:cave_air"));
+
+    assert_eq!(registry.particles.get_id("minecraft:dust"), Some(14));
+    assert_eq!(registry.biomes.get_name(127), Some("minecraft:the_void"));
+    assert_eq!(registry.biomes.get_name(128), None);
+    assert_eq!(registry.sounds.get_name(0), Some("minecraft:ambient.cave"));
+}
@@ -0,0 +1,103 @@
+//! Tests the teams and scoreboard, i.e. the file scoreboard.rs
+use clientbound::*;
+use connection::Packet;
+use scoreboard::*;
+use ClientState;
+
+use std::io::Cursor;
+
+/// Serialize and deserialize the packet, like sending it over the network
+fn roundtrip(packet: ClientboundPacket) -> ClientboundPacket {
+    let data = packet.to_u8().unwrap();
+    let ret = ClientboundPacket::deserialize(&mut Cursor::new(data), &ClientState::Play).unwrap();
+    assert_eq!(ret, packet);
+    ret
+}
+
+fn team_info(display_name: &str) -> TeamInfo {
+    TeamInfo {
+        display_name: format!(r#"{{"text":"{}"}}"#, display_name),
+        friendly_flags: 0x02,
+        name_tag_visibility: "always".to_string(),
+        collision_rule: "pushOtherTeams".to_string(),
+        color: 12,
+        prefix: r#"{"text":"[R] "}"#.to_string(),
+        suffix: r#"{"text":""}"#.to_string(),
+    }
+}
+
+fn teams(team: &str, action: TeamsAction) -> ClientboundPacket {
+    let update = TeamsUpdate {
+        team: team.to_string(),
+        action,
+    };
+    let packet = roundtrip(Teams::new_update(&update).unwrap());
+    match packet {
+        ClientboundPacket::Teams(ref x) => assert_eq!(x.get_update().unwrap(), update),
+        _ => panic!("Expected Teams"),
+    }
+    packet
+}
+
+#[test]
+fn scoreboard_objective_type() {
+    let packet = ScoreboardObjective::new_raw("kills".to_string(),
+                                              0,
+                                              Some(r#"{"text":"Kills"}"#.to_string()),
+                                              Some("hearts".to_string()));
+    let data = roundtrip(packet).to_u8().unwrap();
+    /* The type is the varint 1 at the very end */
+    assert_eq!(data.last(), Some(&1));
+}
+
+#[test]
+fn scoreboard() {
+    let mut scoreboard = Scoreboard::new();
+    let names = |x: &[&str]| x.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+
+    let packets =
+        vec![teams("red", TeamsAction::Create(team_info("Red"), names(&["alice", "bob"]))),
+             teams("blue", TeamsAction::Create(team_info("Blue"), names(&["carol"]))),
+             teams("blue", TeamsAction::AddEntities(names(&["bob"]))),
+             teams("red", TeamsAction::Update(team_info("Reds"))),
+             teams("blue", TeamsAction::RemoveEntities(names(&["carol"]))),
+             roundtrip(ScoreboardObjective::new_raw("kills".to_string(),
+                                                    0,
+                                                    Some(r#"{"text":"Kills"}"#.to_string()),
+                                                    Some("integer".to_string()))),
+             roundtrip(UpdateScore::new_raw("alice".to_string(), 0, "kills".to_string(), Some(3))),
+             roundtrip(UpdateScore::new_raw("bob".to_string(), 0, "kills".to_string(), Some(5))),
+             roundtrip(UpdateScore::new_raw("carol".to_string(), 0, "kills".to_string(), Some(3))),
+             roundtrip(UpdateScore::new_raw("dave".to_string(), 0, "kills".to_string(), Some(1))),
+             roundtrip(UpdateScore::new_raw("dave".to_string(), 1, String::new(), None)),
+             roundtrip(DisplayScoreboard::new(DISPLAY_SIDEBAR, "kills".to_string()))];
+    for packet in &packets {
+        assert!(scoreboard.handle_packet(packet).unwrap());
+    }
+    assert!(!scoreboard.handle_packet(&KeepAlive::new(1)).unwrap());
+
+    assert_eq!(scoreboard.get_team_of("alice").unwrap().name, "red");
+    assert_eq!(scoreboard.get_team_of("bob").unwrap().name, "blue");
+    assert!(scoreboard.get_team_of("carol").is_none());
+    let red = scoreboard.get_team("red").unwrap();
+    assert_eq!(red.info.display_name, r#"{"text":"Reds"}"#);
+    assert!(red.info.sees_invisible_teammates());
+    assert!(!red.info.allows_friendly_fire());
+
+    {
+        let (objective, entries) = scoreboard.get_sidebar().unwrap();
+        assert_eq!(objective.display_name, r#"{"text":"Kills"}"#);
+        assert_eq!(entries, vec![("bob", 5), ("alice", 3), ("carol", 3)]);
+    }
+
+    let _ = scoreboard.handle_packet(&teams("red", TeamsAction::Remove)).unwrap();
+    assert!(scoreboard.get_team_of("alice").is_none());
+    let packet = ScoreboardObjective::new_raw("kills".to_string(), 1, None, None);
+    let _ = scoreboard.handle_packet(&roundtrip(packet)).unwrap();
+    assert!(scoreboard.get_sidebar().is_none());
+    assert!(scoreboard.handle_packet(&UpdateScore::new_raw("x".to_string(),
+                                                           0,
+                                                           "kills".to_string(),
+                                                           Some(1)))
+                .is_err());
+}
@@ -0,0 +1,52 @@
+//! Tests the tags, i.e. the file tags.rs
+use clientbound::{ClientboundPacket, Tags};
+use tags::*;
+
+#[test]
+fn tags() {
+    let mut list = TagList::default();
+    let _ = list.blocks.insert("minecraft:logs".to_string(), vec![33, 34, 35]);
+    let _ = list.blocks.insert("minecraft:planks".to_string(), vec![13]);
+    let _ = list.items.insert("minecraft:logs".to_string(), vec![106]);
+    let _ = list.fluids.insert("minecraft:water".to_string(), vec![1, 2]);
+
+    let list = match Tags::new_tags(&list).unwrap() {
+        ClientboundPacket::Tags(ref x) => {
+            let decoded = x.get_tags().unwrap();
+            assert_eq!(decoded, list);
+            decoded
+        },
+        _ => panic!("Expected Tags"),
+    };
+
+    let mut registry = TagRegistry::new();
+    registry.update(&list);
+    assert!(registry.is_block_in_tag(34, "minecraft:logs"));
+    assert!(!registry.is_block_in_tag(13, "minecraft:logs"));
+    assert!(!registry.is_block_in_tag(34, "minecraft:nonexistent"));
+    assert!(!registry.is_item_in_tag(34, "minecraft:logs"));
+    assert!(registry.is_fluid_in_tag(2, "minecraft:water"));
+    assert_eq!(registry.get_tags_of(TagKind::Block, 13), vec!["minecraft:planks"]);
+
+    registry.update(&TagList::default());
+    assert!(!registry.is_block_in_tag(34, "minecraft:logs"));
+}
+
+#[test]
+fn tags_block_states() {
+    let mut list = TagList::default();
+    let _ = list.blocks.insert("minecraft:logs".to_string(), vec![34, 35, 36, 37, 38, 39]);
+    let mut tags = TagRegistry::new();
+    tags.update(&list);
+
+    /* The states of stone and oak_log in 1.15.2 */
+    let block_of = |state| match state {
+        1 => Some(1),
+        72..=74 => Some(34),
+        _ => None,
+    };
+    assert!(tags.is_block_state_in_tag(block_of, 74, "minecraft:logs"));
+    assert!(!tags.is_block_state_in_tag(block_of, 74, "minecraft:planks"));
+    assert!(!tags.is_block_state_in_tag(block_of, 1, "minecraft:logs"));
+    assert!(!tags.is_block_state_in_tag(block_of, -1, "minecraft:logs"));
+}
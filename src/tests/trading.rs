@@ -0,0 +1,67 @@
+//! Tests the villager trades, i.e. the file trading.rs
+use clientbound::{ClientboundPacket, TradeList};
+use serverbound::ServerboundPacket;
+use slot::Slot;
+use trading::*;
+
+fn trade(input: Slot, output: Slot) -> Trade {
+    Trade {
+        input_item_1: Some(input),
+        output_item: Some(output),
+        input_item_2: None,
+        disabled: false,
+        uses: 0,
+        max_uses: 12,
+        xp: 2,
+        special_price: 0,
+        price_multiplier: 0.05,
+        demand: 0,
+    }
+}
+
+#[test]
+fn trade_list() {
+    let mut paper = trade(Slot::new(615, 24), Slot::new(687, 1));
+    paper.demand = 10;
+    paper.special_price = -2;
+    let mut book = trade(Slot::new(687, 5), Slot::new(618, 1));
+    book.input_item_2 = Some(Slot::new(617, 1));
+    book.disabled = true;
+    let glass = trade(Slot::new(687, 1), Slot::new(231, 4));
+    let data = TradeListData {
+        window_id: 3,
+        trades: vec![paper, book, glass],
+        villager_level: 2,
+        experience: 15,
+        is_regular_villager: true,
+        can_restock: true,
+    };
+    let data = match TradeList::new_trade_list(&data).unwrap() {
+        ClientboundPacket::TradeList(ref x) => {
+            let decoded = x.get_trade_list().unwrap();
+            assert_eq!(decoded, data);
+            decoded
+        },
+        _ => panic!("Expected TradeList"),
+    };
+
+    /* 24 + floor(24 * 10 * 0.05) - 2 */
+    assert_eq!(data.trades[0].get_price(), 34);
+    assert_eq!(data.trades[2].get_price(), 1);
+    /* Prices saturate rather than overflow */
+    let mut expensive = data.trades[0].clone();
+    expensive.demand = i32::MAX;
+    expensive.special_price = i32::MAX;
+    assert_eq!(expensive.get_price(), i32::MAX);
+    expensive.special_price = i32::MIN;
+    assert_eq!(expensive.get_price(), 1);
+
+    assert_eq!(data.find_output(231), Some(2));
+    assert_eq!(data.find_output(618), None);
+    match data.select(2).unwrap() {
+        ServerboundPacket::SelectTrade(ref x) => assert_eq!(*x.get_selected_slot(), 2),
+        _ => panic!("Expected SelectTrade"),
+    }
+    assert!(data.select(1).is_err());
+    assert!(data.select(3).is_err());
+}
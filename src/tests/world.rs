@@ -0,0 +1,143 @@
+//! Tests the world tracker and what it builds on, i.e. the files chunk.rs,
+//! metadata.rs and world.rs
+use chunk::*;
+use clientbound::*;
+use metadata::*;
+use slot::Slot;
+use world::*;
+
+use std::collections::BTreeMap;
+
+fn get_chunk_data(chunk: &ChunkColumn) -> ClientboundPacket {
+    ChunkData::new_chunk(chunk).unwrap()
+}
+
+#[test]
+fn chunk_column() {
+    let mut chunk = ChunkColumn::new(-2, 5);
+    /* A few states use a palette, many use global ids */
+    chunk.set_block(0, 0, 0, 1).unwrap();
+    chunk.set_block(15, 17, 3, 9).unwrap();
+    for i in 0..300 {
+        chunk.set_block(i % 16, 100 + (i / 256) as i32, (i / 16) % 16, i as i32 + 1).unwrap();
+    }
+    /* An all air section is not sent */
+    chunk.set_block(0, 255, 0, 0).unwrap();
+    assert!(chunk.set_block(16, 0, 0, 1).is_err());
+    assert!(chunk.set_block(0, 256, 0, 1).is_err());
+    assert_eq!(chunk.get_primary_bit_mask(), 1 << 0 | 1 << 1 | 1 << 6);
+
+    let packet = match get_chunk_data(&chunk) {
+        ClientboundPacket::ChunkData(x) => x,
+        _ => panic!("Expected ChunkData"),
+    };
+    let decoded = packet.get_chunk().unwrap();
+    assert_eq!(decoded.x, -2);
+    assert_eq!(decoded.z, 5);
+    assert_eq!(decoded.biomes, chunk.biomes);
+    assert!(decoded.sections[15].is_none());
+    for y in 0..256 {
+        for &(x, z) in &[(0, 0), (15, 3), (5, 7), (12, 2)] {
+            assert_eq!(decoded.get_block(x, y, z), chunk.get_block(x, y, z));
+        }
+    }
+    assert_eq!(decoded.get_block(0, 0, 0), Some(1));
+    assert_eq!(decoded.get_block(15, 17, 3), Some(9));
+    assert_eq!(decoded.get_block(11, 101, 2), Some(300));
+    assert_eq!(decoded.get_block(0, -1, 0), None);
+    assert_eq!(decoded.sections[6].as_ref().unwrap().get_block_count(), 300);
+
+    let mut section = ChunkSection::new();
+    section.set_block(15, 15, 15, 4).unwrap();
+    assert_eq!(section.get_block(15, 15, 15), Some(4));
+    assert_eq!(section.get_block(16, 0, 0), None);
+    assert!(section.set_block(0, 16, 0, 1).is_err());
+
+    assert!(ChunkColumn::decode(&[0; 5]).is_err());
+}
+
+#[test]
+fn entity_metadata() {
+    let mut metadata = BTreeMap::new();
+    let _ = metadata.insert(0, MetadataValue::Byte(0x02));
+    let _ = metadata.insert(2, MetadataValue::OptChat(Some("{\"text\":\"Bob\"}".to_string())));
+    let _ = metadata.insert(6, MetadataValue::Pose(5));
+    let _ = metadata.insert(7, MetadataValue::Slot(Some(Slot::new(1, 3))));
+    let _ = metadata.insert(8, MetadataValue::OptVarInt(Some(0)));
+    let _ = metadata.insert(9, MetadataValue::Particle(14, vec![0x3f, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x3f, 0x80, 0, 0]));
+    let _ = metadata.insert(10, MetadataValue::OptPosition(Some((-1, 64, 300))));
+
+    let packet = match EntityMetadata::new_entries(7, &metadata).unwrap() {
+        ClientboundPacket::EntityMetadata(x) => x,
+        _ => panic!("Expected EntityMetadata"),
+    };
+    assert_eq!(packet.get_entries().unwrap(), metadata);
+    assert_eq!(*packet.get_metadata().last().unwrap(), 0xff);
+
+    /* Unknown type */
+    assert!(decode_metadata(&[0, 19, 0xff]).is_err());
+}
+
+#[test]
+fn world() {
+    let mut world = World::new();
+    let join = JoinGame::new(42, 0, 0, 0, 20, "default".to_string(), 10, false, true);
+    assert!(world.handle_packet(&join).unwrap());
+    assert_eq!(world.get_player_entity_id(), Some(42));
+    assert!(world.player_position().is_none());
+
+    let mut chunk = ChunkColumn::new(-1, 0);
+    chunk.set_block(15, 64, 0, 1).unwrap();
+    assert!(world.handle_packet(&get_chunk_data(&chunk)).unwrap());
+    assert_eq!(world.block_at(-1, 64, 0), Some(1));
+    assert_eq!(world.block_at(-1, 65, 0), Some(0));
+    assert_eq!(world.block_at(0, 64, 0), None);
+
+    assert!(world.handle_packet(&BlockChange::new((-2, 64, 1), 5)).unwrap());
+    assert!(world.handle_packet(&MultiBlockChange::new_raw(-1, 0, vec![(0, 10, 0, 7)])).unwrap());
+    assert_eq!(world.block_at(-2, 64, 1), Some(5));
+    assert_eq!(world.block_at(-16, 10, 0), Some(7));
+    assert!(world.handle_packet(&Explosion::new_raw(-1.5, 64.2, 0.5, 3.0, vec![(0, 0, 1), (1, 0, 0)], 0.0, 0.0, 0.0)).unwrap());
+    assert_eq!(world.block_at(-2, 64, 1), Some(0));
+    assert_eq!(world.block_at(-1, 64, 0), Some(0));
+
+    /* A partial chunk only replaces the sent sections */
+    let mut partial = ChunkColumn::new(-1, 0);
+    partial.full_chunk = false;
+    partial.set_block(0, 200, 0, 3).unwrap();
+    assert!(world.handle_packet(&get_chunk_data(&partial)).unwrap());
+    assert_eq!(world.block_at(-16, 200, 0), Some(3));
+    assert_eq!(world.block_at(-16, 10, 0), Some(7));
+
+    assert!(world.handle_packet(&UnloadChunk::new(-1, 0)).unwrap());
+    assert_eq!(world.block_at(-1, 64, 0), None);
+
+    assert!(world.handle_packet(&SpawnMob::new(3, 1, 95, 10.0, 64.0, 10.0, 64, 0, 0, 8000, 0, 0)).unwrap());
+    assert!(world.handle_packet(&SpawnPlayer::new(4, 2, 0.0, 64.0, 0.0, 0, 0)).unwrap());
+    assert!(world.handle_packet(&EntityRelativeMove::new(3, 4096, -2048, 0, true)).unwrap());
+    {
+        let mob = world.get_entity(3).unwrap();
+        assert_eq!(mob.kind, EntityKind::Mob(95));
+        assert_eq!((mob.x, mob.y, mob.z), (11.0, 63.5, 10.0));
+        assert_eq!(mob.yaw, 90.0);
+        assert_eq!(mob.velocity_x, 1.0);
+        assert!(mob.on_ground);
+    }
+    assert!(world.handle_packet(&EntityTeleport::new(4, 1.0, 64.0, 1.0, 0, 0, true)).unwrap());
+
+    let near: Vec<i32> = world.entities_near(10.0, 64.0, 10.0, 20.0).iter().map(|x| x.id).collect();
+    assert_eq!(near, vec![3, 4]);
+    let near: Vec<i32> = world.entities_near(0.0, 64.0, 0.0, 5.0).iter().map(|x| x.id).collect();
+    assert_eq!(near, vec![4]);
+
+    assert!(world.handle_packet(&DestroyEntities::new(vec![3])).unwrap());
+    assert!(world.get_entity(3).is_none());
+
+    assert!(world.handle_packet(&PlayerPositionAndLook::new(1.5, 70.0, -3.5, 90.0, 0.0, 0, 1)).unwrap());
+    assert!(world.handle_packet(&PlayerPositionAndLook::new(1.0, 0.0, 0.0, 0.0, 0.0, 0x01 | 0x08, 2)).unwrap());
+    let position = world.player_position().unwrap();
+    assert_eq!((position.x, position.y, position.z), (2.5, 0.0, 0.0));
+    assert_eq!(position.yaw, 90.0);
+
+    assert!(!world.handle_packet(&KeepAlive::new(1)).unwrap());
+}
@@ -0,0 +1,64 @@
+//! Tests the world border, i.e. the file worldborder.rs
+use clientbound::{ClientboundPacket, WorldBorder};
+use worldborder::*;
+
+use std::time::{Duration, Instant};
+
+#[test]
+fn worldborder() {
+    let actions = vec![WorldBorderAction::Initialize {
+                           x: 100.0,
+                           z: -50.0,
+                           old_diameter: 1000.0,
+                           new_diameter: 1000.0,
+                           speed: 0,
+                           portal_teleport_boundary: 29999984,
+                           warning_time: 15,
+                           warning_blocks: 5,
+                       },
+                       WorldBorderAction::LerpSize {
+                           old_diameter: 1000.0,
+                           new_diameter: 200.0,
+                           speed: 40_000,
+                       },
+                       WorldBorderAction::SetWarningTime(30),
+                       WorldBorderAction::SetWarningBlocks(10)];
+    for action in &actions {
+        match WorldBorder::new_action(action).unwrap() {
+            ClientboundPacket::WorldBorder(ref x) => assert_eq!(x.get_action().unwrap(), *action),
+            _ => panic!("Expected WorldBorder"),
+        }
+    }
+
+    let start = Instant::now();
+    let mut state = WorldBorderState::new();
+    for action in &actions {
+        state.apply(action, start);
+    }
+    assert_eq!(state.warning_time, 30);
+    assert_eq!(state.warning_blocks, 10);
+    assert_eq!(state.get_diameter_at(start), 1000.0);
+    assert_eq!(state.get_diameter_at(start + Duration::from_secs(10)), 800.0);
+    assert_eq!(state.get_diameter_at(start + Duration::from_secs(60)), 200.0);
+    assert!(state.is_moving_at(start + Duration::from_secs(39)));
+    assert!(!state.is_moving_at(start + Duration::from_secs(40)));
+
+    /* After 30 seconds the diameter is 400, so x goes from -100 to 300 */
+    let at = start + Duration::from_secs(30);
+    assert!(state.contains_at(250.0, -50.0, at));
+    assert!(!state.contains_at(350.0, -50.0, at));
+    assert_eq!(state.get_distance_inside_at(280.0, 0.0, at), 20.0);
+
+    state.apply(&WorldBorderAction::SetSize { diameter: 50.0 }, at);
+    assert_eq!(state.get_diameter_at(at), 50.0);
+
+    /* A move that takes longer than an Instant can represent */
+    let slow = WorldBorderAction::LerpSize {
+        old_diameter: 50.0,
+        new_diameter: 100.0,
+        speed: i64::MAX,
+    };
+    state.apply(&slow, at);
+    assert!(state.is_moving_at(at + Duration::from_secs(3600)));
+    assert_eq!(state.get_diameter_at(at), 50.0);
+}
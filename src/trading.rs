@@ -0,0 +1,163 @@
+//! Villager trades, as sent in the TradeList packet
+//!
+//! The TradeList packet is sent when the player opens a villager's (or
+//! wandering trader's) trading window. A trade is picked by sending the
+//! SelectTrade packet with its index, which `TradeListData::select` creates.
+
+use errors::Result;
+use read::*;
+use serverbound::{SelectTrade, ServerboundPacket};
+use slot::*;
+use write::*;
+
+use std::io::{Cursor, Read};
+
+/// A single trade offered by a villager
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trade {
+    /// The first item the villager wants
+    pub input_item_1: Option<Slot>,
+    /// The item the villager gives
+    pub output_item: Option<Slot>,
+    /// The second item the villager wants, if any
+    pub input_item_2: Option<Slot>,
+    /// Whether the trade is disabled because it has been used up
+    pub disabled: bool,
+    /// The number of times the trade has been used
+    pub uses: i32,
+    /// The number of times the trade can be used before restocking
+    pub max_uses: i32,
+    /// The experience the villager gets from the trade
+    pub xp: i32,
+    /// The change of the price, negative for discounts such as from curing
+    /// a zombie villager
+    pub special_price: i32,
+    /// How much demand affects the price
+    pub price_multiplier: f32,
+    /// The demand for the trade, raising the price when positive
+    pub demand: i32,
+}
+impl Trade {
+    fn read<R: Read>(r: &mut R) -> Result<Self> {
+        let input_item_1 = read_slot(r)?;
+        let output_item = read_slot(r)?;
+        let input_item_2 = if read_bool(r)? {
+            read_slot(r)?
+        } else {
+            None
+        };
+        Ok(Trade {
+            input_item_1,
+            output_item,
+            input_item_2,
+            disabled: read_bool(r)?,
+            uses: read_i32(r)?,
+            max_uses: read_i32(r)?,
+            xp: read_i32(r)?,
+            special_price: read_i32(r)?,
+            price_multiplier: read_f32(r)?,
+            demand: read_i32(r)?,
+        })
+    }
+
+    fn write(&self, w: &mut Vec<u8>) -> Result<()> {
+        write_slot(&self.input_item_1, w)?;
+        write_slot(&self.output_item, w)?;
+        write_bool(&self.input_item_2.is_some(), w)?;
+        if self.input_item_2.is_some() {
+            write_slot(&self.input_item_2, w)?;
+        }
+        write_bool(&self.disabled, w)?;
+        write_i32(&self.uses, w)?;
+        write_i32(&self.max_uses, w)?;
+        write_i32(&self.xp, w)?;
+        write_i32(&self.special_price, w)?;
+        write_f32(&self.price_multiplier, w)?;
+        write_i32(&self.demand, w)
+    }
+
+    /// Get the number of the first input item actually needed, after
+    /// applying demand and the special price like vanilla does. The result
+    /// is at least 1, but it is not limited to the item's stack size since
+    /// that isn't known here.
+    pub fn get_price(&self) -> i32 {
+        let base = self.input_item_1.as_ref().map_or(0, |x| i32::from(x.count));
+        let demand = base.saturating_mul(self.demand) as f32;
+        let demand_bonus = (demand * self.price_multiplier).floor() as i32;
+        base.saturating_add(demand_bonus.max(0))
+            .saturating_add(self.special_price)
+            .max(1)
+    }
+}
+
+/// The decoded contents of a TradeList packet
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradeListData {
+    /// The id of the trading window
+    pub window_id: i32,
+    /// The trades offered
+    pub trades: Vec<Trade>,
+    /// The villager's level, from 1 (novice) to 5 (master)
+    pub villager_level: i32,
+    /// The villager's total experience
+    pub experience: i32,
+    /// Whether this is a regular villager, false for wandering traders
+    pub is_regular_villager: bool,
+    /// Whether the villager can restock its trades
+    pub can_restock: bool,
+}
+impl TradeListData {
+    /// Decode the raw data of a TradeList packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let window_id = read_varint(&mut r)?;
+        let count = read_u8(&mut r)?;
+        let mut trades = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            trades.push(Trade::read(&mut r)?);
+        }
+        Ok(TradeListData {
+            window_id,
+            trades,
+            villager_level: read_varint(&mut r)?,
+            experience: read_varint(&mut r)?,
+            is_regular_villager: read_bool(&mut r)?,
+            can_restock: read_bool(&mut r)?,
+        })
+    }
+
+    /// Encode into the raw data of a TradeList packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        if self.trades.len() > 255 {
+            bail!("TradeList can contain at most 255 trades, got {}", self.trades.len());
+        }
+        let mut ret = Vec::new();
+        write_varint(&self.window_id, &mut ret)?;
+        write_u8(&(self.trades.len() as u8), &mut ret)?;
+        for trade in &self.trades {
+            trade.write(&mut ret)?;
+        }
+        write_varint(&self.villager_level, &mut ret)?;
+        write_varint(&self.experience, &mut ret)?;
+        write_bool(&self.is_regular_villager, &mut ret)?;
+        write_bool(&self.can_restock, &mut ret)?;
+        Ok(ret)
+    }
+
+    /// Create the SelectTrade packet selecting the trade with the given
+    /// index. Fails if there is no such trade or it is disabled.
+    pub fn select(&self, index: usize) -> Result<ServerboundPacket> {
+        match self.trades.get(index) {
+            Some(trade) if trade.disabled => bail!("Trade {} is disabled", index),
+            Some(_) => Ok(SelectTrade::new(index as i32)),
+            None => bail!("Tried selecting trade {} out of {}", index, self.trades.len()),
+        }
+    }
+
+    /// Find the index of the first enabled trade giving the given item id
+    pub fn find_output(&self, item_id: i32) -> Option<usize> {
+        self.trades.iter().position(|x| {
+            !x.disabled && x.output_item.as_ref().is_some_and(|x| x.item_id == item_id)
+        })
+    }
+}
@@ -43,6 +43,30 @@ fn chat_to_str_parse_json(json: &Value, ret: &mut String) {
     }
 }
 
+/// Creates a Chat component (as json) containing the given plaintext, e.g.
+/// for use as a disconnect reason or chat message.
+pub fn str_to_chat(text: &str) -> String {
+    json!({ "text": text }).to_string()
+}
+
+/// Calculate the UUID the vanilla server assigns to the player with the given
+/// username when running in offline mode.
+///
+/// This is a version 3 (name based) UUID of the string `OfflinePlayer:<name>`.
+pub fn offline_uuid(username: &str) -> u128 {
+    let name = format!("OfflinePlayer:{}", username);
+    let digest = hash::hash(MessageDigest::md5(), name.as_bytes())
+        .expect("utils::offline_uuid error");
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&digest);
+    /* Set the version to 3 and the variant to IETF */
+    bytes[6] = (bytes[6] & 0x0f) | 0x30;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    bytes.iter().fold(0, |acc, &x| (acc << 8) | x as u128)
+}
+
 /// Create a shared secret as used for protocol encryption
 ///
 /// # Panics
@@ -191,6 +215,12 @@ mod test {
                    "-da0143edc7918223fcc86951a195a5212c77c3f");
     }
 
+    #[test]
+    fn offline_uuid() {
+        assert_eq!(super::offline_uuid("Notch"),
+                   0xb50ad385829d3141a2167e7d7539ba7f);
+    }
+
     #[test]
     fn rsa() {
         use openssl::rsa::Rsa;
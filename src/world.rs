@@ -0,0 +1,447 @@
+//! Client-side world state, built from the packets the server sends
+//!
+//! `World` keeps the loaded chunk columns and the entities the client knows
+//! about, along with the player's own position. Feed it every received
+//! packet with `handle_packet`, packets it doesn't care about are ignored.
+//!
+//! Entity positions are updated from the movement packets only, i.e. the
+//! velocity is kept but not applied between packets.
+
+use chunk::ChunkColumn;
+use clientbound::*;
+use errors::Result;
+use metadata::Metadata;
+
+use std::collections::BTreeMap;
+
+/// The factor of the deltas in the relative entity movement packets
+const MOVE_DELTA_FACTOR: f64 = 4096.0;
+
+/// The factor of the velocities sent in packets, in blocks per tick
+pub(crate) const VELOCITY_FACTOR: f64 = 8000.0;
+
+/// Relative flags of the clientbound PlayerPositionAndLook
+pub(crate) const RELATIVE_X: u8 = 0x01;
+pub(crate) const RELATIVE_Y: u8 = 0x02;
+pub(crate) const RELATIVE_Z: u8 = 0x04;
+pub(crate) const RELATIVE_YAW: u8 = 0x08;
+pub(crate) const RELATIVE_PITCH: u8 = 0x10;
+
+/// What kind of entity something is, with the type from the spawn packet
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntityKind {
+    /// Spawned with SpawnObject, with the entity type and object data
+    Object {
+        object_type: i32,
+        data: i32,
+    },
+    /// Spawned with SpawnMob, with the entity type
+    Mob(i32),
+    /// Spawned with SpawnPlayer
+    Player,
+    /// Spawned with SpawnExperienceOrb, with the amount of experience
+    ExperienceOrb(i16),
+    /// Spawned with SpawnPainting, with the painting id
+    Painting(i32),
+    /// Spawned with SpawnGlobalEntity, i.e. lightning
+    Global(u8),
+}
+
+/// An entity known to the client
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entity {
+    /// The entity id
+    pub id: i32,
+    /// The entity's UUID, if it was sent
+    pub uuid: Option<u128>,
+    /// What kind of entity this is
+    pub kind: EntityKind,
+    /// The x coordinate
+    pub x: f64,
+    /// The y coordinate
+    pub y: f64,
+    /// The z coordinate
+    pub z: f64,
+    /// The yaw in degrees
+    pub yaw: f32,
+    /// The pitch in degrees
+    pub pitch: f32,
+    /// The yaw of the head in degrees
+    pub head_yaw: f32,
+    /// The x velocity in blocks per tick
+    pub velocity_x: f64,
+    /// The y velocity in blocks per tick
+    pub velocity_y: f64,
+    /// The z velocity in blocks per tick
+    pub velocity_z: f64,
+    /// Whether the entity is on the ground
+    pub on_ground: bool,
+    /// The entity's metadata, merged from all EntityMetadata packets
+    pub metadata: Metadata,
+}
+impl Entity {
+    /// Create a new entity at the given position, without rotation, velocity
+    /// or metadata
+    pub fn new(id: i32, kind: EntityKind, x: f64, y: f64, z: f64) -> Self {
+        Entity {
+            id,
+            uuid: None,
+            kind,
+            x,
+            y,
+            z,
+            yaw: 0.0,
+            pitch: 0.0,
+            head_yaw: 0.0,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            velocity_z: 0.0,
+            on_ground: false,
+            metadata: Metadata::new(),
+        }
+    }
+
+    /// Get the squared distance from the entity to the given point
+    pub fn distance_squared(&self, x: f64, y: f64, z: f64) -> f64 {
+        (self.x - x).powi(2) + (self.y - y).powi(2) + (self.z - z).powi(2)
+    }
+
+    fn set_velocity(&mut self, x: i16, y: i16, z: i16) {
+        self.velocity_x = f64::from(x) / VELOCITY_FACTOR;
+        self.velocity_y = f64::from(y) / VELOCITY_FACTOR;
+        self.velocity_z = f64::from(z) / VELOCITY_FACTOR;
+    }
+
+    fn move_relative(&mut self, x: i16, y: i16, z: i16, on_ground: bool) {
+        self.x += f64::from(x) / MOVE_DELTA_FACTOR;
+        self.y += f64::from(y) / MOVE_DELTA_FACTOR;
+        self.z += f64::from(z) / MOVE_DELTA_FACTOR;
+        self.on_ground = on_ground;
+    }
+}
+
+/// The player's own position and rotation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PlayerPosition {
+    /// The x coordinate
+    pub x: f64,
+    /// The y coordinate of the player's feet
+    pub y: f64,
+    /// The z coordinate
+    pub z: f64,
+    /// The yaw in degrees
+    pub yaw: f32,
+    /// The pitch in degrees
+    pub pitch: f32,
+}
+impl PlayerPosition {
+    /// Get the position after the teleport of the given packet, which may
+    /// be relative to this position
+    pub fn teleported(&self, packet: &PlayerPositionAndLook) -> PlayerPosition {
+        let flags = *packet.get_flags();
+        let pick = |flag: u8, old: f64, new: f64| if flags & flag != 0 { old + new } else { new };
+        let pick_f32 = |flag: u8, old: f32, new: f32| if flags & flag != 0 { old + new } else { new };
+        PlayerPosition {
+            x: pick(RELATIVE_X, self.x, *packet.get_x()),
+            y: pick(RELATIVE_Y, self.y, *packet.get_y()),
+            z: pick(RELATIVE_Z, self.z, *packet.get_z()),
+            yaw: pick_f32(RELATIVE_YAW, self.yaw, *packet.get_yaw()),
+            pitch: pick_f32(RELATIVE_PITCH, self.pitch, *packet.get_pitch()),
+        }
+    }
+}
+
+/// The world as seen by the client
+#[derive(Debug, Clone, Default)]
+pub struct World {
+    chunks: BTreeMap<(i32, i32), ChunkColumn>,
+    entities: BTreeMap<i32, Entity>,
+    player_entity_id: Option<i32>,
+    player: Option<PlayerPosition>,
+    dimension: i32,
+}
+impl World {
+    /// Create a new, empty world
+    pub fn new() -> Self {
+        World::default()
+    }
+
+    /// Apply the packet if it changes the world, returning whether it was
+    /// applied
+    pub fn handle_packet(&mut self, packet: &ClientboundPacket) -> Result<bool> {
+        match *packet {
+            ClientboundPacket::JoinGame(ref x) => {
+                self.clear();
+                self.player_entity_id = Some(*x.get_entity_id());
+                self.dimension = *x.get_dimension();
+            },
+            ClientboundPacket::Respawn(ref x) => {
+                self.clear();
+                self.dimension = *x.get_dimension();
+            },
+            ClientboundPacket::ChunkData(ref x) => self.insert_chunk(x.get_chunk()?),
+            ClientboundPacket::UnloadChunk(ref x) => {
+                self.unload_chunk(*x.get_chunk_x(), *x.get_chunk_z());
+            },
+            ClientboundPacket::BlockChange(ref x) => {
+                let (bx, by, bz) = *x.get_position();
+                let _: bool = self.set_block(bx, by, bz, *x.get_new_block());
+            },
+            ClientboundPacket::MultiBlockChange(ref x) => {
+                let base_x = x.get_chunk_x() * 16;
+                let base_z = x.get_chunk_z() * 16;
+                for &(dx, y, dz, state) in x.get_changes() {
+                    let _: bool = self.set_block(base_x + i32::from(dx),
+                                                 i32::from(y),
+                                                 base_z + i32::from(dz),
+                                                 state);
+                }
+            },
+            ClientboundPacket::Explosion(ref x) => self.handle_explosion(x),
+            ClientboundPacket::SpawnObject(ref x) => {
+                let mut entity = Entity::new(*x.get_entity_id(),
+                                             EntityKind::Object {
+                                                 object_type: *x.get_object_type(),
+                                                 data: *x.get_data(),
+                                             },
+                                             *x.get_x(),
+                                             *x.get_y(),
+                                             *x.get_z());
+                entity.uuid = Some(*x.get_object_uuid());
+                entity.yaw = angle_to_degrees(*x.get_yaw());
+                entity.pitch = angle_to_degrees(*x.get_pitch());
+                entity.set_velocity(*x.get_velocity_x(), *x.get_velocity_y(), *x.get_velocity_z());
+                self.insert_entity(entity);
+            },
+            ClientboundPacket::SpawnExperienceOrb(ref x) => {
+                self.insert_entity(Entity::new(*x.get_entity_id(),
+                                               EntityKind::ExperienceOrb(*x.get_count()),
+                                               *x.get_x(),
+                                               *x.get_y(),
+                                               *x.get_z()));
+            },
+            ClientboundPacket::SpawnGlobalEntity(ref x) => {
+                self.insert_entity(Entity::new(*x.get_entity_id(),
+                                               EntityKind::Global(*x.get_entity_type()),
+                                               *x.get_x(),
+                                               *x.get_y(),
+                                               *x.get_z()));
+            },
+            ClientboundPacket::SpawnMob(ref x) => {
+                let mut entity = Entity::new(*x.get_entity_id(),
+                                             EntityKind::Mob(*x.get_mob_type()),
+                                             *x.get_x(),
+                                             *x.get_y(),
+                                             *x.get_z());
+                entity.uuid = Some(*x.get_uuid());
+                entity.yaw = angle_to_degrees(*x.get_yaw());
+                entity.pitch = angle_to_degrees(*x.get_pitch());
+                entity.head_yaw = angle_to_degrees(*x.get_head_pitch());
+                entity.set_velocity(*x.get_velocity_x(), *x.get_velocity_y(), *x.get_velocity_z());
+                self.insert_entity(entity);
+            },
+            ClientboundPacket::SpawnPainting(ref x) => {
+                let (px, py, pz) = *x.get_center_location();
+                let mut entity = Entity::new(*x.get_entity_id(),
+                                             EntityKind::Painting(*x.get_title()),
+                                             f64::from(px) + 0.5,
+                                             f64::from(py) + 0.5,
+                                             f64::from(pz) + 0.5);
+                entity.uuid = Some(*x.get_uuid());
+                self.insert_entity(entity);
+            },
+            ClientboundPacket::SpawnPlayer(ref x) => {
+                let mut entity = Entity::new(*x.get_entity_id(),
+                                             EntityKind::Player,
+                                             *x.get_x(),
+                                             *x.get_y(),
+                                             *x.get_z());
+                entity.uuid = Some(*x.get_uuid());
+                entity.yaw = angle_to_degrees(*x.get_yaw());
+                entity.pitch = angle_to_degrees(*x.get_pitch());
+                entity.head_yaw = entity.yaw;
+                self.insert_entity(entity);
+            },
+            ClientboundPacket::EntityRelativeMove(ref x) => {
+                if let Some(entity) = self.entities.get_mut(x.get_entity_id()) {
+                    entity.move_relative(*x.get_x(), *x.get_y(), *x.get_z(), *x.get_on_ground());
+                }
+            },
+            ClientboundPacket::EntityLookRelativeMove(ref x) => {
+                if let Some(entity) = self.entities.get_mut(x.get_entity_id()) {
+                    entity.move_relative(*x.get_x(), *x.get_y(), *x.get_z(), *x.get_on_ground());
+                    entity.yaw = angle_to_degrees(*x.get_yaw());
+                    entity.pitch = angle_to_degrees(*x.get_pitch());
+                }
+            },
+            ClientboundPacket::EntityLook(ref x) => {
+                if let Some(entity) = self.entities.get_mut(x.get_entity_id()) {
+                    entity.yaw = angle_to_degrees(*x.get_yaw());
+                    entity.pitch = angle_to_degrees(*x.get_pitch());
+                    entity.on_ground = *x.get_on_ground();
+                }
+            },
+            ClientboundPacket::EntityHeadLook(ref x) => {
+                if let Some(entity) = self.entities.get_mut(x.get_entity_id()) {
+                    entity.head_yaw = angle_to_degrees(*x.get_head_yaw());
+                }
+            },
+            ClientboundPacket::EntityTeleport(ref x) => {
+                if let Some(entity) = self.entities.get_mut(x.get_entity_id()) {
+                    entity.x = *x.get_x();
+                    entity.y = *x.get_y();
+                    entity.z = *x.get_z();
+                    entity.yaw = angle_to_degrees(*x.get_yaw());
+                    entity.pitch = angle_to_degrees(*x.get_pitch());
+                    entity.on_ground = *x.get_on_ground();
+                }
+            },
+            ClientboundPacket::EntityVelocity(ref x) => {
+                if let Some(entity) = self.entities.get_mut(x.get_entity_id()) {
+                    entity.set_velocity(*x.get_x_velocity(),
+                                        *x.get_y_velocity(),
+                                        *x.get_z_velocity());
+                }
+            },
+            ClientboundPacket::EntityMetadata(ref x) => {
+                let metadata = x.get_entries()?;
+                if let Some(entity) = self.entities.get_mut(x.get_entity_id()) {
+                    entity.metadata.extend(metadata);
+                }
+            },
+            ClientboundPacket::DestroyEntities(ref x) => {
+                for id in x.get_entity_ids() {
+                    let _ = self.entities.remove(id);
+                }
+            },
+            ClientboundPacket::PlayerPositionAndLook(ref x) => {
+                self.handle_player_position(x);
+            },
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_explosion(&mut self, packet: &Explosion) {
+        let cx = packet.get_x().floor() as i32;
+        let cy = packet.get_y().floor() as i32;
+        let cz = packet.get_z().floor() as i32;
+        for &(dx, dy, dz) in packet.get_affected_blocks() {
+            let _: bool = self.set_block(cx + i32::from(dx), cy + i32::from(dy), cz + i32::from(dz), 0);
+        }
+    }
+
+    fn handle_player_position(&mut self, packet: &PlayerPositionAndLook) {
+        self.player = Some(self.player.unwrap_or_default().teleported(packet));
+    }
+
+    /// Remove all chunks and entities, as happens when changing dimension
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.entities.clear();
+    }
+
+    /// Get the dimension the player is in, -1 for the nether, 0 for the
+    /// overworld and 1 for the end
+    pub fn get_dimension(&self) -> i32 {
+        self.dimension
+    }
+
+    /// Add a chunk column, merging it into the loaded column if it is not a
+    /// full chunk. Partial chunks of columns that aren't loaded are ignored.
+    pub fn insert_chunk(&mut self, chunk: ChunkColumn) {
+        let key = (chunk.x, chunk.z);
+        if let Some(existing) = self.chunks.get_mut(&key) {
+            existing.merge(chunk);
+            return;
+        }
+        if chunk.full_chunk {
+            let _ = self.chunks.insert(key, chunk);
+        }
+    }
+
+    /// Remove the chunk column at the given chunk coordinates
+    pub fn unload_chunk(&mut self, chunk_x: i32, chunk_z: i32) {
+        let _ = self.chunks.remove(&(chunk_x, chunk_z));
+    }
+
+    /// Get the chunk column at the given chunk coordinates, if loaded
+    pub fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Option<&ChunkColumn> {
+        self.chunks.get(&(chunk_x, chunk_z))
+    }
+
+    /// Get the number of loaded chunk columns
+    pub fn get_chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Check whether the chunk column containing the given block is loaded
+    pub fn is_loaded(&self, x: i32, z: i32) -> bool {
+        self.chunks.contains_key(&(x >> 4, z >> 4))
+    }
+
+    /// Get the block state at the given block coordinates. Returns None if
+    /// the chunk isn't loaded or y is outside the world.
+    pub fn block_at(&self, x: i32, y: i32, z: i32) -> Option<i32> {
+        self.chunks
+            .get(&(x >> 4, z >> 4))?
+            .get_block((x & 15) as usize, y, (z & 15) as usize)
+    }
+
+    /// Set the block state at the given block coordinates, returning whether
+    /// the block is in a loaded chunk
+    pub fn set_block(&mut self, x: i32, y: i32, z: i32, state: i32) -> bool {
+        match self.chunks.get_mut(&(x >> 4, z >> 4)) {
+            Some(chunk) => chunk.set_block((x & 15) as usize, y, (z & 15) as usize, state).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Add an entity, replacing any entity with the same id
+    pub fn insert_entity(&mut self, entity: Entity) {
+        let _ = self.entities.insert(entity.id, entity);
+    }
+
+    /// Get the entity with the given id
+    pub fn get_entity(&self, id: i32) -> Option<&Entity> {
+        self.entities.get(&id)
+    }
+
+    /// Get all known entities
+    pub fn get_entities(&self) -> impl Iterator<Item = &Entity> {
+        self.entities.values()
+    }
+
+    /// Get the entities within the given distance of the given point,
+    /// closest first
+    pub fn entities_near(&self, x: f64, y: f64, z: f64, radius: f64) -> Vec<&Entity> {
+        let mut ret: Vec<(f64, &Entity)> = self.entities
+            .values()
+            .map(|e| (e.distance_squared(x, y, z), e))
+            .filter(|&(d, _)| d <= radius * radius)
+            .collect();
+        ret.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(::std::cmp::Ordering::Equal));
+        ret.into_iter().map(|(_, e)| e).collect()
+    }
+
+    /// Get the player's own entity id, known after JoinGame
+    pub fn get_player_entity_id(&self) -> Option<i32> {
+        self.player_entity_id
+    }
+
+    /// Get the player's position, known after the first
+    /// PlayerPositionAndLook
+    pub fn player_position(&self) -> Option<PlayerPosition> {
+        self.player
+    }
+
+    /// Set the player's position, e.g. after moving
+    pub fn set_player_position(&mut self, position: PlayerPosition) {
+        self.player = Some(position);
+    }
+}
+
+/// Convert an angle in 256ths of a full turn to degrees
+fn angle_to_degrees(angle: i8) -> f32 {
+    f32::from(angle as u8) * 360.0 / 256.0
+}
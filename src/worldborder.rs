@@ -0,0 +1,276 @@
+//! The world border, as sent in the WorldBorder packet
+//!
+//! The border may be shrinking or growing, in which case its diameter is
+//! linearly interpolated between the old and new diameter over the given
+//! time. `WorldBorderState` keeps track of this so the current diameter can
+//! be found at any instant.
+
+use clientbound::WorldBorder;
+use errors::Result;
+use read::*;
+use write::*;
+
+use std::io::Cursor;
+use std::time::{Duration, Instant};
+
+/// The action performed by a WorldBorder packet
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorldBorderAction {
+    /// Action 0, set the diameter immediately
+    SetSize {
+        diameter: f64,
+    },
+    /// Action 1, move the diameter from old to new over the given number of
+    /// milliseconds
+    LerpSize {
+        old_diameter: f64,
+        new_diameter: f64,
+        speed: i64,
+    },
+    /// Action 2, set the center
+    SetCenter {
+        x: f64,
+        z: f64,
+    },
+    /// Action 3, set everything, sent when the player joins or changes world
+    Initialize {
+        x: f64,
+        z: f64,
+        old_diameter: f64,
+        new_diameter: f64,
+        /// The number of milliseconds until the new diameter is reached
+        speed: i64,
+        /// How far out nether portals may teleport players, usually
+        /// 29999984
+        portal_teleport_boundary: i32,
+        /// The warning time in seconds
+        warning_time: i32,
+        /// The warning distance in blocks
+        warning_blocks: i32,
+    },
+    /// Action 4, set the warning time in seconds
+    SetWarningTime(i32),
+    /// Action 5, set the warning distance in blocks
+    SetWarningBlocks(i32),
+}
+impl WorldBorderAction {
+    /// Decode the raw data of a WorldBorder packet
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Cursor::new(data);
+        let action = read_varint(&mut r)?;
+        Ok(match action {
+            0 => {
+                WorldBorderAction::SetSize {
+                    diameter: read_f64(&mut r)?,
+                }
+            },
+            1 => {
+                WorldBorderAction::LerpSize {
+                    old_diameter: read_f64(&mut r)?,
+                    new_diameter: read_f64(&mut r)?,
+                    speed: read_varlong(&mut r)?,
+                }
+            },
+            2 => {
+                WorldBorderAction::SetCenter {
+                    x: read_f64(&mut r)?,
+                    z: read_f64(&mut r)?,
+                }
+            },
+            3 => {
+                WorldBorderAction::Initialize {
+                    x: read_f64(&mut r)?,
+                    z: read_f64(&mut r)?,
+                    old_diameter: read_f64(&mut r)?,
+                    new_diameter: read_f64(&mut r)?,
+                    speed: read_varlong(&mut r)?,
+                    portal_teleport_boundary: read_varint(&mut r)?,
+                    warning_time: read_varint(&mut r)?,
+                    warning_blocks: read_varint(&mut r)?,
+                }
+            },
+            4 => WorldBorderAction::SetWarningTime(read_varint(&mut r)?),
+            5 => WorldBorderAction::SetWarningBlocks(read_varint(&mut r)?),
+            _ => bail!("WorldBorder had invalid action {}", action),
+        })
+    }
+
+    /// Encode into the raw data of a WorldBorder packet
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut ret = Vec::new();
+        match *self {
+            WorldBorderAction::SetSize { diameter } => {
+                write_varint(&0, &mut ret)?;
+                write_f64(&diameter, &mut ret)?;
+            },
+            WorldBorderAction::LerpSize { old_diameter, new_diameter, speed } => {
+                write_varint(&1, &mut ret)?;
+                write_f64(&old_diameter, &mut ret)?;
+                write_f64(&new_diameter, &mut ret)?;
+                write_varlong(&speed, &mut ret)?;
+            },
+            WorldBorderAction::SetCenter { x, z } => {
+                write_varint(&2, &mut ret)?;
+                write_f64(&x, &mut ret)?;
+                write_f64(&z, &mut ret)?;
+            },
+            WorldBorderAction::Initialize { x,
+                                            z,
+                                            old_diameter,
+                                            new_diameter,
+                                            speed,
+                                            portal_teleport_boundary,
+                                            warning_time,
+                                            warning_blocks } => {
+                write_varint(&3, &mut ret)?;
+                write_f64(&x, &mut ret)?;
+                write_f64(&z, &mut ret)?;
+                write_f64(&old_diameter, &mut ret)?;
+                write_f64(&new_diameter, &mut ret)?;
+                write_varlong(&speed, &mut ret)?;
+                write_varint(&portal_teleport_boundary, &mut ret)?;
+                write_varint(&warning_time, &mut ret)?;
+                write_varint(&warning_blocks, &mut ret)?;
+            },
+            WorldBorderAction::SetWarningTime(x) => {
+                write_varint(&4, &mut ret)?;
+                write_varint(&x, &mut ret)?;
+            },
+            WorldBorderAction::SetWarningBlocks(x) => {
+                write_varint(&5, &mut ret)?;
+                write_varint(&x, &mut ret)?;
+            },
+        }
+        Ok(ret)
+    }
+}
+
+/// Keeps track of the world border, interpolating its diameter while it is
+/// moving
+#[derive(Debug, Clone)]
+pub struct WorldBorderState {
+    /// The x coordinate of the center
+    pub center_x: f64,
+    /// The z coordinate of the center
+    pub center_z: f64,
+    /// How far out nether portals may teleport players
+    pub portal_teleport_boundary: i32,
+    /// The warning time in seconds
+    pub warning_time: i32,
+    /// The warning distance in blocks
+    pub warning_blocks: i32,
+    old_diameter: f64,
+    new_diameter: f64,
+    lerp_start: Instant,
+    lerp_duration: Duration,
+}
+impl Default for WorldBorderState {
+    /// The vanilla defaults, i.e. the border being (almost) at the edge of
+    /// the world
+    fn default() -> Self {
+        WorldBorderState {
+            center_x: 0.0,
+            center_z: 0.0,
+            portal_teleport_boundary: 29999984,
+            warning_time: 15,
+            warning_blocks: 5,
+            old_diameter: 59999968.0,
+            new_diameter: 59999968.0,
+            lerp_start: Instant::now(),
+            lerp_duration: Duration::from_millis(0),
+        }
+    }
+}
+impl WorldBorderState {
+    /// Create a new state with vanilla's defaults
+    pub fn new() -> Self {
+        WorldBorderState::default()
+    }
+
+    /// Apply a WorldBorder packet received now
+    pub fn handle_worldborder(&mut self, packet: &WorldBorder) -> Result<()> {
+        self.apply(&packet.get_action()?, Instant::now());
+        Ok(())
+    }
+
+    /// Apply a decoded WorldBorder action received at the given instant
+    pub fn apply(&mut self, action: &WorldBorderAction, now: Instant) {
+        match *action {
+            WorldBorderAction::SetSize { diameter } => self.set_lerp(diameter, diameter, 0, now),
+            WorldBorderAction::LerpSize { old_diameter, new_diameter, speed } => {
+                self.set_lerp(old_diameter, new_diameter, speed, now)
+            },
+            WorldBorderAction::SetCenter { x, z } => {
+                self.center_x = x;
+                self.center_z = z;
+            },
+            WorldBorderAction::Initialize { x,
+                                            z,
+                                            old_diameter,
+                                            new_diameter,
+                                            speed,
+                                            portal_teleport_boundary,
+                                            warning_time,
+                                            warning_blocks } => {
+                self.center_x = x;
+                self.center_z = z;
+                self.set_lerp(old_diameter, new_diameter, speed, now);
+                self.portal_teleport_boundary = portal_teleport_boundary;
+                self.warning_time = warning_time;
+                self.warning_blocks = warning_blocks;
+            },
+            WorldBorderAction::SetWarningTime(x) => self.warning_time = x,
+            WorldBorderAction::SetWarningBlocks(x) => self.warning_blocks = x,
+        }
+    }
+
+    fn set_lerp(&mut self, old_diameter: f64, new_diameter: f64, speed: i64, now: Instant) {
+        self.old_diameter = old_diameter;
+        self.new_diameter = new_diameter;
+        self.lerp_start = now;
+        self.lerp_duration = Duration::from_millis(speed.max(0) as u64);
+    }
+
+    /// Get the diameter at the given instant
+    pub fn get_diameter_at(&self, at: Instant) -> f64 {
+        let elapsed = at.saturating_duration_since(self.lerp_start);
+        if elapsed >= self.lerp_duration {
+            return self.new_diameter;
+        }
+        let progress = elapsed.as_secs_f64() / self.lerp_duration.as_secs_f64();
+        self.old_diameter + (self.new_diameter - self.old_diameter) * progress
+    }
+
+    /// Get the diameter right now
+    pub fn get_diameter(&self) -> f64 {
+        self.get_diameter_at(Instant::now())
+    }
+
+    /// Get the diameter the border is moving towards
+    pub fn get_target_diameter(&self) -> f64 {
+        self.new_diameter
+    }
+
+    /// Check whether the border is currently moving
+    pub fn is_moving_at(&self, at: Instant) -> bool {
+        /* A huge speed can put the end of the move beyond what an Instant
+         * can hold, in which case it is effectively never reached */
+        self.old_diameter != self.new_diameter &&
+            self.lerp_start.checked_add(self.lerp_duration).is_none_or(|end| at < end)
+    }
+
+    /// Get the signed distance from the given point to the border at the
+    /// given instant, positive inside and negative outside the border
+    pub fn get_distance_inside_at(&self, x: f64, z: f64, at: Instant) -> f64 {
+        let radius = self.get_diameter_at(at) / 2.0;
+        let dx = radius - (x - self.center_x).abs();
+        let dz = radius - (z - self.center_z).abs();
+        dx.min(dz)
+    }
+
+    /// Check whether the given point is inside the border at the given
+    /// instant
+    pub fn contains_at(&self, x: f64, z: f64, at: Instant) -> bool {
+        self.get_distance_inside_at(x, z, at) > 0.0
+    }
+}